@@ -21,6 +21,7 @@ static void T2a(void) {}
 
 #define T2C 4
 #define T2S "a"
+#define T2SB "a"
 
 enum enum_repr_too_small {
     ENUM_REPR_TOO_SMALL_A
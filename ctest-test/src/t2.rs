@@ -36,6 +36,8 @@ i! {
     pub const T2S: *const c_char = b"b\0".as_ptr().cast();
 }
 
+pub const T2SB: &[u8] = b"b\0";
+
 extern "C" {
     pub fn T2a();
 }
@@ -58,6 +58,7 @@ fn t2() {
         "bad `T2a` function pointer",
         "bad `T2C` value at byte 0",
         "bad const `T2S` string",
+        "bad const `T2SB` string",
         "bad `T2Union` size",
         "bad field offset `b` of `T2Union`",
         "bad field pointer access `b` of `T2Union`",
@@ -119,15 +119,27 @@ impl TestTemplate {
         helper: &TranslateHelper,
     ) -> Result<(), TranslationError> {
         for constant in helper.filtered_ffi_items.constants() {
-            if let syn::Type::Ptr(ptr) = &constant.ty
-                && let syn::Type::Path(path) = &*ptr.elem
-                && path.path.segments.last().unwrap().ident == "c_char"
-                && ptr.mutability.is_none()
-            {
+            let rust_ptr = if is_c_char_ptr(&constant.ty) {
+                Some(constant.ident().into())
+            } else if is_byte_slice(&constant.ty) {
+                // Byte string constants must carry their own nul terminator.
+                Some(
+                    format!(
+                        "CStr::from_bytes_with_nul({}).unwrap().as_ptr()",
+                        constant.ident()
+                    )
+                    .into(),
+                )
+            } else {
+                None
+            };
+
+            if let Some(rust_ptr) = rust_ptr {
                 let item = TestCStr {
                     id: constant.ident().into(),
                     test_name: cstr_test_ident(constant.ident()),
                     rust_val: constant.ident().into(),
+                    rust_ptr,
                     c_val: helper.c_ident(constant).into(),
                 };
                 self.const_cstr_tests.push(item.clone());
@@ -506,6 +518,8 @@ pub(crate) struct TestCStr {
     pub test_name: BoxStr,
     pub id: BoxStr,
     pub rust_val: BoxStr,
+    /// Expression evaluating to a `*const c_char` pointing to the Rust value.
+    pub rust_ptr: BoxStr,
     pub c_val: BoxStr,
 }
 
@@ -564,6 +578,31 @@ pub(crate) struct TestForeignStatic {
     pub rust_ty: BoxStr,
 }
 
+/// Whether the type is `*const c_char`.
+fn is_c_char_ptr(ty: &syn::Type) -> bool {
+    if let syn::Type::Ptr(ptr) = ty
+        && let syn::Type::Path(path) = &*ptr.elem
+        && path.path.segments.last().unwrap().ident == "c_char"
+        && ptr.mutability.is_none()
+    {
+        return true;
+    }
+    false
+}
+
+/// Whether the type is `&[u8]`, which is used for byte string constants.
+fn is_byte_slice(ty: &syn::Type) -> bool {
+    if let syn::Type::Reference(reference) = ty
+        && let syn::Type::Slice(slice) = &*reference.elem
+        && let syn::Type::Path(path) = &*slice.elem
+        && path.path.is_ident("u8")
+        && reference.mutability.is_none()
+    {
+        return true;
+    }
+    false
+}
+
 fn signededness_test_ident(ident: &str) -> BoxStr {
     format!("ctest_signededness_{ident}").into()
 }
@@ -85,7 +85,7 @@ mod generated_tests {
 
         {# /* SAFETY: we assume that `c_char` pointer consts are for C strings. */ #}
         let r_val = unsafe {
-            let r_ptr: *const c_char = {{ const_cstr.rust_val }};
+            let r_ptr: *const c_char = {{ const_cstr.rust_ptr }};
             assert!(!r_ptr.is_null(), "const `{{ const_cstr.rust_val }}` is null");
             CStr::from_ptr(r_ptr)
         };
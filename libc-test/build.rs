@@ -3817,6 +3817,11 @@ fn test_linux(target: &str) {
     let mips64 = target.contains("mips64");
     let mips32 = mips && !mips64;
 
+    // Distributions may ship the DRM UAPI headers separately from the kernel headers.
+    let drm = !wasm32 && !l4re && has_header("drm/drm.h");
+    // FIXME(linux): Requires >= 6.2 kernel headers.
+    let iommufd = !wasm32 && !l4re && has_header("linux/iommufd.h");
+
     let musl_v1_2_3 = env::var("CARGO_CFG_LIBC_UNSTABLE_MUSL_V1_2_3").is_ok();
     if musl_v1_2_3 {
         assert!(musl);
@@ -3924,6 +3929,7 @@ fn test_linux(target: &str) {
         (!l4re, "sys/random.h"),
         (!l4re, "sys/reboot.h"),
         "sys/resource.h",
+        (gnu && !sparc64, "sys/rseq.h"),
         "sys/sem.h",
         (!l4re, "sys/sendfile.h"),
         "sys/shm.h",
@@ -3980,20 +3986,30 @@ fn test_linux(target: &str) {
     if !wasm32 && !l4re {
         headers!(
             cfg,
-            (gnu, "linux/aio_abi.h"),
+            "linux/aio_abi.h",
+            "linux/audit.h",
+            "linux/bpf.h",
             "linux/can.h",
             "linux/can/bcm.h",
             "linux/can/error.h",
             "linux/can/j1939.h",
             "linux/can/netlink.h",
             "linux/can/raw.h",
+            "linux/capability.h",
+            "linux/cgroupstats.h",
             "linux/cn_proc.h",
             "linux/connector.h",
             "linux/dccp.h",
+            "linux/devlink.h",
+            "linux/dm-ioctl.h",
             "linux/errqueue.h",
+            "linux/ethtool.h",
+            "linux/ethtool_netlink.h",
             "linux/falloc.h",
             "linux/filter.h",
             "linux/fs.h",
+            "linux/fscrypt.h",
+            "linux/fsverity.h",
             "linux/futex.h",
             "linux/genetlink.h",
             "linux/if.h",
@@ -4004,9 +4020,12 @@ fn test_linux(target: &str) {
             "linux/if_tun.h",
             "linux/if_xdp.h",
             "linux/input.h",
+            "linux/io_uring.h",
             "linux/ipv6.h",
             "linux/kexec.h",
             "linux/keyctl.h",
+            "linux/landlock.h",
+            "linux/loop.h",
             "linux/magic.h",
             "linux/memfd.h",
             "linux/membarrier.h",
@@ -4014,6 +4033,7 @@ fn test_linux(target: &str) {
             "linux/mman.h",
             "linux/module.h",
             "linux/mount.h",
+            "linux/neighbour.h",
             "linux/net_tstamp.h",
             "linux/netfilter/nfnetlink.h",
             "linux/netfilter/nfnetlink_log.h",
@@ -4025,14 +4045,17 @@ fn test_linux(target: &str) {
             "linux/netfilter_ipv6.h",
             "linux/netfilter_ipv6/ip6_tables.h",
             "linux/netlink.h",
+            "linux/nl80211.h",
             "linux/nsfs.h",
             "linux/openat2.h",
+            "linux/perf_event.h",
             // FIXME(linux): some items require Linux >= 5.6:
             "linux/ptp_clock.h",
             "linux/ptrace.h",
             "linux/quota.h",
             "linux/random.h",
             "linux/reboot.h",
+            "linux/rseq.h",
             "linux/rtnetlink.h",
             "linux/sched.h",
             "linux/sctp.h",
@@ -4040,23 +4063,49 @@ fn test_linux(target: &str) {
             "linux/securebits.h",
             "linux/sock_diag.h",
             "linux/sockios.h",
+            "linux/taskstats.h",
+            "linux/time_types.h",
             "linux/tls.h",
             "linux/uinput.h",
+            "linux/userfaultfd.h",
+            "linux/v4l2-common.h",
+            "linux/vfio.h",
+            "linux/videodev2.h",
             "linux/vm_sockets.h",
             "linux/wait.h",
             "linux/wireless.h",
             "sys/fanotify.h",
             "sys/auxv.h",
             (gnu || musl, "linux/close_range.h"),
+            (drm, "drm/drm.h"),
+            (drm, "drm/drm_mode.h"),
+            (iommufd, "linux/iommufd.h"),
+            (x86_64 || aarch64 || riscv64 || s390x, "linux/kvm.h"),
             (uclibc, "linux/fanotify.h"),
             (uclibc, "linux/auxvec.h"),
             (uclibc, "linux/close_range.h"),
             (uclibc, "linux/if_packet.h"),
             (uclibc, "linux/elf-em.h"),
+            (gnu, "linux/xattr.h"),
             (uclibc, "sys/resource.h"),
         );
     }
 
+    if !drm {
+        fn is_drm_item(name: &str) -> bool {
+            name.starts_with("drm_")
+                || name.starts_with("DRM_")
+                || name.starts_with("_DRM_")
+                || name.starts_with("hdr_")
+                || name == "FORMAT_BLOB_CURRENT"
+        }
+
+        cfg.skip_alias(|ty| is_drm_item(ty.ident()));
+        cfg.skip_struct(|s| is_drm_item(s.ident()));
+        cfg.skip_union(|u| is_drm_item(u.ident()));
+        cfg.skip_const(|c| is_drm_item(c.ident()));
+    }
+
     // note: aio.h must be included before sys/mount.h
     headers!(
         cfg,
@@ -4117,6 +4166,21 @@ fn test_linux(target: &str) {
             // but `type` is a Rust keyword, so these fields are translated
             // to `type_` in Rust.
             ("input_event" | "input_mask" | "ff_effect", "type_") => Some("type".to_string()),
+            ("perf_event_attr" | "perf_event_header", "type_") => Some("type".to_string()),
+            ("bpf_prog_info" | "bpf_map_info", "type_") => Some("type".to_string()),
+            ("uffdio_move", "move_") => Some("move".to_string()),
+            ("ethtool_modinfo", "type_") => Some("type".to_string()),
+            ("fscrypt_key_specifier" | "fscrypt_provisioning_key_payload", "type_") => {
+                Some("type".to_string())
+            }
+            (s, "type_") if s.starts_with("v4l2_") => Some("type".to_string()),
+            // `priv` and `match` are reserved keywords in Rust.
+            (s, "priv_") if s.starts_with("v4l2_") => Some("priv".to_string()),
+            ("v4l2_dbg_register" | "v4l2_dbg_chip_info", "match_") => Some("match".to_string()),
+            (s, "type_") if s.starts_with("drm_") => Some("type".to_string()),
+            ("drm_buf_map", "virtual_") => Some("virtual".to_string()),
+            (s, "type_") if s.starts_with("vfio_") => Some("type".to_string()),
+            (s, "type_") if s.starts_with("kvm_") => Some("type".to_string()),
 
             // FIXME(1.0): field has a different name on loongarch
             ("ucontext_t", "uc_flags") if loongarch64 => Some("__uc_flags".to_string()),
@@ -4155,6 +4219,38 @@ fn test_linux(target: &str) {
             // specific type.
             "Ioctl" => true,
 
+            // FIXME(linux32): Requires >= 6.7 kernel headers.
+            "io_uring_socket_op" if uclibc || pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.6 kernel headers.
+            "bpf_perf_event_type" if uclibc || pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.9 kernel headers.
+            "bpf_cond_pseudo_jmp" | "bpf_addr_space_cast"
+                if musl || uclibc || pointer_width == 32 =>
+            {
+                true
+            }
+            // FIXME(linux): Requires >= 5.19 kernel headers.
+            "devlink_linecard_state" if uclibc => true,
+            // FIXME(linux): Requires >= 6.1 kernel headers.
+            "devlink_attr_selftest_id"
+            | "devlink_selftest_status"
+            | "devlink_attr_selftest_result"
+                if uclibc =>
+            {
+                true
+            }
+            // FIXME(linux): Requires >= 6.0 kernel headers.
+            "ethtool_link_ext_substate_module"
+            | "ethtool_podl_pse_admin_state"
+            | "ethtool_podl_pse_pw_d_status"
+                if uclibc =>
+            {
+                true
+            }
+            // FIXME(linux): Requires >= 6.0 kernel headers.
+            "vfio_device_mig_state" if uclibc => true,
+            t if !iommufd && t.starts_with("iommufd_") => true,
+
             t => {
                 if musl {
                     // LFS64 types have been removed in musl 1.2.4+
@@ -4225,10 +4321,6 @@ fn test_linux(target: &str) {
             // which is absent in musl, has to be defined.
             "__exit_status" if musl => true,
 
-            // FIXME(ppc): tests fail due to a field type mismatch (`long long unsigned` vs
-            // `long unsigned`).
-            "clone_args" if ppc64 => true,
-
             // Linux >= 6.13 (pidfd_info.exit_code: Linux >= 6.15)
             // Might differ between kernel versions
             "pidfd_info" => true,
@@ -4276,6 +4368,68 @@ fn test_linux(target: &str) {
             // FIXME(linux): Requires >= 6.12 kernel headers.
             "mnt_ns_info" => true,
 
+            // FIXME(linux): Requires >= 6.13 kernel headers.
+            "statmount" | "mnt_id_req" if musl || uclibc || pointer_width == 32 => true,
+
+            // FIXME(linux32): Requires >= 6.6 kernel headers.
+            "io_uring_sync_cancel_reg" if pointer_width == 32 => true,
+            // FIXME(linux32): Requires >= 6.8 kernel headers.
+            "io_uring_buf_status" if musl || pointer_width == 32 => true,
+            // FIXME(linux): `config3` requires >= 6.3 kernel headers.
+            "perf_event_attr" if uclibc => true,
+            // FIXME(linux): the `bpf_attr` members and the info structs grow with every
+            // kernel release, only the gnu headers are recent enough.
+            "bpf_attr" if musl || uclibc || pointer_width == 32 => true,
+            "bpf_prog_info" | "bpf_map_info" | "bpf_btf_info" if uclibc => true,
+            // FIXME(linux): Requires >= 6.6 kernel headers.
+            "uffdio_poison" if uclibc || pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.8 kernel headers.
+            "uffdio_move" if musl || uclibc || pointer_width == 32 => true,
+            // FIXME(linux): `rate_matching` requires >= 6.0 kernel headers.
+            "ethtool_link_settings" if uclibc => true,
+            // FIXME(linux): `handled_access_net` requires >= 6.7 and `scoped` >= 6.12 kernel
+            // headers.
+            "landlock_ruleset_attr" if musl || uclibc || pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.7 kernel headers.
+            "landlock_net_port_attr" if musl || uclibc || pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.5 kernel headers.
+            "cachestat" | "cachestat_range" if uclibc => true,
+            // FIXME(linux): Requires >= 6.6 kernel headers.
+            "proc_input" if uclibc || pointer_width == 32 => true,
+            // FIXME(linux): Version 14 of the struct requires >= 6.8 kernel headers.
+            "taskstats" if musl || uclibc || pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.10 kernel headers.
+            "v4l2_remove_buffers" if musl || uclibc || pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.8 kernel headers.
+            "drm_mode_closefb" if musl || uclibc || pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.6 kernel headers.
+            "drm_syncobj_eventfd" if uclibc || pointer_width == 32 => true,
+            // FIXME(linux): `deadline_nsec` requires >= 6.5 kernel headers.
+            "drm_syncobj_wait" | "drm_syncobj_timeline_wait" if uclibc => true,
+            // FIXME(linux): Requires >= 6.0 kernel headers.
+            "vfio_device_feature_migration"
+            | "vfio_device_feature_mig_state"
+            | "vfio_device_low_power_entry_with_wakeup"
+            | "vfio_device_feature_dma_logging_control"
+            | "vfio_device_feature_dma_logging_range"
+            | "vfio_device_feature_dma_logging_report"
+                if uclibc =>
+            {
+                true
+            }
+            t if !iommufd && t.starts_with("iommu_") => true,
+            // FIXME(linux): Requires >= 6.8 kernel headers.
+            "kvm_userspace_memory_region2" if musl => true,
+            // The canonical uClibc toolchain uses Linux 5.15, which predates most of the
+            // current io_uring layouts.
+            t if uclibc
+                && (t.starts_with("io_uring_")
+                    || t == "io_sqring_offsets"
+                    || t == "io_cqring_offsets") =>
+            {
+                true
+            }
+
             // FIXME(musl): Struct has changed for new musl versions
             "tcp_info" if musl => true,
 
@@ -4320,6 +4474,17 @@ fn test_linux(target: &str) {
             return true;
         }
 
+        // FIXME(linux): statmount(2)/listmount(2) require >= 6.8 kernel headers (some masks
+        // >= 6.13).
+        if (musl || uclibc || pointer_width == 32)
+            && (name.starts_with("STATMOUNT_")
+                || name.starts_with("MNT_ID_REQ_SIZE_VER")
+                || name == "LSMT_ROOT"
+                || name == "LISTMOUNT_REVERSE")
+        {
+            return true;
+        }
+
         // L4Re requires a min stack size of 64k; that isn't defined in uClibc, but
         // somewhere in the core libraries. uClibc wants 16k, but that's not enough.
         if l4re && name == "PTHREAD_STACK_MIN" {
@@ -4339,32 +4504,52 @@ fn test_linux(target: &str) {
             // updated regularly and seem likely to cause breakage.
             if name.starts_with("AF_")
                 || name.starts_with("ARPHRD_")
+                || name.starts_with("AUDIT_")
+                || name.starts_with("DEVLINK_")
                 || name.starts_with("EPOLL")
+                || name.starts_with("ETHTOOL_")
+                || name.starts_with("ETH_SS_")
                 || name.starts_with("F_")
                 || name.starts_with("FALLOC_FL_")
                 || name.starts_with("FSCONFIG_")
+                || name.starts_with("FSCRYPT_")
                 || name.starts_with("FSMOUNT_")
+                || name.starts_with("BPF_")
                 || name.starts_with("FSOPEN_")
                 || name.starts_with("FSPICK_")
                 || name.starts_with("FUTEX2_")
                 || name.starts_with("IFLA_")
+                || name.starts_with("IORING_")
+                || name.starts_with("IOSQE_")
+                || name.starts_with("IOU_")
+                || name.starts_with("IO_URING_")
                 || name.starts_with("KEXEC_")
+                || name.starts_with("LANDLOCK_")
                 || name.starts_with("MS_")
                 || name.starts_with("MSG_")
+                || name.starts_with("NL80211_")
                 || name.starts_with("OPEN_TREE_")
                 || name.starts_with("P_")
+                || name.starts_with("PERF_")
                 || name.starts_with("PF_")
                 || name.starts_with("PIDFD_")
+                || name.starts_with("RATE_MATCH_")
                 || name.starts_with("RLIMIT_")
                 || name.starts_with("RTEXT_FILTER_")
+                || name.starts_with("SOCKET_URING_OP_")
                 || name.starts_with("SOL_")
                 || name.starts_with("STATX_")
                 || name.starts_with("SW_")
                 || name.starts_with("SYS_")
                 || name.starts_with("SYS3264_")
                 || name.starts_with("TCP_")
+                || name.starts_with("UFFD")
                 || name.starts_with("UINPUT_")
+                || name.starts_with("USERFAULTFD_")
+                || name.starts_with("V4L2_")
+                || name.starts_with("VIDIOC_")
                 || name.starts_with("VMADDR_")
+                || name.starts_with("_UFFDIO_")
             {
                 return true;
             }
@@ -4457,7 +4642,9 @@ fn test_linux(target: &str) {
                 | "PR_SCHED_CORE_SCOPE_THREAD_GROUP"
                 | "PR_SCHED_CORE_SCOPE_THREAD"
                 | "NF_NETDEV_EGRESS"
-                | "SO_RESERVE_MEM" => return true,
+                | "SO_RESERVE_MEM"
+                | "NDA_FLAGS_EXT"
+                | "NTF_EXT_MANAGED" => return true,
 
                 // TLS_CIPHER_SM4_[GC]CM requires linux 5.16
                 "TLS_CIPHER_SM4_CCM_IV_SIZE"
@@ -4481,6 +4668,9 @@ fn test_linux(target: &str) {
                 // requires linux 5.18
                 "RTNLGRP_STATS"
                 | "RTNLGRP_TUNNEL"
+                | "RTM_NEWTUNNEL"
+                | "RTM_DELTUNNEL"
+                | "RTM_GETTUNNEL"
                 | "TLS_TX_ZEROCOPY_RO"
                 | "MADV_DONTNEED_LOCKED"
                 | "NFQA_PRIORITY"
@@ -4502,6 +4692,10 @@ fn test_linux(target: &str) {
 
                 // requires linux 6.0
                 "IFF_NO_CARRIER"
+                | "NDA_NDM_FLAGS_MASK"
+                | "NDA_NDM_STATE_MASK"
+                | "NDTPA_INTERVAL_PROBE_TIME_MS"
+                | "RTEXT_FILTER_MST"
                 | "TLS_INFO_RX_NO_PAD"
                 | "TLS_RX_EXPECT_NO_PAD" => return true,
 
@@ -4535,6 +4729,13 @@ fn test_linux(target: &str) {
                 | "TLS_CIPHER_ARIA_GCM_256_TAG_SIZE"
                 | "TLS_CIPHER_ARIA_GCM_256" => return true,
 
+                // requires linux 6.1
+                "IFA_PROTO"
+                | "IFAPROT_UNSPEC"
+                | "IFAPROT_KERNEL_LO"
+                | "IFAPROT_KERNEL_RA"
+                | "IFAPROT_KERNEL_LL" => return true,
+
                 // requires linux 6.2
                 "ALG_SET_KEY_BY_KEY_SERIAL"
                 | "PACKET_FANOUT_FLAG_IGNORE_OUTGOING"
@@ -4555,6 +4756,7 @@ fn test_linux(target: &str) {
 
                 // requires linux 6.4
                 "PACKET_VNET_HDR_SZ" => return true,
+                "TCA_EXT_WARN_MSG" | "TCA_ROOT_EXT_WARN_MSG" => return true,
                 "PR_GET_MEMORY_MERGE" => return true,
                 "PR_SET_MEMORY_MERGE" => return true,
 
@@ -4564,7 +4766,13 @@ fn test_linux(target: &str) {
 
                 // requires linux 6.6
                 "PR_MDWE_NO_INHERIT"
-                | "PR_MDWE_REFUSE_EXEC_GAIN" => return true,
+                | "PR_MDWE_REFUSE_EXEC_GAIN"
+                | "SECCOMP_IOCTL_NOTIF_SET_FLAGS"
+                | "SECCOMP_USER_NOTIF_FD_SYNC_WAKE_UP"
+                | "PROC_EVENT_ALL" => return true,
+
+                // requires linux 6.8
+                "TASKSTATS_VERSION" => return true,
 
                 // defined as a synonym for EM_ARC_COMPACT in gnu but not uclibc
                 "EM_ARC_A5" => return true,
@@ -4749,6 +4957,118 @@ fn test_linux(target: &str) {
 
             // FIXME(linux32): Requires >= 6.6 kernel headers.
             "XDP_USE_SG" | "XDP_PKT_CONTD" if pointer_width == 32 => true,
+            // FIXME(linux32): Requires >= 6.6 kernel headers.
+            "SECCOMP_IOCTL_NOTIF_SET_FLAGS" | "SECCOMP_USER_NOTIF_FD_SYNC_WAKE_UP"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+            // FIXME(linux32): Requires >= 6.6 kernel headers.
+            "PROC_EVENT_ALL" if pointer_width == 32 => true,
+            // FIXME(linux32): Requires newer kernel headers.
+            "FSCRYPT_MODE_SM4_XTS" | "FSCRYPT_MODE_SM4_CTS" if pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.12 kernel headers.
+            "AUDIT_IPE_ACCESS" | "AUDIT_IPE_CONFIG_CHANGE" | "AUDIT_IPE_POLICY_LOAD"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+            // FIXME(linux): Requires >= 6.8 kernel headers.
+            "TASKSTATS_VERSION" if musl || pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.8 kernel headers.
+            "V4L2_BUF_CAP_SUPPORTS_MAX_NUM_BUFFERS" if pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.10 kernel headers.
+            "V4L2_BUF_CAP_SUPPORTS_REMOVE_BUFS" | "VIDIOC_REMOVE_BUFS" if pointer_width == 32 => {
+                true
+            }
+            // FIXME(linux): Requires >= 6.8 kernel headers.
+            "DRM_CAP_ATOMIC_ASYNC_PAGE_FLIP"
+            | "DRM_CLIENT_CAP_CURSOR_PLANE_HOTSPOT"
+            | "DRM_IOCTL_MODE_CLOSEFB"
+                if musl || uclibc || pointer_width == 32 =>
+            {
+                true
+            }
+            // FIXME(linux): Requires >= 6.6 kernel headers.
+            "DRM_IOCTL_SYNCOBJ_EVENTFD" if uclibc || pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.5 kernel headers.
+            "DRM_SYNCOBJ_WAIT_FLAGS_WAIT_DEADLINE"
+            | "DRM_IOCTL_SYNCOBJ_WAIT"
+            | "DRM_IOCTL_SYNCOBJ_TIMELINE_WAIT"
+                if uclibc =>
+            {
+                true
+            }
+            // FIXME(linux): Requires >= 6.0 kernel headers.
+            "DRM_MODE_CONNECTOR_USB" if uclibc => true,
+            // FIXME(linux): Requires >= 6.0 kernel headers, previously the v1 migration
+            // region interface.
+            "VFIO_REGION_TYPE_MIGRATION_DEPRECATED"
+            | "VFIO_REGION_SUBTYPE_MIGRATION_DEPRECATED"
+            | "VFIO_MIGRATION_STOP_COPY"
+            | "VFIO_MIGRATION_P2P"
+                if uclibc =>
+            {
+                true
+            }
+            n if uclibc
+                && (n.starts_with("VFIO_DEVICE_STATE_")
+                    || n.starts_with("VFIO_DEVICE_FEATURE_MIG")
+                    || n.starts_with("VFIO_DEVICE_FEATURE_LOW_POWER_")
+                    || n.starts_with("VFIO_DEVICE_FEATURE_DMA_LOGGING_")) =>
+            {
+                true
+            }
+            n if !iommufd && (n.starts_with("IOMMU_") || n.starts_with("IOMMUFD_")) => true,
+            // FIXME(linux): Requires >= 6.8 kernel headers.
+            "KVM_CAP_USER_MEMORY2" | "KVM_MEM_GUEST_MEMFD" | "KVM_SET_USER_MEMORY_REGION2"
+                if musl =>
+            {
+                true
+            }
+            // FIXME(linux): Requires >= 6.7 kernel headers.
+            "RTAX_FEATURE_TCP_USEC_TS" | "RTAX_FEATURE_MASK"
+                if musl || uclibc || pointer_width == 32 =>
+            {
+                true
+            }
+            // FIXME(linux): Requires >= 6.1 kernel headers.
+            "KEY_ALL_APPLICATIONS"
+            | "KEY_DICTATE"
+            | "KEY_FN_RIGHT_SHIFT"
+            | "KEY_NEXT_ELEMENT"
+            | "KEY_PREVIOUS_ELEMENT"
+            | "KEY_AUTOPILOT_ENGAGE_TOGGLE"
+            | "KEY_MARK_WAYPOINT"
+            | "KEY_SOS"
+            | "KEY_NAV_CHART"
+            | "KEY_FISHING_CHART"
+            | "KEY_SINGLE_RANGE_RADAR"
+            | "KEY_DUAL_RANGE_RADAR"
+            | "KEY_RADAR_OVERLAY"
+            | "KEY_TRADITIONAL_SONAR"
+            | "KEY_CLEARVU_SONAR"
+            | "KEY_SIDEVU_SONAR"
+            | "KEY_NAV_INFO"
+            | "KEY_BRIGHTNESS_MENU"
+            | "ABS_PROFILE"
+            | "BUS_AMD_SFH"
+                if uclibc =>
+            {
+                true
+            }
+
+            // FIXME(linux32): Landlock ABI v4+ requires >= 6.7 kernel headers.
+            "LANDLOCK_RULE_NET_PORT"
+            | "LANDLOCK_ACCESS_FS_IOCTL_DEV"
+            | "LANDLOCK_ACCESS_NET_BIND_TCP"
+            | "LANDLOCK_ACCESS_NET_CONNECT_TCP"
+            | "LANDLOCK_SCOPE_ABSTRACT_UNIX_SOCKET"
+            | "LANDLOCK_SCOPE_SIGNAL"
+                if pointer_width == 32 =>
+            {
+                true
+            }
 
             // FIXME(linux): Missing only on this platform for some reason
             "PR_MDWE_NO_INHERIT" if gnueabihf => true,
@@ -4813,6 +5133,12 @@ fn test_linux(target: &str) {
             // FIXME(linux): Value changed in 6.14
             "SECURE_ALL_BITS" | "SECURE_ALL_LOCKS" => true,
 
+            // FIXME(linux): Value changed in 6.4
+            "DM_VERSION_MINOR" | "DM_VERSION_EXTRA" if uclibc => true,
+
+            // `linux/xattr.h` clashes with `sys/xattr.h` outside of glibc
+            "XATTR_NAME_CAPS" if !gnu => true,
+
             // FIXME(linux): Requires >= 6.9 kernel headers.
             "AT_HWCAP3" | "AT_HWCAP4" => true,
 
@@ -4832,6 +5158,139 @@ fn test_linux(target: &str) {
             // eabihf targets are tested using an older version of glibc
             "AT_HANDLE_FID" if musl || eabihf => true,
 
+            // FIXME(linux32): Requires >= 6.6 kernel headers.
+            "IORING_SETUP_NO_SQARRAY"
+            | "IORING_ASYNC_CANCEL_USERDATA"
+            | "IORING_ASYNC_CANCEL_OP"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+            // FIXME(linux32): Requires >= 6.7 kernel headers.
+            "IORING_OP_READ_MULTISHOT"
+            | "IORING_OP_WAITID"
+            | "IORING_OP_FUTEX_WAIT"
+            | "IORING_OP_FUTEX_WAKE"
+            | "IORING_OP_FUTEX_WAITV"
+            | "SOCKET_URING_OP_SIOCINQ"
+            | "SOCKET_URING_OP_SIOCOUTQ"
+            | "SOCKET_URING_OP_GETSOCKOPT"
+            | "SOCKET_URING_OP_SETSOCKOPT"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+            // FIXME(linux32): Requires >= 6.8 kernel headers.
+            "IORING_OP_FIXED_FD_INSTALL" | "IORING_FIXED_FD_NO_CLOEXEC" if pointer_width == 32 => {
+                true
+            }
+            // FIXME(linux32): Requires >= 6.11 kernel headers.
+            "IORING_OP_FTRUNCATE"
+            | "IORING_OP_BIND"
+            | "IORING_OP_LISTEN"
+            | "IORING_RECVSEND_BUNDLE"
+            | "IORING_ACCEPT_DONTWAIT"
+            | "IORING_ACCEPT_POLL_FIRST"
+            | "IORING_FEAT_RECVSEND_BUNDLE"
+            | "IORING_REGISTER_NAPI"
+            | "IORING_UNREGISTER_NAPI"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+            // FIXME(linux32): Requires >= 6.12 kernel headers.
+            "IORING_CQE_F_BUF_MORE"
+            | "IORING_ENTER_ABS_TIMER"
+            | "IORING_FEAT_MIN_TIMEOUT"
+            | "IORING_REGISTER_CLOCK"
+            | "IORING_REGISTER_CLONE_BUFFERS"
+            | "IOU_PBUF_RING_INC"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+            // FIXME(linux): Requires >= 6.13 kernel headers.
+            "IORING_SETUP_HYBRID_IOPOLL" => true,
+
+            // FIXME(linux32): Requires >= 6.8 kernel headers.
+            "PERF_SAMPLE_BRANCH_COUNTERS" | "PERF_SAMPLE_BRANCH_COUNTERS_SHIFT"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+
+            // FIXME(linux32): Requires >= 6.6 kernel headers.
+            "BPF_MEMSX"
+            | "BPF_TCX_INGRESS"
+            | "BPF_TCX_EGRESS"
+            | "BPF_TRACE_UPROBE_MULTI"
+            | "BPF_LINK_TYPE_TCX"
+            | "BPF_LINK_TYPE_UPROBE_MULTI"
+            | "BPF_F_BEFORE"
+            | "BPF_F_AFTER"
+            | "BPF_F_ID"
+            | "BPF_F_UPROBE_MULTI_RETURN"
+            | "BPF_F_NETFILTER_IP_DEFRAG"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+            t if t.starts_with("BPF_PERF_EVENT_") && pointer_width == 32 => true,
+            // FIXME(linux32): Requires >= 6.7 kernel headers.
+            "BPF_NETKIT_PRIMARY"
+            | "BPF_NETKIT_PEER"
+            | "BPF_LINK_TYPE_NETKIT"
+            | "BPF_F_TEST_REG_INVARIANTS"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+            t if t.starts_with("BPF_CGROUP_UNIX_") && pointer_width == 32 => true,
+            // FIXME(linux32): Requires >= 6.9 kernel headers.
+            "BPF_MAY_GOTO"
+            | "BPF_TOKEN_CREATE"
+            | "BPF_MAP_TYPE_ARENA"
+            | "BPF_ADDR_SPACE_CAST"
+            | "BPF_F_VTYPE_BTF_OBJ_FD"
+            | "BPF_F_TOKEN_FD"
+            | "BPF_F_SEGV_ON_FAULT"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+            // FIXME(linux32): Requires >= 6.10 kernel headers.
+            "BPF_JCOND"
+            | "BPF_TRACE_KPROBE_SESSION"
+            | "BPF_LINK_TYPE_SOCKMAP"
+            | "BPF_F_NO_USER_CONV"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+            // FIXME(linux32): Requires >= 6.11 kernel headers.
+            "BPF_F_TEST_SKB_CHECKSUM_COMPLETE" if pointer_width == 32 => true,
+
+            // FIXME(linux32): Requires >= 6.6 kernel headers.
+            "_UFFDIO_POISON"
+            | "UFFDIO_POISON"
+            | "UFFDIO_POISON_MODE_DONTWAKE"
+            | "UFFD_FEATURE_POISON"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+            // FIXME(linux32): Requires >= 6.8 kernel headers.
+            "_UFFDIO_MOVE"
+            | "UFFDIO_MOVE"
+            | "UFFDIO_MOVE_MODE_DONTWAKE"
+            | "UFFDIO_MOVE_MODE_ALLOW_SRC_HOLES"
+            | "UFFD_FEATURE_MOVE"
+            | "UFFD_FEATURE_WP_ASYNC"
+                if pointer_width == 32 =>
+            {
+                true
+            }
+
             _ => false,
         }
     });
@@ -4839,17 +5298,164 @@ fn test_linux(target: &str) {
     let c_enums = [
         "can_state",
         "fsconfig_command",
+        "io_uring_op",
+        "io_uring_socket_op",
+        "io_uring_msg_ring_flags",
+        "io_uring_register_op",
+        "io_wq_type",
+        "io_uring_register_restriction_op",
+        "perf_type_id",
+        "perf_hw_id",
+        "perf_hw_cache_id",
+        "perf_hw_cache_op_id",
+        "perf_hw_cache_op_result_id",
+        "perf_sw_ids",
+        "perf_event_sample_format",
+        "perf_branch_sample_type_shift",
+        "perf_branch_sample_type",
+        "perf_sample_regs_abi",
+        "perf_event_read_format",
+        "perf_event_ioc_flags",
+        "perf_event_type",
+        "perf_record_ksymbol_type",
+        "perf_bpf_event_type",
+        "bpf_cond_pseudo_jmp",
+        "bpf_cmd",
+        "bpf_map_type",
+        "bpf_prog_type",
+        "bpf_attach_type",
+        "bpf_link_type",
+        "bpf_perf_event_type",
+        "bpf_addr_space_cast",
+        "bpf_stats_type",
+        "xdp_action",
+        "sk_action",
+        "bpf_task_fd_type",
+        "tunable_id",
+        "tunable_type_id",
+        "phy_tunable_id",
+        "ethtool_link_ext_state",
+        "ethtool_link_ext_substate_autoneg",
+        "ethtool_link_ext_substate_link_training",
+        "ethtool_link_ext_substate_link_logical_mismatch",
+        "ethtool_link_ext_substate_bad_signal_integrity",
+        "ethtool_link_ext_substate_cable_issue",
+        "ethtool_link_ext_substate_module",
+        "ethtool_stringset",
+        "ethtool_module_power_mode_policy",
+        "ethtool_module_power_mode",
+        "ethtool_podl_pse_admin_state",
+        "ethtool_podl_pse_pw_d_status",
+        "ethtool_test_flags",
+        "ethtool_flags",
+        "ethtool_flash_op_type",
+        "ethtool_fec_config_bits",
+        "ethtool_link_mode_bit_indices",
+        "ethtool_reset_flags",
+        "landlock_rule_type",
+        "audit_nlgrps",
+        "rseq_cpu_id_state",
+        "rseq_flags",
+        "rseq_cs_flags_bit",
+        "rseq_cs_flags",
+        "devlink_command",
+        "devlink_port_type",
+        "devlink_sb_pool_type",
+        "devlink_sb_threshold_type",
+        "devlink_eswitch_mode",
+        "devlink_eswitch_inline_mode",
+        "devlink_eswitch_encap_mode",
+        "devlink_port_flavour",
+        "devlink_rate_type",
+        "devlink_param_cmode",
+        "devlink_param_fw_load_policy_value",
+        "devlink_param_reset_dev_on_drv_probe_value",
+        "devlink_attr_selftest_id",
+        "devlink_selftest_status",
+        "devlink_attr_selftest_result",
+        "devlink_trap_action",
+        "devlink_trap_type",
+        "devlink_reload_action",
+        "devlink_reload_limit",
+        "devlink_linecard_state",
+        "devlink_attr",
+        "devlink_dpipe_field_mapping_type",
+        "devlink_dpipe_match_type",
+        "devlink_dpipe_action_type",
+        "devlink_dpipe_field_ethernet_id",
+        "devlink_dpipe_field_ipv4_id",
+        "devlink_dpipe_field_ipv6_id",
+        "devlink_dpipe_header_id",
+        "devlink_resource_unit",
+        "devlink_port_function_attr",
+        "devlink_port_fn_state",
+        "devlink_port_fn_opstate",
+        "nl80211_commands",
+        "nl80211_attrs",
+        "nl80211_iftype",
+        "nl80211_sta_flags",
+        "nl80211_rate_info",
+        "nl80211_sta_bss_param",
+        "nl80211_sta_info",
+        "nl80211_band_attr",
+        "nl80211_frequency_attr",
+        "nl80211_bitrate_attr",
+        "nl80211_channel_type",
+        "nl80211_chan_width",
+        "nl80211_bss_scan_width",
+        "nl80211_bss",
+        "nl80211_bss_status",
+        "nl80211_band",
         "membarrier_cmd",
         "pid_type",
         "proc_cn_event",
         "proc_cn_mcast_op",
         "tpacket_versions",
+        "v4l2_field",
+        "v4l2_buf_type",
+        "v4l2_tuner_type",
+        "v4l2_memory",
+        "v4l2_colorspace",
+        "v4l2_xfer_func",
+        "v4l2_ycbcr_encoding",
+        "v4l2_hsv_encoding",
+        "v4l2_quantization",
+        "v4l2_priority",
+        "v4l2_frmsizetypes",
+        "v4l2_frmivaltypes",
+        "v4l2_ctrl_type",
+        "drm_map_type",
+        "drm_map_flags",
+        "drm_stat_type",
+        "drm_lock_flags",
+        "drm_dma_flags",
+        "drm_ctx_flags",
+        "drm_vblank_seq_type",
+        "drm_mode_subconnector",
+        "vfio_device_mig_state",
+        "iommufd_ioas_map_flags",
+        "iommufd_option",
+        "iommufd_option_ops",
+        "iommufd_vfio_ioas_op",
+        "kvm_device_type",
     ];
     cfg.alias_is_c_enum(move |e| c_enums.contains(&e));
 
     // FIXME(libc): `pid_type` and `proc_cn_event` is hidden.
     cfg.skip_c_enum(|e| e == "pid_type" || e == "proc_cn_event");
 
+    // FIXME(linux): These `io_uring` enums are anonymous in older kernel headers.
+    cfg.skip_c_enum(move |e| {
+        (musl || uclibc || pointer_width == 32)
+            && matches!(
+                e,
+                "io_uring_msg_ring_flags"
+                    | "io_uring_register_op"
+                    | "io_wq_type"
+                    | "io_uring_register_restriction_op"
+            )
+    });
+
     cfg.skip_signededness(move |c| match c {
         // FIXME(1.0): uses the enum default signedness
         "membarrier_cmd" => true,
@@ -4978,6 +5584,41 @@ fn test_linux(target: &str) {
             ("utmpx", "ut_exit") if musl => true,
             // `can_addr` is an anonymous union
             ("sockaddr_can", "can_addr") => true,
+            // anonymous unions
+            ("proc_event", "event_data") => true,
+            ("fscrypt_get_policy_ex_arg", "policy") => true,
+            ("fscrypt_key_specifier", "u") => true,
+            ("id_proc_event", "r" | "e") => true,
+            // arrays of anonymous structs
+            ("vfs_cap_data" | "vfs_ns_cap_data", "data") => true,
+            ("v4l2_plane" | "v4l2_buffer", "m") => true,
+            ("v4l2_framebuffer" | "v4l2_format", "fmt") => true,
+            ("v4l2_streamparm", "parm") => true,
+            ("v4l2_event", "u") => true,
+            (s, f) if s.starts_with("v4l2_") && f.starts_with("anonymous_") => true,
+            ("drm_stats", "data") => true,
+            ("hdr_metadata_infoframe", "display_primaries" | "white_point") => true,
+            // anonymous union
+            ("hdr_output_metadata", "anonymous_1") => true,
+            ("vfio_device_gfx_plane_info" | "vfio_eeh_pe_op", "anonymous_1") => true,
+            (
+                "kvm_run"
+                | "kvm_irq_level"
+                | "kvm_coalesced_mmio_zone"
+                | "kvm_coalesced_mmio"
+                | "kvm_dirty_log"
+                | "kvm_clear_dirty_log"
+                | "kvm_irq_routing_msi",
+                "anonymous_1",
+            ) => true,
+            ("kvm_run", "s")
+            | ("kvm_hyperv_exit" | "kvm_xen_exit" | "kvm_irq_routing_entry", "u") => true,
+            // anonymous structs
+            ("kvm_vcpu_events", "exception" | "interrupt" | "nmi" | "smi" | "triple_fault") => true,
+            // `func` is an anonymous enum
+            ("drm_control", "func") => true,
+            // FIXME(linux): Requires >= 6.6 kernel headers, previously `enum what`.
+            ("proc_event", "what") if uclibc || pointer_width == 32 => true,
             // `anonymous_1` is an anonymous union
             ("ptp_perout_request", "anonymous_1") => true,
             // `anonymous_2` is an anonymous union
@@ -5089,10 +5730,100 @@ fn test_linux(target: &str) {
             ("file_handle", "f_handle") if musl || uclibc => true,
             // FIXME(ctest): ctest does not translate the rust code which computes the padding size
             ("pthread_cond_t", "__padding") if l4re => true,
+            // anonymous unions
+            ("io_uring_sqe", f) if f.starts_with("anonymous_") => true,
+            ("io_uring_restriction" | "io_uring_buf_ring", "anonymous_1") => true,
+            // FAM
+            ("io_uring_cqe", "big_cqe") | ("io_uring_probe", "ops") => true,
+            // FAM
+            ("vfio_region_info_cap_sparse_mmap", "areas")
+            | ("vfio_pci_hot_reset_info", "devices")
+            | ("vfio_pci_hot_reset", "group_fds")
+            | ("vfio_iommu_type1_info_cap_iova_range", "iova_ranges")
+            | (
+                "vfio_irq_set"
+                | "vfio_device_feature"
+                | "vfio_iommu_type1_dma_unmap"
+                | "vfio_iommu_type1_dirty_bitmap",
+                "data",
+            ) => true,
+            // FAM
+            ("kvm_coalesced_mmio_ring", "coalesced_mmio")
+            | ("kvm_irq_routing" | "kvm_msrs" | "kvm_cpuid2", "entries")
+            | ("kvm_signal_mask", "sigset")
+            | ("kvm_reg_list", "reg")
+            | ("kvm_msr_list", "indices") => true,
+            // FIXME(linux): `min_wait_usec` requires >= 6.12 kernel headers, it was `pad` before
+            ("io_uring_getevents_arg", "min_wait_usec") if musl || pointer_width == 32 => true,
+            // Anonymous unions
+            ("perf_event_attr", f) if f.starts_with("anonymous_") => true,
+            // FAM
+            ("perf_event_query_bpf", "ids") => true,
+            // FIXME(linux): Requires >= 6.13 kernel headers, previously `__reserved_3`.
+            ("perf_event_attr", "aux_action") if musl || uclibc || pointer_width == 32 => true,
+            // FIXME(linux): Requires >= 6.8 kernel headers, previously padding.
+            ("bpf_map_info", "btf_vmlinux_id") if musl || pointer_width == 32 => true,
+            // anonymous union
+            ("uffd_msg", "arg") => true,
+            // FAM
+            (
+                "ethtool_tunable" | "ethtool_regs" | "ethtool_eeprom" | "ethtool_gstrings"
+                | "ethtool_sset_info" | "ethtool_test" | "ethtool_stats" | "ethtool_perm_addr"
+                | "ethtool_dump",
+                "data",
+            ) => true,
+            ("ethtool_link_settings", "link_mode_masks") => true,
+            ("rtvia", "rtvia_addr") => true,
+            ("rseq", "end") => true,
+            ("cn_msg", "data") => true,
+            ("audit_rule_data", "buf") => true,
+            ("fsverity_digest" | "fsverity_formatted_digest", "digest") => true,
+            ("fscrypt_provisioning_key_payload" | "fscrypt_add_key_arg", "raw") => true,
+            ("dm_target_deps", "dev") => true,
+            ("dm_name_list" | "dm_target_versions", "name") => true,
+            ("dm_target_msg", "message") => true,
+            // FIXME(linux): Requires >= 6.7 kernel headers, previously `__reserved[4]`.
+            ("fscrypt_policy_v2", "log2_data_unit_size" | "__reserved")
+                if musl || uclibc || pointer_width == 32 =>
+            {
+                true
+            }
+            // anonymous union
+            ("audit_status", "anonymous_1") => true,
+            // anonymous unions
+            (s, f) if s.starts_with("v4l2_") && f.starts_with("anonymous_") => true,
+            ("hdr_output_metadata", "anonymous_1") => true,
+            ("vfio_device_gfx_plane_info" | "vfio_eeh_pe_op", "anonymous_1") => true,
+            (
+                "kvm_run"
+                | "kvm_irq_level"
+                | "kvm_coalesced_mmio_zone"
+                | "kvm_coalesced_mmio"
+                | "kvm_dirty_log"
+                | "kvm_clear_dirty_log"
+                | "kvm_irq_routing_msi",
+                "anonymous_1",
+            ) => true,
+            // FIXME(linux): Requires >= 6.8 kernel headers, previously `reserved[6]`.
+            ("v4l2_create_buffers", "max_num_buffers" | "reserved")
+                if musl || uclibc || pointer_width == 32 =>
+            {
+                true
+            }
+            // Padding for the `__attribute__((aligned(8)))` fields.
+            ("taskstats", "__pad1" | "__pad2" | "__pad3" | "__pad4") => true,
+            // FIXME(linux): Requires >= 6.3 kernel headers.
+            ("rseq", "node_id" | "mm_cid") if uclibc => true,
             _ => false,
         }
     });
 
+    // Needs glibc 2.35 or later.
+    cfg.skip_static(move |static_| gnu && sparc64 && static_.ident().starts_with("__rseq_"));
+
+    // All members of `bpf_attr` are anonymous structs in the C header.
+    cfg.skip_union_field(|union_, _field| union_.ident() == "bpf_attr");
+
     cfg.skip_roundtrip(move |s| match s {
         // FIXME(1.0):
         "mcontext_t" if s390x => true,
@@ -5128,6 +5859,25 @@ fn test_linux(target: &str) {
         "fanotify_event_info_fid" => true,
         "cmsghdr" => true,
         "bcm_msg_head" => true,
+        "io_uring_cqe" | "io_uring_probe" | "io_uring_buf_ring" => true,
+        "perf_event_query_bpf" => true,
+        "ethtool_tunable"
+        | "ethtool_regs"
+        | "ethtool_eeprom"
+        | "ethtool_gstrings"
+        | "ethtool_sset_info"
+        | "ethtool_test"
+        | "ethtool_stats"
+        | "ethtool_perm_addr"
+        | "ethtool_dump"
+        | "ethtool_link_settings" => true,
+        "rtvia" => true,
+        "rseq" => true,
+        "cn_msg" => true,
+        "audit_rule_data" => true,
+        "fsverity_digest" | "fsverity_formatted_digest" => true,
+        "fscrypt_provisioning_key_payload" | "fscrypt_add_key_arg" => true,
+        "dm_target_deps" | "dm_name_list" | "dm_target_versions" | "dm_target_msg" => true,
 
         // FIXME(linux): the call ABI of max_align_t is incorrect on these platforms:
         "max_align_t" if i686 || ppc64 => true,
@@ -6183,6 +6933,24 @@ fn test_qurt(target: &str) {
     ctest::generate_test(&mut cfg, "../src/lib.rs", "ctest_output.rs").unwrap();
 }
 
+/// Check whether the C compiler used for the ctest build can find `header`.
+///
+/// Some UAPI headers are not shipped by every distribution's kernel header package (e.g. the DRM
+/// headers are provided by libdrm on Debian), or only exist in recent kernel headers.
+fn has_header(header: &str) -> bool {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let name = header.replace(['/', '.', '-'], "_");
+    let file = out_dir.join(format!("has_header_{name}.c"));
+    std::fs::write(&file, format!("#include <{header}>\n")).unwrap();
+
+    cc::Build::new()
+        .file(&file)
+        .cargo_metadata(false)
+        .cargo_warnings(false)
+        .try_expand()
+        .is_ok()
+}
+
 /// Attempt to execute a command and collect its output, If the command fails for whatever
 /// reason, return `None`.
 fn try_command_output(cmd: &str, args: &[&str]) -> Option<String> {
@@ -409,6 +409,39 @@ macro_rules! safe_f {
     )+};
 }
 
+/// Implement getters and setters for C bitfields packed into a single integer field.
+///
+/// Offsets and widths are in declaration order, as written in the C header. Big-endian targets
+/// allocate bitfields starting from the most significant bit.
+macro_rules! bitfield_accessors {
+    (
+        $storage:ident: $ty:ty;
+        $($get:ident, $set:ident: $offset:literal, $width:literal;)+
+    ) => {$(
+        #[inline]
+        pub fn $get(&self) -> $ty {
+            const SHIFT: u32 = if cfg!(target_endian = "big") {
+                <$ty>::BITS - $offset - $width
+            } else {
+                $offset
+            };
+            const MASK: $ty = (1 << $width) - 1;
+            (self.$storage >> SHIFT) & MASK
+        }
+
+        #[inline]
+        pub fn $set(&mut self, val: $ty) {
+            const SHIFT: u32 = if cfg!(target_endian = "big") {
+                <$ty>::BITS - $offset - $width
+            } else {
+                $offset
+            };
+            const MASK: $ty = ((1 << $width) - 1) << SHIFT;
+            self.$storage = (self.$storage & !MASK) | ((val << SHIFT) & MASK);
+        }
+    )+};
+}
+
 /// Polyfill for std's `offset_of`.
 // FIXME(msrv): stabilized in std in 1.77
 macro_rules! offset_of {
@@ -560,6 +593,40 @@ mod tests {
         assert_eq!(PRIV_ON_1, 42u16);
     }
 
+    #[test]
+    fn bitfield_accessors() {
+        #[derive(Default)]
+        struct Bits {
+            storage: u16,
+        }
+
+        impl Bits {
+            bitfield_accessors! {
+                storage: u16;
+                a, set_a: 0, 1;
+                b, set_b: 1, 3;
+                c, set_c: 4, 12;
+            }
+        }
+
+        let mut bits = Bits::default();
+        bits.set_a(1);
+        bits.set_b(0b101);
+        bits.set_c(0xfff);
+        assert_eq!((bits.a(), bits.b(), bits.c()), (1, 0b101, 0xfff));
+
+        // Out of range values are truncated and don't spill into neighbouring fields.
+        bits.set_b(0b1010);
+        assert_eq!((bits.a(), bits.b(), bits.c()), (1, 0b010, 0xfff));
+
+        let expected = if cfg!(target_endian = "big") {
+            0xafff
+        } else {
+            0xfff5
+        };
+        assert_eq!(bits.storage, expected);
+    }
+
     fn type_id_of_val<T: 'static>(_: &T) -> TypeId {
         TypeId::of::<T>()
     }
@@ -8,3 +8,10 @@
 pub(crate) mod net {
     pub(crate) mod route;
 }
+
+/// Directory: `sys/`
+///
+/// Source directory: `sysdeps/unix/sysv/linux/sys`
+pub(crate) mod sys {
+    pub(crate) mod rseq;
+}
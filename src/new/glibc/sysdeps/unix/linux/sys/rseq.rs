@@ -0,0 +1,43 @@
+//! Header: `sys/rseq.h`
+//!
+//! Source header: `sysdeps/unix/sysv/linux/sys/rseq.h`
+//! <https://github.com/bminor/glibc/blob/master/sysdeps/unix/sysv/linux/sys/rseq.h>
+//!
+//! The per-architecture `RSEQ_SIG` values come from `sysdeps/unix/sysv/linux/<arch>/bits/rseq.h`.
+
+use crate::prelude::*;
+
+cfg_if! {
+    if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
+        pub const RSEQ_SIG: c_uint = 0x53053053;
+    } else if #[cfg(target_arch = "aarch64")] {
+        #[cfg(target_endian = "little")]
+        pub const RSEQ_SIG: c_uint = 0xd428bc00;
+        #[cfg(target_endian = "big")]
+        pub const RSEQ_SIG: c_uint = 0x00bc28d4;
+    } else if #[cfg(target_arch = "arm")] {
+        #[cfg(target_endian = "little")]
+        pub const RSEQ_SIG: c_uint = 0xe7f5def3;
+        #[cfg(target_endian = "big")]
+        pub const RSEQ_SIG: c_uint = 0xf3def5e7;
+    } else if #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))] {
+        pub const RSEQ_SIG: c_uint = 0x0fe5000b;
+    } else if #[cfg(target_arch = "s390x")] {
+        pub const RSEQ_SIG: c_uint = 0xb2ff0fff;
+    } else if #[cfg(any(target_arch = "mips", target_arch = "mips64"))] {
+        pub const RSEQ_SIG: c_uint = 0x0350004d;
+    } else if #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))] {
+        pub const RSEQ_SIG: c_uint = 0xf1401073;
+    } else if #[cfg(target_arch = "loongarch64")] {
+        pub const RSEQ_SIG: c_uint = 0x002a0010;
+    }
+}
+
+extern "C" {
+    /// Offset from the thread pointer to the `rseq` area registered by glibc.
+    pub static __rseq_offset: crate::ptrdiff_t;
+    /// Size of the registered `rseq` area, or zero if registration failed or was disabled.
+    pub static __rseq_size: c_uint;
+    /// Flags used to register the `rseq` area.
+    pub static __rseq_flags: c_uint;
+}
@@ -0,0 +1,1407 @@
+//! Header: `uapi/drm/drm.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IO,
+    _IOR,
+    _IOW,
+    _IOWR,
+};
+
+/* Name in kernel, /dev, and /proc */
+pub const DRM_NAME: &[u8] = b"drm\0";
+/* At least 2^5 bytes = 32 bytes */
+pub const DRM_MIN_ORDER: c_int = 5;
+/* Up to 2^22 bytes = 4MB */
+pub const DRM_MAX_ORDER: c_int = 22;
+/* How much system ram can we lock? */
+pub const DRM_RAM_PERCENT: c_int = 10;
+
+/* Hardware lock is held */
+pub const _DRM_LOCK_HELD: c_uint = 0x80000000;
+/* Hardware lock is contended */
+pub const _DRM_LOCK_CONT: c_uint = 0x40000000;
+
+pub type drm_handle_t = c_uint;
+pub type drm_context_t = c_uint;
+pub type drm_drawable_t = c_uint;
+pub type drm_magic_t = c_uint;
+
+s! {
+    /*
+     * Cliprect.
+     *
+     * Note: For compatibility with X, the cliprect values must be unsigned shorts.
+     */
+    pub struct drm_clip_rect {
+        pub x1: c_ushort,
+        pub y1: c_ushort,
+        pub x2: c_ushort,
+        pub y2: c_ushort,
+    }
+
+    /*
+     * DRM_IOCTL_VERSION ioctl argument type.
+     *
+     * Note: The string buffers are written by the kernel and must be provided by
+     * the caller along with their lengths.
+     */
+    pub struct drm_version {
+        /* Major version */
+        pub version_major: c_int,
+        /* Minor version */
+        pub version_minor: c_int,
+        /* Patch level */
+        pub version_patchlevel: c_int,
+        /* Length of name buffer */
+        pub name_len: size_t,
+        /* Name of driver */
+        pub name: *mut c_char,
+        /* Length of date buffer */
+        pub date_len: size_t,
+        /* User-space buffer to hold date */
+        pub date: *mut c_char,
+        /* Length of desc buffer */
+        pub desc_len: size_t,
+        /* User-space buffer to hold desc */
+        pub desc: *mut c_char,
+    }
+
+    /*
+     * DRM_IOCTL_GET_UNIQUE ioctl argument type.
+     */
+    pub struct drm_unique {
+        /* Length of unique */
+        pub unique_len: size_t,
+        /* Unique name for driver instantiation */
+        pub unique: *mut c_char,
+    }
+
+    pub struct drm_list {
+        /* Length of user-space structures */
+        pub count: c_int,
+        pub version: *mut drm_version,
+    }
+
+    pub struct drm_block {
+        pub unused: c_int,
+    }
+
+    /*
+     * DRM_IOCTL_CONTROL ioctl argument type.
+     */
+    pub struct drm_control {
+        pub func: c_uint,
+        pub irq: c_int,
+    }
+}
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        pub DRM_ADD_COMMAND,
+        pub DRM_RM_COMMAND,
+        pub DRM_INST_HANDLER,
+        pub DRM_UNINST_HANDLER,
+    }
+
+    /*
+     * Type of memory to map.
+     */
+    pub enum drm_map_type {
+        /* WC (no caching), no core dump */
+        pub _DRM_FRAME_BUFFER = 0,
+        /* no caching, no core dump */
+        pub _DRM_REGISTERS = 1,
+        /* shared, cached */
+        pub _DRM_SHM = 2,
+        /* AGP/GART */
+        pub _DRM_AGP = 3,
+        /* Scatter/gather memory for PCI DMA */
+        pub _DRM_SCATTER_GATHER = 4,
+        /* Consistent memory for PCI DMA */
+        pub _DRM_CONSISTENT = 5,
+    }
+
+    /*
+     * Memory mapping flags.
+     */
+    pub enum drm_map_flags {
+        /* Cannot be mapped to user-virtual */
+        pub _DRM_RESTRICTED = 0x01,
+        pub _DRM_READ_ONLY = 0x02,
+        /* shared, cached, locked */
+        pub _DRM_LOCKED = 0x04,
+        /* kernel requires access */
+        pub _DRM_KERNEL = 0x08,
+        /* use write-combining if available */
+        pub _DRM_WRITE_COMBINING = 0x10,
+        /* SHM page that contains lock */
+        pub _DRM_CONTAINS_LOCK = 0x20,
+        /* Removable mapping */
+        pub _DRM_REMOVABLE = 0x40,
+        /* Managed by driver */
+        pub _DRM_DRIVER = 0x80,
+    }
+}
+
+s! {
+    pub struct drm_ctx_priv_map {
+        /* Context requesting private mapping */
+        pub ctx_id: c_uint,
+        /* Handle of map */
+        pub handle: *mut c_void,
+    }
+
+    /*
+     * DRM_IOCTL_GET_MAP, DRM_IOCTL_ADD_MAP and DRM_IOCTL_RM_MAP ioctls
+     * argument type.
+     */
+    pub struct drm_map {
+        /* Requested physical address (0 for SAREA) */
+        pub offset: c_ulong,
+        /* Requested physical size (bytes) */
+        pub size: c_ulong,
+        /* Type of memory to map */
+        pub type_: drm_map_type,
+        /* Flags */
+        pub flags: drm_map_flags,
+        /* User-space: "Handle" to pass to mmap() */
+        pub handle: *mut c_void,
+        /* MTRR slot used */
+        pub mtrr: c_int,
+    }
+
+    /*
+     * DRM_IOCTL_GET_CLIENT ioctl argument type.
+     */
+    pub struct drm_client {
+        /* Which client desired? */
+        pub idx: c_int,
+        /* Is client authenticated? */
+        pub auth: c_int,
+        /* Process ID */
+        pub pid: c_ulong,
+        /* User ID */
+        pub uid: c_ulong,
+        /* Magic */
+        pub magic: c_ulong,
+        /* Ioctl count */
+        pub iocs: c_ulong,
+    }
+}
+
+c_enum! {
+    pub enum drm_stat_type {
+        pub _DRM_STAT_LOCK,
+        pub _DRM_STAT_OPENS,
+        pub _DRM_STAT_CLOSES,
+        pub _DRM_STAT_IOCTLS,
+        pub _DRM_STAT_LOCKS,
+        pub _DRM_STAT_UNLOCKS,
+        /* Generic value */
+        pub _DRM_STAT_VALUE,
+        /* Generic byte counter (1024bytes/K) */
+        pub _DRM_STAT_BYTE,
+        /* Generic non-byte counter (1000/k) */
+        pub _DRM_STAT_COUNT,
+
+        /* IRQ */
+        pub _DRM_STAT_IRQ,
+        /* Primary DMA bytes */
+        pub _DRM_STAT_PRIMARY,
+        /* Secondary DMA bytes */
+        pub _DRM_STAT_SECONDARY,
+        /* DMA */
+        pub _DRM_STAT_DMA,
+        /* Special DMA (e.g., priority or polled) */
+        pub _DRM_STAT_SPECIAL,
+        /* Missed DMA opportunity */
+        pub _DRM_STAT_MISSED,
+        /* Add to the *END* of the list */
+    }
+}
+
+s! {
+    pub struct __c_anonymous_drm_stats_data {
+        pub value: c_ulong,
+        pub type_: drm_stat_type,
+    }
+
+    /*
+     * DRM_IOCTL_GET_STATS ioctl argument type.
+     */
+    pub struct drm_stats {
+        pub count: c_ulong,
+        pub data: [__c_anonymous_drm_stats_data; 15],
+    }
+}
+
+c_enum! {
+    /*
+     * Hardware locking flags.
+     */
+    pub enum drm_lock_flags {
+        /* Wait until hardware is ready for DMA */
+        pub _DRM_LOCK_READY = 0x01,
+        /* Wait until hardware quiescent */
+        pub _DRM_LOCK_QUIESCENT = 0x02,
+        /* Flush this context's DMA queue first */
+        pub _DRM_LOCK_FLUSH = 0x04,
+        /* Flush all DMA queues first */
+        pub _DRM_LOCK_FLUSH_ALL = 0x08,
+        /* These *HALT* flags aren't supported yet
+           -- they will be used to support the
+           full-screen DGA-like mode. */
+        /* Halt all current and future queues */
+        pub _DRM_HALT_ALL_QUEUES = 0x10,
+        /* Halt all current queues */
+        pub _DRM_HALT_CUR_QUEUES = 0x20,
+    }
+}
+
+s! {
+    /*
+     * DRM_IOCTL_LOCK, DRM_IOCTL_UNLOCK and DRM_IOCTL_FINISH ioctl argument type.
+     *
+     * Note: The interface will be implemented with a flag in the driver.
+     */
+    pub struct drm_lock {
+        pub context: c_int,
+        pub flags: drm_lock_flags,
+    }
+}
+
+c_enum! {
+    /*
+     * DMA flags
+     *
+     * Note: Some of these flags have been removed and can no longer be used.
+     */
+    pub enum drm_dma_flags {
+        /* Flags for DMA buffer dispatch */
+        /*
+         * Block until buffer dispatched.
+         *
+         * Note: The buffer may not yet have been processed by the hardware --
+         * getting a hardware lock with the hardware quiescent will ensure
+         * that the buffer has been processed.
+         */
+        pub _DRM_DMA_BLOCK = 0x01,
+        /* Dispatch while lock held */
+        pub _DRM_DMA_WHILE_LOCKED = 0x02,
+        /* High priority dispatch */
+        pub _DRM_DMA_PRIORITY = 0x04,
+
+        /* Flags for DMA buffer request */
+        /* Wait for free buffers */
+        pub _DRM_DMA_WAIT = 0x10,
+        /* Smaller-than-requested buffers OK */
+        pub _DRM_DMA_SMALLER_OK = 0x20,
+        /* Larger-than-requested buffers OK */
+        pub _DRM_DMA_LARGER_OK = 0x40,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        /* Align on page boundaries for DMA */
+        pub _DRM_PAGE_ALIGN = 0x01,
+        /* Buffer is in AGP space */
+        pub _DRM_AGP_BUFFER = 0x02,
+        /* Scatter/gather memory buffer */
+        pub _DRM_SG_BUFFER = 0x04,
+        /* Buffer is in frame buffer */
+        pub _DRM_FB_BUFFER = 0x08,
+        /* Map PCI DMA buffer read-only */
+        pub _DRM_PCI_BUFFER_RO = 0x10,
+    }
+}
+
+s! {
+    /*
+     * DRM_IOCTL_ADD_BUFS and DRM_IOCTL_MARK_BUFS ioctl argument type.
+     */
+    pub struct drm_buf_desc {
+        /* Number of buffers of this size */
+        pub count: c_int,
+        /* Size in bytes */
+        pub size: c_int,
+        /* Low water mark */
+        pub low_mark: c_int,
+        /* High water mark */
+        pub high_mark: c_int,
+        pub flags: c_uint,
+        /*
+         * Start address of where the AGP buffers are
+         * in the AGP aperture
+         */
+        pub agp_start: c_ulong,
+    }
+
+    /*
+     * DRM_IOCTL_INFO_BUFS ioctl argument type.
+     */
+    pub struct drm_buf_info {
+        /* Entries in list */
+        pub count: c_int,
+        pub list: *mut drm_buf_desc,
+    }
+
+    /*
+     * DRM_IOCTL_FREE_BUFS ioctl argument type.
+     */
+    pub struct drm_buf_free {
+        pub count: c_int,
+        pub list: *mut c_int,
+    }
+
+    /*
+     * Buffer information
+     */
+    pub struct drm_buf_pub {
+        /* Index into the master buffer list */
+        pub idx: c_int,
+        /* Buffer size */
+        pub total: c_int,
+        /* Amount of buffer in use (for DMA) */
+        pub used: c_int,
+        /* Address of buffer */
+        pub address: *mut c_void,
+    }
+
+    /*
+     * DRM_IOCTL_MAP_BUFS ioctl argument type.
+     */
+    pub struct drm_buf_map {
+        /* Length of the buffer list */
+        pub count: c_int,
+        /* Mmap'd area in user-virtual */
+        pub virtual_: *mut c_void,
+        /* Buffer information */
+        pub list: *mut drm_buf_pub,
+    }
+
+    /*
+     * DRM_IOCTL_DMA ioctl argument type.
+     *
+     * Indices here refer to the offset into the buffer list in drm_buf_get.
+     */
+    pub struct drm_dma {
+        /* Context handle */
+        pub context: c_int,
+        /* Number of buffers to send */
+        pub send_count: c_int,
+        /* List of handles to buffers */
+        pub send_indices: *mut c_int,
+        /* Lengths of data to send */
+        pub send_sizes: *mut c_int,
+        /* Flags */
+        pub flags: drm_dma_flags,
+        /* Number of buffers requested */
+        pub request_count: c_int,
+        /* Desired size for buffers */
+        pub request_size: c_int,
+        /* Buffer information */
+        pub request_indices: *mut c_int,
+        pub request_sizes: *mut c_int,
+        /* Number of buffers granted */
+        pub granted_count: c_int,
+    }
+}
+
+c_enum! {
+    pub enum drm_ctx_flags {
+        pub _DRM_CONTEXT_PRESERVED = 0x01,
+        pub _DRM_CONTEXT_2DONLY = 0x02,
+    }
+}
+
+s! {
+    /*
+     * DRM_IOCTL_ADD_CTX ioctl argument type.
+     */
+    pub struct drm_ctx {
+        pub handle: drm_context_t,
+        pub flags: drm_ctx_flags,
+    }
+
+    /*
+     * DRM_IOCTL_RES_CTX ioctl argument type.
+     */
+    pub struct drm_ctx_res {
+        pub count: c_int,
+        pub contexts: *mut drm_ctx,
+    }
+
+    /*
+     * DRM_IOCTL_ADD_DRAW and DRM_IOCTL_RM_DRAW ioctl argument type.
+     */
+    pub struct drm_draw {
+        pub handle: drm_drawable_t,
+    }
+
+    /*
+     * DRM_IOCTL_UPDATE_DRAW ioctl argument type.
+     */
+    pub struct drm_update_draw {
+        pub handle: drm_drawable_t,
+        pub type_: c_uint,
+        pub num: c_uint,
+        pub data: c_ulonglong,
+    }
+
+    /*
+     * DRM_IOCTL_GET_MAGIC and DRM_IOCTL_AUTH_MAGIC ioctl argument type.
+     */
+    pub struct drm_auth {
+        pub magic: drm_magic_t,
+    }
+
+    /*
+     * DRM_IOCTL_IRQ_BUSID ioctl argument type.
+     *
+     * See also drm_irq_busid.
+     */
+    pub struct drm_irq_busid {
+        /* IRQ number */
+        pub irq: c_int,
+        /* bus number */
+        pub busnum: c_int,
+        /* device number */
+        pub devnum: c_int,
+        /* function number */
+        pub funcnum: c_int,
+    }
+}
+
+c_enum! {
+    pub enum drm_vblank_seq_type {
+        /* Wait for specific vblank sequence number */
+        pub _DRM_VBLANK_ABSOLUTE = 0x0,
+        /* Wait for given number of vblanks */
+        pub _DRM_VBLANK_RELATIVE = 0x1,
+        /* bits 1-6 are reserved for high crtcs */
+        pub _DRM_VBLANK_HIGH_CRTC_MASK = 0x0000003e,
+        /* Send event instead of blocking */
+        pub _DRM_VBLANK_EVENT = 0x4000000,
+        /* Scheduled buffer swap should flip */
+        pub _DRM_VBLANK_FLIP = 0x8000000,
+        /* If missed, wait for next vblank */
+        pub _DRM_VBLANK_NEXTONMISS = 0x10000000,
+        /* Secondary display controller */
+        pub _DRM_VBLANK_SECONDARY = 0x20000000,
+        /* Send signal instead of blocking, unsupported */
+        pub _DRM_VBLANK_SIGNAL = 0x40000000,
+    }
+}
+
+pub const _DRM_VBLANK_HIGH_CRTC_SHIFT: c_int = 1;
+
+pub const _DRM_VBLANK_TYPES_MASK: drm_vblank_seq_type = _DRM_VBLANK_ABSOLUTE | _DRM_VBLANK_RELATIVE;
+pub const _DRM_VBLANK_FLAGS_MASK: drm_vblank_seq_type =
+    _DRM_VBLANK_EVENT | _DRM_VBLANK_SIGNAL | _DRM_VBLANK_SECONDARY | _DRM_VBLANK_NEXTONMISS;
+
+s! {
+    pub struct drm_wait_vblank_request {
+        pub type_: drm_vblank_seq_type,
+        pub sequence: c_uint,
+        pub signal: c_ulong,
+    }
+
+    pub struct drm_wait_vblank_reply {
+        pub type_: drm_vblank_seq_type,
+        pub sequence: c_uint,
+        pub tval_sec: c_long,
+        pub tval_usec: c_long,
+    }
+}
+
+s_no_extra_traits! {
+    /*
+     * DRM_IOCTL_WAIT_VBLANK ioctl argument type.
+     *
+     * See also drm_wait_vblank().
+     */
+    pub union drm_wait_vblank {
+        pub request: drm_wait_vblank_request,
+        pub reply: drm_wait_vblank_reply,
+    }
+}
+
+pub const _DRM_PRE_MODESET: c_int = 1;
+pub const _DRM_POST_MODESET: c_int = 2;
+
+s! {
+    /*
+     * DRM_IOCTL_MODESET_CTL ioctl argument type
+     *
+     * See also drm_modeset_ctl().
+     */
+    pub struct drm_modeset_ctl {
+        pub crtc: crate::__u32,
+        pub cmd: crate::__u32,
+    }
+
+    /*
+     * DRM_IOCTL_AGP_ENABLE ioctl argument type.
+     */
+    pub struct drm_agp_mode {
+        /* AGP mode */
+        pub mode: c_ulong,
+    }
+
+    /*
+     * DRM_IOCTL_AGP_ALLOC and DRM_IOCTL_AGP_FREE ioctls argument type.
+     */
+    pub struct drm_agp_buffer {
+        /* In bytes -- will round to page boundary */
+        pub size: c_ulong,
+        /* Used for binding / unbinding */
+        pub handle: c_ulong,
+        /* Type of memory to allocate */
+        pub type_: c_ulong,
+        /* Physical used by i810 */
+        pub physical: c_ulong,
+    }
+
+    /*
+     * DRM_IOCTL_AGP_BIND and DRM_IOCTL_AGP_UNBIND ioctls argument type.
+     */
+    pub struct drm_agp_binding {
+        /* From drm_agp_buffer */
+        pub handle: c_ulong,
+        /* In bytes -- will round to page boundary */
+        pub offset: c_ulong,
+    }
+
+    /*
+     * DRM_IOCTL_AGP_INFO ioctl argument type.
+     */
+    pub struct drm_agp_info {
+        pub agp_version_major: c_int,
+        pub agp_version_minor: c_int,
+        pub mode: c_ulong,
+        /* physical address */
+        pub aperture_base: c_ulong,
+        /* bytes */
+        pub aperture_size: c_ulong,
+        /* bytes */
+        pub memory_allowed: c_ulong,
+        pub memory_used: c_ulong,
+
+        /* PCI information */
+        pub id_vendor: c_ushort,
+        pub id_device: c_ushort,
+    }
+
+    /*
+     * DRM_IOCTL_SG_ALLOC ioctl argument type.
+     */
+    pub struct drm_scatter_gather {
+        /* In bytes -- will round to page boundary */
+        pub size: c_ulong,
+        /* Used for mapping / unmapping */
+        pub handle: c_ulong,
+    }
+
+    /*
+     * DRM_IOCTL_SET_VERSION ioctl argument type.
+     */
+    pub struct drm_set_version {
+        pub drm_di_major: c_int,
+        pub drm_di_minor: c_int,
+        pub drm_dd_major: c_int,
+        pub drm_dd_minor: c_int,
+    }
+
+    /*
+     * struct drm_gem_close - Argument for &DRM_IOCTL_GEM_CLOSE ioctl.
+     * @handle: Handle of the object to be closed.
+     * @pad: Padding.
+     *
+     * Releases the handle to an mm object.
+     */
+    pub struct drm_gem_close {
+        pub handle: crate::__u32,
+        pub pad: crate::__u32,
+    }
+
+    /*
+     * struct drm_gem_flink - Argument for &DRM_IOCTL_GEM_FLINK ioctl.
+     * @handle: Handle for the object being named.
+     * @name: Returned global name.
+     *
+     * Create a global name for an object, returning the name.
+     *
+     * Note that the name does not hold a reference; when the object
+     * is freed, the name goes away.
+     */
+    pub struct drm_gem_flink {
+        pub handle: crate::__u32,
+        pub name: crate::__u32,
+    }
+
+    /*
+     * struct drm_gem_open - Argument for &DRM_IOCTL_GEM_OPEN ioctl.
+     * @name: Name of object being opened.
+     * @handle: Returned handle for the object.
+     * @size: Returned size of the object
+     *
+     * Open an object using the global name, returning a handle and the size.
+     *
+     * This handle (of course) holds a reference to the object, so the object
+     * will not go away until the handle is deleted.
+     */
+    pub struct drm_gem_open {
+        pub name: crate::__u32,
+        pub handle: crate::__u32,
+        pub size: crate::__u64,
+    }
+}
+
+/*
+ * DRM_CAP_DUMB_BUFFER
+ *
+ * If set to 1, the driver supports creating dumb buffers via the
+ * &DRM_IOCTL_MODE_CREATE_DUMB ioctl.
+ */
+pub const DRM_CAP_DUMB_BUFFER: crate::__u64 = 0x1;
+/*
+ * DRM_CAP_VBLANK_HIGH_CRTC
+ *
+ * If set to 1, the kernel supports specifying a :ref:`CRTC index<crtc_index>`
+ * in the high bits of &drm_wait_vblank_request.type.
+ *
+ * Starting kernel version 2.6.39, this capability is always set to 1.
+ */
+pub const DRM_CAP_VBLANK_HIGH_CRTC: crate::__u64 = 0x2;
+/*
+ * DRM_CAP_DUMB_PREFERRED_DEPTH
+ *
+ * The preferred bit depth for dumb buffers.
+ *
+ * The bit depth is the number of bits used to indicate the color of a single
+ * pixel excluding any padding. This is different from the number of bits per
+ * pixel. For instance, XRGB8888 has a bit depth of 24 but has 32 bits per
+ * pixel.
+ *
+ * Note that this preference only applies to dumb buffers, it's irrelevant for
+ * other types of buffers.
+ */
+pub const DRM_CAP_DUMB_PREFERRED_DEPTH: crate::__u64 = 0x3;
+/*
+ * DRM_CAP_DUMB_PREFER_SHADOW
+ *
+ * If set to 1, the driver prefers userspace to render to a shadow buffer
+ * instead of directly rendering to a dumb buffer. For best speed, userspace
+ * should do streaming ordered memory copies into the dumb buffer and never
+ * read from it.
+ *
+ * Note that this preference only applies to dumb buffers, it's irrelevant for
+ * other types of buffers.
+ */
+pub const DRM_CAP_DUMB_PREFER_SHADOW: crate::__u64 = 0x4;
+/*
+ * DRM_CAP_PRIME
+ *
+ * Bitfield of supported PRIME sharing capabilities. See &DRM_PRIME_CAP_IMPORT
+ * and &DRM_PRIME_CAP_EXPORT.
+ *
+ * Starting from kernel version 6.6, both &DRM_PRIME_CAP_IMPORT and
+ * &DRM_PRIME_CAP_EXPORT are always advertised.
+ *
+ * PRIME buffers are exposed as dma-buf file descriptors.
+ * See :ref:`prime_buffer_sharing`.
+ */
+pub const DRM_CAP_PRIME: crate::__u64 = 0x5;
+/*
+ * DRM_PRIME_CAP_IMPORT
+ *
+ * If this bit is set in &DRM_CAP_PRIME, the driver supports importing PRIME
+ * buffers via the &DRM_IOCTL_PRIME_FD_TO_HANDLE ioctl.
+ *
+ * Starting from kernel version 6.6, this bit is always set in &DRM_CAP_PRIME.
+ */
+pub const DRM_PRIME_CAP_IMPORT: crate::__u64 = 0x1;
+/*
+ * DRM_PRIME_CAP_EXPORT
+ *
+ * If this bit is set in &DRM_CAP_PRIME, the driver supports exporting PRIME
+ * buffers via the &DRM_IOCTL_PRIME_HANDLE_TO_FD ioctl.
+ *
+ * Starting from kernel version 6.6, this bit is always set in &DRM_CAP_PRIME.
+ */
+pub const DRM_PRIME_CAP_EXPORT: crate::__u64 = 0x2;
+/*
+ * DRM_CAP_TIMESTAMP_MONOTONIC
+ *
+ * If set to 0, the kernel will report timestamps with ``CLOCK_REALTIME`` in
+ * struct drm_event_vblank. If set to 1, the kernel will report timestamps with
+ * ``CLOCK_MONOTONIC``. See ``clock_gettime(2)`` for the definition of these
+ * clocks.
+ *
+ * Starting from kernel version 2.6.39, the default value for this capability
+ * is 1. Starting kernel version 4.15, this capability is always set to 1.
+ */
+pub const DRM_CAP_TIMESTAMP_MONOTONIC: crate::__u64 = 0x6;
+/*
+ * DRM_CAP_ASYNC_PAGE_FLIP
+ *
+ * If set to 1, the driver supports &DRM_MODE_PAGE_FLIP_ASYNC for legacy
+ * page-flips.
+ */
+pub const DRM_CAP_ASYNC_PAGE_FLIP: crate::__u64 = 0x7;
+/*
+ * DRM_CAP_CURSOR_WIDTH
+ *
+ * The ``CURSOR_WIDTH`` and ``CURSOR_HEIGHT`` capabilities return a valid
+ * width x height combination for the hardware cursor. The intention is that a
+ * hardware agnostic userspace can query a cursor plane size to use.
+ *
+ * Note that the cross-driver contract is to merely return a valid size;
+ * drivers are free to attach another meaning on top, eg. i915 returns the
+ * maximum plane size.
+ */
+pub const DRM_CAP_CURSOR_WIDTH: crate::__u64 = 0x8;
+/*
+ * DRM_CAP_CURSOR_HEIGHT
+ *
+ * See &DRM_CAP_CURSOR_WIDTH.
+ */
+pub const DRM_CAP_CURSOR_HEIGHT: crate::__u64 = 0x9;
+/*
+ * DRM_CAP_ADDFB2_MODIFIERS
+ *
+ * If set to 1, the driver supports supplying modifiers in the
+ * &DRM_IOCTL_MODE_ADDFB2 ioctl.
+ */
+pub const DRM_CAP_ADDFB2_MODIFIERS: crate::__u64 = 0x10;
+/*
+ * DRM_CAP_PAGE_FLIP_TARGET
+ *
+ * If set to 1, the driver supports the &DRM_MODE_PAGE_FLIP_TARGET_ABSOLUTE and
+ * &DRM_MODE_PAGE_FLIP_TARGET_RELATIVE flags in
+ * &drm_mode_crtc_page_flip_target.flags for the &DRM_IOCTL_MODE_PAGE_FLIP
+ * ioctl.
+ */
+pub const DRM_CAP_PAGE_FLIP_TARGET: crate::__u64 = 0x11;
+/*
+ * DRM_CAP_CRTC_IN_VBLANK_EVENT
+ *
+ * If set to 1, the kernel supports reporting the CRTC ID in
+ * &drm_event_vblank.crtc_id for the &DRM_EVENT_VBLANK and
+ * &DRM_EVENT_FLIP_COMPLETE events.
+ *
+ * Starting kernel version 4.12, this capability is always set to 1.
+ */
+pub const DRM_CAP_CRTC_IN_VBLANK_EVENT: crate::__u64 = 0x12;
+/*
+ * DRM_CAP_SYNCOBJ
+ *
+ * If set to 1, the driver supports sync objects. See :ref:`drm_sync_objects`.
+ */
+pub const DRM_CAP_SYNCOBJ: crate::__u64 = 0x13;
+/*
+ * DRM_CAP_SYNCOBJ_TIMELINE
+ *
+ * If set to 1, the driver supports timeline operations on sync objects. See
+ * :ref:`drm_sync_objects`.
+ */
+pub const DRM_CAP_SYNCOBJ_TIMELINE: crate::__u64 = 0x14;
+/*
+ * DRM_CAP_ATOMIC_ASYNC_PAGE_FLIP
+ *
+ * If set to 1, the driver supports &DRM_MODE_PAGE_FLIP_ASYNC for atomic
+ * commits.
+ */
+pub const DRM_CAP_ATOMIC_ASYNC_PAGE_FLIP: crate::__u64 = 0x15;
+
+s! {
+    /* DRM_IOCTL_GET_CAP ioctl argument type */
+    pub struct drm_get_cap {
+        pub capability: crate::__u64,
+        pub value: crate::__u64,
+    }
+}
+
+/*
+ * DRM_CLIENT_CAP_STEREO_3D
+ *
+ * If set to 1, the DRM core will expose the stereo 3D capabilities of the
+ * monitor by advertising the supported 3D layouts in the flags of struct
+ * drm_mode_modeinfo. See ``DRM_MODE_FLAG_3D_*``.
+ *
+ * This capability is always supported for all drivers starting from kernel
+ * version 3.13.
+ */
+pub const DRM_CLIENT_CAP_STEREO_3D: crate::__u64 = 1;
+
+/*
+ * DRM_CLIENT_CAP_UNIVERSAL_PLANES
+ *
+ * If set to 1, the DRM core will expose all planes (overlay, primary, and
+ * cursor) to userspace.
+ *
+ * This capability has been introduced in kernel version 3.15. Starting from
+ * kernel version 3.17, this capability is always supported for all drivers.
+ */
+pub const DRM_CLIENT_CAP_UNIVERSAL_PLANES: crate::__u64 = 2;
+
+/*
+ * DRM_CLIENT_CAP_ATOMIC
+ *
+ * If set to 1, the DRM core will expose atomic properties to userspace. This
+ * implicitly enables &DRM_CLIENT_CAP_UNIVERSAL_PLANES and
+ * &DRM_CLIENT_CAP_ASPECT_RATIO.
+ *
+ * If the driver doesn't support atomic mode-setting, enabling this capability
+ * will fail with -EOPNOTSUPP.
+ *
+ * This capability has been introduced in kernel version 4.0. Starting from
+ * kernel version 4.2, this capability is always supported for atomic-capable
+ * drivers.
+ */
+pub const DRM_CLIENT_CAP_ATOMIC: crate::__u64 = 3;
+
+/*
+ * DRM_CLIENT_CAP_ASPECT_RATIO
+ *
+ * If set to 1, the DRM core will provide aspect ratio information in modes.
+ * See ``DRM_MODE_FLAG_PIC_AR_*``.
+ *
+ * This capability is always supported for all drivers starting from kernel
+ * version 4.18.
+ */
+pub const DRM_CLIENT_CAP_ASPECT_RATIO: crate::__u64 = 4;
+
+/*
+ * DRM_CLIENT_CAP_WRITEBACK_CONNECTORS
+ *
+ * If set to 1, the DRM core will expose special connectors to be used for
+ * writing back to memory the scene setup in the commit. The client must enable
+ * &DRM_CLIENT_CAP_ATOMIC first.
+ *
+ * This capability is always supported for atomic-capable drivers starting from
+ * kernel version 4.19.
+ */
+pub const DRM_CLIENT_CAP_WRITEBACK_CONNECTORS: crate::__u64 = 5;
+
+/*
+ * DRM_CLIENT_CAP_CURSOR_PLANE_HOTSPOT
+ *
+ * Drivers for para-virtualized hardware (e.g. vmwgfx, qxl, virtio and
+ * virtualbox) have additional restrictions for cursor planes (thus
+ * making cursor planes on those drivers not truly universal,) e.g.
+ * they need cursor planes to act like one would expect from a mouse
+ * cursor and have correctly set hotspot properties.
+ * If this client cap is not set the DRM core will hide cursor plane on
+ * those virtualized drivers because not setting it implies that the
+ * client is not capable of dealing with those extra restictions.
+ * Clients which do set cursor hotspot and treat the cursor plane
+ * like a mouse cursor should set this property.
+ * The client must enable &DRM_CLIENT_CAP_ATOMIC first.
+ *
+ * Setting this property on drivers which do not special case
+ * cursor planes (i.e. non-virtualized drivers) will return
+ * EOPNOTSUPP, which can be used by userspace to gauge
+ * requirements of the hardware/drivers they're running on.
+ *
+ * This capability is always supported for atomic-capable virtualized
+ * drivers starting from kernel version 6.6.
+ */
+pub const DRM_CLIENT_CAP_CURSOR_PLANE_HOTSPOT: crate::__u64 = 6;
+
+s! {
+    /* DRM_IOCTL_SET_CLIENT_CAP ioctl argument type */
+    pub struct drm_set_client_cap {
+        pub capability: crate::__u64,
+        pub value: crate::__u64,
+    }
+}
+
+pub const DRM_RDWR: c_int = crate::O_RDWR;
+pub const DRM_CLOEXEC: c_int = crate::O_CLOEXEC;
+
+s! {
+    pub struct drm_prime_handle {
+        pub handle: crate::__u32,
+
+        /* Flags.. only applicable for handle->fd */
+        pub flags: crate::__u32,
+
+        /* Returned dmabuf file descriptor */
+        pub fd: crate::__s32,
+    }
+
+    pub struct drm_syncobj_create {
+        pub handle: crate::__u32,
+        pub flags: crate::__u32,
+    }
+}
+
+pub const DRM_SYNCOBJ_CREATE_SIGNALED: crate::__u32 = 1 << 0;
+
+s! {
+    pub struct drm_syncobj_destroy {
+        pub handle: crate::__u32,
+        pub pad: crate::__u32,
+    }
+}
+
+pub const DRM_SYNCOBJ_FD_TO_HANDLE_FLAGS_IMPORT_SYNC_FILE: crate::__u32 = 1 << 0;
+pub const DRM_SYNCOBJ_HANDLE_TO_FD_FLAGS_EXPORT_SYNC_FILE: crate::__u32 = 1 << 0;
+
+s! {
+    pub struct drm_syncobj_handle {
+        pub handle: crate::__u32,
+        pub flags: crate::__u32,
+
+        pub fd: crate::__s32,
+        pub pad: crate::__u32,
+    }
+
+    pub struct drm_syncobj_transfer {
+        pub src_handle: crate::__u32,
+        pub dst_handle: crate::__u32,
+        pub src_point: crate::__u64,
+        pub dst_point: crate::__u64,
+        pub flags: crate::__u32,
+        pub pad: crate::__u32,
+    }
+}
+
+pub const DRM_SYNCOBJ_WAIT_FLAGS_WAIT_ALL: crate::__u32 = 1 << 0;
+pub const DRM_SYNCOBJ_WAIT_FLAGS_WAIT_FOR_SUBMIT: crate::__u32 = 1 << 1;
+/* wait for time point to become available */
+pub const DRM_SYNCOBJ_WAIT_FLAGS_WAIT_AVAILABLE: crate::__u32 = 1 << 2;
+/* set fence deadline to deadline_nsec */
+pub const DRM_SYNCOBJ_WAIT_FLAGS_WAIT_DEADLINE: crate::__u32 = 1 << 3;
+
+s! {
+    pub struct drm_syncobj_wait {
+        pub handles: crate::__u64,
+        /* absolute timeout */
+        pub timeout_nsec: crate::__s64,
+        pub count_handles: crate::__u32,
+        pub flags: crate::__u32,
+        /* only valid when not waiting all */
+        pub first_signaled: crate::__u32,
+        pub pad: crate::__u32,
+        /*
+         * @deadline_nsec - fence deadline hint
+         *
+         * Deadline hint, in absolute CLOCK_MONOTONIC, to set on backing
+         * fence(s) if the DRM_SYNCOBJ_WAIT_FLAGS_WAIT_DEADLINE flag is
+         * set.
+         */
+        pub deadline_nsec: crate::__u64,
+    }
+
+    pub struct drm_syncobj_timeline_wait {
+        pub handles: crate::__u64,
+        /* wait on specific timeline point for every handles*/
+        pub points: crate::__u64,
+        /* absolute timeout */
+        pub timeout_nsec: crate::__s64,
+        pub count_handles: crate::__u32,
+        pub flags: crate::__u32,
+        /* only valid when not waiting all */
+        pub first_signaled: crate::__u32,
+        pub pad: crate::__u32,
+        /*
+         * @deadline_nsec - fence deadline hint
+         *
+         * Deadline hint, in absolute CLOCK_MONOTONIC, to set on backing
+         * fence(s) if the DRM_SYNCOBJ_WAIT_FLAGS_WAIT_DEADLINE flag is
+         * set.
+         */
+        pub deadline_nsec: crate::__u64,
+    }
+
+    /*
+     * struct drm_syncobj_eventfd
+     * @handle: syncobj handle.
+     * @flags: Zero to wait for the point to be signalled, or
+     *         &DRM_SYNCOBJ_WAIT_FLAGS_WAIT_AVAILABLE to wait for a fence to be
+     *         available for the point.
+     * @point: syncobj timeline point (set to zero for binary syncobjs).
+     * @fd: Existing eventfd to sent events to.
+     * @pad: Must be zero.
+     *
+     * Register an eventfd to be signalled by a syncobj. The eventfd counter will
+     * be incremented by one.
+     */
+    pub struct drm_syncobj_eventfd {
+        pub handle: crate::__u32,
+        pub flags: crate::__u32,
+        pub point: crate::__u64,
+        pub fd: crate::__s32,
+        pub pad: crate::__u32,
+    }
+
+    pub struct drm_syncobj_array {
+        pub handles: crate::__u64,
+        pub count_handles: crate::__u32,
+        pub pad: crate::__u32,
+    }
+}
+
+/* last available point on timeline syncobj */
+pub const DRM_SYNCOBJ_QUERY_FLAGS_LAST_SUBMITTED: crate::__u32 = 1 << 0;
+
+s! {
+    pub struct drm_syncobj_timeline_array {
+        pub handles: crate::__u64,
+        pub points: crate::__u64,
+        pub count_handles: crate::__u32,
+        pub flags: crate::__u32,
+    }
+
+    /* Query current scanout sequence number */
+    pub struct drm_crtc_get_sequence {
+        /* requested crtc_id */
+        pub crtc_id: crate::__u32,
+        /* return: crtc output is active */
+        pub active: crate::__u32,
+        /* return: most recent vblank sequence */
+        pub sequence: crate::__u64,
+        /* return: most recent time of first pixel out */
+        pub sequence_ns: crate::__s64,
+    }
+}
+
+/* Queue event to be delivered at specified sequence. Time stamp marks
+ * when the first pixel of the refresh cycle leaves the display engine
+ * for the display
+ */
+/* sequence is relative to current */
+pub const DRM_CRTC_SEQUENCE_RELATIVE: crate::__u32 = 0x00000001;
+/* Use next sequence if we've missed */
+pub const DRM_CRTC_SEQUENCE_NEXT_ON_MISS: crate::__u32 = 0x00000002;
+
+s! {
+    pub struct drm_crtc_queue_sequence {
+        pub crtc_id: crate::__u32,
+        pub flags: crate::__u32,
+        /* on input, target sequence. on output, actual sequence */
+        pub sequence: crate::__u64,
+        /* user data passed to event */
+        pub user_data: crate::__u64,
+    }
+}
+
+pub const DRM_IOCTL_BASE: c_uint = b'd' as c_uint;
+
+pub const DRM_IOCTL_VERSION: Ioctl = _IOWR::<drm_version>(DRM_IOCTL_BASE, 0x00);
+pub const DRM_IOCTL_GET_UNIQUE: Ioctl = _IOWR::<drm_unique>(DRM_IOCTL_BASE, 0x01);
+pub const DRM_IOCTL_GET_MAGIC: Ioctl = _IOR::<drm_auth>(DRM_IOCTL_BASE, 0x02);
+pub const DRM_IOCTL_IRQ_BUSID: Ioctl = _IOWR::<drm_irq_busid>(DRM_IOCTL_BASE, 0x03);
+pub const DRM_IOCTL_GET_MAP: Ioctl = _IOWR::<drm_map>(DRM_IOCTL_BASE, 0x04);
+pub const DRM_IOCTL_GET_CLIENT: Ioctl = _IOWR::<drm_client>(DRM_IOCTL_BASE, 0x05);
+pub const DRM_IOCTL_GET_STATS: Ioctl = _IOR::<drm_stats>(DRM_IOCTL_BASE, 0x06);
+pub const DRM_IOCTL_SET_VERSION: Ioctl = _IOWR::<drm_set_version>(DRM_IOCTL_BASE, 0x07);
+pub const DRM_IOCTL_MODESET_CTL: Ioctl = _IOW::<drm_modeset_ctl>(DRM_IOCTL_BASE, 0x08);
+/* DRM_IOCTL_GEM_CLOSE - Close a GEM handle. */
+pub const DRM_IOCTL_GEM_CLOSE: Ioctl = _IOW::<drm_gem_close>(DRM_IOCTL_BASE, 0x09);
+pub const DRM_IOCTL_GEM_FLINK: Ioctl = _IOWR::<drm_gem_flink>(DRM_IOCTL_BASE, 0x0a);
+pub const DRM_IOCTL_GEM_OPEN: Ioctl = _IOWR::<drm_gem_open>(DRM_IOCTL_BASE, 0x0b);
+pub const DRM_IOCTL_GET_CAP: Ioctl = _IOWR::<drm_get_cap>(DRM_IOCTL_BASE, 0x0c);
+pub const DRM_IOCTL_SET_CLIENT_CAP: Ioctl = _IOW::<drm_set_client_cap>(DRM_IOCTL_BASE, 0x0d);
+
+pub const DRM_IOCTL_SET_UNIQUE: Ioctl = _IOW::<drm_unique>(DRM_IOCTL_BASE, 0x10);
+pub const DRM_IOCTL_AUTH_MAGIC: Ioctl = _IOW::<drm_auth>(DRM_IOCTL_BASE, 0x11);
+pub const DRM_IOCTL_BLOCK: Ioctl = _IOWR::<drm_block>(DRM_IOCTL_BASE, 0x12);
+pub const DRM_IOCTL_UNBLOCK: Ioctl = _IOWR::<drm_block>(DRM_IOCTL_BASE, 0x13);
+pub const DRM_IOCTL_CONTROL: Ioctl = _IOW::<drm_control>(DRM_IOCTL_BASE, 0x14);
+pub const DRM_IOCTL_ADD_MAP: Ioctl = _IOWR::<drm_map>(DRM_IOCTL_BASE, 0x15);
+pub const DRM_IOCTL_ADD_BUFS: Ioctl = _IOWR::<drm_buf_desc>(DRM_IOCTL_BASE, 0x16);
+pub const DRM_IOCTL_MARK_BUFS: Ioctl = _IOW::<drm_buf_desc>(DRM_IOCTL_BASE, 0x17);
+pub const DRM_IOCTL_INFO_BUFS: Ioctl = _IOWR::<drm_buf_info>(DRM_IOCTL_BASE, 0x18);
+pub const DRM_IOCTL_MAP_BUFS: Ioctl = _IOWR::<drm_buf_map>(DRM_IOCTL_BASE, 0x19);
+pub const DRM_IOCTL_FREE_BUFS: Ioctl = _IOW::<drm_buf_free>(DRM_IOCTL_BASE, 0x1a);
+
+pub const DRM_IOCTL_RM_MAP: Ioctl = _IOW::<drm_map>(DRM_IOCTL_BASE, 0x1b);
+
+pub const DRM_IOCTL_SET_SAREA_CTX: Ioctl = _IOW::<drm_ctx_priv_map>(DRM_IOCTL_BASE, 0x1c);
+pub const DRM_IOCTL_GET_SAREA_CTX: Ioctl = _IOWR::<drm_ctx_priv_map>(DRM_IOCTL_BASE, 0x1d);
+
+pub const DRM_IOCTL_SET_MASTER: Ioctl = _IO(DRM_IOCTL_BASE, 0x1e);
+pub const DRM_IOCTL_DROP_MASTER: Ioctl = _IO(DRM_IOCTL_BASE, 0x1f);
+
+pub const DRM_IOCTL_ADD_CTX: Ioctl = _IOWR::<drm_ctx>(DRM_IOCTL_BASE, 0x20);
+pub const DRM_IOCTL_RM_CTX: Ioctl = _IOWR::<drm_ctx>(DRM_IOCTL_BASE, 0x21);
+pub const DRM_IOCTL_MOD_CTX: Ioctl = _IOW::<drm_ctx>(DRM_IOCTL_BASE, 0x22);
+pub const DRM_IOCTL_GET_CTX: Ioctl = _IOWR::<drm_ctx>(DRM_IOCTL_BASE, 0x23);
+pub const DRM_IOCTL_SWITCH_CTX: Ioctl = _IOW::<drm_ctx>(DRM_IOCTL_BASE, 0x24);
+pub const DRM_IOCTL_NEW_CTX: Ioctl = _IOW::<drm_ctx>(DRM_IOCTL_BASE, 0x25);
+pub const DRM_IOCTL_RES_CTX: Ioctl = _IOWR::<drm_ctx_res>(DRM_IOCTL_BASE, 0x26);
+pub const DRM_IOCTL_ADD_DRAW: Ioctl = _IOWR::<drm_draw>(DRM_IOCTL_BASE, 0x27);
+pub const DRM_IOCTL_RM_DRAW: Ioctl = _IOWR::<drm_draw>(DRM_IOCTL_BASE, 0x28);
+pub const DRM_IOCTL_DMA: Ioctl = _IOWR::<drm_dma>(DRM_IOCTL_BASE, 0x29);
+pub const DRM_IOCTL_LOCK: Ioctl = _IOW::<drm_lock>(DRM_IOCTL_BASE, 0x2a);
+pub const DRM_IOCTL_UNLOCK: Ioctl = _IOW::<drm_lock>(DRM_IOCTL_BASE, 0x2b);
+pub const DRM_IOCTL_FINISH: Ioctl = _IOW::<drm_lock>(DRM_IOCTL_BASE, 0x2c);
+
+pub const DRM_IOCTL_PRIME_HANDLE_TO_FD: Ioctl = _IOWR::<drm_prime_handle>(DRM_IOCTL_BASE, 0x2d);
+pub const DRM_IOCTL_PRIME_FD_TO_HANDLE: Ioctl = _IOWR::<drm_prime_handle>(DRM_IOCTL_BASE, 0x2e);
+
+pub const DRM_IOCTL_AGP_ACQUIRE: Ioctl = _IO(DRM_IOCTL_BASE, 0x30);
+pub const DRM_IOCTL_AGP_RELEASE: Ioctl = _IO(DRM_IOCTL_BASE, 0x31);
+pub const DRM_IOCTL_AGP_ENABLE: Ioctl = _IOW::<drm_agp_mode>(DRM_IOCTL_BASE, 0x32);
+pub const DRM_IOCTL_AGP_INFO: Ioctl = _IOR::<drm_agp_info>(DRM_IOCTL_BASE, 0x33);
+pub const DRM_IOCTL_AGP_ALLOC: Ioctl = _IOWR::<drm_agp_buffer>(DRM_IOCTL_BASE, 0x34);
+pub const DRM_IOCTL_AGP_FREE: Ioctl = _IOW::<drm_agp_buffer>(DRM_IOCTL_BASE, 0x35);
+pub const DRM_IOCTL_AGP_BIND: Ioctl = _IOW::<drm_agp_binding>(DRM_IOCTL_BASE, 0x36);
+pub const DRM_IOCTL_AGP_UNBIND: Ioctl = _IOW::<drm_agp_binding>(DRM_IOCTL_BASE, 0x37);
+
+pub const DRM_IOCTL_SG_ALLOC: Ioctl = _IOWR::<drm_scatter_gather>(DRM_IOCTL_BASE, 0x38);
+pub const DRM_IOCTL_SG_FREE: Ioctl = _IOW::<drm_scatter_gather>(DRM_IOCTL_BASE, 0x39);
+
+pub const DRM_IOCTL_WAIT_VBLANK: Ioctl = _IOWR::<drm_wait_vblank>(DRM_IOCTL_BASE, 0x3a);
+
+pub const DRM_IOCTL_CRTC_GET_SEQUENCE: Ioctl = _IOWR::<drm_crtc_get_sequence>(DRM_IOCTL_BASE, 0x3b);
+pub const DRM_IOCTL_CRTC_QUEUE_SEQUENCE: Ioctl =
+    _IOWR::<drm_crtc_queue_sequence>(DRM_IOCTL_BASE, 0x3c);
+
+pub const DRM_IOCTL_UPDATE_DRAW: Ioctl = _IOW::<drm_update_draw>(DRM_IOCTL_BASE, 0x3f);
+
+pub const DRM_IOCTL_MODE_GETRESOURCES: Ioctl =
+    _IOWR::<crate::drm_mode_card_res>(DRM_IOCTL_BASE, 0xA0);
+pub const DRM_IOCTL_MODE_GETCRTC: Ioctl = _IOWR::<crate::drm_mode_crtc>(DRM_IOCTL_BASE, 0xA1);
+pub const DRM_IOCTL_MODE_SETCRTC: Ioctl = _IOWR::<crate::drm_mode_crtc>(DRM_IOCTL_BASE, 0xA2);
+pub const DRM_IOCTL_MODE_CURSOR: Ioctl = _IOWR::<crate::drm_mode_cursor>(DRM_IOCTL_BASE, 0xA3);
+pub const DRM_IOCTL_MODE_GETGAMMA: Ioctl = _IOWR::<crate::drm_mode_crtc_lut>(DRM_IOCTL_BASE, 0xA4);
+pub const DRM_IOCTL_MODE_SETGAMMA: Ioctl = _IOWR::<crate::drm_mode_crtc_lut>(DRM_IOCTL_BASE, 0xA5);
+pub const DRM_IOCTL_MODE_GETENCODER: Ioctl =
+    _IOWR::<crate::drm_mode_get_encoder>(DRM_IOCTL_BASE, 0xA6);
+pub const DRM_IOCTL_MODE_GETCONNECTOR: Ioctl =
+    _IOWR::<crate::drm_mode_get_connector>(DRM_IOCTL_BASE, 0xA7);
+/* deprecated (never worked) */
+pub const DRM_IOCTL_MODE_ATTACHMODE: Ioctl =
+    _IOWR::<crate::drm_mode_mode_cmd>(DRM_IOCTL_BASE, 0xA8);
+/* deprecated (never worked) */
+pub const DRM_IOCTL_MODE_DETACHMODE: Ioctl =
+    _IOWR::<crate::drm_mode_mode_cmd>(DRM_IOCTL_BASE, 0xA9);
+
+pub const DRM_IOCTL_MODE_GETPROPERTY: Ioctl =
+    _IOWR::<crate::drm_mode_get_property>(DRM_IOCTL_BASE, 0xAA);
+pub const DRM_IOCTL_MODE_SETPROPERTY: Ioctl =
+    _IOWR::<crate::drm_mode_connector_set_property>(DRM_IOCTL_BASE, 0xAB);
+pub const DRM_IOCTL_MODE_GETPROPBLOB: Ioctl =
+    _IOWR::<crate::drm_mode_get_blob>(DRM_IOCTL_BASE, 0xAC);
+pub const DRM_IOCTL_MODE_GETFB: Ioctl = _IOWR::<crate::drm_mode_fb_cmd>(DRM_IOCTL_BASE, 0xAD);
+pub const DRM_IOCTL_MODE_ADDFB: Ioctl = _IOWR::<crate::drm_mode_fb_cmd>(DRM_IOCTL_BASE, 0xAE);
+/*
+ * DRM_IOCTL_MODE_RMFB - Remove a framebuffer.
+ *
+ * This removes a framebuffer previously added via ADDFB/ADDFB2. The IOCTL
+ * argument is a framebuffer object ID.
+ *
+ * Warning: removing a framebuffer currently in-use on an enabled plane will
+ * disable that plane. The CRTC the plane is linked to may also be disabled
+ * (depending on driver capabilities).
+ */
+pub const DRM_IOCTL_MODE_RMFB: Ioctl = _IOWR::<c_uint>(DRM_IOCTL_BASE, 0xAF);
+pub const DRM_IOCTL_MODE_PAGE_FLIP: Ioctl =
+    _IOWR::<crate::drm_mode_crtc_page_flip>(DRM_IOCTL_BASE, 0xB0);
+pub const DRM_IOCTL_MODE_DIRTYFB: Ioctl =
+    _IOWR::<crate::drm_mode_fb_dirty_cmd>(DRM_IOCTL_BASE, 0xB1);
+
+/*
+ * DRM_IOCTL_MODE_CREATE_DUMB - Create a new dumb buffer object.
+ *
+ * KMS dumb buffers provide a very primitive way to allocate a buffer object
+ * suitable for scanout and map it for software rendering. KMS dumb buffers are
+ * not suitable for hardware-accelerated rendering nor video decoding. KMS dumb
+ * buffers are not suitable to be displayed on any other device than the KMS
+ * device where they were allocated from. Also see
+ * :ref:`kms_dumb_buffer_objects`.
+ *
+ * The IOCTL argument is a struct drm_mode_create_dumb.
+ *
+ * User-space is expected to create a KMS dumb buffer via this IOCTL, then add
+ * it as a KMS framebuffer via &DRM_IOCTL_MODE_ADDFB and map it via
+ * &DRM_IOCTL_MODE_MAP_DUMB.
+ *
+ * &DRM_CAP_DUMB_BUFFER indicates whether this IOCTL is supported.
+ * &DRM_CAP_DUMB_PREFERRED_DEPTH and &DRM_CAP_DUMB_PREFER_SHADOW indicate
+ * driver preferences for dumb buffers.
+ */
+pub const DRM_IOCTL_MODE_CREATE_DUMB: Ioctl =
+    _IOWR::<crate::drm_mode_create_dumb>(DRM_IOCTL_BASE, 0xB2);
+pub const DRM_IOCTL_MODE_MAP_DUMB: Ioctl = _IOWR::<crate::drm_mode_map_dumb>(DRM_IOCTL_BASE, 0xB3);
+pub const DRM_IOCTL_MODE_DESTROY_DUMB: Ioctl =
+    _IOWR::<crate::drm_mode_destroy_dumb>(DRM_IOCTL_BASE, 0xB4);
+pub const DRM_IOCTL_MODE_GETPLANERESOURCES: Ioctl =
+    _IOWR::<crate::drm_mode_get_plane_res>(DRM_IOCTL_BASE, 0xB5);
+pub const DRM_IOCTL_MODE_GETPLANE: Ioctl = _IOWR::<crate::drm_mode_get_plane>(DRM_IOCTL_BASE, 0xB6);
+pub const DRM_IOCTL_MODE_SETPLANE: Ioctl = _IOWR::<crate::drm_mode_set_plane>(DRM_IOCTL_BASE, 0xB7);
+pub const DRM_IOCTL_MODE_ADDFB2: Ioctl = _IOWR::<crate::drm_mode_fb_cmd2>(DRM_IOCTL_BASE, 0xB8);
+pub const DRM_IOCTL_MODE_OBJ_GETPROPERTIES: Ioctl =
+    _IOWR::<crate::drm_mode_obj_get_properties>(DRM_IOCTL_BASE, 0xB9);
+pub const DRM_IOCTL_MODE_OBJ_SETPROPERTY: Ioctl =
+    _IOWR::<crate::drm_mode_obj_set_property>(DRM_IOCTL_BASE, 0xBA);
+pub const DRM_IOCTL_MODE_CURSOR2: Ioctl = _IOWR::<crate::drm_mode_cursor2>(DRM_IOCTL_BASE, 0xBB);
+pub const DRM_IOCTL_MODE_ATOMIC: Ioctl = _IOWR::<crate::drm_mode_atomic>(DRM_IOCTL_BASE, 0xBC);
+pub const DRM_IOCTL_MODE_CREATEPROPBLOB: Ioctl =
+    _IOWR::<crate::drm_mode_create_blob>(DRM_IOCTL_BASE, 0xBD);
+pub const DRM_IOCTL_MODE_DESTROYPROPBLOB: Ioctl =
+    _IOWR::<crate::drm_mode_destroy_blob>(DRM_IOCTL_BASE, 0xBE);
+
+pub const DRM_IOCTL_SYNCOBJ_CREATE: Ioctl = _IOWR::<drm_syncobj_create>(DRM_IOCTL_BASE, 0xBF);
+pub const DRM_IOCTL_SYNCOBJ_DESTROY: Ioctl = _IOWR::<drm_syncobj_destroy>(DRM_IOCTL_BASE, 0xC0);
+pub const DRM_IOCTL_SYNCOBJ_HANDLE_TO_FD: Ioctl = _IOWR::<drm_syncobj_handle>(DRM_IOCTL_BASE, 0xC1);
+pub const DRM_IOCTL_SYNCOBJ_FD_TO_HANDLE: Ioctl = _IOWR::<drm_syncobj_handle>(DRM_IOCTL_BASE, 0xC2);
+pub const DRM_IOCTL_SYNCOBJ_WAIT: Ioctl = _IOWR::<drm_syncobj_wait>(DRM_IOCTL_BASE, 0xC3);
+pub const DRM_IOCTL_SYNCOBJ_RESET: Ioctl = _IOWR::<drm_syncobj_array>(DRM_IOCTL_BASE, 0xC4);
+pub const DRM_IOCTL_SYNCOBJ_SIGNAL: Ioctl = _IOWR::<drm_syncobj_array>(DRM_IOCTL_BASE, 0xC5);
+
+pub const DRM_IOCTL_MODE_CREATE_LEASE: Ioctl =
+    _IOWR::<crate::drm_mode_create_lease>(DRM_IOCTL_BASE, 0xC6);
+pub const DRM_IOCTL_MODE_LIST_LESSEES: Ioctl =
+    _IOWR::<crate::drm_mode_list_lessees>(DRM_IOCTL_BASE, 0xC7);
+pub const DRM_IOCTL_MODE_GET_LEASE: Ioctl =
+    _IOWR::<crate::drm_mode_get_lease>(DRM_IOCTL_BASE, 0xC8);
+pub const DRM_IOCTL_MODE_REVOKE_LEASE: Ioctl =
+    _IOWR::<crate::drm_mode_revoke_lease>(DRM_IOCTL_BASE, 0xC9);
+
+pub const DRM_IOCTL_SYNCOBJ_TIMELINE_WAIT: Ioctl =
+    _IOWR::<drm_syncobj_timeline_wait>(DRM_IOCTL_BASE, 0xCA);
+pub const DRM_IOCTL_SYNCOBJ_QUERY: Ioctl =
+    _IOWR::<drm_syncobj_timeline_array>(DRM_IOCTL_BASE, 0xCB);
+pub const DRM_IOCTL_SYNCOBJ_TRANSFER: Ioctl = _IOWR::<drm_syncobj_transfer>(DRM_IOCTL_BASE, 0xCC);
+pub const DRM_IOCTL_SYNCOBJ_TIMELINE_SIGNAL: Ioctl =
+    _IOWR::<drm_syncobj_timeline_array>(DRM_IOCTL_BASE, 0xCD);
+
+/*
+ * DRM_IOCTL_MODE_GETFB2 - Get framebuffer metadata.
+ *
+ * This queries metadata about a framebuffer. User-space fills
+ * &drm_mode_fb_cmd2.fb_id as the input, and the kernels fills the rest of the
+ * struct as the output.
+ *
+ * If the client is DRM master or has &CAP_SYS_ADMIN, &drm_mode_fb_cmd2.handles
+ * will be filled with GEM buffer handles. Fresh new GEM handles are always
+ * returned, even if another GEM handle referring to the same memory object
+ * already exists on the DRM file description. The caller is responsible for
+ * removing the new handles, e.g. via the &DRM_IOCTL_GEM_CLOSE IOCTL. The same
+ * new handle will be returned for multiple planes in case they use the same
+ * memory object. Planes are valid until one has a zero handle -- this can be
+ * used to compute the number of planes.
+ *
+ * Otherwise, &drm_mode_fb_cmd2.handles will be zeroed and planes are valid
+ * until one has a zero &drm_mode_fb_cmd2.pitches.
+ *
+ * If the framebuffer has a format modifier, &DRM_MODE_FB_MODIFIERS will be set
+ * in &drm_mode_fb_cmd2.flags and &drm_mode_fb_cmd2.modifier will contain the
+ * modifier. Otherwise, user-space must ignore &drm_mode_fb_cmd2.modifier.
+ *
+ * To obtain DMA-BUF FDs for each plane without leaking GEM handles, user-space
+ * can export each handle via &DRM_IOCTL_PRIME_HANDLE_TO_FD, then immediately
+ * close each unique handle via &DRM_IOCTL_GEM_CLOSE, making sure to not
+ * double-close handles which are specified multiple times in the array.
+ */
+pub const DRM_IOCTL_MODE_GETFB2: Ioctl = _IOWR::<crate::drm_mode_fb_cmd2>(DRM_IOCTL_BASE, 0xCE);
+
+pub const DRM_IOCTL_SYNCOBJ_EVENTFD: Ioctl = _IOWR::<drm_syncobj_eventfd>(DRM_IOCTL_BASE, 0xCF);
+
+/*
+ * DRM_IOCTL_MODE_CLOSEFB - Close a framebuffer.
+ *
+ * This closes a framebuffer previously added via ADDFB/ADDFB2. The IOCTL
+ * argument is a framebuffer object ID.
+ *
+ * This IOCTL is similar to &DRM_IOCTL_MODE_RMFB, except it doesn't disable
+ * planes and CRTCs. As long as the framebuffer is used by a plane, it's kept
+ * alive. When the plane no longer uses the framebuffer (because the
+ * framebuffer is replaced with another one, or the plane is disabled), the
+ * framebuffer is cleaned up.
+ *
+ * This is useful to implement flicker-free transitions between two processes.
+ *
+ * Depending on the threat model, user-space may want to ensure that the
+ * framebuffer doesn't expose any sensitive user information: closed
+ * framebuffers attached to a plane can be read back by the next DRM master.
+ */
+pub const DRM_IOCTL_MODE_CLOSEFB: Ioctl = _IOWR::<crate::drm_mode_closefb>(DRM_IOCTL_BASE, 0xD0);
+
+/*
+ * Device specific ioctls should only be in their respective headers
+ * The device specific ioctl range is from 0x40 to 0x9f.
+ * Generic IOCTLS restart at 0xA0.
+ *
+ * \sa drmCommandNone(), drmCommandRead(), drmCommandWrite(), and
+ * drmCommandReadWrite().
+ */
+pub const DRM_COMMAND_BASE: c_uint = 0x40;
+pub const DRM_COMMAND_END: c_uint = 0xA0;
+
+s! {
+    /*
+     * struct drm_event - Header for DRM events
+     * @type: event type.
+     * @length: total number of payload bytes (including header).
+     *
+     * This struct is a header for events written back to user-space on the DRM FD.
+     * A read on the DRM FD will always only return complete events: e.g. if the
+     * read buffer is 100 bytes large and there are two 64 byte events pending,
+     * only one will be returned.
+     *
+     * Event types 0 - 0x7fffffff are generic DRM events, 0x80000000 and
+     * up are chipset specific. Generic DRM events include &DRM_EVENT_VBLANK,
+     * &DRM_EVENT_FLIP_COMPLETE and &DRM_EVENT_CRTC_SEQUENCE.
+     */
+    pub struct drm_event {
+        pub type_: crate::__u32,
+        pub length: crate::__u32,
+    }
+}
+
+/*
+ * DRM_EVENT_VBLANK - vertical blanking event
+ *
+ * This event is sent in response to &DRM_IOCTL_WAIT_VBLANK with the
+ * &_DRM_VBLANK_EVENT flag set.
+ *
+ * The event payload is a struct drm_event_vblank.
+ */
+pub const DRM_EVENT_VBLANK: crate::__u32 = 0x01;
+/*
+ * DRM_EVENT_FLIP_COMPLETE - page-flip completion event
+ *
+ * This event is sent in response to an atomic commit or legacy page-flip with
+ * the &DRM_MODE_PAGE_FLIP_EVENT flag set.
+ *
+ * The event payload is a struct drm_event_vblank.
+ */
+pub const DRM_EVENT_FLIP_COMPLETE: crate::__u32 = 0x02;
+/*
+ * DRM_EVENT_CRTC_SEQUENCE - CRTC sequence event
+ *
+ * This event is sent in response to &DRM_IOCTL_CRTC_QUEUE_SEQUENCE.
+ *
+ * The event payload is a struct drm_event_crtc_sequence.
+ */
+pub const DRM_EVENT_CRTC_SEQUENCE: crate::__u32 = 0x03;
+
+s! {
+    pub struct drm_event_vblank {
+        pub base: drm_event,
+        pub user_data: crate::__u64,
+        pub tv_sec: crate::__u32,
+        pub tv_usec: crate::__u32,
+        pub sequence: crate::__u32,
+        pub crtc_id: crate::__u32,
+    }
+
+    /* Event delivered at sequence. Time stamp marks when the first pixel
+     * of the refresh cycle leaves the display engine for the display
+     */
+    pub struct drm_event_crtc_sequence {
+        pub base: drm_event,
+        pub user_data: crate::__u64,
+        pub time_ns: crate::__s64,
+        pub sequence: crate::__u64,
+    }
+}
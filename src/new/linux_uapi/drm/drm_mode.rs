@@ -0,0 +1,1240 @@
+//! Header: `uapi/drm/drm_mode.h`
+
+use crate::prelude::*;
+
+pub const DRM_CONNECTOR_NAME_LEN: c_int = 32;
+pub const DRM_DISPLAY_MODE_LEN: c_int = 32;
+pub const DRM_PROP_NAME_LEN: c_int = 32;
+
+pub const DRM_MODE_TYPE_BUILTIN: c_uint = 1 << 0; /* deprecated */
+pub const DRM_MODE_TYPE_CLOCK_C: c_uint = (1 << 1) | DRM_MODE_TYPE_BUILTIN; /* deprecated */
+pub const DRM_MODE_TYPE_CRTC_C: c_uint = (1 << 2) | DRM_MODE_TYPE_BUILTIN; /* deprecated */
+pub const DRM_MODE_TYPE_PREFERRED: c_uint = 1 << 3;
+pub const DRM_MODE_TYPE_DEFAULT: c_uint = 1 << 4; /* deprecated */
+pub const DRM_MODE_TYPE_USERDEF: c_uint = 1 << 5;
+pub const DRM_MODE_TYPE_DRIVER: c_uint = 1 << 6;
+
+pub const DRM_MODE_TYPE_ALL: c_uint =
+    DRM_MODE_TYPE_PREFERRED | DRM_MODE_TYPE_USERDEF | DRM_MODE_TYPE_DRIVER;
+
+/* Video mode flags */
+/* bit compatible with the xrandr RR_ definitions (bits 0-13)
+ *
+ * ABI warning: Existing userspace really expects
+ * the mode flags to match the xrandr definitions. Any
+ * changes that don't match the xrandr definitions will
+ * likely need a new client cap or some other mechanism
+ * to avoid breaking existing userspace. This includes
+ * allocating new flags in the previously unused bits!
+ */
+pub const DRM_MODE_FLAG_PHSYNC: c_uint = 1 << 0;
+pub const DRM_MODE_FLAG_NHSYNC: c_uint = 1 << 1;
+pub const DRM_MODE_FLAG_PVSYNC: c_uint = 1 << 2;
+pub const DRM_MODE_FLAG_NVSYNC: c_uint = 1 << 3;
+pub const DRM_MODE_FLAG_INTERLACE: c_uint = 1 << 4;
+pub const DRM_MODE_FLAG_DBLSCAN: c_uint = 1 << 5;
+pub const DRM_MODE_FLAG_CSYNC: c_uint = 1 << 6;
+pub const DRM_MODE_FLAG_PCSYNC: c_uint = 1 << 7;
+pub const DRM_MODE_FLAG_NCSYNC: c_uint = 1 << 8;
+pub const DRM_MODE_FLAG_HSKEW: c_uint = 1 << 9; /* hskew provided */
+pub const DRM_MODE_FLAG_BCAST: c_uint = 1 << 10; /* deprecated */
+pub const DRM_MODE_FLAG_PIXMUX: c_uint = 1 << 11; /* deprecated */
+pub const DRM_MODE_FLAG_DBLCLK: c_uint = 1 << 12;
+pub const DRM_MODE_FLAG_CLKDIV2: c_uint = 1 << 13;
+/*
+ * When adding a new stereo mode don't forget to adjust DRM_MODE_FLAGS_3D_MAX
+ * (define not exposed to user space).
+ */
+pub const DRM_MODE_FLAG_3D_MASK: c_uint = 0x1f << 14;
+pub const DRM_MODE_FLAG_3D_NONE: c_uint = 0 << 14;
+pub const DRM_MODE_FLAG_3D_FRAME_PACKING: c_uint = 1 << 14;
+pub const DRM_MODE_FLAG_3D_FIELD_ALTERNATIVE: c_uint = 2 << 14;
+pub const DRM_MODE_FLAG_3D_LINE_ALTERNATIVE: c_uint = 3 << 14;
+pub const DRM_MODE_FLAG_3D_SIDE_BY_SIDE_FULL: c_uint = 4 << 14;
+pub const DRM_MODE_FLAG_3D_L_DEPTH: c_uint = 5 << 14;
+pub const DRM_MODE_FLAG_3D_L_DEPTH_GFX_GFX_DEPTH: c_uint = 6 << 14;
+pub const DRM_MODE_FLAG_3D_TOP_AND_BOTTOM: c_uint = 7 << 14;
+pub const DRM_MODE_FLAG_3D_SIDE_BY_SIDE_HALF: c_uint = 8 << 14;
+
+/* Picture aspect ratio options */
+pub const DRM_MODE_PICTURE_ASPECT_NONE: c_uint = 0;
+pub const DRM_MODE_PICTURE_ASPECT_4_3: c_uint = 1;
+pub const DRM_MODE_PICTURE_ASPECT_16_9: c_uint = 2;
+pub const DRM_MODE_PICTURE_ASPECT_64_27: c_uint = 3;
+pub const DRM_MODE_PICTURE_ASPECT_256_135: c_uint = 4;
+
+/* Content type options */
+pub const DRM_MODE_CONTENT_TYPE_NO_DATA: c_uint = 0;
+pub const DRM_MODE_CONTENT_TYPE_GRAPHICS: c_uint = 1;
+pub const DRM_MODE_CONTENT_TYPE_PHOTO: c_uint = 2;
+pub const DRM_MODE_CONTENT_TYPE_CINEMA: c_uint = 3;
+pub const DRM_MODE_CONTENT_TYPE_GAME: c_uint = 4;
+
+/* Aspect ratio flag bitmask (4 bits 22:19) */
+pub const DRM_MODE_FLAG_PIC_AR_MASK: c_uint = 0x0F << 19;
+pub const DRM_MODE_FLAG_PIC_AR_NONE: c_uint = DRM_MODE_PICTURE_ASPECT_NONE << 19;
+pub const DRM_MODE_FLAG_PIC_AR_4_3: c_uint = DRM_MODE_PICTURE_ASPECT_4_3 << 19;
+pub const DRM_MODE_FLAG_PIC_AR_16_9: c_uint = DRM_MODE_PICTURE_ASPECT_16_9 << 19;
+pub const DRM_MODE_FLAG_PIC_AR_64_27: c_uint = DRM_MODE_PICTURE_ASPECT_64_27 << 19;
+pub const DRM_MODE_FLAG_PIC_AR_256_135: c_uint = DRM_MODE_PICTURE_ASPECT_256_135 << 19;
+
+pub const DRM_MODE_FLAG_ALL: c_uint = DRM_MODE_FLAG_PHSYNC
+    | DRM_MODE_FLAG_NHSYNC
+    | DRM_MODE_FLAG_PVSYNC
+    | DRM_MODE_FLAG_NVSYNC
+    | DRM_MODE_FLAG_INTERLACE
+    | DRM_MODE_FLAG_DBLSCAN
+    | DRM_MODE_FLAG_CSYNC
+    | DRM_MODE_FLAG_PCSYNC
+    | DRM_MODE_FLAG_NCSYNC
+    | DRM_MODE_FLAG_HSKEW
+    | DRM_MODE_FLAG_DBLCLK
+    | DRM_MODE_FLAG_CLKDIV2
+    | DRM_MODE_FLAG_3D_MASK;
+
+/* DPMS flags */
+/* bit compatible with the xorg definitions. */
+pub const DRM_MODE_DPMS_ON: c_int = 0;
+pub const DRM_MODE_DPMS_STANDBY: c_int = 1;
+pub const DRM_MODE_DPMS_SUSPEND: c_int = 2;
+pub const DRM_MODE_DPMS_OFF: c_int = 3;
+
+/* Scaling mode options */
+/* Unmodified timing (display or software can still scale) */
+pub const DRM_MODE_SCALE_NONE: c_int = 0;
+/* Full screen, ignore aspect */
+pub const DRM_MODE_SCALE_FULLSCREEN: c_int = 1;
+/* Centered, no scaling */
+pub const DRM_MODE_SCALE_CENTER: c_int = 2;
+/* Full screen, preserve aspect */
+pub const DRM_MODE_SCALE_ASPECT: c_int = 3;
+
+/* Dithering mode options */
+pub const DRM_MODE_DITHERING_OFF: c_int = 0;
+pub const DRM_MODE_DITHERING_ON: c_int = 1;
+pub const DRM_MODE_DITHERING_AUTO: c_int = 2;
+
+/* Dirty info options */
+pub const DRM_MODE_DIRTY_OFF: c_int = 0;
+pub const DRM_MODE_DIRTY_ON: c_int = 1;
+pub const DRM_MODE_DIRTY_ANNOTATE: c_int = 2;
+
+/* Link Status options */
+pub const DRM_MODE_LINK_STATUS_GOOD: c_int = 0;
+pub const DRM_MODE_LINK_STATUS_BAD: c_int = 1;
+
+/*
+ * DRM_MODE_ROTATE_<degrees>
+ *
+ * Signals that a drm plane is been rotated <degrees> degrees in counter
+ * clockwise direction.
+ *
+ * This define is provided as a convenience, looking up the property id
+ * using the name->prop id lookup is the preferred method.
+ */
+pub const DRM_MODE_ROTATE_0: c_uint = 1 << 0;
+pub const DRM_MODE_ROTATE_90: c_uint = 1 << 1;
+pub const DRM_MODE_ROTATE_180: c_uint = 1 << 2;
+pub const DRM_MODE_ROTATE_270: c_uint = 1 << 3;
+
+/*
+ * DRM_MODE_ROTATE_MASK
+ *
+ * Bitmask used to look for drm plane rotations.
+ */
+pub const DRM_MODE_ROTATE_MASK: c_uint =
+    DRM_MODE_ROTATE_0 | DRM_MODE_ROTATE_90 | DRM_MODE_ROTATE_180 | DRM_MODE_ROTATE_270;
+
+/*
+ * DRM_MODE_REFLECT_<axis>
+ *
+ * Signals that the contents of a drm plane is reflected along the <axis> axis,
+ * in the same way as mirroring.
+ * See kerneldoc chapter "Plane Composition Properties" for more details.
+ *
+ * This define is provided as a convenience, looking up the property id
+ * using the name->prop id lookup is the preferred method.
+ */
+pub const DRM_MODE_REFLECT_X: c_uint = 1 << 4;
+pub const DRM_MODE_REFLECT_Y: c_uint = 1 << 5;
+
+/*
+ * DRM_MODE_REFLECT_MASK
+ *
+ * Bitmask used to look for drm plane reflections.
+ */
+pub const DRM_MODE_REFLECT_MASK: c_uint = DRM_MODE_REFLECT_X | DRM_MODE_REFLECT_Y;
+
+/* Content Protection Flags */
+pub const DRM_MODE_CONTENT_PROTECTION_UNDESIRED: c_int = 0;
+pub const DRM_MODE_CONTENT_PROTECTION_DESIRED: c_int = 1;
+pub const DRM_MODE_CONTENT_PROTECTION_ENABLED: c_int = 2;
+
+s! {
+    /*
+     * struct drm_mode_modeinfo - Display mode information.
+     *
+     * This is the user-space API display mode information structure. For the
+     * kernel version see struct drm_display_mode.
+     */
+    pub struct drm_mode_modeinfo {
+        /* pixel clock in kHz */
+        pub clock: crate::__u32,
+        /* horizontal display size */
+        pub hdisplay: crate::__u16,
+        /* horizontal sync start */
+        pub hsync_start: crate::__u16,
+        /* horizontal sync end */
+        pub hsync_end: crate::__u16,
+        /* horizontal total size */
+        pub htotal: crate::__u16,
+        /* horizontal skew */
+        pub hskew: crate::__u16,
+        /* vertical display size */
+        pub vdisplay: crate::__u16,
+        /* vertical sync start */
+        pub vsync_start: crate::__u16,
+        /* vertical sync end */
+        pub vsync_end: crate::__u16,
+        /* vertical total size */
+        pub vtotal: crate::__u16,
+        /* vertical scan */
+        pub vscan: crate::__u16,
+        /* approximate vertical refresh rate in Hz */
+        pub vrefresh: crate::__u32,
+        /* bitmask of misc. flags, see DRM_MODE_FLAG_* defines */
+        pub flags: crate::__u32,
+        /* bitmask of type flags, see DRM_MODE_TYPE_* defines */
+        pub type_: crate::__u32,
+        /* string describing the mode resolution */
+        pub name: [c_char; DRM_DISPLAY_MODE_LEN as usize],
+    }
+
+    pub struct drm_mode_card_res {
+        pub fb_id_ptr: crate::__u64,
+        pub crtc_id_ptr: crate::__u64,
+        pub connector_id_ptr: crate::__u64,
+        pub encoder_id_ptr: crate::__u64,
+        pub count_fbs: crate::__u32,
+        pub count_crtcs: crate::__u32,
+        pub count_connectors: crate::__u32,
+        pub count_encoders: crate::__u32,
+        pub min_width: crate::__u32,
+        pub max_width: crate::__u32,
+        pub min_height: crate::__u32,
+        pub max_height: crate::__u32,
+    }
+
+    pub struct drm_mode_crtc {
+        pub set_connectors_ptr: crate::__u64,
+        pub count_connectors: crate::__u32,
+
+        /* Id */
+        pub crtc_id: crate::__u32,
+        /* Id of framebuffer */
+        pub fb_id: crate::__u32,
+
+        /* x Position on the framebuffer */
+        pub x: crate::__u32,
+        /* y Position on the framebuffer */
+        pub y: crate::__u32,
+
+        pub gamma_size: crate::__u32,
+        pub mode_valid: crate::__u32,
+        pub mode: drm_mode_modeinfo,
+    }
+}
+
+pub const DRM_MODE_PRESENT_TOP_FIELD: c_uint = 1 << 0;
+pub const DRM_MODE_PRESENT_BOTTOM_FIELD: c_uint = 1 << 1;
+
+s! {
+    /* Planes blend with or override other bits on the CRTC */
+    pub struct drm_mode_set_plane {
+        pub plane_id: crate::__u32,
+        pub crtc_id: crate::__u32,
+        /* fb object contains surface format type */
+        pub fb_id: crate::__u32,
+        /* see above flags */
+        pub flags: crate::__u32,
+
+        /* Signed dest location allows it to be partially off screen */
+        pub crtc_x: crate::__s32,
+        pub crtc_y: crate::__s32,
+        pub crtc_w: crate::__u32,
+        pub crtc_h: crate::__u32,
+
+        /* Source values are 16.16 fixed point */
+        pub src_x: crate::__u32,
+        pub src_y: crate::__u32,
+        pub src_h: crate::__u32,
+        pub src_w: crate::__u32,
+    }
+
+    /*
+     * struct drm_mode_get_plane - Get plane metadata.
+     *
+     * Userspace can perform a GETPLANE ioctl to retrieve information about a
+     * plane.
+     *
+     * To retrieve the number of formats supported, set @count_format_types to zero
+     * and call the ioctl. @count_format_types will be updated with the value.
+     *
+     * To retrieve these formats, allocate an array with the memory needed to store
+     * @count_format_types formats. Point @format_type_ptr to this array and call
+     * the ioctl again (with @count_format_types still set to the value returned in
+     * the first ioctl call).
+     */
+    pub struct drm_mode_get_plane {
+        /* Object ID of the plane whose information should be retrieved. Set by
+         * caller. */
+        pub plane_id: crate::__u32,
+
+        /* Object ID of the current CRTC. */
+        pub crtc_id: crate::__u32,
+        /* Object ID of the current fb. */
+        pub fb_id: crate::__u32,
+
+        /* Bitmask of CRTC's compatible with the plane. CRTC's are created and
+         * they receive an index, which corresponds to their position in the
+         * bitmask. Bit N corresponds to
+         * :ref:`CRTC index<crtc_index>` N. */
+        pub possible_crtcs: crate::__u32,
+        /* Never used. */
+        pub gamma_size: crate::__u32,
+
+        /* Number of formats. */
+        pub count_format_types: crate::__u32,
+        /* Pointer to ``__u32`` array of formats that are supported by the plane.
+         * These formats do not require modifiers. */
+        pub format_type_ptr: crate::__u64,
+    }
+
+    pub struct drm_mode_get_plane_res {
+        pub plane_id_ptr: crate::__u64,
+        pub count_planes: crate::__u32,
+    }
+}
+
+pub const DRM_MODE_ENCODER_NONE: c_int = 0;
+pub const DRM_MODE_ENCODER_DAC: c_int = 1;
+pub const DRM_MODE_ENCODER_TMDS: c_int = 2;
+pub const DRM_MODE_ENCODER_LVDS: c_int = 3;
+pub const DRM_MODE_ENCODER_TVDAC: c_int = 4;
+pub const DRM_MODE_ENCODER_VIRTUAL: c_int = 5;
+pub const DRM_MODE_ENCODER_DSI: c_int = 6;
+pub const DRM_MODE_ENCODER_DPMST: c_int = 7;
+pub const DRM_MODE_ENCODER_DPI: c_int = 8;
+
+s! {
+    pub struct drm_mode_get_encoder {
+        pub encoder_id: crate::__u32,
+        pub encoder_type: crate::__u32,
+
+        /* Id of crtc */
+        pub crtc_id: crate::__u32,
+
+        pub possible_crtcs: crate::__u32,
+        pub possible_clones: crate::__u32,
+    }
+}
+
+c_enum! {
+    /* This is for connectors with multiple signal types. */
+    /* Try to match DRM_MODE_CONNECTOR_X as closely as possible. */
+    pub enum drm_mode_subconnector {
+        pub DRM_MODE_SUBCONNECTOR_Automatic = 0, /* DVI-I, TV     */
+        pub DRM_MODE_SUBCONNECTOR_Unknown = 0, /* DVI-I, TV, DP */
+        pub DRM_MODE_SUBCONNECTOR_VGA = 1, /*            DP */
+        pub DRM_MODE_SUBCONNECTOR_DVID = 3, /* DVI-I      DP */
+        pub DRM_MODE_SUBCONNECTOR_DVIA = 4, /* DVI-I         */
+        pub DRM_MODE_SUBCONNECTOR_Composite = 5, /*        TV     */
+        pub DRM_MODE_SUBCONNECTOR_SVIDEO = 6, /*        TV     */
+        pub DRM_MODE_SUBCONNECTOR_Component = 8, /*        TV     */
+        pub DRM_MODE_SUBCONNECTOR_SCART = 9, /*        TV     */
+        pub DRM_MODE_SUBCONNECTOR_DisplayPort = 10, /*        TV, DP */
+        pub DRM_MODE_SUBCONNECTOR_HDMIA = 11, /*            DP */
+        pub DRM_MODE_SUBCONNECTOR_Native = 15, /*            DP */
+        pub DRM_MODE_SUBCONNECTOR_Wireless = 18, /*            DP */
+    }
+}
+
+pub const DRM_MODE_CONNECTOR_Unknown: c_int = 0;
+pub const DRM_MODE_CONNECTOR_VGA: c_int = 1;
+pub const DRM_MODE_CONNECTOR_DVII: c_int = 2;
+pub const DRM_MODE_CONNECTOR_DVID: c_int = 3;
+pub const DRM_MODE_CONNECTOR_DVIA: c_int = 4;
+pub const DRM_MODE_CONNECTOR_Composite: c_int = 5;
+pub const DRM_MODE_CONNECTOR_SVIDEO: c_int = 6;
+pub const DRM_MODE_CONNECTOR_LVDS: c_int = 7;
+pub const DRM_MODE_CONNECTOR_Component: c_int = 8;
+pub const DRM_MODE_CONNECTOR_9PinDIN: c_int = 9;
+pub const DRM_MODE_CONNECTOR_DisplayPort: c_int = 10;
+pub const DRM_MODE_CONNECTOR_HDMIA: c_int = 11;
+pub const DRM_MODE_CONNECTOR_HDMIB: c_int = 12;
+pub const DRM_MODE_CONNECTOR_TV: c_int = 13;
+pub const DRM_MODE_CONNECTOR_eDP: c_int = 14;
+pub const DRM_MODE_CONNECTOR_VIRTUAL: c_int = 15;
+pub const DRM_MODE_CONNECTOR_DSI: c_int = 16;
+pub const DRM_MODE_CONNECTOR_DPI: c_int = 17;
+pub const DRM_MODE_CONNECTOR_WRITEBACK: c_int = 18;
+pub const DRM_MODE_CONNECTOR_SPI: c_int = 19;
+pub const DRM_MODE_CONNECTOR_USB: c_int = 20;
+
+s! {
+    /*
+     * struct drm_mode_get_connector - Get connector metadata.
+     *
+     * User-space can perform a GETCONNECTOR ioctl to retrieve information about a
+     * connector. User-space is expected to retrieve encoders, modes and properties
+     * by performing this ioctl at least twice: the first time to retrieve the
+     * number of elements, the second time to retrieve the elements themselves.
+     *
+     * To retrieve the number of elements, set @count_props and @count_encoders to
+     * zero, set @count_modes to 1, and set @modes_ptr to a temporary struct
+     * drm_mode_modeinfo element.
+     *
+     * To retrieve the elements, allocate arrays for @encoders_ptr, @modes_ptr,
+     * @props_ptr and @prop_values_ptr, then set @count_modes, @count_props and
+     * @count_encoders to their capacity.
+     *
+     * Performing the ioctl only twice may be racy: the number of elements may have
+     * changed with a hotplug event in-between the two ioctls. User-space is
+     * expected to retry the last ioctl until the number of elements stabilizes.
+     * The kernel won't fill any array which doesn't have the expected length.
+     *
+     * **Force-probing a connector**
+     *
+     * If the @count_modes field is set to zero and the DRM client is the current
+     * DRM master, the kernel will perform a forced probe on the connector to
+     * refresh the connector status, modes and EDID. A forced-probe can be slow,
+     * might cause flickering and the ioctl will block.
+     *
+     * User-space needs to force-probe connectors to ensure their metadata is
+     * up-to-date at startup and after receiving a hot-plug event. User-space
+     * may perform a forced-probe when the user explicitly requests it. User-space
+     * shouldn't perform a forced-probe in other situations.
+     */
+    pub struct drm_mode_get_connector {
+        /* Pointer to ``__u32`` array of object IDs. */
+        pub encoders_ptr: crate::__u64,
+        /* Pointer to struct drm_mode_modeinfo array. */
+        pub modes_ptr: crate::__u64,
+        /* Pointer to ``__u32`` array of property IDs. */
+        pub props_ptr: crate::__u64,
+        /* Pointer to ``__u64`` array of property values. */
+        pub prop_values_ptr: crate::__u64,
+
+        /* Number of modes. */
+        pub count_modes: crate::__u32,
+        /* Number of properties. */
+        pub count_props: crate::__u32,
+        /* Number of encoders. */
+        pub count_encoders: crate::__u32,
+
+        /* Object ID of the current encoder. */
+        pub encoder_id: crate::__u32,
+        /* Object ID of the connector. */
+        pub connector_id: crate::__u32,
+        /* Type of the connector.
+         *
+         * See DRM_MODE_CONNECTOR_* defines.
+         */
+        pub connector_type: crate::__u32,
+        /* Type-specific connector number.
+         *
+         * This is not an object ID. This is a per-type connector number. Each
+         * (type, type_id) combination is unique across all connectors of a DRM
+         * device.
+         *
+         * The (type, type_id) combination is not a stable identifier: the
+         * type_id can change depending on the driver probe order.
+         */
+        pub connector_type_id: crate::__u32,
+
+        /* Status of the connector.
+         *
+         * See enum drm_connector_status.
+         */
+        pub connection: crate::__u32,
+        /* Width of the connected sink in millimeters. */
+        pub mm_width: crate::__u32,
+        /* Height of the connected sink in millimeters. */
+        pub mm_height: crate::__u32,
+        /* Subpixel order of the connected sink.
+         *
+         * See enum subpixel_order.
+         */
+        pub subpixel: crate::__u32,
+
+        /* Padding, must be zero. */
+        pub pad: crate::__u32,
+    }
+}
+
+pub const DRM_MODE_PROP_PENDING: c_uint = 1 << 0; /* deprecated, do not use */
+pub const DRM_MODE_PROP_RANGE: c_uint = 1 << 1;
+pub const DRM_MODE_PROP_IMMUTABLE: c_uint = 1 << 2;
+pub const DRM_MODE_PROP_ENUM: c_uint = 1 << 3; /* enumerated type with text strings */
+pub const DRM_MODE_PROP_BLOB: c_uint = 1 << 4;
+pub const DRM_MODE_PROP_BITMASK: c_uint = 1 << 5; /* bitmask of enumerated types */
+
+/* non-extended types: legacy bitmask, one bit per type: */
+pub const DRM_MODE_PROP_LEGACY_TYPE: c_uint =
+    DRM_MODE_PROP_RANGE | DRM_MODE_PROP_ENUM | DRM_MODE_PROP_BLOB | DRM_MODE_PROP_BITMASK;
+
+/* extended-types: rather than continue to consume a bit per type,
+ * grab a chunk of the bits to use as integer type id.
+ */
+pub const DRM_MODE_PROP_EXTENDED_TYPE: c_uint = 0x0000ffc0;
+pub const DRM_MODE_PROP_OBJECT: c_uint = 1 << 6;
+pub const DRM_MODE_PROP_SIGNED_RANGE: c_uint = 2 << 6;
+
+/* the PROP_ATOMIC flag is used to hide properties from userspace that
+ * is not aware of atomic properties.  This is mostly to work around
+ * older userspace (DDX drivers) that read/write each prop they find,
+ * without being aware that this could be triggering a lengthy modeset.
+ */
+pub const DRM_MODE_PROP_ATOMIC: c_uint = 0x80000000;
+
+s! {
+    /*
+     * struct drm_mode_property_enum - Description for an enum/bitfield entry.
+     * @value: numeric value for this enum entry.
+     * @name: symbolic name for this enum entry.
+     *
+     * See struct drm_property_enum for details.
+     */
+    pub struct drm_mode_property_enum {
+        pub value: crate::__u64,
+        pub name: [c_char; DRM_PROP_NAME_LEN as usize],
+    }
+
+    /*
+     * struct drm_mode_get_property - Get property metadata.
+     *
+     * User-space can perform a GETPROPERTY ioctl to retrieve information about a
+     * property. The same property may be attached to multiple objects, see
+     * "Modeset Base Object Abstraction".
+     *
+     * The meaning of the @values_ptr field changes depending on the property type.
+     * See &drm_property.flags for more details.
+     *
+     * The @enum_blob_ptr and @count_enum_blobs fields are only meaningful when the
+     * property has the type &DRM_MODE_PROP_ENUM or &DRM_MODE_PROP_BITMASK. For
+     * backwards compatibility, the kernel will always set @count_enum_blobs to
+     * zero when the property has the type &DRM_MODE_PROP_BLOB. User-space must
+     * ignore these two fields if the property has a different type.
+     *
+     * User-space is expected to retrieve values and enums by performing this ioctl
+     * at least twice: the first time to retrieve the number of elements, the
+     * second time to retrieve the elements themselves.
+     *
+     * To retrieve the number of elements, set @count_values and @count_enum_blobs
+     * to zero, then call the ioctl. @count_values will be updated with the number
+     * of elements. If the property has the type &DRM_MODE_PROP_ENUM or
+     * &DRM_MODE_PROP_BITMASK, @count_enum_blobs will be updated as well.
+     *
+     * To retrieve the elements themselves, allocate an array for @values_ptr and
+     * set @count_values to its capacity. If the property has the type
+     * &DRM_MODE_PROP_ENUM or &DRM_MODE_PROP_BITMASK, allocate an array for
+     * @enum_blob_ptr and set @count_enum_blobs to its capacity. Calling the ioctl
+     * again will fill the arrays.
+     */
+    pub struct drm_mode_get_property {
+        /* Pointer to a ``__u64`` array. */
+        pub values_ptr: crate::__u64,
+        /* Pointer to a struct drm_mode_property_enum array. */
+        pub enum_blob_ptr: crate::__u64,
+
+        /* Object ID of the property which should be retrieved. Set by the
+         * caller. */
+        pub prop_id: crate::__u32,
+        /* ``DRM_MODE_PROP_*`` bitfield. See &drm_property.flags for a
+         * definition of the flags. */
+        pub flags: crate::__u32,
+        /* Symbolic property name. User-space should use this field to
+         * recognize properties. */
+        pub name: [c_char; DRM_PROP_NAME_LEN as usize],
+
+        /* Number of elements in @values_ptr. */
+        pub count_values: crate::__u32,
+        /* This is only used to count enum values, not blobs. The _blobs is
+         * simply because of a historical reason, i.e. backwards compat. */
+        pub count_enum_blobs: crate::__u32,
+    }
+
+    pub struct drm_mode_connector_set_property {
+        pub value: crate::__u64,
+        pub prop_id: crate::__u32,
+        pub connector_id: crate::__u32,
+    }
+}
+
+pub const DRM_MODE_OBJECT_CRTC: c_uint = 0xcccccccc;
+pub const DRM_MODE_OBJECT_CONNECTOR: c_uint = 0xc0c0c0c0;
+pub const DRM_MODE_OBJECT_ENCODER: c_uint = 0xe0e0e0e0;
+pub const DRM_MODE_OBJECT_MODE: c_uint = 0xdededede;
+pub const DRM_MODE_OBJECT_PROPERTY: c_uint = 0xb0b0b0b0;
+pub const DRM_MODE_OBJECT_FB: c_uint = 0xfbfbfbfb;
+pub const DRM_MODE_OBJECT_BLOB: c_uint = 0xbbbbbbbb;
+pub const DRM_MODE_OBJECT_PLANE: c_uint = 0xeeeeeeee;
+pub const DRM_MODE_OBJECT_ANY: c_uint = 0;
+
+s! {
+    pub struct drm_mode_obj_get_properties {
+        pub props_ptr: crate::__u64,
+        pub prop_values_ptr: crate::__u64,
+        pub count_props: crate::__u32,
+        pub obj_id: crate::__u32,
+        pub obj_type: crate::__u32,
+    }
+
+    pub struct drm_mode_obj_set_property {
+        pub value: crate::__u64,
+        pub prop_id: crate::__u32,
+        pub obj_id: crate::__u32,
+        pub obj_type: crate::__u32,
+    }
+
+    pub struct drm_mode_get_blob {
+        pub blob_id: crate::__u32,
+        pub length: crate::__u32,
+        pub data: crate::__u64,
+    }
+
+    pub struct drm_mode_fb_cmd {
+        pub fb_id: crate::__u32,
+        pub width: crate::__u32,
+        pub height: crate::__u32,
+        pub pitch: crate::__u32,
+        pub bpp: crate::__u32,
+        pub depth: crate::__u32,
+        /* driver specific handle */
+        pub handle: crate::__u32,
+    }
+}
+
+pub const DRM_MODE_FB_INTERLACED: c_uint = 1 << 0; /* for interlaced framebuffers */
+pub const DRM_MODE_FB_MODIFIERS: c_uint = 1 << 1; /* enables ->modifier[] */
+
+s! {
+    /*
+     * struct drm_mode_fb_cmd2 - Frame-buffer metadata.
+     *
+     * This struct holds frame-buffer metadata. There are two ways to use it:
+     *
+     * - User-space can fill this struct and perform a &DRM_IOCTL_MODE_ADDFB2
+     *   ioctl to register a new frame-buffer. The new frame-buffer object ID will
+     *   be set by the kernel in @fb_id.
+     * - User-space can set @fb_id and perform a &DRM_IOCTL_MODE_GETFB2 ioctl to
+     *   fetch metadata about an existing frame-buffer.
+     *
+     * In case of planar formats, this struct allows up to 4 buffer objects with
+     * offsets and pitches per plane. The pitch and offset order are dictated by
+     * the format FourCC as defined by ``drm_fourcc.h``, e.g. NV12 is described as:
+     *
+     *     YUV 4:2:0 image with a plane of 8-bit Y samples followed by an
+     *     interleaved U/V plane containing 8-bit 2x2 subsampled colour difference
+     *     samples.
+     *
+     * So it would consist of a Y plane at ``offsets[0]`` and a UV plane at
+     * ``offsets[1]``.
+     *
+     * To accommodate tiled, compressed, etc formats, a modifier can be specified.
+     * For more information see the "Format Modifiers" section. Note that even
+     * though it looks like we have a modifier per-plane, we in fact do not. The
+     * modifier for each plane must be identical. Thus all combinations of
+     * different data layouts for multi-plane formats must be enumerated as
+     * separate modifiers.
+     *
+     * All of the entries in @handles, @pitches, @offsets and @modifier must be
+     * zero when unused. Warning, for @offsets and @modifier zero can't be used to
+     * figure out whether the entry is used or not since it's a valid value (a zero
+     * offset is common, and a zero modifier is &DRM_FORMAT_MOD_LINEAR).
+     */
+    pub struct drm_mode_fb_cmd2 {
+        /* Object ID of the frame-buffer. */
+        pub fb_id: crate::__u32,
+        /* Width of the frame-buffer. */
+        pub width: crate::__u32,
+        /* Height of the frame-buffer. */
+        pub height: crate::__u32,
+        /* FourCC format code, see ``DRM_FORMAT_*`` constants in
+         * ``drm_fourcc.h``. */
+        pub pixel_format: crate::__u32,
+        /* Frame-buffer flags (see &DRM_MODE_FB_INTERLACED and
+         * &DRM_MODE_FB_MODIFIERS). */
+        pub flags: crate::__u32,
+
+        /* GEM buffer handle, one per plane. Set to 0 if the plane is unused.
+         * The same handle can be used for multiple planes. */
+        pub handles: [crate::__u32; 4],
+        /* Pitch (aka. stride) in bytes, one per plane. */
+        pub pitches: [crate::__u32; 4],
+        /* Offset into the buffer in bytes, one per plane. */
+        pub offsets: [crate::__u32; 4],
+        /* Format modifier, one per plane. See ``DRM_FORMAT_MOD_*`` constants
+         * in ``drm_fourcc.h``. All planes must use the same modifier.
+         * Ignored unless &DRM_MODE_FB_MODIFIERS is set in @flags. */
+        pub modifier: [crate::__u64; 4],
+    }
+}
+
+pub const DRM_MODE_FB_DIRTY_ANNOTATE_COPY: c_uint = 0x01;
+pub const DRM_MODE_FB_DIRTY_ANNOTATE_FILL: c_uint = 0x02;
+pub const DRM_MODE_FB_DIRTY_FLAGS: c_uint = 0x03;
+
+pub const DRM_MODE_FB_DIRTY_MAX_CLIPS: c_uint = 256;
+
+s! {
+    /*
+     * Mark a region of a framebuffer as dirty.
+     *
+     * Some hardware does not automatically update display contents
+     * as a hardware or software draw to a framebuffer. This ioctl
+     * allows userspace to tell the kernel and the hardware what
+     * regions of the framebuffer have changed.
+     *
+     * The kernel or hardware is free to update more then just the
+     * region specified by the clip rects. The kernel or hardware
+     * may also delay and/or coalesce several calls to dirty into a
+     * single update.
+     *
+     * Userspace may annotate the updates, the annotates are a
+     * promise made by the caller that the change is either a copy
+     * of pixels or a fill of a single color in the region specified.
+     *
+     * If the DRM_MODE_FB_DIRTY_ANNOTATE_COPY flag is given then
+     * the number of updated regions are half of num_clips given,
+     * where the clip rects are paired in src and dst. The width and
+     * height of each one of the pairs must match.
+     *
+     * If the DRM_MODE_FB_DIRTY_ANNOTATE_FILL flag is given the caller
+     * promises that the region specified of the clip rects is filled
+     * completely with a single color as given in the color argument.
+     */
+    pub struct drm_mode_fb_dirty_cmd {
+        pub fb_id: crate::__u32,
+        pub flags: crate::__u32,
+        pub color: crate::__u32,
+        pub num_clips: crate::__u32,
+        pub clips_ptr: crate::__u64,
+    }
+
+    pub struct drm_mode_mode_cmd {
+        pub connector_id: crate::__u32,
+        pub mode: drm_mode_modeinfo,
+    }
+}
+
+pub const DRM_MODE_CURSOR_BO: c_uint = 0x01;
+pub const DRM_MODE_CURSOR_MOVE: c_uint = 0x02;
+pub const DRM_MODE_CURSOR_FLAGS: c_uint = 0x03;
+
+s! {
+    /*
+     * depending on the value in flags different members are used.
+     *
+     * CURSOR_BO uses
+     *    crtc_id
+     *    width
+     *    height
+     *    handle - if 0 turns the cursor off
+     *
+     * CURSOR_MOVE uses
+     *    crtc_id
+     *    x
+     *    y
+     */
+    pub struct drm_mode_cursor {
+        pub flags: crate::__u32,
+        pub crtc_id: crate::__u32,
+        pub x: crate::__s32,
+        pub y: crate::__s32,
+        pub width: crate::__u32,
+        pub height: crate::__u32,
+        /* driver specific handle */
+        pub handle: crate::__u32,
+    }
+
+    pub struct drm_mode_cursor2 {
+        pub flags: crate::__u32,
+        pub crtc_id: crate::__u32,
+        pub x: crate::__s32,
+        pub y: crate::__s32,
+        pub width: crate::__u32,
+        pub height: crate::__u32,
+        /* driver specific handle */
+        pub handle: crate::__u32,
+        pub hot_x: crate::__s32,
+        pub hot_y: crate::__s32,
+    }
+
+    pub struct drm_mode_crtc_lut {
+        pub crtc_id: crate::__u32,
+        pub gamma_size: crate::__u32,
+
+        /* pointers to arrays */
+        pub red: crate::__u64,
+        pub green: crate::__u64,
+        pub blue: crate::__u64,
+    }
+
+    pub struct drm_color_ctm {
+        /*
+         * Conversion matrix in S31.32 sign-magnitude
+         * (not two's complement!) format.
+         *
+         * out   matrix    in
+         * |R|   |0 1 2|   |R|
+         * |G| = |3 4 5| x |G|
+         * |B|   |6 7 8|   |B|
+         */
+        pub matrix: [crate::__u64; 9],
+    }
+
+    pub struct drm_color_lut {
+        /*
+         * Values are mapped linearly to 0.0 - 1.0 range, with 0x0 == 0.0 and
+         * 0xffff == 1.0.
+         */
+        pub red: crate::__u16,
+        pub green: crate::__u16,
+        pub blue: crate::__u16,
+        pub reserved: crate::__u16,
+    }
+
+    pub struct __c_anonymous_hdr_metadata_infoframe_primaries {
+        pub x: crate::__u16,
+        pub y: crate::__u16,
+    }
+
+    /*
+     * struct hdr_metadata_infoframe - HDR Metadata Infoframe Data.
+     *
+     * HDR Metadata Infoframe as per CTA 861.G spec. This is expected
+     * to match exactly with the spec.
+     *
+     * Userspace is expected to pass the metadata information as per
+     * the format described in this structure.
+     */
+    pub struct hdr_metadata_infoframe {
+        /* Electro-Optical Transfer Function (EOTF) used in the stream. */
+        pub eotf: crate::__u8,
+        /* Static_Metadata_Descriptor_ID. */
+        pub metadata_type: crate::__u8,
+        /* Color Primaries of the Data.
+         *
+         * These are coded as unsigned 16-bit values in units of
+         * 0.00002, where 0x0000 represents zero and 0xC350
+         * represents 1.0000.
+         */
+        pub display_primaries: [__c_anonymous_hdr_metadata_infoframe_primaries; 3],
+        /* White Point of Colorspace Data.
+         *
+         * These are coded as unsigned 16-bit values in units of
+         * 0.00002, where 0x0000 represents zero and 0xC350
+         * represents 1.0000.
+         */
+        pub white_point: __c_anonymous_hdr_metadata_infoframe_primaries,
+        /* Max Mastering Display Luminance.
+         *
+         * This value is coded as an unsigned 16-bit value in units of 1 cd/m2,
+         * where 0x0001 represents 1 cd/m2 and 0xFFFF represents 65535 cd/m2.
+         */
+        pub max_display_mastering_luminance: crate::__u16,
+        /* Min Mastering Display Luminance.
+         *
+         * This value is coded as an unsigned 16-bit value in units of
+         * 0.0001 cd/m2, where 0x0001 represents 0.0001 cd/m2 and 0xFFFF
+         * represents 6.5535 cd/m2.
+         */
+        pub min_display_mastering_luminance: crate::__u16,
+        /* Max Content Light Level.
+         *
+         * This value is coded as an unsigned 16-bit value in units of 1 cd/m2,
+         * where 0x0001 represents 1 cd/m2 and 0xFFFF represents 65535 cd/m2.
+         */
+        pub max_cll: crate::__u16,
+        /* Max Frame Average Light Level.
+         *
+         * This value is coded as an unsigned 16-bit value in units of 1 cd/m2,
+         * where 0x0001 represents 1 cd/m2 and 0xFFFF represents 65535 cd/m2.
+         */
+        pub max_fall: crate::__u16,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_hdr_output_metadata_1 {
+        pub hdmi_metadata_type1: hdr_metadata_infoframe,
+    }
+
+    /*
+     * struct hdr_output_metadata - HDR output metadata
+     *
+     * Metadata Information to be passed from userspace
+     */
+    pub struct hdr_output_metadata {
+        /* Infoframe Type. */
+        pub metadata_type: crate::__u32,
+        /* HDR Metadata Infoframe. */
+        pub anonymous_1: __c_anonymous_hdr_output_metadata_1,
+    }
+}
+
+/*
+ * DRM_MODE_PAGE_FLIP_EVENT
+ *
+ * Request that the kernel sends back a vblank event (see
+ * struct drm_event_vblank) with the &DRM_EVENT_FLIP_COMPLETE type when the
+ * page-flip is done.
+ */
+pub const DRM_MODE_PAGE_FLIP_EVENT: c_uint = 0x01;
+/*
+ * DRM_MODE_PAGE_FLIP_ASYNC
+ *
+ * Request that the page-flip is performed as soon as possible, ie. with no
+ * delay due to waiting for vblank. This may cause tearing to be visible on
+ * the screen.
+ *
+ * When used with atomic uAPI, the driver will return an error if the hardware
+ * doesn't support performing an asynchronous page-flip for this update.
+ * User-space should handle this, e.g. by falling back to a regular page-flip.
+ *
+ * Note, some hardware might need to perform one last synchronous page-flip
+ * before being able to switch to asynchronous page-flips. As an exception,
+ * the driver will return success even though that first page-flip is not
+ * asynchronous.
+ */
+pub const DRM_MODE_PAGE_FLIP_ASYNC: c_uint = 0x02;
+pub const DRM_MODE_PAGE_FLIP_TARGET_ABSOLUTE: c_uint = 0x4;
+pub const DRM_MODE_PAGE_FLIP_TARGET_RELATIVE: c_uint = 0x8;
+pub const DRM_MODE_PAGE_FLIP_TARGET: c_uint =
+    DRM_MODE_PAGE_FLIP_TARGET_ABSOLUTE | DRM_MODE_PAGE_FLIP_TARGET_RELATIVE;
+/*
+ * DRM_MODE_PAGE_FLIP_FLAGS
+ *
+ * Bitmask of flags suitable for &drm_mode_crtc_page_flip_target.flags.
+ */
+pub const DRM_MODE_PAGE_FLIP_FLAGS: c_uint =
+    DRM_MODE_PAGE_FLIP_EVENT | DRM_MODE_PAGE_FLIP_ASYNC | DRM_MODE_PAGE_FLIP_TARGET;
+
+s! {
+    /*
+     * Request a page flip on the specified crtc.
+     *
+     * This ioctl will ask KMS to schedule a page flip for the specified
+     * crtc.  Once any pending rendering targeting the specified fb (as of
+     * ioctl time) has completed, the crtc will be reprogrammed to display
+     * that fb after the next vertical refresh.  The ioctl returns
+     * immediately, but subsequent rendering to the current fb will block
+     * in the execbuffer ioctl until the page flip happens.  If a page
+     * flip is already pending as the ioctl is called, EBUSY will be
+     * returned.
+     *
+     * Flag DRM_MODE_PAGE_FLIP_EVENT requests that drm sends back a vblank
+     * event (see drm.h: struct drm_event_vblank) when the page flip is
+     * done.  The user_data field passed in with this ioctl will be
+     * returned as the user_data field in the vblank event struct.
+     *
+     * Flag DRM_MODE_PAGE_FLIP_ASYNC requests that the flip happen
+     * 'as soon as possible', meaning that it not delay waiting for vblank.
+     * This may cause tearing on the screen.
+     *
+     * The reserved field must be zero.
+     */
+    pub struct drm_mode_crtc_page_flip {
+        pub crtc_id: crate::__u32,
+        pub fb_id: crate::__u32,
+        pub flags: crate::__u32,
+        pub reserved: crate::__u32,
+        pub user_data: crate::__u64,
+    }
+
+    /*
+     * Request a page flip on the specified crtc.
+     *
+     * Same as struct drm_mode_crtc_page_flip, but supports new flags and
+     * re-purposes the reserved field:
+     *
+     * The sequence field must be zero unless either of the
+     * DRM_MODE_PAGE_FLIP_TARGET_ABSOLUTE/RELATIVE flags is specified. When
+     * the ABSOLUTE flag is specified, the sequence field denotes the absolute
+     * vblank sequence when the flip should take effect. When the RELATIVE
+     * flag is specified, the sequence field denotes the relative (to the
+     * current one when the ioctl is called) vblank sequence when the flip
+     * should take effect. NOTE: DRM_IOCTL_WAIT_VBLANK must still be used to
+     * make sure the vblank sequence before the target one has passed before
+     * calling this ioctl. The purpose of the
+     * DRM_MODE_PAGE_FLIP_TARGET_ABSOLUTE/RELATIVE flags is merely to clarify
+     * the target for when code dealing with a page flip runs during a
+     * vertical blank period.
+     */
+    pub struct drm_mode_crtc_page_flip_target {
+        pub crtc_id: crate::__u32,
+        pub fb_id: crate::__u32,
+        pub flags: crate::__u32,
+        pub sequence: crate::__u32,
+        pub user_data: crate::__u64,
+    }
+
+    /*
+     * struct drm_mode_create_dumb - Create a KMS dumb buffer for scanout.
+     * @height: buffer height in pixels
+     * @width: buffer width in pixels
+     * @bpp: bits per pixel
+     * @flags: must be zero
+     * @handle: buffer object handle
+     * @pitch: number of bytes between two consecutive lines
+     * @size: size of the whole buffer in bytes
+     *
+     * User-space fills @height, @width, @bpp and @flags. If the IOCTL succeeds,
+     * the kernel fills @handle, @pitch and @size.
+     */
+    pub struct drm_mode_create_dumb {
+        pub height: crate::__u32,
+        pub width: crate::__u32,
+        pub bpp: crate::__u32,
+        pub flags: crate::__u32,
+
+        pub handle: crate::__u32,
+        pub pitch: crate::__u32,
+        pub size: crate::__u64,
+    }
+
+    /* set up for mmap of a dumb scanout buffer */
+    pub struct drm_mode_map_dumb {
+        /* Handle for the object being mapped. */
+        pub handle: crate::__u32,
+        pub pad: crate::__u32,
+        /*
+         * Fake offset to use for subsequent mmap call
+         *
+         * This is a fixed-size type for 32/64 compatibility.
+         */
+        pub offset: crate::__u64,
+    }
+
+    pub struct drm_mode_destroy_dumb {
+        pub handle: crate::__u32,
+    }
+}
+
+/* page-flip flags are valid, plus: */
+pub const DRM_MODE_ATOMIC_TEST_ONLY: c_uint = 0x0100;
+pub const DRM_MODE_ATOMIC_NONBLOCK: c_uint = 0x0200;
+pub const DRM_MODE_ATOMIC_ALLOW_MODESET: c_uint = 0x0400;
+
+pub const DRM_MODE_ATOMIC_FLAGS: c_uint = DRM_MODE_PAGE_FLIP_EVENT
+    | DRM_MODE_PAGE_FLIP_ASYNC
+    | DRM_MODE_ATOMIC_TEST_ONLY
+    | DRM_MODE_ATOMIC_NONBLOCK
+    | DRM_MODE_ATOMIC_ALLOW_MODESET;
+
+s! {
+    pub struct drm_mode_atomic {
+        pub flags: crate::__u32,
+        pub count_objs: crate::__u32,
+        pub objs_ptr: crate::__u64,
+        pub count_props_ptr: crate::__u64,
+        pub props_ptr: crate::__u64,
+        pub prop_values_ptr: crate::__u64,
+        pub reserved: crate::__u64,
+        pub user_data: crate::__u64,
+    }
+}
+
+pub const FORMAT_BLOB_CURRENT: c_int = 1;
+
+s! {
+    pub struct drm_format_modifier_blob {
+        /* Version of this blob format */
+        pub version: crate::__u32,
+
+        /* Flags */
+        pub flags: crate::__u32,
+
+        /* Number of fourcc formats supported */
+        pub count_formats: crate::__u32,
+
+        /* Where in this blob the formats exist (in bytes) */
+        pub formats_offset: crate::__u32,
+
+        /* Number of drm_format_modifiers */
+        pub count_modifiers: crate::__u32,
+
+        /* Where in this blob the modifiers exist (in bytes) */
+        pub modifiers_offset: crate::__u32,
+        /* __u32 formats[] */
+        /* struct drm_format_modifier modifiers[] */
+    }
+
+    pub struct drm_format_modifier {
+        /* Bitmask of formats in get_plane format list this info applies to. The
+         * offset allows a sliding window of which 64 formats (bits).
+         *
+         * Some examples:
+         * In today's world with < 65 formats, and formats 0, and 2 are
+         * supported
+         * 0x0000000000000005
+         *                  ^-offset = 0, formats = 5
+         *
+         * If the number formats grew to 128, and formats 98-102 are
+         * supported with the modifier:
+         *
+         * 0x0000007c00000000 0000000000000000
+         *                    ^
+         *                    |__offset = 64, formats = 0x7c00000000
+         *
+         */
+        pub formats: crate::__u64,
+        pub offset: crate::__u32,
+        pub pad: crate::__u32,
+
+        /* The modifier that applies to the >get_plane format list bitmask. */
+        pub modifier: crate::__u64,
+    }
+
+    /*
+     * struct drm_mode_create_blob - Create New blob property
+     *
+     * Create a new 'blob' data property, copying length bytes from data pointer,
+     * and returning new blob ID.
+     */
+    pub struct drm_mode_create_blob {
+        /* Pointer to data to copy. */
+        pub data: crate::__u64,
+        /* Length of data to copy. */
+        pub length: crate::__u32,
+        /* Return: new property ID. */
+        pub blob_id: crate::__u32,
+    }
+
+    /*
+     * struct drm_mode_destroy_blob - Destroy user blob
+     * @blob_id: blob_id to destroy
+     *
+     * Destroy a user-created blob property.
+     *
+     * User-space can release blobs as soon as they do not need to refer to them by
+     * their blob object ID.  For instance, if you are using a MODE_ID blob in an
+     * atomic commit and you will not make another commit re-using the same ID, you
+     * can destroy the blob as soon as the commit has been issued, without waiting
+     * for it to complete.
+     */
+    pub struct drm_mode_destroy_blob {
+        pub blob_id: crate::__u32,
+    }
+
+    /*
+     * struct drm_mode_create_lease - Create lease
+     *
+     * Lease mode resources, creating another drm_master.
+     *
+     * The @object_ids array must reference at least one CRTC, one connector and
+     * one plane if &DRM_CLIENT_CAP_UNIVERSAL_PLANES is enabled. Alternatively,
+     * the lease can be completely empty.
+     */
+    pub struct drm_mode_create_lease {
+        /* Pointer to array of object ids (__u32) */
+        pub object_ids: crate::__u64,
+        /* Number of object ids */
+        pub object_count: crate::__u32,
+        /* flags for new FD (O_CLOEXEC, etc) */
+        pub flags: crate::__u32,
+
+        /* Return: unique identifier for lessee. */
+        pub lessee_id: crate::__u32,
+        /* Return: file descriptor to new drm_master file */
+        pub fd: crate::__u32,
+    }
+
+    /*
+     * struct drm_mode_list_lessees - List lessees
+     *
+     * List lesses from a drm_master.
+     */
+    pub struct drm_mode_list_lessees {
+        /* Number of lessees.
+         *
+         * On input, provides length of the array.
+         * On output, provides total number. No
+         * more than the input number will be written
+         * back, so two calls can be used to get
+         * the size and then the data.
+         */
+        pub count_lessees: crate::__u32,
+        /* Padding. */
+        pub pad: crate::__u32,
+
+        /* Pointer to lessees.
+         *
+         * Pointer to __u64 array of lessee ids
+         */
+        pub lessees_ptr: crate::__u64,
+    }
+
+    /*
+     * struct drm_mode_get_lease - Get Lease
+     *
+     * Get leased objects.
+     */
+    pub struct drm_mode_get_lease {
+        /* Number of leased objects.
+         *
+         * On input, provides length of the array.
+         * On output, provides total number. No
+         * more than the input number will be written
+         * back, so two calls can be used to get
+         * the size and then the data.
+         */
+        pub count_objects: crate::__u32,
+        /* Padding. */
+        pub pad: crate::__u32,
+
+        /* Pointer to objects.
+         *
+         * Pointer to __u32 array of object ids.
+         */
+        pub objects_ptr: crate::__u64,
+    }
+
+    /*
+     * struct drm_mode_revoke_lease - Revoke lease
+     */
+    pub struct drm_mode_revoke_lease {
+        /* Unique ID of lessee */
+        pub lessee_id: crate::__u32,
+    }
+
+    /*
+     * struct drm_mode_rect - Two dimensional rectangle.
+     * @x1: Horizontal starting coordinate (inclusive).
+     * @y1: Vertical starting coordinate (inclusive).
+     * @x2: Horizontal ending coordinate (exclusive).
+     * @y2: Vertical ending coordinate (exclusive).
+     *
+     * With drm subsystem using struct drm_rect to manage rectangular area this
+     * export it to user-space.
+     *
+     * Currently used by drm_mode_atomic blob property FB_DAMAGE_CLIPS.
+     */
+    pub struct drm_mode_rect {
+        pub x1: crate::__s32,
+        pub y1: crate::__s32,
+        pub x2: crate::__s32,
+        pub y2: crate::__s32,
+    }
+
+    /*
+     * struct drm_mode_closefb
+     * @fb_id: Framebuffer ID.
+     * @pad: Must be zero.
+     */
+    pub struct drm_mode_closefb {
+        pub fb_id: crate::__u32,
+        pub pad: crate::__u32,
+    }
+}
@@ -0,0 +1,7 @@
+//! Directory: `drm/`
+//!
+//! <https://github.com/torvalds/linux/tree/master/include/uapi/drm>
+
+#[allow(clippy::module_inception)]
+pub(crate) mod drm;
+pub(crate) mod drm_mode;
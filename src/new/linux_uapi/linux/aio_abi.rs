@@ -0,0 +1,94 @@
+//! Header: `uapi/linux/aio_abi.h`
+
+use crate::prelude::*;
+
+cfg_if! {
+    if #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))] {
+        pub type aio_context_t = crate::__u64;
+    } else {
+        pub type aio_context_t = c_ulong;
+    }
+}
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        pub IOCB_CMD_PREAD = 0,
+        pub IOCB_CMD_PWRITE = 1,
+        pub IOCB_CMD_FSYNC = 2,
+        pub IOCB_CMD_FDSYNC = 3,
+        /* 4 was the experimental IOCB_CMD_PREADX */
+        pub IOCB_CMD_POLL = 5,
+        pub IOCB_CMD_NOOP = 6,
+        pub IOCB_CMD_PREADV = 7,
+        pub IOCB_CMD_PWRITEV = 8,
+    }
+}
+
+/*
+ * Valid flags for the "aio_flags" member of the "struct iocb".
+ *
+ * IOCB_FLAG_RESFD - Set if the "aio_resfd" member of the "struct iocb"
+ *                   is valid.
+ * IOCB_FLAG_IOPRIO - Set if the "aio_reqprio" member of the "struct iocb"
+ *                    is valid.
+ */
+pub const IOCB_FLAG_RESFD: c_uint = 1 << 0;
+pub const IOCB_FLAG_IOPRIO: c_uint = 1 << 1;
+
+s! {
+    /* read() from /dev/aio returns these structures. */
+    pub struct io_event {
+        /* the data field from the iocb */
+        pub data: crate::__u64,
+        /* what iocb this event came from */
+        pub obj: crate::__u64,
+        /* result code for this event */
+        pub res: crate::__s64,
+        /* secondary result */
+        pub res2: crate::__s64,
+    }
+
+    /*
+     * we always use a 64bit off_t when communicating
+     * with userland.  its up to libraries to do the
+     * proper padding and aio_error abstraction
+     */
+    pub struct iocb {
+        /* these are internal to the kernel/libc. */
+        /* data to be returned in event's data */
+        pub aio_data: crate::__u64,
+        /* the kernel sets aio_key to the req # */
+        #[cfg(target_endian = "little")]
+        pub aio_key: crate::__u32,
+        /* RWF_* flags */
+        #[cfg(target_endian = "little")]
+        pub aio_rw_flags: crate::__kernel_rwf_t,
+        #[cfg(target_endian = "big")]
+        pub aio_rw_flags: crate::__kernel_rwf_t,
+        #[cfg(target_endian = "big")]
+        pub aio_key: crate::__u32,
+
+        /* common fields */
+        /* see IOCB_CMD_ above */
+        pub aio_lio_opcode: crate::__u16,
+        pub aio_reqprio: crate::__s16,
+        pub aio_fildes: crate::__u32,
+
+        pub aio_buf: crate::__u64,
+        pub aio_nbytes: crate::__u64,
+        pub aio_offset: crate::__s64,
+
+        /* extra parameters */
+        aio_reserved2: Padding<crate::__u64>,
+
+        /* flags for the "struct iocb" */
+        pub aio_flags: crate::__u32,
+
+        /*
+         * if the IOCB_FLAG_RESFD flag of "aio_flags" is set, this is an
+         * eventfd to signal AIO readiness to
+         */
+        pub aio_resfd: crate::__u32,
+    }
+}
@@ -0,0 +1,513 @@
+//! Header: `uapi/linux/audit.h`
+
+use crate::prelude::*;
+
+/* The netlink messages for the audit system is divided into blocks:
+ * 1000 - 1099 are for commanding the audit system
+ * 1100 - 1199 user space trusted application messages
+ * 1200 - 1299 messages internal to the audit daemon
+ * 1300 - 1399 audit event messages
+ * 1400 - 1499 SE Linux use
+ * 1500 - 1599 kernel LSPP events
+ * 1600 - 1699 kernel crypto events
+ * 1700 - 1799 kernel anomaly records
+ * 1800 - 1899 kernel integrity events
+ * 1900 - 1999 future kernel use
+ * 2000 is for otherwise unclassified kernel audit messages (legacy)
+ * 2001 - 2099 unused (kernel)
+ * 2100 - 2199 user space anomaly records
+ * 2200 - 2299 user space actions taken in response to anomalies
+ * 2300 - 2399 user space generated LSPP events
+ * 2400 - 2499 user space crypto events
+ * 2500 - 2999 future user space (maybe integrity labels and related events)
+ */
+/* Requests */
+pub const AUDIT_GET: c_uint = 1000;
+pub const AUDIT_SET: c_uint = 1001;
+pub const AUDIT_LIST: c_uint = 1002;
+pub const AUDIT_ADD: c_uint = 1003;
+pub const AUDIT_DEL: c_uint = 1004;
+pub const AUDIT_USER: c_uint = 1005;
+pub const AUDIT_LOGIN: c_uint = 1006;
+pub const AUDIT_WATCH_INS: c_uint = 1007;
+pub const AUDIT_WATCH_REM: c_uint = 1008;
+pub const AUDIT_WATCH_LIST: c_uint = 1009;
+pub const AUDIT_SIGNAL_INFO: c_uint = 1010;
+pub const AUDIT_ADD_RULE: c_uint = 1011;
+pub const AUDIT_DEL_RULE: c_uint = 1012;
+pub const AUDIT_LIST_RULES: c_uint = 1013;
+pub const AUDIT_TRIM: c_uint = 1014;
+pub const AUDIT_MAKE_EQUIV: c_uint = 1015;
+pub const AUDIT_TTY_GET: c_uint = 1016;
+pub const AUDIT_TTY_SET: c_uint = 1017;
+pub const AUDIT_SET_FEATURE: c_uint = 1018;
+pub const AUDIT_GET_FEATURE: c_uint = 1019;
+
+pub const AUDIT_FIRST_USER_MSG: c_uint = 1100;
+pub const AUDIT_USER_AVC: c_uint = 1107;
+pub const AUDIT_USER_TTY: c_uint = 1124;
+pub const AUDIT_LAST_USER_MSG: c_uint = 1199;
+pub const AUDIT_FIRST_USER_MSG2: c_uint = 2100;
+pub const AUDIT_LAST_USER_MSG2: c_uint = 2999;
+
+pub const AUDIT_DAEMON_START: c_uint = 1200;
+pub const AUDIT_DAEMON_END: c_uint = 1201;
+pub const AUDIT_DAEMON_ABORT: c_uint = 1202;
+pub const AUDIT_DAEMON_CONFIG: c_uint = 1203;
+
+pub const AUDIT_SYSCALL: c_uint = 1300;
+pub const AUDIT_PATH: c_uint = 1302;
+pub const AUDIT_IPC: c_uint = 1303;
+pub const AUDIT_SOCKETCALL: c_uint = 1304;
+pub const AUDIT_CONFIG_CHANGE: c_uint = 1305;
+pub const AUDIT_SOCKADDR: c_uint = 1306;
+pub const AUDIT_CWD: c_uint = 1307;
+pub const AUDIT_EXECVE: c_uint = 1309;
+pub const AUDIT_IPC_SET_PERM: c_uint = 1311;
+pub const AUDIT_MQ_OPEN: c_uint = 1312;
+pub const AUDIT_MQ_SENDRECV: c_uint = 1313;
+pub const AUDIT_MQ_NOTIFY: c_uint = 1314;
+pub const AUDIT_MQ_GETSETATTR: c_uint = 1315;
+pub const AUDIT_KERNEL_OTHER: c_uint = 1316;
+pub const AUDIT_FD_PAIR: c_uint = 1317;
+pub const AUDIT_OBJ_PID: c_uint = 1318;
+pub const AUDIT_TTY: c_uint = 1319;
+pub const AUDIT_EOE: c_uint = 1320;
+pub const AUDIT_BPRM_FCAPS: c_uint = 1321;
+pub const AUDIT_CAPSET: c_uint = 1322;
+pub const AUDIT_MMAP: c_uint = 1323;
+pub const AUDIT_NETFILTER_PKT: c_uint = 1324;
+pub const AUDIT_NETFILTER_CFG: c_uint = 1325;
+pub const AUDIT_SECCOMP: c_uint = 1326;
+pub const AUDIT_PROCTITLE: c_uint = 1327;
+pub const AUDIT_FEATURE_CHANGE: c_uint = 1328;
+pub const AUDIT_REPLACE: c_uint = 1329;
+pub const AUDIT_KERN_MODULE: c_uint = 1330;
+pub const AUDIT_FANOTIFY: c_uint = 1331;
+pub const AUDIT_TIME_INJOFFSET: c_uint = 1332;
+pub const AUDIT_TIME_ADJNTPVAL: c_uint = 1333;
+pub const AUDIT_BPF: c_uint = 1334;
+pub const AUDIT_EVENT_LISTENER: c_uint = 1335;
+pub const AUDIT_URINGOP: c_uint = 1336;
+pub const AUDIT_OPENAT2: c_uint = 1337;
+pub const AUDIT_DM_CTRL: c_uint = 1338;
+pub const AUDIT_DM_EVENT: c_uint = 1339;
+
+pub const AUDIT_AVC: c_uint = 1400;
+pub const AUDIT_SELINUX_ERR: c_uint = 1401;
+pub const AUDIT_AVC_PATH: c_uint = 1402;
+pub const AUDIT_MAC_POLICY_LOAD: c_uint = 1403;
+pub const AUDIT_MAC_STATUS: c_uint = 1404;
+pub const AUDIT_MAC_CONFIG_CHANGE: c_uint = 1405;
+pub const AUDIT_MAC_UNLBL_ALLOW: c_uint = 1406;
+pub const AUDIT_MAC_CIPSOV4_ADD: c_uint = 1407;
+pub const AUDIT_MAC_CIPSOV4_DEL: c_uint = 1408;
+pub const AUDIT_MAC_MAP_ADD: c_uint = 1409;
+pub const AUDIT_MAC_MAP_DEL: c_uint = 1410;
+pub const AUDIT_MAC_IPSEC_ADDSA: c_uint = 1411;
+pub const AUDIT_MAC_IPSEC_DELSA: c_uint = 1412;
+pub const AUDIT_MAC_IPSEC_ADDSPD: c_uint = 1413;
+pub const AUDIT_MAC_IPSEC_DELSPD: c_uint = 1414;
+pub const AUDIT_MAC_IPSEC_EVENT: c_uint = 1415;
+pub const AUDIT_MAC_UNLBL_STCADD: c_uint = 1416;
+pub const AUDIT_MAC_UNLBL_STCDEL: c_uint = 1417;
+pub const AUDIT_MAC_CALIPSO_ADD: c_uint = 1418;
+pub const AUDIT_MAC_CALIPSO_DEL: c_uint = 1419;
+pub const AUDIT_IPE_ACCESS: c_uint = 1420;
+pub const AUDIT_IPE_CONFIG_CHANGE: c_uint = 1421;
+pub const AUDIT_IPE_POLICY_LOAD: c_uint = 1422;
+
+pub const AUDIT_FIRST_KERN_ANOM_MSG: c_uint = 1700;
+pub const AUDIT_LAST_KERN_ANOM_MSG: c_uint = 1799;
+pub const AUDIT_ANOM_PROMISCUOUS: c_uint = 1700;
+pub const AUDIT_ANOM_ABEND: c_uint = 1701;
+pub const AUDIT_ANOM_LINK: c_uint = 1702;
+pub const AUDIT_ANOM_CREAT: c_uint = 1703;
+pub const AUDIT_INTEGRITY_DATA: c_uint = 1800;
+pub const AUDIT_INTEGRITY_METADATA: c_uint = 1801;
+pub const AUDIT_INTEGRITY_STATUS: c_uint = 1802;
+pub const AUDIT_INTEGRITY_HASH: c_uint = 1803;
+pub const AUDIT_INTEGRITY_PCR: c_uint = 1804;
+pub const AUDIT_INTEGRITY_RULE: c_uint = 1805;
+pub const AUDIT_INTEGRITY_EVM_XATTR: c_uint = 1806;
+pub const AUDIT_INTEGRITY_POLICY_RULE: c_uint = 1807;
+
+pub const AUDIT_KERNEL: c_uint = 2000;
+
+/* Rule flags */
+pub const AUDIT_FILTER_USER: c_uint = 0x00;
+pub const AUDIT_FILTER_TASK: c_uint = 0x01;
+pub const AUDIT_FILTER_ENTRY: c_uint = 0x02;
+pub const AUDIT_FILTER_WATCH: c_uint = 0x03;
+pub const AUDIT_FILTER_EXIT: c_uint = 0x04;
+pub const AUDIT_FILTER_EXCLUDE: c_uint = 0x05;
+pub const AUDIT_FILTER_TYPE: c_uint = AUDIT_FILTER_EXCLUDE;
+pub const AUDIT_FILTER_FS: c_uint = 0x06;
+pub const AUDIT_FILTER_URING_EXIT: c_uint = 0x07;
+
+pub const AUDIT_NR_FILTERS: c_uint = 8;
+
+pub const AUDIT_FILTER_PREPEND: c_uint = 0x10;
+
+/* Rule actions */
+pub const AUDIT_NEVER: c_uint = 0;
+pub const AUDIT_POSSIBLE: c_uint = 1;
+pub const AUDIT_ALWAYS: c_uint = 2;
+
+/* Rule structure sizes -- if these change, different AUDIT_ADD and
+ * AUDIT_LIST commands must be implemented. */
+pub const AUDIT_MAX_FIELDS: c_uint = 64;
+pub const AUDIT_MAX_KEY_LEN: c_uint = 256;
+pub const AUDIT_BITMASK_SIZE: c_uint = 64;
+
+pub const AUDIT_SYSCALL_CLASSES: c_uint = 16;
+pub const AUDIT_CLASS_DIR_WRITE: c_uint = 0;
+pub const AUDIT_CLASS_DIR_WRITE_32: c_uint = 1;
+pub const AUDIT_CLASS_CHATTR: c_uint = 2;
+pub const AUDIT_CLASS_CHATTR_32: c_uint = 3;
+pub const AUDIT_CLASS_READ: c_uint = 4;
+pub const AUDIT_CLASS_READ_32: c_uint = 5;
+pub const AUDIT_CLASS_WRITE: c_uint = 6;
+pub const AUDIT_CLASS_WRITE_32: c_uint = 7;
+pub const AUDIT_CLASS_SIGNAL: c_uint = 8;
+pub const AUDIT_CLASS_SIGNAL_32: c_uint = 9;
+
+/* This bitmask is used to validate user input.  It represents all bits that
+ * are currently used in an audit field constant understood by the kernel.
+ * If you are adding a new #define AUDIT_<whatever>, please ensure that
+ * AUDIT_UNUSED_BITS is updated if need be. */
+pub const AUDIT_UNUSED_BITS: c_uint = 0x07FFFC00;
+
+/* AUDIT_FIELD_COMPARE rule list */
+pub const AUDIT_COMPARE_UID_TO_OBJ_UID: c_uint = 1;
+pub const AUDIT_COMPARE_GID_TO_OBJ_GID: c_uint = 2;
+pub const AUDIT_COMPARE_EUID_TO_OBJ_UID: c_uint = 3;
+pub const AUDIT_COMPARE_EGID_TO_OBJ_GID: c_uint = 4;
+pub const AUDIT_COMPARE_AUID_TO_OBJ_UID: c_uint = 5;
+pub const AUDIT_COMPARE_SUID_TO_OBJ_UID: c_uint = 6;
+pub const AUDIT_COMPARE_SGID_TO_OBJ_GID: c_uint = 7;
+pub const AUDIT_COMPARE_FSUID_TO_OBJ_UID: c_uint = 8;
+pub const AUDIT_COMPARE_FSGID_TO_OBJ_GID: c_uint = 9;
+
+pub const AUDIT_COMPARE_UID_TO_AUID: c_uint = 10;
+pub const AUDIT_COMPARE_UID_TO_EUID: c_uint = 11;
+pub const AUDIT_COMPARE_UID_TO_FSUID: c_uint = 12;
+pub const AUDIT_COMPARE_UID_TO_SUID: c_uint = 13;
+
+pub const AUDIT_COMPARE_AUID_TO_FSUID: c_uint = 14;
+pub const AUDIT_COMPARE_AUID_TO_SUID: c_uint = 15;
+pub const AUDIT_COMPARE_AUID_TO_EUID: c_uint = 16;
+
+pub const AUDIT_COMPARE_EUID_TO_SUID: c_uint = 17;
+pub const AUDIT_COMPARE_EUID_TO_FSUID: c_uint = 18;
+
+pub const AUDIT_COMPARE_SUID_TO_FSUID: c_uint = 19;
+
+pub const AUDIT_COMPARE_GID_TO_EGID: c_uint = 20;
+pub const AUDIT_COMPARE_GID_TO_FSGID: c_uint = 21;
+pub const AUDIT_COMPARE_GID_TO_SGID: c_uint = 22;
+
+pub const AUDIT_COMPARE_EGID_TO_FSGID: c_uint = 23;
+pub const AUDIT_COMPARE_EGID_TO_SGID: c_uint = 24;
+pub const AUDIT_COMPARE_SGID_TO_FSGID: c_uint = 25;
+
+pub const AUDIT_MAX_FIELD_COMPARE: c_uint = AUDIT_COMPARE_SGID_TO_FSGID;
+
+/* Rule fields */
+pub const AUDIT_PID: c_uint = 0;
+pub const AUDIT_UID: c_uint = 1;
+pub const AUDIT_EUID: c_uint = 2;
+pub const AUDIT_SUID: c_uint = 3;
+pub const AUDIT_FSUID: c_uint = 4;
+pub const AUDIT_GID: c_uint = 5;
+pub const AUDIT_EGID: c_uint = 6;
+pub const AUDIT_SGID: c_uint = 7;
+pub const AUDIT_FSGID: c_uint = 8;
+pub const AUDIT_LOGINUID: c_uint = 9;
+pub const AUDIT_PERS: c_uint = 10;
+pub const AUDIT_ARCH: c_uint = 11;
+pub const AUDIT_MSGTYPE: c_uint = 12;
+pub const AUDIT_SUBJ_USER: c_uint = 13;
+pub const AUDIT_SUBJ_ROLE: c_uint = 14;
+pub const AUDIT_SUBJ_TYPE: c_uint = 15;
+pub const AUDIT_SUBJ_SEN: c_uint = 16;
+pub const AUDIT_SUBJ_CLR: c_uint = 17;
+pub const AUDIT_PPID: c_uint = 18;
+pub const AUDIT_OBJ_USER: c_uint = 19;
+pub const AUDIT_OBJ_ROLE: c_uint = 20;
+pub const AUDIT_OBJ_TYPE: c_uint = 21;
+pub const AUDIT_OBJ_LEV_LOW: c_uint = 22;
+pub const AUDIT_OBJ_LEV_HIGH: c_uint = 23;
+pub const AUDIT_LOGINUID_SET: c_uint = 24;
+pub const AUDIT_SESSIONID: c_uint = 25;
+pub const AUDIT_FSTYPE: c_uint = 26;
+
+pub const AUDIT_DEVMAJOR: c_uint = 100;
+pub const AUDIT_DEVMINOR: c_uint = 101;
+pub const AUDIT_INODE: c_uint = 102;
+pub const AUDIT_EXIT: c_uint = 103;
+pub const AUDIT_SUCCESS: c_uint = 104;
+pub const AUDIT_WATCH: c_uint = 105;
+pub const AUDIT_PERM: c_uint = 106;
+pub const AUDIT_DIR: c_uint = 107;
+pub const AUDIT_FILETYPE: c_uint = 108;
+pub const AUDIT_OBJ_UID: c_uint = 109;
+pub const AUDIT_OBJ_GID: c_uint = 110;
+pub const AUDIT_FIELD_COMPARE: c_uint = 111;
+pub const AUDIT_EXE: c_uint = 112;
+pub const AUDIT_SADDR_FAM: c_uint = 113;
+
+pub const AUDIT_ARG0: c_uint = 200;
+pub const AUDIT_ARG1: c_uint = AUDIT_ARG0 + 1;
+pub const AUDIT_ARG2: c_uint = AUDIT_ARG0 + 2;
+pub const AUDIT_ARG3: c_uint = AUDIT_ARG0 + 3;
+
+pub const AUDIT_FILTERKEY: c_uint = 210;
+
+/* These are the supported operators. */
+pub const AUDIT_NEGATE: c_uint = 0x80000000;
+
+pub const AUDIT_BIT_MASK: c_uint = 0x08000000;
+pub const AUDIT_LESS_THAN: c_uint = 0x10000000;
+pub const AUDIT_GREATER_THAN: c_uint = 0x20000000;
+pub const AUDIT_NOT_EQUAL: c_uint = 0x30000000;
+pub const AUDIT_EQUAL: c_uint = 0x40000000;
+pub const AUDIT_BIT_TEST: c_uint = AUDIT_BIT_MASK | AUDIT_EQUAL;
+pub const AUDIT_LESS_THAN_OR_EQUAL: c_uint = AUDIT_LESS_THAN | AUDIT_EQUAL;
+pub const AUDIT_GREATER_THAN_OR_EQUAL: c_uint = AUDIT_GREATER_THAN | AUDIT_EQUAL;
+pub const AUDIT_OPERATORS: c_uint = AUDIT_EQUAL | AUDIT_NOT_EQUAL | AUDIT_BIT_MASK;
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        pub Audit_equal = 0,
+        pub Audit_not_equal,
+        pub Audit_bitmask,
+        pub Audit_bittest,
+        pub Audit_lt,
+        pub Audit_gt,
+        pub Audit_le,
+        pub Audit_ge,
+        pub Audit_bad,
+    }
+}
+
+/* Status symbols */
+pub const AUDIT_STATUS_ENABLED: c_uint = 0x0001;
+pub const AUDIT_STATUS_FAILURE: c_uint = 0x0002;
+pub const AUDIT_STATUS_PID: c_uint = 0x0004;
+pub const AUDIT_STATUS_RATE_LIMIT: c_uint = 0x0008;
+pub const AUDIT_STATUS_BACKLOG_LIMIT: c_uint = 0x0010;
+pub const AUDIT_STATUS_BACKLOG_WAIT_TIME: c_uint = 0x0020;
+pub const AUDIT_STATUS_LOST: c_uint = 0x0040;
+pub const AUDIT_STATUS_BACKLOG_WAIT_TIME_ACTUAL: c_uint = 0x0080;
+
+pub const AUDIT_FEATURE_BITMAP_BACKLOG_LIMIT: c_uint = 0x00000001;
+pub const AUDIT_FEATURE_BITMAP_BACKLOG_WAIT_TIME: c_uint = 0x00000002;
+pub const AUDIT_FEATURE_BITMAP_EXECUTABLE_PATH: c_uint = 0x00000004;
+pub const AUDIT_FEATURE_BITMAP_EXCLUDE_EXTEND: c_uint = 0x00000008;
+pub const AUDIT_FEATURE_BITMAP_SESSIONID_FILTER: c_uint = 0x00000010;
+pub const AUDIT_FEATURE_BITMAP_LOST_RESET: c_uint = 0x00000020;
+pub const AUDIT_FEATURE_BITMAP_FILTER_FS: c_uint = 0x00000040;
+
+pub const AUDIT_FEATURE_BITMAP_ALL: c_uint = AUDIT_FEATURE_BITMAP_BACKLOG_LIMIT
+    | AUDIT_FEATURE_BITMAP_BACKLOG_WAIT_TIME
+    | AUDIT_FEATURE_BITMAP_EXECUTABLE_PATH
+    | AUDIT_FEATURE_BITMAP_EXCLUDE_EXTEND
+    | AUDIT_FEATURE_BITMAP_SESSIONID_FILTER
+    | AUDIT_FEATURE_BITMAP_LOST_RESET
+    | AUDIT_FEATURE_BITMAP_FILTER_FS;
+
+pub const AUDIT_VERSION_LATEST: c_uint = AUDIT_FEATURE_BITMAP_ALL;
+pub const AUDIT_VERSION_BACKLOG_LIMIT: c_uint = AUDIT_FEATURE_BITMAP_BACKLOG_LIMIT;
+pub const AUDIT_VERSION_BACKLOG_WAIT_TIME: c_uint = AUDIT_FEATURE_BITMAP_BACKLOG_WAIT_TIME;
+
+/* Failure-to-log actions */
+pub const AUDIT_FAIL_SILENT: c_uint = 0;
+pub const AUDIT_FAIL_PRINTK: c_uint = 1;
+pub const AUDIT_FAIL_PANIC: c_uint = 2;
+
+/* distinguish syscall tables */
+const __AUDIT_ARCH_CONVENTION_MIPS64_N32: c_uint = 0x20000000;
+const __AUDIT_ARCH_64BIT: c_uint = 0x80000000;
+const __AUDIT_ARCH_LE: c_uint = 0x40000000;
+
+/* from linux/elf-em.h, for machines without a `crate::EM_*` constant */
+const EM_UNICORE: c_uint = 110;
+const EM_ALTERA_NIOS2: c_uint = 113;
+const EM_TI_C6000: c_uint = 140;
+const EM_HEXAGON: c_uint = 164;
+const EM_NDS32: c_uint = 167;
+const EM_MICROBLAZE: c_uint = 189;
+const EM_ARCV2: c_uint = 195;
+const EM_CSKY: c_uint = 252;
+const EM_LOONGARCH: c_uint = 258;
+const EM_FRV: c_uint = 0x5441;
+
+pub const AUDIT_ARCH_AARCH64: c_uint =
+    crate::EM_AARCH64 as c_uint | __AUDIT_ARCH_64BIT | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_ALPHA: c_uint =
+    crate::EM_ALPHA as c_uint | __AUDIT_ARCH_64BIT | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_ARCOMPACT: c_uint = crate::EM_ARC_A5 as c_uint | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_ARCOMPACTBE: c_uint = crate::EM_ARC_A5 as c_uint;
+pub const AUDIT_ARCH_ARCV2: c_uint = EM_ARCV2 | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_ARCV2BE: c_uint = EM_ARCV2;
+pub const AUDIT_ARCH_ARM: c_uint = crate::EM_ARM as c_uint | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_ARMEB: c_uint = crate::EM_ARM as c_uint;
+pub const AUDIT_ARCH_C6X: c_uint = EM_TI_C6000 | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_C6XBE: c_uint = EM_TI_C6000;
+pub const AUDIT_ARCH_CRIS: c_uint = crate::EM_CRIS as c_uint | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_CSKY: c_uint = EM_CSKY | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_FRV: c_uint = EM_FRV;
+pub const AUDIT_ARCH_H8300: c_uint = crate::EM_H8_300 as c_uint;
+pub const AUDIT_ARCH_HEXAGON: c_uint = EM_HEXAGON;
+pub const AUDIT_ARCH_I386: c_uint = crate::EM_386 as c_uint | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_IA64: c_uint =
+    crate::EM_IA_64 as c_uint | __AUDIT_ARCH_64BIT | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_M32R: c_uint = crate::EM_M32R as c_uint;
+pub const AUDIT_ARCH_M68K: c_uint = crate::EM_68K as c_uint;
+pub const AUDIT_ARCH_MICROBLAZE: c_uint = EM_MICROBLAZE;
+pub const AUDIT_ARCH_MIPS: c_uint = crate::EM_MIPS as c_uint;
+pub const AUDIT_ARCH_MIPSEL: c_uint = crate::EM_MIPS as c_uint | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_MIPS64: c_uint = crate::EM_MIPS as c_uint | __AUDIT_ARCH_64BIT;
+pub const AUDIT_ARCH_MIPS64N32: c_uint =
+    crate::EM_MIPS as c_uint | __AUDIT_ARCH_64BIT | __AUDIT_ARCH_CONVENTION_MIPS64_N32;
+pub const AUDIT_ARCH_MIPSEL64: c_uint =
+    crate::EM_MIPS as c_uint | __AUDIT_ARCH_64BIT | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_MIPSEL64N32: c_uint = crate::EM_MIPS as c_uint
+    | __AUDIT_ARCH_64BIT
+    | __AUDIT_ARCH_LE
+    | __AUDIT_ARCH_CONVENTION_MIPS64_N32;
+pub const AUDIT_ARCH_NDS32: c_uint = EM_NDS32 | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_NDS32BE: c_uint = EM_NDS32;
+pub const AUDIT_ARCH_NIOS2: c_uint = EM_ALTERA_NIOS2 | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_OPENRISC: c_uint = crate::EM_OPENRISC as c_uint;
+pub const AUDIT_ARCH_PARISC: c_uint = crate::EM_PARISC as c_uint;
+pub const AUDIT_ARCH_PARISC64: c_uint = crate::EM_PARISC as c_uint | __AUDIT_ARCH_64BIT;
+pub const AUDIT_ARCH_PPC: c_uint = crate::EM_PPC as c_uint;
+pub const AUDIT_ARCH_PPC64: c_uint = crate::EM_PPC64 as c_uint | __AUDIT_ARCH_64BIT;
+pub const AUDIT_ARCH_PPC64LE: c_uint =
+    crate::EM_PPC64 as c_uint | __AUDIT_ARCH_64BIT | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_RISCV32: c_uint = crate::EM_RISCV as c_uint | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_RISCV64: c_uint =
+    crate::EM_RISCV as c_uint | __AUDIT_ARCH_64BIT | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_S390: c_uint = crate::EM_S390 as c_uint;
+pub const AUDIT_ARCH_S390X: c_uint = crate::EM_S390 as c_uint | __AUDIT_ARCH_64BIT;
+pub const AUDIT_ARCH_SH: c_uint = crate::EM_SH as c_uint;
+pub const AUDIT_ARCH_SHEL: c_uint = crate::EM_SH as c_uint | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_SH64: c_uint = crate::EM_SH as c_uint | __AUDIT_ARCH_64BIT;
+pub const AUDIT_ARCH_SHEL64: c_uint = crate::EM_SH as c_uint | __AUDIT_ARCH_64BIT | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_SPARC: c_uint = crate::EM_SPARC as c_uint;
+pub const AUDIT_ARCH_SPARC64: c_uint = crate::EM_SPARCV9 as c_uint | __AUDIT_ARCH_64BIT;
+pub const AUDIT_ARCH_TILEGX: c_uint =
+    crate::EM_TILEGX as c_uint | __AUDIT_ARCH_64BIT | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_TILEGX32: c_uint = crate::EM_TILEGX as c_uint | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_TILEPRO: c_uint = crate::EM_TILEPRO as c_uint | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_UNICORE: c_uint = EM_UNICORE | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_X86_64: c_uint =
+    crate::EM_X86_64 as c_uint | __AUDIT_ARCH_64BIT | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_XTENSA: c_uint = crate::EM_XTENSA as c_uint;
+pub const AUDIT_ARCH_LOONGARCH32: c_uint = EM_LOONGARCH | __AUDIT_ARCH_LE;
+pub const AUDIT_ARCH_LOONGARCH64: c_uint = EM_LOONGARCH | __AUDIT_ARCH_64BIT | __AUDIT_ARCH_LE;
+
+/* audit_rule_data permission bits */
+pub const AUDIT_PERM_EXEC: c_uint = 1;
+pub const AUDIT_PERM_WRITE: c_uint = 2;
+pub const AUDIT_PERM_READ: c_uint = 4;
+pub const AUDIT_PERM_ATTR: c_uint = 8;
+
+/* MAX_AUDIT_MESSAGE_LENGTH minus netlink header */
+pub const AUDIT_MESSAGE_TEXT_MAX: c_uint = 8560;
+
+c_enum! {
+    pub enum audit_nlgrps {
+        pub AUDIT_NLGRP_NONE = 0,
+        pub AUDIT_NLGRP_READLOG,
+    }
+}
+
+s_no_extra_traits! {
+    pub struct audit_status {
+        /* Bit mask for valid entries */
+        pub mask: crate::__u32,
+        /* 1 = enabled, 0 = disabled */
+        pub enabled: crate::__u32,
+        /* Failure-to-log action */
+        pub failure: crate::__u32,
+        /* pid of auditd process */
+        pub pid: crate::__u32,
+        /* messages rate limit (per second) */
+        pub rate_limit: crate::__u32,
+        /* waiting messages limit */
+        pub backlog_limit: crate::__u32,
+        /* messages lost */
+        pub lost: crate::__u32,
+        /* messages waiting in queue */
+        pub backlog: crate::__u32,
+        pub anonymous_1: __c_anonymous_audit_status_1,
+        /* message queue wait timeout */
+        pub backlog_wait_time: crate::__u32,
+        /* time spent waiting while message limit exceeded */
+        pub backlog_wait_time_actual: crate::__u32,
+    }
+
+    pub union __c_anonymous_audit_status_1 {
+        /* deprecated: audit api version num */
+        pub version: crate::__u32,
+        /* bitmap of kernel audit features */
+        pub feature_bitmap: crate::__u32,
+    }
+}
+
+pub const AUDIT_FEATURE_VERSION: c_uint = 1;
+
+s! {
+    pub struct audit_features {
+        pub vers: crate::__u32,
+        /* which bits we are dealing with */
+        pub mask: crate::__u32,
+        /* which feature to enable/disable */
+        pub features: crate::__u32,
+        /* which features to lock */
+        pub lock: crate::__u32,
+    }
+}
+
+pub const AUDIT_FEATURE_ONLY_UNSET_LOGINUID: c_uint = 0;
+pub const AUDIT_FEATURE_LOGINUID_IMMUTABLE: c_uint = 1;
+pub const AUDIT_LAST_FEATURE: c_uint = AUDIT_FEATURE_LOGINUID_IMMUTABLE;
+
+s! {
+    pub struct audit_tty_status {
+        /* 1 = enabled, 0 = disabled */
+        pub enabled: crate::__u32,
+        /* 1 = enabled, 0 = disabled */
+        pub log_passwd: crate::__u32,
+    }
+}
+
+pub const AUDIT_UID_UNSET: c_uint = !0;
+pub const AUDIT_SID_UNSET: c_uint = !0;
+
+s! {
+    /* audit_rule_data supports filter rules with both integer and string
+     * fields.  It corresponds with AUDIT_ADD_RULE, AUDIT_DEL_RULE and
+     * AUDIT_LIST_RULES requests.
+     */
+    pub struct audit_rule_data {
+        /* AUDIT_PER_{TASK,CALL}, AUDIT_PREPEND */
+        pub flags: crate::__u32,
+        /* AUDIT_NEVER, AUDIT_POSSIBLE, AUDIT_ALWAYS */
+        pub action: crate::__u32,
+        pub field_count: crate::__u32,
+        /* syscall(s) affected */
+        pub mask: [crate::__u32; AUDIT_BITMASK_SIZE as usize],
+        pub fields: [crate::__u32; AUDIT_MAX_FIELDS as usize],
+        pub values: [crate::__u32; AUDIT_MAX_FIELDS as usize],
+        pub fieldflags: [crate::__u32; AUDIT_MAX_FIELDS as usize],
+        /* total length of string fields */
+        pub buflen: crate::__u32,
+        /* string fields buffer */
+        pub buf: [c_char; 0],
+    }
+}
@@ -0,0 +1,872 @@
+//! Header: `uapi/linux/bpf.h`
+
+use crate::prelude::*;
+
+/* Extended instruction set based on top of classic BPF */
+
+/* instruction classes */
+pub const BPF_JMP32: crate::__u32 = 0x06;
+pub const BPF_ALU64: crate::__u32 = 0x07;
+
+/* ld/ldx fields */
+pub const BPF_DW: crate::__u32 = 0x18;
+pub const BPF_MEMSX: crate::__u32 = 0x80;
+pub const BPF_ATOMIC: crate::__u32 = 0xc0;
+pub const BPF_XADD: crate::__u32 = 0xc0;
+
+/* alu/jmp fields */
+pub const BPF_MOV: crate::__u32 = 0xb0;
+pub const BPF_ARSH: crate::__u32 = 0xc0;
+
+/* change endianness of a register */
+pub const BPF_END: crate::__u32 = 0xd0;
+pub const BPF_TO_LE: crate::__u32 = 0x00;
+pub const BPF_TO_BE: crate::__u32 = 0x08;
+pub const BPF_FROM_LE: crate::__u32 = BPF_TO_LE;
+pub const BPF_FROM_BE: crate::__u32 = BPF_TO_BE;
+
+/* jmp encodings */
+pub const BPF_JNE: crate::__u32 = 0x50;
+pub const BPF_JLT: crate::__u32 = 0xa0;
+pub const BPF_JLE: crate::__u32 = 0xb0;
+pub const BPF_JSGT: crate::__u32 = 0x60;
+pub const BPF_JSGE: crate::__u32 = 0x70;
+pub const BPF_JSLT: crate::__u32 = 0xc0;
+pub const BPF_JSLE: crate::__u32 = 0xd0;
+pub const BPF_JCOND: crate::__u32 = 0xe0;
+pub const BPF_CALL: crate::__u32 = 0x80;
+pub const BPF_EXIT: crate::__u32 = 0x90;
+
+/* atomic op type fields (stored in immediate) */
+pub const BPF_FETCH: crate::__u32 = 0x01;
+pub const BPF_XCHG: crate::__u32 = 0xe0 | BPF_FETCH;
+pub const BPF_CMPXCHG: crate::__u32 = 0xf0 | BPF_FETCH;
+
+c_enum! {
+    pub enum bpf_cond_pseudo_jmp {
+        pub BPF_MAY_GOTO = 0,
+    }
+}
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub BPF_REG_0 = 0,
+        pub BPF_REG_1,
+        pub BPF_REG_2,
+        pub BPF_REG_3,
+        pub BPF_REG_4,
+        pub BPF_REG_5,
+        pub BPF_REG_6,
+        pub BPF_REG_7,
+        pub BPF_REG_8,
+        pub BPF_REG_9,
+        pub BPF_REG_10,
+        __MAX_BPF_REG,
+    }
+}
+
+pub const MAX_BPF_REG: c_uint = __MAX_BPF_REG;
+
+s! {
+    pub struct bpf_insn {
+        pub code: crate::__u8,
+        /// Storage for the `dst_reg` and `src_reg` bitfields, see the accessor methods.
+        __bitfield_1: crate::__u8,
+        pub off: crate::__s16,
+        pub imm: crate::__s32,
+    }
+}
+
+impl bpf_insn {
+    bitfield_accessors! {
+        __bitfield_1: crate::__u8;
+        dst_reg, set_dst_reg: 0, 4;
+        src_reg, set_src_reg: 4, 4;
+    }
+}
+
+c_enum! {
+    pub enum bpf_cmd {
+        pub BPF_MAP_CREATE,
+        pub BPF_MAP_LOOKUP_ELEM,
+        pub BPF_MAP_UPDATE_ELEM,
+        pub BPF_MAP_DELETE_ELEM,
+        pub BPF_MAP_GET_NEXT_KEY,
+        pub BPF_PROG_LOAD,
+        pub BPF_OBJ_PIN,
+        pub BPF_OBJ_GET,
+        pub BPF_PROG_ATTACH,
+        pub BPF_PROG_DETACH,
+        pub BPF_PROG_TEST_RUN,
+        pub BPF_PROG_GET_NEXT_ID,
+        pub BPF_MAP_GET_NEXT_ID,
+        pub BPF_PROG_GET_FD_BY_ID,
+        pub BPF_MAP_GET_FD_BY_ID,
+        pub BPF_OBJ_GET_INFO_BY_FD,
+        pub BPF_PROG_QUERY,
+        pub BPF_RAW_TRACEPOINT_OPEN,
+        pub BPF_BTF_LOAD,
+        pub BPF_BTF_GET_FD_BY_ID,
+        pub BPF_TASK_FD_QUERY,
+        pub BPF_MAP_LOOKUP_AND_DELETE_ELEM,
+        pub BPF_MAP_FREEZE,
+        pub BPF_BTF_GET_NEXT_ID,
+        pub BPF_MAP_LOOKUP_BATCH,
+        pub BPF_MAP_LOOKUP_AND_DELETE_BATCH,
+        pub BPF_MAP_UPDATE_BATCH,
+        pub BPF_MAP_DELETE_BATCH,
+        pub BPF_LINK_CREATE,
+        pub BPF_LINK_UPDATE,
+        pub BPF_LINK_GET_FD_BY_ID,
+        pub BPF_LINK_GET_NEXT_ID,
+        pub BPF_ENABLE_STATS,
+        pub BPF_ITER_CREATE,
+        pub BPF_LINK_DETACH,
+        pub BPF_PROG_BIND_MAP,
+        pub BPF_TOKEN_CREATE,
+    }
+}
+
+pub const BPF_PROG_RUN: bpf_cmd = BPF_PROG_TEST_RUN;
+
+c_enum! {
+    pub enum bpf_map_type {
+        pub BPF_MAP_TYPE_UNSPEC,
+        pub BPF_MAP_TYPE_HASH,
+        pub BPF_MAP_TYPE_ARRAY,
+        pub BPF_MAP_TYPE_PROG_ARRAY,
+        pub BPF_MAP_TYPE_PERF_EVENT_ARRAY,
+        pub BPF_MAP_TYPE_PERCPU_HASH,
+        pub BPF_MAP_TYPE_PERCPU_ARRAY,
+        pub BPF_MAP_TYPE_STACK_TRACE,
+        pub BPF_MAP_TYPE_CGROUP_ARRAY,
+        pub BPF_MAP_TYPE_LRU_HASH,
+        pub BPF_MAP_TYPE_LRU_PERCPU_HASH,
+        pub BPF_MAP_TYPE_LPM_TRIE,
+        pub BPF_MAP_TYPE_ARRAY_OF_MAPS,
+        pub BPF_MAP_TYPE_HASH_OF_MAPS,
+        pub BPF_MAP_TYPE_DEVMAP,
+        pub BPF_MAP_TYPE_SOCKMAP,
+        pub BPF_MAP_TYPE_CPUMAP,
+        pub BPF_MAP_TYPE_XSKMAP,
+        pub BPF_MAP_TYPE_SOCKHASH,
+        pub BPF_MAP_TYPE_CGROUP_STORAGE_DEPRECATED,
+        pub BPF_MAP_TYPE_REUSEPORT_SOCKARRAY,
+        pub BPF_MAP_TYPE_PERCPU_CGROUP_STORAGE_DEPRECATED,
+        pub BPF_MAP_TYPE_QUEUE,
+        pub BPF_MAP_TYPE_STACK,
+        pub BPF_MAP_TYPE_SK_STORAGE,
+        pub BPF_MAP_TYPE_DEVMAP_HASH,
+        pub BPF_MAP_TYPE_STRUCT_OPS,
+        pub BPF_MAP_TYPE_RINGBUF,
+        pub BPF_MAP_TYPE_INODE_STORAGE,
+        pub BPF_MAP_TYPE_TASK_STORAGE,
+        pub BPF_MAP_TYPE_BLOOM_FILTER,
+        pub BPF_MAP_TYPE_USER_RINGBUF,
+        pub BPF_MAP_TYPE_CGRP_STORAGE,
+        pub BPF_MAP_TYPE_ARENA,
+    }
+}
+
+pub const BPF_MAP_TYPE_CGROUP_STORAGE: bpf_map_type = BPF_MAP_TYPE_CGROUP_STORAGE_DEPRECATED;
+pub const BPF_MAP_TYPE_PERCPU_CGROUP_STORAGE: bpf_map_type =
+    BPF_MAP_TYPE_PERCPU_CGROUP_STORAGE_DEPRECATED;
+
+c_enum! {
+    pub enum bpf_prog_type {
+        pub BPF_PROG_TYPE_UNSPEC,
+        pub BPF_PROG_TYPE_SOCKET_FILTER,
+        pub BPF_PROG_TYPE_KPROBE,
+        pub BPF_PROG_TYPE_SCHED_CLS,
+        pub BPF_PROG_TYPE_SCHED_ACT,
+        pub BPF_PROG_TYPE_TRACEPOINT,
+        pub BPF_PROG_TYPE_XDP,
+        pub BPF_PROG_TYPE_PERF_EVENT,
+        pub BPF_PROG_TYPE_CGROUP_SKB,
+        pub BPF_PROG_TYPE_CGROUP_SOCK,
+        pub BPF_PROG_TYPE_LWT_IN,
+        pub BPF_PROG_TYPE_LWT_OUT,
+        pub BPF_PROG_TYPE_LWT_XMIT,
+        pub BPF_PROG_TYPE_SOCK_OPS,
+        pub BPF_PROG_TYPE_SK_SKB,
+        pub BPF_PROG_TYPE_CGROUP_DEVICE,
+        pub BPF_PROG_TYPE_SK_MSG,
+        pub BPF_PROG_TYPE_RAW_TRACEPOINT,
+        pub BPF_PROG_TYPE_CGROUP_SOCK_ADDR,
+        pub BPF_PROG_TYPE_LWT_SEG6LOCAL,
+        pub BPF_PROG_TYPE_LIRC_MODE2,
+        pub BPF_PROG_TYPE_SK_REUSEPORT,
+        pub BPF_PROG_TYPE_FLOW_DISSECTOR,
+        pub BPF_PROG_TYPE_CGROUP_SYSCTL,
+        pub BPF_PROG_TYPE_RAW_TRACEPOINT_WRITABLE,
+        pub BPF_PROG_TYPE_CGROUP_SOCKOPT,
+        pub BPF_PROG_TYPE_TRACING,
+        pub BPF_PROG_TYPE_STRUCT_OPS,
+        pub BPF_PROG_TYPE_EXT,
+        pub BPF_PROG_TYPE_LSM,
+        pub BPF_PROG_TYPE_SK_LOOKUP,
+        pub BPF_PROG_TYPE_SYSCALL,
+        pub BPF_PROG_TYPE_NETFILTER,
+    }
+
+    pub enum bpf_attach_type {
+        pub BPF_CGROUP_INET_INGRESS,
+        pub BPF_CGROUP_INET_EGRESS,
+        pub BPF_CGROUP_INET_SOCK_CREATE,
+        pub BPF_CGROUP_SOCK_OPS,
+        pub BPF_SK_SKB_STREAM_PARSER,
+        pub BPF_SK_SKB_STREAM_VERDICT,
+        pub BPF_CGROUP_DEVICE,
+        pub BPF_SK_MSG_VERDICT,
+        pub BPF_CGROUP_INET4_BIND,
+        pub BPF_CGROUP_INET6_BIND,
+        pub BPF_CGROUP_INET4_CONNECT,
+        pub BPF_CGROUP_INET6_CONNECT,
+        pub BPF_CGROUP_INET4_POST_BIND,
+        pub BPF_CGROUP_INET6_POST_BIND,
+        pub BPF_CGROUP_UDP4_SENDMSG,
+        pub BPF_CGROUP_UDP6_SENDMSG,
+        pub BPF_LIRC_MODE2,
+        pub BPF_FLOW_DISSECTOR,
+        pub BPF_CGROUP_SYSCTL,
+        pub BPF_CGROUP_UDP4_RECVMSG,
+        pub BPF_CGROUP_UDP6_RECVMSG,
+        pub BPF_CGROUP_GETSOCKOPT,
+        pub BPF_CGROUP_SETSOCKOPT,
+        pub BPF_TRACE_RAW_TP,
+        pub BPF_TRACE_FENTRY,
+        pub BPF_TRACE_FEXIT,
+        pub BPF_MODIFY_RETURN,
+        pub BPF_LSM_MAC,
+        pub BPF_TRACE_ITER,
+        pub BPF_CGROUP_INET4_GETPEERNAME,
+        pub BPF_CGROUP_INET6_GETPEERNAME,
+        pub BPF_CGROUP_INET4_GETSOCKNAME,
+        pub BPF_CGROUP_INET6_GETSOCKNAME,
+        pub BPF_XDP_DEVMAP,
+        pub BPF_CGROUP_INET_SOCK_RELEASE,
+        pub BPF_XDP_CPUMAP,
+        pub BPF_SK_LOOKUP,
+        pub BPF_XDP,
+        pub BPF_SK_SKB_VERDICT,
+        pub BPF_SK_REUSEPORT_SELECT,
+        pub BPF_SK_REUSEPORT_SELECT_OR_MIGRATE,
+        pub BPF_PERF_EVENT,
+        pub BPF_TRACE_KPROBE_MULTI,
+        pub BPF_LSM_CGROUP,
+        pub BPF_STRUCT_OPS,
+        pub BPF_NETFILTER,
+        pub BPF_TCX_INGRESS,
+        pub BPF_TCX_EGRESS,
+        pub BPF_TRACE_UPROBE_MULTI,
+        pub BPF_CGROUP_UNIX_CONNECT,
+        pub BPF_CGROUP_UNIX_SENDMSG,
+        pub BPF_CGROUP_UNIX_RECVMSG,
+        pub BPF_CGROUP_UNIX_GETPEERNAME,
+        pub BPF_CGROUP_UNIX_GETSOCKNAME,
+        pub BPF_NETKIT_PRIMARY,
+        pub BPF_NETKIT_PEER,
+        pub BPF_TRACE_KPROBE_SESSION,
+    }
+
+    pub enum bpf_link_type {
+        pub BPF_LINK_TYPE_UNSPEC = 0,
+        pub BPF_LINK_TYPE_RAW_TRACEPOINT = 1,
+        pub BPF_LINK_TYPE_TRACING = 2,
+        pub BPF_LINK_TYPE_CGROUP = 3,
+        pub BPF_LINK_TYPE_ITER = 4,
+        pub BPF_LINK_TYPE_NETNS = 5,
+        pub BPF_LINK_TYPE_XDP = 6,
+        pub BPF_LINK_TYPE_PERF_EVENT = 7,
+        pub BPF_LINK_TYPE_KPROBE_MULTI = 8,
+        pub BPF_LINK_TYPE_STRUCT_OPS = 9,
+        pub BPF_LINK_TYPE_NETFILTER = 10,
+        pub BPF_LINK_TYPE_TCX = 11,
+        pub BPF_LINK_TYPE_UPROBE_MULTI = 12,
+        pub BPF_LINK_TYPE_NETKIT = 13,
+        pub BPF_LINK_TYPE_SOCKMAP = 14,
+    }
+
+    pub enum bpf_perf_event_type {
+        pub BPF_PERF_EVENT_UNSPEC = 0,
+        pub BPF_PERF_EVENT_UPROBE = 1,
+        pub BPF_PERF_EVENT_URETPROBE = 2,
+        pub BPF_PERF_EVENT_KPROBE = 3,
+        pub BPF_PERF_EVENT_KRETPROBE = 4,
+        pub BPF_PERF_EVENT_TRACEPOINT = 5,
+        pub BPF_PERF_EVENT_EVENT = 6,
+    }
+}
+
+/* cgroup-bpf attach flags used in BPF_PROG_ATTACH command */
+pub const BPF_F_ALLOW_OVERRIDE: c_uint = 1 << 0;
+pub const BPF_F_ALLOW_MULTI: c_uint = 1 << 1;
+pub const BPF_F_REPLACE: c_uint = 1 << 2;
+pub const BPF_F_BEFORE: c_uint = 1 << 3;
+pub const BPF_F_AFTER: c_uint = 1 << 4;
+pub const BPF_F_ID: c_uint = 1 << 5;
+
+/* flags for BPF_PROG_LOAD command */
+pub const BPF_F_STRICT_ALIGNMENT: c_uint = 1 << 0;
+pub const BPF_F_ANY_ALIGNMENT: c_uint = 1 << 1;
+pub const BPF_F_TEST_RND_HI32: c_uint = 1 << 2;
+pub const BPF_F_TEST_STATE_FREQ: c_uint = 1 << 3;
+pub const BPF_F_SLEEPABLE: c_uint = 1 << 4;
+pub const BPF_F_XDP_HAS_FRAGS: c_uint = 1 << 5;
+pub const BPF_F_XDP_DEV_BOUND_ONLY: c_uint = 1 << 6;
+pub const BPF_F_TEST_REG_INVARIANTS: c_uint = 1 << 7;
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub BPF_F_KPROBE_MULTI_RETURN = 1 << 0,
+    }
+
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub BPF_F_UPROBE_MULTI_RETURN = 1 << 0,
+    }
+}
+
+pub const BPF_F_NETFILTER_IP_DEFRAG: c_uint = 1 << 0;
+
+/* src_reg encodings for BPF_LD_IMM64 */
+pub const BPF_PSEUDO_MAP_FD: c_int = 1;
+pub const BPF_PSEUDO_MAP_IDX: c_int = 5;
+pub const BPF_PSEUDO_MAP_VALUE: c_int = 2;
+pub const BPF_PSEUDO_MAP_IDX_VALUE: c_int = 6;
+pub const BPF_PSEUDO_BTF_ID: c_int = 3;
+pub const BPF_PSEUDO_FUNC: c_int = 4;
+
+/* src_reg encodings for BPF_CALL */
+pub const BPF_PSEUDO_CALL: c_int = 1;
+pub const BPF_PSEUDO_KFUNC_CALL: c_int = 2;
+
+c_enum! {
+    pub enum bpf_addr_space_cast {
+        pub BPF_ADDR_SPACE_CAST = 1,
+    }
+}
+
+c_enum! {
+    /* flags for BPF_MAP_UPDATE_ELEM command */
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub BPF_ANY = 0,
+        pub BPF_NOEXIST = 1,
+        pub BPF_EXIST = 2,
+        pub BPF_F_LOCK = 4,
+    }
+
+    /* flags for BPF_MAP_CREATE command */
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub BPF_F_NO_PREALLOC = 1 << 0,
+        pub BPF_F_NO_COMMON_LRU = 1 << 1,
+        pub BPF_F_NUMA_NODE = 1 << 2,
+        pub BPF_F_RDONLY = 1 << 3,
+        pub BPF_F_WRONLY = 1 << 4,
+        pub BPF_F_STACK_BUILD_ID = 1 << 5,
+        pub BPF_F_ZERO_SEED = 1 << 6,
+        pub BPF_F_RDONLY_PROG = 1 << 7,
+        pub BPF_F_WRONLY_PROG = 1 << 8,
+        pub BPF_F_CLONE = 1 << 9,
+        pub BPF_F_MMAPABLE = 1 << 10,
+        pub BPF_F_PRESERVE_ELEMS = 1 << 11,
+        pub BPF_F_INNER_MAP = 1 << 12,
+        pub BPF_F_LINK = 1 << 13,
+        pub BPF_F_PATH_FD = 1 << 14,
+        pub BPF_F_VTYPE_BTF_OBJ_FD = 1 << 15,
+        pub BPF_F_TOKEN_FD = 1 << 16,
+        pub BPF_F_SEGV_ON_FAULT = 1 << 17,
+        pub BPF_F_NO_USER_CONV = 1 << 18,
+    }
+}
+
+/* Flags for BPF_PROG_QUERY. */
+pub const BPF_F_QUERY_EFFECTIVE: c_uint = 1 << 0;
+
+/* Flags for BPF_PROG_TEST_RUN */
+pub const BPF_F_TEST_RUN_ON_CPU: c_uint = 1 << 0;
+pub const BPF_F_TEST_XDP_LIVE_FRAMES: c_uint = 1 << 1;
+pub const BPF_F_TEST_SKB_CHECKSUM_COMPLETE: c_uint = 1 << 2;
+
+c_enum! {
+    pub enum bpf_stats_type {
+        pub BPF_STATS_RUN_TIME = 0,
+    }
+}
+
+pub const BPF_BUILD_ID_SIZE: c_int = 20;
+pub const BPF_OBJ_NAME_LEN: c_uint = 16;
+
+s_no_extra_traits! {
+    #[repr(align(8))]
+    pub union bpf_attr {
+        /* anonymous struct used by BPF_MAP_CREATE command */
+        pub anonymous_1: __c_anonymous_bpf_attr_map_create,
+        /* anonymous struct used by BPF_MAP_*_ELEM and BPF_MAP_FREEZE commands */
+        pub anonymous_2: __c_anonymous_bpf_attr_map_elem,
+        /* struct used by BPF_MAP_*_BATCH commands */
+        pub batch: __c_anonymous_bpf_attr_batch,
+        /* anonymous struct used by BPF_PROG_LOAD command */
+        pub anonymous_3: __c_anonymous_bpf_attr_prog_load,
+        /* anonymous struct used by BPF_OBJ_* commands */
+        pub anonymous_4: __c_anonymous_bpf_attr_obj,
+        /* anonymous struct used by BPF_PROG_ATTACH/DETACH commands */
+        pub anonymous_5: __c_anonymous_bpf_attr_prog_attach,
+        /* anonymous struct used by BPF_PROG_TEST_RUN command */
+        pub test: __c_anonymous_bpf_attr_test,
+        /* anonymous struct used by BPF_*_GET_*_ID */
+        pub anonymous_6: __c_anonymous_bpf_attr_get_id,
+        /* anonymous struct used by BPF_OBJ_GET_INFO_BY_FD */
+        pub info: __c_anonymous_bpf_attr_info,
+        /* anonymous struct used by BPF_PROG_QUERY command */
+        pub query: __c_anonymous_bpf_attr_query,
+        /* anonymous struct used by BPF_RAW_TRACEPOINT_OPEN command */
+        pub raw_tracepoint: __c_anonymous_bpf_attr_raw_tracepoint,
+        /* anonymous struct for BPF_BTF_LOAD */
+        pub anonymous_7: __c_anonymous_bpf_attr_btf_load,
+        pub task_fd_query: __c_anonymous_bpf_attr_task_fd_query,
+        /* struct used by BPF_LINK_CREATE command */
+        pub link_create: __c_anonymous_bpf_attr_link_create,
+        /* struct used by BPF_LINK_UPDATE command */
+        pub link_update: __c_anonymous_bpf_attr_link_update,
+        pub link_detach: __c_anonymous_bpf_attr_link_detach,
+        /* struct used by BPF_ENABLE_STATS command */
+        pub enable_stats: __c_anonymous_bpf_attr_enable_stats,
+        /* struct used by BPF_ITER_CREATE command */
+        pub iter_create: __c_anonymous_bpf_attr_iter_create,
+        /* struct used by BPF_PROG_BIND_MAP command */
+        pub prog_bind_map: __c_anonymous_bpf_attr_prog_bind_map,
+        /* struct used by BPF_TOKEN_CREATE command */
+        pub token_create: __c_anonymous_bpf_attr_token_create,
+    }
+
+    pub struct __c_anonymous_bpf_attr_map_create {
+        pub map_type: crate::__u32,
+        pub key_size: crate::__u32,
+        pub value_size: crate::__u32,
+        pub max_entries: crate::__u32,
+        pub map_flags: crate::__u32,
+        pub inner_map_fd: crate::__u32,
+        pub numa_node: crate::__u32,
+        pub map_name: [c_char; BPF_OBJ_NAME_LEN as usize],
+        pub map_ifindex: crate::__u32,
+        pub btf_fd: crate::__u32,
+        pub btf_key_type_id: crate::__u32,
+        pub btf_value_type_id: crate::__u32,
+        pub btf_vmlinux_value_type_id: crate::__u32,
+        pub map_extra: crate::__u64,
+        pub value_type_btf_obj_fd: crate::__s32,
+        pub map_token_fd: crate::__s32,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_map_elem {
+        pub map_fd: crate::__u32,
+        pub key: crate::__u64,
+        pub anonymous_1: __c_anonymous_bpf_attr_map_elem_1,
+        pub flags: crate::__u64,
+    }
+
+    #[repr(align(8))]
+    pub union __c_anonymous_bpf_attr_map_elem_1 {
+        pub value: crate::__u64,
+        pub next_key: crate::__u64,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_batch {
+        pub in_batch: crate::__u64,
+        pub out_batch: crate::__u64,
+        pub keys: crate::__u64,
+        pub values: crate::__u64,
+        pub count: crate::__u32,
+        pub map_fd: crate::__u32,
+        pub elem_flags: crate::__u64,
+        pub flags: crate::__u64,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_prog_load {
+        pub prog_type: crate::__u32,
+        pub insn_cnt: crate::__u32,
+        pub insns: crate::__u64,
+        pub license: crate::__u64,
+        pub log_level: crate::__u32,
+        pub log_size: crate::__u32,
+        pub log_buf: crate::__u64,
+        pub kern_version: crate::__u32,
+        pub prog_flags: crate::__u32,
+        pub prog_name: [c_char; BPF_OBJ_NAME_LEN as usize],
+        pub prog_ifindex: crate::__u32,
+        pub expected_attach_type: crate::__u32,
+        pub prog_btf_fd: crate::__u32,
+        pub func_info_rec_size: crate::__u32,
+        pub func_info: crate::__u64,
+        pub func_info_cnt: crate::__u32,
+        pub line_info_rec_size: crate::__u32,
+        pub line_info: crate::__u64,
+        pub line_info_cnt: crate::__u32,
+        pub attach_btf_id: crate::__u32,
+        pub anonymous_1: __c_anonymous_bpf_attr_prog_load_1,
+        pub core_relo_cnt: crate::__u32,
+        pub fd_array: crate::__u64,
+        pub core_relos: crate::__u64,
+        pub core_relo_rec_size: crate::__u32,
+        pub log_true_size: crate::__u32,
+        pub prog_token_fd: crate::__s32,
+    }
+
+    pub union __c_anonymous_bpf_attr_prog_load_1 {
+        pub attach_prog_fd: crate::__u32,
+        pub attach_btf_obj_fd: crate::__u32,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_obj {
+        pub pathname: crate::__u64,
+        pub bpf_fd: crate::__u32,
+        pub file_flags: crate::__u32,
+        pub path_fd: crate::__s32,
+    }
+
+    pub struct __c_anonymous_bpf_attr_prog_attach {
+        pub anonymous_1: __c_anonymous_bpf_attr_target,
+        pub attach_bpf_fd: crate::__u32,
+        pub attach_type: crate::__u32,
+        pub attach_flags: crate::__u32,
+        pub replace_bpf_fd: crate::__u32,
+        pub anonymous_2: __c_anonymous_bpf_attr_relative,
+        pub expected_revision: crate::__u64,
+    }
+
+    pub union __c_anonymous_bpf_attr_target {
+        pub target_fd: crate::__u32,
+        pub target_ifindex: crate::__u32,
+    }
+
+    pub union __c_anonymous_bpf_attr_relative {
+        pub relative_fd: crate::__u32,
+        pub relative_id: crate::__u32,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_test {
+        pub prog_fd: crate::__u32,
+        pub retval: crate::__u32,
+        pub data_size_in: crate::__u32,
+        pub data_size_out: crate::__u32,
+        pub data_in: crate::__u64,
+        pub data_out: crate::__u64,
+        pub repeat: crate::__u32,
+        pub duration: crate::__u32,
+        pub ctx_size_in: crate::__u32,
+        pub ctx_size_out: crate::__u32,
+        pub ctx_in: crate::__u64,
+        pub ctx_out: crate::__u64,
+        pub flags: crate::__u32,
+        pub cpu: crate::__u32,
+        pub batch_size: crate::__u32,
+    }
+
+    pub struct __c_anonymous_bpf_attr_get_id {
+        pub anonymous_1: __c_anonymous_bpf_attr_get_id_1,
+        pub next_id: crate::__u32,
+        pub open_flags: crate::__u32,
+    }
+
+    pub union __c_anonymous_bpf_attr_get_id_1 {
+        pub start_id: crate::__u32,
+        pub prog_id: crate::__u32,
+        pub map_id: crate::__u32,
+        pub btf_id: crate::__u32,
+        pub link_id: crate::__u32,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_info {
+        pub bpf_fd: crate::__u32,
+        pub info_len: crate::__u32,
+        pub info: crate::__u64,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_query {
+        pub anonymous_1: __c_anonymous_bpf_attr_target,
+        pub attach_type: crate::__u32,
+        pub query_flags: crate::__u32,
+        pub attach_flags: crate::__u32,
+        pub prog_ids: crate::__u64,
+        pub anonymous_2: __c_anonymous_bpf_attr_query_2,
+        __pad: Padding<crate::__u32>,
+        pub prog_attach_flags: crate::__u64,
+        pub link_ids: crate::__u64,
+        pub link_attach_flags: crate::__u64,
+        pub revision: crate::__u64,
+    }
+
+    pub union __c_anonymous_bpf_attr_query_2 {
+        pub prog_cnt: crate::__u32,
+        pub count: crate::__u32,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_raw_tracepoint {
+        pub name: crate::__u64,
+        pub prog_fd: crate::__u32,
+        __pad: Padding<crate::__u32>,
+        pub cookie: crate::__u64,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_btf_load {
+        pub btf: crate::__u64,
+        pub btf_log_buf: crate::__u64,
+        pub btf_size: crate::__u32,
+        pub btf_log_size: crate::__u32,
+        pub btf_log_level: crate::__u32,
+        pub btf_log_true_size: crate::__u32,
+        pub btf_flags: crate::__u32,
+        pub btf_token_fd: crate::__s32,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_task_fd_query {
+        pub pid: crate::__u32,
+        pub fd: crate::__u32,
+        pub flags: crate::__u32,
+        pub buf_len: crate::__u32,
+        pub buf: crate::__u64,
+        pub prog_id: crate::__u32,
+        pub fd_type: crate::__u32,
+        pub probe_offset: crate::__u64,
+        pub probe_addr: crate::__u64,
+    }
+
+    pub struct __c_anonymous_bpf_attr_link_create {
+        pub anonymous_1: __c_anonymous_bpf_attr_link_create_1,
+        pub anonymous_2: __c_anonymous_bpf_attr_target,
+        pub attach_type: crate::__u32,
+        pub flags: crate::__u32,
+        pub anonymous_3: __c_anonymous_bpf_attr_link_create_3,
+    }
+
+    pub union __c_anonymous_bpf_attr_link_create_1 {
+        pub prog_fd: crate::__u32,
+        pub map_fd: crate::__u32,
+    }
+
+    pub union __c_anonymous_bpf_attr_link_create_3 {
+        pub target_btf_id: crate::__u32,
+        pub anonymous_1: __c_anonymous_bpf_attr_link_create_iter,
+        pub perf_event: __c_anonymous_bpf_attr_link_create_perf_event,
+        pub kprobe_multi: __c_anonymous_bpf_attr_link_create_kprobe_multi,
+        pub tracing: __c_anonymous_bpf_attr_link_create_tracing,
+        pub netfilter: __c_anonymous_bpf_attr_link_create_netfilter,
+        pub tcx: __c_anonymous_bpf_attr_link_create_mprog,
+        pub uprobe_multi: __c_anonymous_bpf_attr_link_create_uprobe_multi,
+        pub netkit: __c_anonymous_bpf_attr_link_create_mprog,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_link_create_iter {
+        pub iter_info: crate::__u64,
+        pub iter_info_len: crate::__u32,
+    }
+
+    pub struct __c_anonymous_bpf_attr_link_create_perf_event {
+        pub bpf_cookie: crate::__u64,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_link_create_kprobe_multi {
+        pub flags: crate::__u32,
+        pub cnt: crate::__u32,
+        pub syms: crate::__u64,
+        pub addrs: crate::__u64,
+        pub cookies: crate::__u64,
+    }
+
+    pub struct __c_anonymous_bpf_attr_link_create_tracing {
+        pub target_btf_id: crate::__u32,
+        pub cookie: crate::__u64,
+    }
+
+    pub struct __c_anonymous_bpf_attr_link_create_netfilter {
+        pub pf: crate::__u32,
+        pub hooknum: crate::__u32,
+        pub priority: crate::__s32,
+        pub flags: crate::__u32,
+    }
+
+    pub struct __c_anonymous_bpf_attr_link_create_mprog {
+        pub anonymous_1: __c_anonymous_bpf_attr_relative,
+        pub expected_revision: crate::__u64,
+    }
+
+    #[repr(align(8))]
+    pub struct __c_anonymous_bpf_attr_link_create_uprobe_multi {
+        pub path: crate::__u64,
+        pub offsets: crate::__u64,
+        pub ref_ctr_offsets: crate::__u64,
+        pub cookies: crate::__u64,
+        pub cnt: crate::__u32,
+        pub flags: crate::__u32,
+        pub pid: crate::__u32,
+    }
+
+    pub struct __c_anonymous_bpf_attr_link_update {
+        pub link_fd: crate::__u32,
+        pub anonymous_1: __c_anonymous_bpf_attr_link_update_1,
+        pub flags: crate::__u32,
+        pub anonymous_2: __c_anonymous_bpf_attr_link_update_2,
+    }
+
+    pub union __c_anonymous_bpf_attr_link_update_1 {
+        pub new_prog_fd: crate::__u32,
+        pub new_map_fd: crate::__u32,
+    }
+
+    pub union __c_anonymous_bpf_attr_link_update_2 {
+        pub old_prog_fd: crate::__u32,
+        pub old_map_fd: crate::__u32,
+    }
+
+    pub struct __c_anonymous_bpf_attr_link_detach {
+        pub link_fd: crate::__u32,
+    }
+
+    pub struct __c_anonymous_bpf_attr_enable_stats {
+        pub type_: crate::__u32,
+    }
+
+    pub struct __c_anonymous_bpf_attr_iter_create {
+        pub link_fd: crate::__u32,
+        pub flags: crate::__u32,
+    }
+
+    pub struct __c_anonymous_bpf_attr_prog_bind_map {
+        pub prog_fd: crate::__u32,
+        pub map_fd: crate::__u32,
+        pub flags: crate::__u32,
+    }
+
+    pub struct __c_anonymous_bpf_attr_token_create {
+        pub flags: crate::__u32,
+        pub bpffs_fd: crate::__u32,
+    }
+}
+
+c_enum! {
+    /* User return codes for XDP prog type. */
+    pub enum xdp_action {
+        pub XDP_ABORTED = 0,
+        pub XDP_DROP,
+        pub XDP_PASS,
+        pub XDP_TX,
+        pub XDP_REDIRECT,
+    }
+
+    pub enum sk_action {
+        pub SK_DROP = 0,
+        pub SK_PASS,
+    }
+}
+
+pub const BPF_TAG_SIZE: c_int = 8;
+
+s! {
+    #[repr(align(8))]
+    pub struct bpf_prog_info {
+        pub type_: crate::__u32,
+        pub id: crate::__u32,
+        pub tag: [crate::__u8; BPF_TAG_SIZE as usize],
+        pub jited_prog_len: crate::__u32,
+        pub xlated_prog_len: crate::__u32,
+        pub jited_prog_insns: crate::__u64,
+        pub xlated_prog_insns: crate::__u64,
+        pub load_time: crate::__u64,
+        pub created_by_uid: crate::__u32,
+        pub nr_map_ids: crate::__u32,
+        pub map_ids: crate::__u64,
+        pub name: [c_char; BPF_OBJ_NAME_LEN as usize],
+        pub ifindex: crate::__u32,
+        /// Storage for the `gpl_compatible` bitfield, see the accessor methods.
+        __bitfield_1: crate::__u32,
+        pub netns_dev: crate::__u64,
+        pub netns_ino: crate::__u64,
+        pub nr_jited_ksyms: crate::__u32,
+        pub nr_jited_func_lens: crate::__u32,
+        pub jited_ksyms: crate::__u64,
+        pub jited_func_lens: crate::__u64,
+        pub btf_id: crate::__u32,
+        pub func_info_rec_size: crate::__u32,
+        pub func_info: crate::__u64,
+        pub nr_func_info: crate::__u32,
+        pub nr_line_info: crate::__u32,
+        pub line_info: crate::__u64,
+        pub jited_line_info: crate::__u64,
+        pub nr_jited_line_info: crate::__u32,
+        pub line_info_rec_size: crate::__u32,
+        pub jited_line_info_rec_size: crate::__u32,
+        pub nr_prog_tags: crate::__u32,
+        pub prog_tags: crate::__u64,
+        pub run_time_ns: crate::__u64,
+        pub run_cnt: crate::__u64,
+        pub recursion_misses: crate::__u64,
+        pub verified_insns: crate::__u32,
+        pub attach_btf_obj_id: crate::__u32,
+        pub attach_btf_id: crate::__u32,
+    }
+
+    #[repr(align(8))]
+    pub struct bpf_map_info {
+        pub type_: crate::__u32,
+        pub id: crate::__u32,
+        pub key_size: crate::__u32,
+        pub value_size: crate::__u32,
+        pub max_entries: crate::__u32,
+        pub map_flags: crate::__u32,
+        pub name: [c_char; BPF_OBJ_NAME_LEN as usize],
+        pub ifindex: crate::__u32,
+        pub btf_vmlinux_value_type_id: crate::__u32,
+        pub netns_dev: crate::__u64,
+        pub netns_ino: crate::__u64,
+        pub btf_id: crate::__u32,
+        pub btf_key_type_id: crate::__u32,
+        pub btf_value_type_id: crate::__u32,
+        pub btf_vmlinux_id: crate::__u32,
+        pub map_extra: crate::__u64,
+    }
+
+    #[repr(align(8))]
+    pub struct bpf_btf_info {
+        pub btf: crate::__u64,
+        pub btf_size: crate::__u32,
+        pub id: crate::__u32,
+        pub name: crate::__u64,
+        pub name_len: crate::__u32,
+        pub kernel_btf: crate::__u32,
+    }
+}
+
+impl bpf_prog_info {
+    bitfield_accessors! {
+        __bitfield_1: crate::__u32;
+        gpl_compatible, set_gpl_compatible: 0, 1;
+    }
+}
+
+c_enum! {
+    pub enum bpf_task_fd_type {
+        pub BPF_FD_TYPE_RAW_TRACEPOINT,
+        pub BPF_FD_TYPE_TRACEPOINT,
+        pub BPF_FD_TYPE_KPROBE,
+        pub BPF_FD_TYPE_KRETPROBE,
+        pub BPF_FD_TYPE_UPROBE,
+        pub BPF_FD_TYPE_URETPROBE,
+    }
+}
@@ -0,0 +1,69 @@
+//! Header: `uapi/linux/bpf_common.h`
+
+use crate::prelude::*;
+
+/* Instruction classes */
+pub const BPF_LD: crate::__u32 = 0x00;
+pub const BPF_LDX: crate::__u32 = 0x01;
+pub const BPF_ST: crate::__u32 = 0x02;
+pub const BPF_STX: crate::__u32 = 0x03;
+pub const BPF_ALU: crate::__u32 = 0x04;
+pub const BPF_JMP: crate::__u32 = 0x05;
+pub const BPF_RET: crate::__u32 = 0x06;
+pub const BPF_MISC: crate::__u32 = 0x07;
+
+/* ld/ldx fields */
+pub const BPF_W: crate::__u32 = 0x00;
+pub const BPF_H: crate::__u32 = 0x08;
+pub const BPF_B: crate::__u32 = 0x10;
+pub const BPF_IMM: crate::__u32 = 0x00;
+pub const BPF_ABS: crate::__u32 = 0x20;
+pub const BPF_IND: crate::__u32 = 0x40;
+pub const BPF_MEM: crate::__u32 = 0x60;
+pub const BPF_LEN: crate::__u32 = 0x80;
+pub const BPF_MSH: crate::__u32 = 0xa0;
+
+/* alu/jmp fields */
+pub const BPF_ADD: crate::__u32 = 0x00;
+pub const BPF_SUB: crate::__u32 = 0x10;
+pub const BPF_MUL: crate::__u32 = 0x20;
+pub const BPF_DIV: crate::__u32 = 0x30;
+pub const BPF_OR: crate::__u32 = 0x40;
+pub const BPF_AND: crate::__u32 = 0x50;
+pub const BPF_LSH: crate::__u32 = 0x60;
+pub const BPF_RSH: crate::__u32 = 0x70;
+pub const BPF_NEG: crate::__u32 = 0x80;
+pub const BPF_MOD: crate::__u32 = 0x90;
+pub const BPF_XOR: crate::__u32 = 0xa0;
+
+pub const BPF_JA: crate::__u32 = 0x00;
+pub const BPF_JEQ: crate::__u32 = 0x10;
+pub const BPF_JGT: crate::__u32 = 0x20;
+pub const BPF_JGE: crate::__u32 = 0x30;
+pub const BPF_JSET: crate::__u32 = 0x40;
+pub const BPF_K: crate::__u32 = 0x00;
+pub const BPF_X: crate::__u32 = 0x08;
+
+pub const BPF_MAXINSNS: c_int = 4096;
+
+f! {
+    pub fn BPF_CLASS(code: crate::__u32) -> crate::__u32 {
+        code & 0x07
+    }
+
+    pub fn BPF_SIZE(code: crate::__u32) -> crate::__u32 {
+        code & 0x18
+    }
+
+    pub fn BPF_MODE(code: crate::__u32) -> crate::__u32 {
+        code & 0xe0
+    }
+
+    pub fn BPF_OP(code: crate::__u32) -> crate::__u32 {
+        code & 0xf0
+    }
+
+    pub fn BPF_SRC(code: crate::__u32) -> crate::__u32 {
+        code & 0x08
+    }
+}
@@ -0,0 +1,151 @@
+//! Header: `uapi/linux/capability.h`
+
+use crate::prelude::*;
+
+/* User-level do most of the mapping between kernel and user
+capabilities based on the version tag given by the kernel. The
+kernel might be somewhat backwards compatible, but don't bet on
+it. */
+
+/* Note, cap_t, is defined by POSIX (draft) to be an "opaque" pointer to
+a set of three capability sets.  The transposition of 3*the
+following structure to such a composite is better handled in a user
+library since the draft standard requires the use of malloc/free
+etc.. */
+
+pub const _LINUX_CAPABILITY_VERSION_1: crate::__u32 = 0x19980330;
+pub const _LINUX_CAPABILITY_U32S_1: usize = 1;
+
+/* deprecated - use v3 */
+pub const _LINUX_CAPABILITY_VERSION_2: crate::__u32 = 0x20071026;
+pub const _LINUX_CAPABILITY_U32S_2: usize = 2;
+
+pub const _LINUX_CAPABILITY_VERSION_3: crate::__u32 = 0x20080522;
+pub const _LINUX_CAPABILITY_U32S_3: usize = 2;
+
+pub type cap_user_header_t = *mut __user_cap_header_struct;
+pub type cap_user_data_t = *mut __user_cap_data_struct;
+
+s! {
+    pub struct __user_cap_header_struct {
+        pub version: crate::__u32,
+        pub pid: c_int,
+    }
+
+    pub struct __user_cap_data_struct {
+        pub effective: crate::__u32,
+        pub permitted: crate::__u32,
+        pub inheritable: crate::__u32,
+    }
+}
+
+pub const VFS_CAP_REVISION_MASK: c_uint = 0xFF000000;
+pub const VFS_CAP_REVISION_SHIFT: c_uint = 24;
+pub const VFS_CAP_FLAGS_MASK: c_uint = !VFS_CAP_REVISION_MASK;
+pub const VFS_CAP_FLAGS_EFFECTIVE: c_uint = 0x000001;
+
+pub const VFS_CAP_REVISION_1: c_uint = 0x01000000;
+pub const VFS_CAP_U32_1: usize = 1;
+pub const XATTR_CAPS_SZ_1: usize = size_of::<crate::__u32>() * (1 + 2 * VFS_CAP_U32_1);
+
+pub const VFS_CAP_REVISION_2: c_uint = 0x02000000;
+pub const VFS_CAP_U32_2: usize = 2;
+pub const XATTR_CAPS_SZ_2: usize = size_of::<crate::__u32>() * (1 + 2 * VFS_CAP_U32_2);
+
+pub const VFS_CAP_REVISION_3: c_uint = 0x03000000;
+pub const VFS_CAP_U32_3: usize = 2;
+pub const XATTR_CAPS_SZ_3: usize = size_of::<crate::__u32>() * (2 + 2 * VFS_CAP_U32_3);
+
+pub const XATTR_CAPS_SZ: usize = XATTR_CAPS_SZ_3;
+pub const VFS_CAP_U32: usize = VFS_CAP_U32_3;
+pub const VFS_CAP_REVISION: c_uint = VFS_CAP_REVISION_3;
+
+/* from linux/xattr.h */
+pub const XATTR_NAME_CAPS: &[u8] = b"security.capability\0";
+
+s! {
+    pub struct vfs_cap_data {
+        /* Little endian */
+        pub magic_etc: crate::__u32,
+        pub data: [__c_anonymous_vfs_cap_data_data; VFS_CAP_U32],
+    }
+
+    pub struct __c_anonymous_vfs_cap_data_data {
+        /* Little endian */
+        pub permitted: crate::__u32,
+        /* Little endian */
+        pub inheritable: crate::__u32,
+    }
+
+    pub struct vfs_ns_cap_data {
+        pub magic_etc: crate::__u32,
+        pub data: [__c_anonymous_vfs_ns_cap_data_data; VFS_CAP_U32],
+        pub rootid: crate::__u32,
+    }
+
+    pub struct __c_anonymous_vfs_ns_cap_data_data {
+        /* Little endian */
+        pub permitted: crate::__u32,
+        /* Little endian */
+        pub inheritable: crate::__u32,
+    }
+}
+
+/*
+ * Backwardly compatible definition for source code - trapped in a
+ * 32-bit world. If you find you need this, please consider using
+ * libcap to untrap yourself...
+ */
+pub const _LINUX_CAPABILITY_VERSION: crate::__u32 = _LINUX_CAPABILITY_VERSION_1;
+pub const _LINUX_CAPABILITY_U32S: usize = _LINUX_CAPABILITY_U32S_1;
+
+/*
+ * POSIX-draft defined capabilities.
+ */
+pub const CAP_CHOWN: c_int = 0;
+pub const CAP_DAC_OVERRIDE: c_int = 1;
+pub const CAP_DAC_READ_SEARCH: c_int = 2;
+pub const CAP_FOWNER: c_int = 3;
+pub const CAP_FSETID: c_int = 4;
+pub const CAP_KILL: c_int = 5;
+pub const CAP_SETGID: c_int = 6;
+pub const CAP_SETUID: c_int = 7;
+
+/*
+ * Linux-specific capabilities
+ */
+pub const CAP_SETPCAP: c_int = 8;
+pub const CAP_LINUX_IMMUTABLE: c_int = 9;
+pub const CAP_NET_BIND_SERVICE: c_int = 10;
+pub const CAP_NET_BROADCAST: c_int = 11;
+pub const CAP_NET_ADMIN: c_int = 12;
+pub const CAP_NET_RAW: c_int = 13;
+pub const CAP_IPC_LOCK: c_int = 14;
+pub const CAP_IPC_OWNER: c_int = 15;
+pub const CAP_SYS_MODULE: c_int = 16;
+pub const CAP_SYS_RAWIO: c_int = 17;
+pub const CAP_SYS_CHROOT: c_int = 18;
+pub const CAP_SYS_PTRACE: c_int = 19;
+pub const CAP_SYS_PACCT: c_int = 20;
+pub const CAP_SYS_ADMIN: c_int = 21;
+pub const CAP_SYS_BOOT: c_int = 22;
+pub const CAP_SYS_NICE: c_int = 23;
+pub const CAP_SYS_RESOURCE: c_int = 24;
+pub const CAP_SYS_TIME: c_int = 25;
+pub const CAP_SYS_TTY_CONFIG: c_int = 26;
+pub const CAP_MKNOD: c_int = 27;
+pub const CAP_LEASE: c_int = 28;
+pub const CAP_AUDIT_WRITE: c_int = 29;
+pub const CAP_AUDIT_CONTROL: c_int = 30;
+pub const CAP_SETFCAP: c_int = 31;
+pub const CAP_MAC_OVERRIDE: c_int = 32;
+pub const CAP_MAC_ADMIN: c_int = 33;
+pub const CAP_SYSLOG: c_int = 34;
+pub const CAP_WAKE_ALARM: c_int = 35;
+pub const CAP_BLOCK_SUSPEND: c_int = 36;
+pub const CAP_AUDIT_READ: c_int = 37;
+pub const CAP_PERFMON: c_int = 38;
+pub const CAP_BPF: c_int = 39;
+pub const CAP_CHECKPOINT_RESTORE: c_int = 40;
+
+pub const CAP_LAST_CAP: c_int = CAP_CHECKPOINT_RESTORE;
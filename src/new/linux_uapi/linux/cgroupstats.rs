@@ -0,0 +1,52 @@
+//! Header: `uapi/linux/cgroupstats.h`
+
+use crate::prelude::*;
+
+s! {
+    pub struct cgroupstats {
+        /* Number of tasks sleeping */
+        pub nr_sleeping: crate::__u64,
+        /* Number of tasks running */
+        pub nr_running: crate::__u64,
+        /* Number of tasks in stopped state */
+        pub nr_stopped: crate::__u64,
+        /* Number of tasks in uninterruptible state */
+        pub nr_uninterruptible: crate::__u64,
+        /* Number of tasks waiting on IO */
+        pub nr_io_wait: crate::__u64,
+    }
+}
+
+/*
+ * Commands sent from userspace
+ * Not versioned. New commands should only be inserted at the enum's end.
+ */
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        /* Reserved, follows `__TASKSTATS_CMD_MAX` */
+        pub CGROUPSTATS_CMD_UNSPEC = 3,
+        /* user->kernel request/get-response */
+        pub CGROUPSTATS_CMD_GET,
+        /* kernel->user event */
+        pub CGROUPSTATS_CMD_NEW,
+    }
+}
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        /* Reserved */
+        pub CGROUPSTATS_TYPE_UNSPEC = 0,
+        /* contains name + stats */
+        pub CGROUPSTATS_TYPE_CGROUP_STATS,
+    }
+}
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CGROUPSTATS_CMD_ATTR_UNSPEC = 0,
+        pub CGROUPSTATS_CMD_ATTR_FD,
+    }
+}
@@ -0,0 +1,162 @@
+//! Header: `uapi/linux/cn_proc.h`
+
+use crate::prelude::*;
+
+/*
+ * Userspace sends this enum to register with the kernel that it is listening
+ * for events on the connector.
+ */
+c_enum! {
+    pub enum proc_cn_mcast_op {
+        pub PROC_CN_MCAST_LISTEN = 1,
+        pub PROC_CN_MCAST_IGNORE = 2,
+    }
+}
+
+c_enum! {
+    pub enum proc_cn_event {
+        /* Use successive bits so the enums can be used to record
+         * sets of events as well
+         */
+        pub PROC_EVENT_NONE = 0x00000000,
+        pub PROC_EVENT_FORK = 0x00000001,
+        pub PROC_EVENT_EXEC = 0x00000002,
+        pub PROC_EVENT_UID = 0x00000004,
+        pub PROC_EVENT_GID = 0x00000040,
+        pub PROC_EVENT_SID = 0x00000080,
+        pub PROC_EVENT_PTRACE = 0x00000100,
+        pub PROC_EVENT_COMM = 0x00000200,
+        /* "next" should be 0x00000400 */
+        /* "last" is the last process event: exit,
+         * while "next to last" is coredumping event
+         * before that is report only if process dies
+         * with non-zero exit status
+         */
+        pub PROC_EVENT_NONZERO_EXIT = 0x20000000,
+        pub PROC_EVENT_COREDUMP = 0x40000000,
+        pub PROC_EVENT_EXIT = 0x80000000,
+    }
+}
+
+pub const PROC_EVENT_ALL: proc_cn_event = PROC_EVENT_FORK
+    | PROC_EVENT_EXEC
+    | PROC_EVENT_UID
+    | PROC_EVENT_GID
+    | PROC_EVENT_SID
+    | PROC_EVENT_PTRACE
+    | PROC_EVENT_COMM
+    | PROC_EVENT_NONZERO_EXIT
+    | PROC_EVENT_COREDUMP
+    | PROC_EVENT_EXIT;
+
+s! {
+    pub struct proc_input {
+        pub mcast_op: proc_cn_mcast_op,
+        pub event_type: proc_cn_event,
+    }
+
+    pub struct fork_proc_event {
+        pub parent_pid: crate::pid_t,
+        pub parent_tgid: crate::pid_t,
+        pub child_pid: crate::pid_t,
+        pub child_tgid: crate::pid_t,
+    }
+
+    pub struct exec_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+    }
+
+    pub struct sid_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+    }
+
+    pub struct ptrace_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub tracer_pid: crate::pid_t,
+        pub tracer_tgid: crate::pid_t,
+    }
+
+    pub struct comm_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub comm: [c_char; 16],
+    }
+
+    pub struct coredump_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub parent_pid: crate::pid_t,
+        pub parent_tgid: crate::pid_t,
+    }
+
+    pub struct exit_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub exit_code: crate::__u32,
+        pub exit_signal: crate::__u32,
+        pub parent_pid: crate::pid_t,
+        pub parent_tgid: crate::pid_t,
+    }
+
+    pub struct __c_anonymous_proc_event_ack {
+        pub err: crate::__u32,
+    }
+}
+
+s_no_extra_traits! {
+    /*
+     * From the user's point of view, the process
+     * ID is the thread group ID and thread ID is the internal
+     * kernel "pid". So, fields are assigned as follow:
+     *
+     *  In user space     -  In  kernel space
+     *
+     * parent process ID  =  parent->tgid
+     * parent thread  ID  =  parent->pid
+     * child  process ID  =  child->tgid
+     * child  thread  ID  =  child->pid
+     */
+    #[repr(align(8))]
+    pub struct proc_event {
+        pub what: proc_cn_event,
+        pub cpu: crate::__u32,
+        /* Number of nano seconds since system boot */
+        pub timestamp_ns: crate::__u64,
+        /* must be last field of proc_event struct */
+        pub event_data: __c_anonymous_proc_event_event_data,
+    }
+
+    pub union __c_anonymous_proc_event_event_data {
+        pub ack: __c_anonymous_proc_event_ack,
+        pub fork: fork_proc_event,
+        pub exec: exec_proc_event,
+        pub id: id_proc_event,
+        pub sid: sid_proc_event,
+        pub ptrace: ptrace_proc_event,
+        pub comm: comm_proc_event,
+        pub coredump: coredump_proc_event,
+        pub exit: exit_proc_event,
+    }
+
+    pub struct id_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub r: __c_anonymous_id_proc_event_r,
+        pub e: __c_anonymous_id_proc_event_e,
+    }
+
+    pub union __c_anonymous_id_proc_event_r {
+        /* task uid */
+        pub ruid: crate::__u32,
+        /* task gid */
+        pub rgid: crate::__u32,
+    }
+
+    pub union __c_anonymous_id_proc_event_e {
+        pub euid: crate::__u32,
+        pub egid: crate::__u32,
+    }
+}
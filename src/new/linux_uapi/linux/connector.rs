@@ -0,0 +1,56 @@
+//! Header: `uapi/linux/connector.h`
+
+use crate::prelude::*;
+
+/*
+ * Process Events connector unique ids -- used for message routing
+ */
+pub const CN_IDX_PROC: c_uint = 0x1;
+pub const CN_VAL_PROC: c_uint = 0x1;
+pub const CN_IDX_CIFS: c_uint = 0x2;
+pub const CN_VAL_CIFS: c_uint = 0x1;
+pub const CN_W1_IDX: c_uint = 0x3;
+pub const CN_W1_VAL: c_uint = 0x1;
+pub const CN_IDX_V86D: c_uint = 0x4;
+pub const CN_VAL_V86D_UVESAFB: c_uint = 0x1;
+pub const CN_IDX_BB: c_uint = 0x5;
+pub const CN_DST_IDX: c_uint = 0x6;
+pub const CN_DST_VAL: c_uint = 0x1;
+pub const CN_IDX_DM: c_uint = 0x7;
+pub const CN_VAL_DM_USERSPACE_LOG: c_uint = 0x1;
+pub const CN_IDX_DRBD: c_uint = 0x8;
+pub const CN_VAL_DRBD: c_uint = 0x1;
+pub const CN_KVP_IDX: c_uint = 0x9;
+pub const CN_KVP_VAL: c_uint = 0x1;
+pub const CN_VSS_IDX: c_uint = 0xA;
+pub const CN_VSS_VAL: c_uint = 0x1;
+
+pub const CN_NETLINK_USERS: c_uint = 11;
+
+/*
+ * Maximum connector's message size.
+ */
+pub const CONNECTOR_MAX_MSG_SIZE: c_uint = 16384;
+
+s! {
+    /*
+     * idx and val are unique identifiers which
+     * are used for message routing and
+     * must be registered in connector.h for in-kernel usage.
+     */
+    pub struct cb_id {
+        pub idx: crate::__u32,
+        pub val: crate::__u32,
+    }
+
+    pub struct cn_msg {
+        pub id: cb_id,
+
+        pub seq: crate::__u32,
+        pub ack: crate::__u32,
+
+        pub len: crate::__u16,
+        pub flags: crate::__u16,
+        pub data: [crate::__u8; 0],
+    }
+}
@@ -0,0 +1,557 @@
+//! Header: `uapi/linux/devlink.h`
+
+use crate::prelude::*;
+
+pub const DEVLINK_GENL_NAME: &[u8] = b"devlink\0";
+pub const DEVLINK_GENL_VERSION: c_int = 0x1;
+pub const DEVLINK_GENL_MCGRP_CONFIG_NAME: &[u8] = b"config\0";
+
+c_enum! {
+    pub enum devlink_command {
+        pub DEVLINK_CMD_UNSPEC,
+        pub DEVLINK_CMD_GET,
+        pub DEVLINK_CMD_SET,
+        pub DEVLINK_CMD_NEW,
+        pub DEVLINK_CMD_DEL,
+        pub DEVLINK_CMD_PORT_GET,
+        pub DEVLINK_CMD_PORT_SET,
+        pub DEVLINK_CMD_PORT_NEW,
+        pub DEVLINK_CMD_PORT_DEL,
+        pub DEVLINK_CMD_PORT_SPLIT,
+        pub DEVLINK_CMD_PORT_UNSPLIT,
+        pub DEVLINK_CMD_SB_GET,
+        pub DEVLINK_CMD_SB_SET,
+        pub DEVLINK_CMD_SB_NEW,
+        pub DEVLINK_CMD_SB_DEL,
+        pub DEVLINK_CMD_SB_POOL_GET,
+        pub DEVLINK_CMD_SB_POOL_SET,
+        pub DEVLINK_CMD_SB_POOL_NEW,
+        pub DEVLINK_CMD_SB_POOL_DEL,
+        pub DEVLINK_CMD_SB_PORT_POOL_GET,
+        pub DEVLINK_CMD_SB_PORT_POOL_SET,
+        pub DEVLINK_CMD_SB_PORT_POOL_NEW,
+        pub DEVLINK_CMD_SB_PORT_POOL_DEL,
+        pub DEVLINK_CMD_SB_TC_POOL_BIND_GET,
+        pub DEVLINK_CMD_SB_TC_POOL_BIND_SET,
+        pub DEVLINK_CMD_SB_TC_POOL_BIND_NEW,
+        pub DEVLINK_CMD_SB_TC_POOL_BIND_DEL,
+        pub DEVLINK_CMD_SB_OCC_SNAPSHOT,
+        pub DEVLINK_CMD_SB_OCC_MAX_CLEAR,
+        pub DEVLINK_CMD_ESWITCH_GET,
+        pub DEVLINK_CMD_ESWITCH_SET,
+        pub DEVLINK_CMD_DPIPE_TABLE_GET,
+        pub DEVLINK_CMD_DPIPE_ENTRIES_GET,
+        pub DEVLINK_CMD_DPIPE_HEADERS_GET,
+        pub DEVLINK_CMD_DPIPE_TABLE_COUNTERS_SET,
+        pub DEVLINK_CMD_RESOURCE_SET,
+        pub DEVLINK_CMD_RESOURCE_DUMP,
+        pub DEVLINK_CMD_RELOAD,
+        pub DEVLINK_CMD_PARAM_GET,
+        pub DEVLINK_CMD_PARAM_SET,
+        pub DEVLINK_CMD_PARAM_NEW,
+        pub DEVLINK_CMD_PARAM_DEL,
+        pub DEVLINK_CMD_REGION_GET,
+        pub DEVLINK_CMD_REGION_SET,
+        pub DEVLINK_CMD_REGION_NEW,
+        pub DEVLINK_CMD_REGION_DEL,
+        pub DEVLINK_CMD_REGION_READ,
+        pub DEVLINK_CMD_PORT_PARAM_GET,
+        pub DEVLINK_CMD_PORT_PARAM_SET,
+        pub DEVLINK_CMD_PORT_PARAM_NEW,
+        pub DEVLINK_CMD_PORT_PARAM_DEL,
+        pub DEVLINK_CMD_INFO_GET,
+        pub DEVLINK_CMD_HEALTH_REPORTER_GET,
+        pub DEVLINK_CMD_HEALTH_REPORTER_SET,
+        pub DEVLINK_CMD_HEALTH_REPORTER_RECOVER,
+        pub DEVLINK_CMD_HEALTH_REPORTER_DIAGNOSE,
+        pub DEVLINK_CMD_HEALTH_REPORTER_DUMP_GET,
+        pub DEVLINK_CMD_HEALTH_REPORTER_DUMP_CLEAR,
+        pub DEVLINK_CMD_FLASH_UPDATE,
+        pub DEVLINK_CMD_FLASH_UPDATE_END,
+        pub DEVLINK_CMD_FLASH_UPDATE_STATUS,
+        pub DEVLINK_CMD_TRAP_GET,
+        pub DEVLINK_CMD_TRAP_SET,
+        pub DEVLINK_CMD_TRAP_NEW,
+        pub DEVLINK_CMD_TRAP_DEL,
+        pub DEVLINK_CMD_TRAP_GROUP_GET,
+        pub DEVLINK_CMD_TRAP_GROUP_SET,
+        pub DEVLINK_CMD_TRAP_GROUP_NEW,
+        pub DEVLINK_CMD_TRAP_GROUP_DEL,
+        pub DEVLINK_CMD_TRAP_POLICER_GET,
+        pub DEVLINK_CMD_TRAP_POLICER_SET,
+        pub DEVLINK_CMD_TRAP_POLICER_NEW,
+        pub DEVLINK_CMD_TRAP_POLICER_DEL,
+        pub DEVLINK_CMD_HEALTH_REPORTER_TEST,
+        pub DEVLINK_CMD_RATE_GET,
+        pub DEVLINK_CMD_RATE_SET,
+        pub DEVLINK_CMD_RATE_NEW,
+        pub DEVLINK_CMD_RATE_DEL,
+        pub DEVLINK_CMD_LINECARD_GET,
+        pub DEVLINK_CMD_LINECARD_SET,
+        pub DEVLINK_CMD_LINECARD_NEW,
+        pub DEVLINK_CMD_LINECARD_DEL,
+        pub DEVLINK_CMD_SELFTESTS_GET,
+        pub DEVLINK_CMD_SELFTESTS_RUN,
+    }
+}
+
+/* obsolete, never use this! */
+pub const DEVLINK_CMD_ESWITCH_MODE_GET: devlink_command = DEVLINK_CMD_ESWITCH_GET;
+pub const DEVLINK_CMD_ESWITCH_MODE_SET: devlink_command = DEVLINK_CMD_ESWITCH_SET;
+
+c_enum! {
+    pub enum devlink_port_type {
+        pub DEVLINK_PORT_TYPE_NOTSET,
+        pub DEVLINK_PORT_TYPE_AUTO,
+        pub DEVLINK_PORT_TYPE_ETH,
+        pub DEVLINK_PORT_TYPE_IB,
+    }
+}
+
+c_enum! {
+    pub enum devlink_sb_pool_type {
+        pub DEVLINK_SB_POOL_TYPE_INGRESS,
+        pub DEVLINK_SB_POOL_TYPE_EGRESS,
+    }
+}
+
+c_enum! {
+    pub enum devlink_sb_threshold_type {
+        pub DEVLINK_SB_THRESHOLD_TYPE_STATIC,
+        pub DEVLINK_SB_THRESHOLD_TYPE_DYNAMIC,
+    }
+}
+
+pub const DEVLINK_SB_THRESHOLD_TO_ALPHA_MAX: c_int = 20;
+
+c_enum! {
+    pub enum devlink_eswitch_mode {
+        pub DEVLINK_ESWITCH_MODE_LEGACY,
+        pub DEVLINK_ESWITCH_MODE_SWITCHDEV,
+    }
+}
+
+c_enum! {
+    pub enum devlink_eswitch_inline_mode {
+        pub DEVLINK_ESWITCH_INLINE_MODE_NONE,
+        pub DEVLINK_ESWITCH_INLINE_MODE_LINK,
+        pub DEVLINK_ESWITCH_INLINE_MODE_NETWORK,
+        pub DEVLINK_ESWITCH_INLINE_MODE_TRANSPORT,
+    }
+}
+
+c_enum! {
+    pub enum devlink_eswitch_encap_mode {
+        pub DEVLINK_ESWITCH_ENCAP_MODE_NONE,
+        pub DEVLINK_ESWITCH_ENCAP_MODE_BASIC,
+    }
+}
+
+c_enum! {
+    pub enum devlink_port_flavour {
+        pub DEVLINK_PORT_FLAVOUR_PHYSICAL,
+        pub DEVLINK_PORT_FLAVOUR_CPU,
+        pub DEVLINK_PORT_FLAVOUR_DSA,
+        pub DEVLINK_PORT_FLAVOUR_PCI_PF,
+        pub DEVLINK_PORT_FLAVOUR_PCI_VF,
+        pub DEVLINK_PORT_FLAVOUR_VIRTUAL,
+        pub DEVLINK_PORT_FLAVOUR_UNUSED,
+        pub DEVLINK_PORT_FLAVOUR_PCI_SF,
+    }
+}
+
+c_enum! {
+    pub enum devlink_rate_type {
+        pub DEVLINK_RATE_TYPE_LEAF,
+        pub DEVLINK_RATE_TYPE_NODE,
+    }
+}
+
+c_enum! {
+    pub enum devlink_param_cmode {
+        pub DEVLINK_PARAM_CMODE_RUNTIME,
+        pub DEVLINK_PARAM_CMODE_DRIVERINIT,
+        pub DEVLINK_PARAM_CMODE_PERMANENT,
+    }
+}
+
+c_enum! {
+    pub enum devlink_param_fw_load_policy_value {
+        pub DEVLINK_PARAM_FW_LOAD_POLICY_VALUE_DRIVER,
+        pub DEVLINK_PARAM_FW_LOAD_POLICY_VALUE_FLASH,
+        pub DEVLINK_PARAM_FW_LOAD_POLICY_VALUE_DISK,
+        pub DEVLINK_PARAM_FW_LOAD_POLICY_VALUE_UNKNOWN,
+    }
+}
+
+c_enum! {
+    pub enum devlink_param_reset_dev_on_drv_probe_value {
+        pub DEVLINK_PARAM_RESET_DEV_ON_DRV_PROBE_VALUE_UNKNOWN,
+        pub DEVLINK_PARAM_RESET_DEV_ON_DRV_PROBE_VALUE_ALWAYS,
+        pub DEVLINK_PARAM_RESET_DEV_ON_DRV_PROBE_VALUE_NEVER,
+        pub DEVLINK_PARAM_RESET_DEV_ON_DRV_PROBE_VALUE_DISK,
+    }
+}
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub DEVLINK_ATTR_STATS_RX_PACKETS,
+        pub DEVLINK_ATTR_STATS_RX_BYTES,
+        pub DEVLINK_ATTR_STATS_RX_DROPPED,
+    }
+}
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub DEVLINK_FLASH_OVERWRITE_SETTINGS_BIT,
+        pub DEVLINK_FLASH_OVERWRITE_IDENTIFIERS_BIT,
+    }
+}
+
+pub const DEVLINK_FLASH_OVERWRITE_SETTINGS: c_ulong = 1 << DEVLINK_FLASH_OVERWRITE_SETTINGS_BIT;
+pub const DEVLINK_FLASH_OVERWRITE_IDENTIFIERS: c_ulong = 1 << DEVLINK_FLASH_OVERWRITE_IDENTIFIERS_BIT;
+
+c_enum! {
+    pub enum devlink_attr_selftest_id {
+        pub DEVLINK_ATTR_SELFTEST_ID_UNSPEC,
+        pub DEVLINK_ATTR_SELFTEST_ID_FLASH,
+    }
+}
+
+c_enum! {
+    pub enum devlink_selftest_status {
+        pub DEVLINK_SELFTEST_STATUS_SKIP,
+        pub DEVLINK_SELFTEST_STATUS_PASS,
+        pub DEVLINK_SELFTEST_STATUS_FAIL,
+    }
+}
+
+c_enum! {
+    pub enum devlink_attr_selftest_result {
+        pub DEVLINK_ATTR_SELFTEST_RESULT_UNSPEC,
+        pub DEVLINK_ATTR_SELFTEST_RESULT,
+        pub DEVLINK_ATTR_SELFTEST_RESULT_ID,
+        pub DEVLINK_ATTR_SELFTEST_RESULT_STATUS,
+    }
+}
+
+c_enum! {
+    pub enum devlink_trap_action {
+        pub DEVLINK_TRAP_ACTION_DROP,
+        pub DEVLINK_TRAP_ACTION_TRAP,
+        pub DEVLINK_TRAP_ACTION_MIRROR,
+    }
+}
+
+c_enum! {
+    pub enum devlink_trap_type {
+        pub DEVLINK_TRAP_TYPE_DROP,
+        pub DEVLINK_TRAP_TYPE_EXCEPTION,
+        pub DEVLINK_TRAP_TYPE_CONTROL,
+    }
+}
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub DEVLINK_ATTR_TRAP_METADATA_TYPE_IN_PORT,
+        pub DEVLINK_ATTR_TRAP_METADATA_TYPE_FA_COOKIE,
+    }
+}
+
+c_enum! {
+    pub enum devlink_reload_action {
+        pub DEVLINK_RELOAD_ACTION_UNSPEC,
+        pub DEVLINK_RELOAD_ACTION_DRIVER_REINIT,
+        pub DEVLINK_RELOAD_ACTION_FW_ACTIVATE,
+    }
+}
+
+c_enum! {
+    pub enum devlink_reload_limit {
+        pub DEVLINK_RELOAD_LIMIT_UNSPEC,
+        pub DEVLINK_RELOAD_LIMIT_NO_RESET,
+    }
+}
+
+c_enum! {
+    pub enum devlink_linecard_state {
+        pub DEVLINK_LINECARD_STATE_UNSPEC,
+        pub DEVLINK_LINECARD_STATE_UNPROVISIONED,
+        pub DEVLINK_LINECARD_STATE_UNPROVISIONING,
+        pub DEVLINK_LINECARD_STATE_PROVISIONING,
+        pub DEVLINK_LINECARD_STATE_PROVISIONING_FAILED,
+        pub DEVLINK_LINECARD_STATE_PROVISIONED,
+        pub DEVLINK_LINECARD_STATE_ACTIVE,
+    }
+}
+
+c_enum! {
+    pub enum devlink_attr {
+        pub DEVLINK_ATTR_UNSPEC,
+        pub DEVLINK_ATTR_BUS_NAME,
+        pub DEVLINK_ATTR_DEV_NAME,
+        pub DEVLINK_ATTR_PORT_INDEX,
+        pub DEVLINK_ATTR_PORT_TYPE,
+        pub DEVLINK_ATTR_PORT_DESIRED_TYPE,
+        pub DEVLINK_ATTR_PORT_NETDEV_IFINDEX,
+        pub DEVLINK_ATTR_PORT_NETDEV_NAME,
+        pub DEVLINK_ATTR_PORT_IBDEV_NAME,
+        pub DEVLINK_ATTR_PORT_SPLIT_COUNT,
+        pub DEVLINK_ATTR_PORT_SPLIT_GROUP,
+        pub DEVLINK_ATTR_SB_INDEX,
+        pub DEVLINK_ATTR_SB_SIZE,
+        pub DEVLINK_ATTR_SB_INGRESS_POOL_COUNT,
+        pub DEVLINK_ATTR_SB_EGRESS_POOL_COUNT,
+        pub DEVLINK_ATTR_SB_INGRESS_TC_COUNT,
+        pub DEVLINK_ATTR_SB_EGRESS_TC_COUNT,
+        pub DEVLINK_ATTR_SB_POOL_INDEX,
+        pub DEVLINK_ATTR_SB_POOL_TYPE,
+        pub DEVLINK_ATTR_SB_POOL_SIZE,
+        pub DEVLINK_ATTR_SB_POOL_THRESHOLD_TYPE,
+        pub DEVLINK_ATTR_SB_THRESHOLD,
+        pub DEVLINK_ATTR_SB_TC_INDEX,
+        pub DEVLINK_ATTR_SB_OCC_CUR,
+        pub DEVLINK_ATTR_SB_OCC_MAX,
+        pub DEVLINK_ATTR_ESWITCH_MODE,
+        pub DEVLINK_ATTR_ESWITCH_INLINE_MODE,
+        pub DEVLINK_ATTR_DPIPE_TABLES,
+        pub DEVLINK_ATTR_DPIPE_TABLE,
+        pub DEVLINK_ATTR_DPIPE_TABLE_NAME,
+        pub DEVLINK_ATTR_DPIPE_TABLE_SIZE,
+        pub DEVLINK_ATTR_DPIPE_TABLE_MATCHES,
+        pub DEVLINK_ATTR_DPIPE_TABLE_ACTIONS,
+        pub DEVLINK_ATTR_DPIPE_TABLE_COUNTERS_ENABLED,
+        pub DEVLINK_ATTR_DPIPE_ENTRIES,
+        pub DEVLINK_ATTR_DPIPE_ENTRY,
+        pub DEVLINK_ATTR_DPIPE_ENTRY_INDEX,
+        pub DEVLINK_ATTR_DPIPE_ENTRY_MATCH_VALUES,
+        pub DEVLINK_ATTR_DPIPE_ENTRY_ACTION_VALUES,
+        pub DEVLINK_ATTR_DPIPE_ENTRY_COUNTER,
+        pub DEVLINK_ATTR_DPIPE_MATCH,
+        pub DEVLINK_ATTR_DPIPE_MATCH_VALUE,
+        pub DEVLINK_ATTR_DPIPE_MATCH_TYPE,
+        pub DEVLINK_ATTR_DPIPE_ACTION,
+        pub DEVLINK_ATTR_DPIPE_ACTION_VALUE,
+        pub DEVLINK_ATTR_DPIPE_ACTION_TYPE,
+        pub DEVLINK_ATTR_DPIPE_VALUE,
+        pub DEVLINK_ATTR_DPIPE_VALUE_MASK,
+        pub DEVLINK_ATTR_DPIPE_VALUE_MAPPING,
+        pub DEVLINK_ATTR_DPIPE_HEADERS,
+        pub DEVLINK_ATTR_DPIPE_HEADER,
+        pub DEVLINK_ATTR_DPIPE_HEADER_NAME,
+        pub DEVLINK_ATTR_DPIPE_HEADER_ID,
+        pub DEVLINK_ATTR_DPIPE_HEADER_FIELDS,
+        pub DEVLINK_ATTR_DPIPE_HEADER_GLOBAL,
+        pub DEVLINK_ATTR_DPIPE_HEADER_INDEX,
+        pub DEVLINK_ATTR_DPIPE_FIELD,
+        pub DEVLINK_ATTR_DPIPE_FIELD_NAME,
+        pub DEVLINK_ATTR_DPIPE_FIELD_ID,
+        pub DEVLINK_ATTR_DPIPE_FIELD_BITWIDTH,
+        pub DEVLINK_ATTR_DPIPE_FIELD_MAPPING_TYPE,
+        pub DEVLINK_ATTR_PAD,
+        pub DEVLINK_ATTR_ESWITCH_ENCAP_MODE,
+        pub DEVLINK_ATTR_RESOURCE_LIST,
+        pub DEVLINK_ATTR_RESOURCE,
+        pub DEVLINK_ATTR_RESOURCE_NAME,
+        pub DEVLINK_ATTR_RESOURCE_ID,
+        pub DEVLINK_ATTR_RESOURCE_SIZE,
+        pub DEVLINK_ATTR_RESOURCE_SIZE_NEW,
+        pub DEVLINK_ATTR_RESOURCE_SIZE_VALID,
+        pub DEVLINK_ATTR_RESOURCE_SIZE_MIN,
+        pub DEVLINK_ATTR_RESOURCE_SIZE_MAX,
+        pub DEVLINK_ATTR_RESOURCE_SIZE_GRAN,
+        pub DEVLINK_ATTR_RESOURCE_UNIT,
+        pub DEVLINK_ATTR_RESOURCE_OCC,
+        pub DEVLINK_ATTR_DPIPE_TABLE_RESOURCE_ID,
+        pub DEVLINK_ATTR_DPIPE_TABLE_RESOURCE_UNITS,
+        pub DEVLINK_ATTR_PORT_FLAVOUR,
+        pub DEVLINK_ATTR_PORT_NUMBER,
+        pub DEVLINK_ATTR_PORT_SPLIT_SUBPORT_NUMBER,
+        pub DEVLINK_ATTR_PARAM,
+        pub DEVLINK_ATTR_PARAM_NAME,
+        pub DEVLINK_ATTR_PARAM_GENERIC,
+        pub DEVLINK_ATTR_PARAM_TYPE,
+        pub DEVLINK_ATTR_PARAM_VALUES_LIST,
+        pub DEVLINK_ATTR_PARAM_VALUE,
+        pub DEVLINK_ATTR_PARAM_VALUE_DATA,
+        pub DEVLINK_ATTR_PARAM_VALUE_CMODE,
+    }
+}
+
+// Continuation of `enum devlink_attr`, split to stay within the `c_enum!` recursion limit.
+c_enum! {
+    #[repr(devlink_attr)]
+    pub enum #anon {
+        pub DEVLINK_ATTR_REGION_NAME = 88,
+        pub DEVLINK_ATTR_REGION_SIZE,
+        pub DEVLINK_ATTR_REGION_SNAPSHOTS,
+        pub DEVLINK_ATTR_REGION_SNAPSHOT,
+        pub DEVLINK_ATTR_REGION_SNAPSHOT_ID,
+        pub DEVLINK_ATTR_REGION_CHUNKS,
+        pub DEVLINK_ATTR_REGION_CHUNK,
+        pub DEVLINK_ATTR_REGION_CHUNK_DATA,
+        pub DEVLINK_ATTR_REGION_CHUNK_ADDR,
+        pub DEVLINK_ATTR_REGION_CHUNK_LEN,
+        pub DEVLINK_ATTR_INFO_DRIVER_NAME,
+        pub DEVLINK_ATTR_INFO_SERIAL_NUMBER,
+        pub DEVLINK_ATTR_INFO_VERSION_FIXED,
+        pub DEVLINK_ATTR_INFO_VERSION_RUNNING,
+        pub DEVLINK_ATTR_INFO_VERSION_STORED,
+        pub DEVLINK_ATTR_INFO_VERSION_NAME,
+        pub DEVLINK_ATTR_INFO_VERSION_VALUE,
+        pub DEVLINK_ATTR_SB_POOL_CELL_SIZE,
+        pub DEVLINK_ATTR_FMSG,
+        pub DEVLINK_ATTR_FMSG_OBJ_NEST_START,
+        pub DEVLINK_ATTR_FMSG_PAIR_NEST_START,
+        pub DEVLINK_ATTR_FMSG_ARR_NEST_START,
+        pub DEVLINK_ATTR_FMSG_NEST_END,
+        pub DEVLINK_ATTR_FMSG_OBJ_NAME,
+        pub DEVLINK_ATTR_FMSG_OBJ_VALUE_TYPE,
+        pub DEVLINK_ATTR_FMSG_OBJ_VALUE_DATA,
+        pub DEVLINK_ATTR_HEALTH_REPORTER,
+        pub DEVLINK_ATTR_HEALTH_REPORTER_NAME,
+        pub DEVLINK_ATTR_HEALTH_REPORTER_STATE,
+        pub DEVLINK_ATTR_HEALTH_REPORTER_ERR_COUNT,
+        pub DEVLINK_ATTR_HEALTH_REPORTER_RECOVER_COUNT,
+        pub DEVLINK_ATTR_HEALTH_REPORTER_DUMP_TS,
+        pub DEVLINK_ATTR_HEALTH_REPORTER_GRACEFUL_PERIOD,
+        pub DEVLINK_ATTR_HEALTH_REPORTER_AUTO_RECOVER,
+        pub DEVLINK_ATTR_FLASH_UPDATE_FILE_NAME,
+        pub DEVLINK_ATTR_FLASH_UPDATE_COMPONENT,
+        pub DEVLINK_ATTR_FLASH_UPDATE_STATUS_MSG,
+        pub DEVLINK_ATTR_FLASH_UPDATE_STATUS_DONE,
+        pub DEVLINK_ATTR_FLASH_UPDATE_STATUS_TOTAL,
+        pub DEVLINK_ATTR_PORT_PCI_PF_NUMBER,
+        pub DEVLINK_ATTR_PORT_PCI_VF_NUMBER,
+        pub DEVLINK_ATTR_STATS,
+        pub DEVLINK_ATTR_TRAP_NAME,
+        pub DEVLINK_ATTR_TRAP_ACTION,
+        pub DEVLINK_ATTR_TRAP_TYPE,
+        pub DEVLINK_ATTR_TRAP_GENERIC,
+        pub DEVLINK_ATTR_TRAP_METADATA,
+        pub DEVLINK_ATTR_TRAP_GROUP_NAME,
+        pub DEVLINK_ATTR_RELOAD_FAILED,
+        pub DEVLINK_ATTR_HEALTH_REPORTER_DUMP_TS_NS,
+        pub DEVLINK_ATTR_NETNS_FD,
+        pub DEVLINK_ATTR_NETNS_PID,
+        pub DEVLINK_ATTR_NETNS_ID,
+        pub DEVLINK_ATTR_HEALTH_REPORTER_AUTO_DUMP,
+        pub DEVLINK_ATTR_TRAP_POLICER_ID,
+        pub DEVLINK_ATTR_TRAP_POLICER_RATE,
+        pub DEVLINK_ATTR_TRAP_POLICER_BURST,
+        pub DEVLINK_ATTR_PORT_FUNCTION,
+        pub DEVLINK_ATTR_INFO_BOARD_SERIAL_NUMBER,
+        pub DEVLINK_ATTR_PORT_LANES,
+        pub DEVLINK_ATTR_PORT_SPLITTABLE,
+        pub DEVLINK_ATTR_PORT_EXTERNAL,
+        pub DEVLINK_ATTR_PORT_CONTROLLER_NUMBER,
+        pub DEVLINK_ATTR_FLASH_UPDATE_STATUS_TIMEOUT,
+        pub DEVLINK_ATTR_FLASH_UPDATE_OVERWRITE_MASK,
+        pub DEVLINK_ATTR_RELOAD_ACTION,
+        pub DEVLINK_ATTR_RELOAD_ACTIONS_PERFORMED,
+        pub DEVLINK_ATTR_RELOAD_LIMITS,
+        pub DEVLINK_ATTR_DEV_STATS,
+        pub DEVLINK_ATTR_RELOAD_STATS,
+        pub DEVLINK_ATTR_RELOAD_STATS_ENTRY,
+        pub DEVLINK_ATTR_RELOAD_STATS_LIMIT,
+        pub DEVLINK_ATTR_RELOAD_STATS_VALUE,
+        pub DEVLINK_ATTR_REMOTE_RELOAD_STATS,
+        pub DEVLINK_ATTR_RELOAD_ACTION_INFO,
+        pub DEVLINK_ATTR_RELOAD_ACTION_STATS,
+        pub DEVLINK_ATTR_PORT_PCI_SF_NUMBER,
+        pub DEVLINK_ATTR_RATE_TYPE,
+        pub DEVLINK_ATTR_RATE_TX_SHARE,
+        pub DEVLINK_ATTR_RATE_TX_MAX,
+        pub DEVLINK_ATTR_RATE_NODE_NAME,
+        pub DEVLINK_ATTR_RATE_PARENT_NODE_NAME,
+        pub DEVLINK_ATTR_REGION_MAX_SNAPSHOTS,
+        pub DEVLINK_ATTR_LINECARD_INDEX,
+        pub DEVLINK_ATTR_LINECARD_STATE,
+        pub DEVLINK_ATTR_LINECARD_TYPE,
+        pub DEVLINK_ATTR_LINECARD_SUPPORTED_TYPES,
+        pub DEVLINK_ATTR_NESTED_DEVLINK,
+        pub DEVLINK_ATTR_SELFTESTS,
+    }
+}
+
+/* Mapping between internal resource described by the field and system
+ * structure
+ */
+c_enum! {
+    pub enum devlink_dpipe_field_mapping_type {
+        pub DEVLINK_DPIPE_FIELD_MAPPING_TYPE_NONE,
+        pub DEVLINK_DPIPE_FIELD_MAPPING_TYPE_IFINDEX,
+    }
+}
+
+/* Match type - specify the type of the match */
+c_enum! {
+    pub enum devlink_dpipe_match_type {
+        pub DEVLINK_DPIPE_MATCH_TYPE_FIELD_EXACT,
+    }
+}
+
+/* Action type - specify the action type */
+c_enum! {
+    pub enum devlink_dpipe_action_type {
+        pub DEVLINK_DPIPE_ACTION_TYPE_FIELD_MODIFY,
+    }
+}
+
+c_enum! {
+    pub enum devlink_dpipe_field_ethernet_id {
+        pub DEVLINK_DPIPE_FIELD_ETHERNET_DST_MAC,
+    }
+}
+
+c_enum! {
+    pub enum devlink_dpipe_field_ipv4_id {
+        pub DEVLINK_DPIPE_FIELD_IPV4_DST_IP,
+    }
+}
+
+c_enum! {
+    pub enum devlink_dpipe_field_ipv6_id {
+        pub DEVLINK_DPIPE_FIELD_IPV6_DST_IP,
+    }
+}
+
+c_enum! {
+    pub enum devlink_dpipe_header_id {
+        pub DEVLINK_DPIPE_HEADER_ETHERNET,
+        pub DEVLINK_DPIPE_HEADER_IPV4,
+        pub DEVLINK_DPIPE_HEADER_IPV6,
+    }
+}
+
+c_enum! {
+    pub enum devlink_resource_unit {
+        pub DEVLINK_RESOURCE_UNIT_ENTRY,
+    }
+}
+
+c_enum! {
+    pub enum devlink_port_function_attr {
+        pub DEVLINK_PORT_FUNCTION_ATTR_UNSPEC,
+        pub DEVLINK_PORT_FUNCTION_ATTR_HW_ADDR,
+        pub DEVLINK_PORT_FN_ATTR_STATE,
+        pub DEVLINK_PORT_FN_ATTR_OPSTATE,
+    }
+}
+
+c_enum! {
+    pub enum devlink_port_fn_state {
+        pub DEVLINK_PORT_FN_STATE_INACTIVE,
+        pub DEVLINK_PORT_FN_STATE_ACTIVE,
+    }
+}
+
+c_enum! {
+    pub enum devlink_port_fn_opstate {
+        pub DEVLINK_PORT_FN_OPSTATE_DETACHED,
+        pub DEVLINK_PORT_FN_OPSTATE_ATTACHED,
+    }
+}
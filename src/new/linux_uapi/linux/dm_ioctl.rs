@@ -0,0 +1,338 @@
+//! Header: `uapi/linux/dm-ioctl.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IOWR,
+};
+
+/*
+ * A traditional ioctl interface for the device mapper.
+ *
+ * Each device can have two tables associated with it, an
+ * 'active' table which is the one currently used by io passing
+ * through the device, and an 'inactive' one which is a table
+ * that is being prepared as a replacement for the 'active' one.
+ */
+
+/* Slashes not supported */
+pub const DM_DIR: &[u8] = b"mapper\0";
+pub const DM_CONTROL_NODE: &[u8] = b"control\0";
+pub const DM_MAX_TYPE_NAME: usize = 16;
+pub const DM_NAME_LEN: usize = 128;
+pub const DM_UUID_LEN: usize = 129;
+
+s! {
+    /*
+     * All ioctl arguments consist of a single chunk of memory, with
+     * this structure at the start.  If a uuid is specified any
+     * lookup (eg. for a DM_INFO) will be done on that, *not* the
+     * name.
+     */
+    pub struct dm_ioctl {
+        /* in/out */
+        pub version: [crate::__u32; 3],
+        /* total size of data passed in including this struct */
+        pub data_size: crate::__u32,
+        /* offset to start of data relative to start of this struct */
+        pub data_start: crate::__u32,
+
+        /* in/out */
+        pub target_count: crate::__u32,
+        /* out */
+        pub open_count: crate::__s32,
+        /* in/out */
+        pub flags: crate::__u32,
+
+        /*
+         * event_nr holds either the event number (input and output) or the
+         * udev cookie value (input only).
+         * The DM_DEV_WAIT ioctl takes an event number as input.
+         * The DM_SUSPEND, DM_DEV_REMOVE and DM_DEV_RENAME ioctls
+         * use the field as a cookie to return in the DM_COOKIE
+         * variable with the uevents they issue.
+         * For output, the ioctls return the event number, not the cookie.
+         */
+        /* in/out */
+        pub event_nr: crate::__u32,
+        padding: Padding<crate::__u32>,
+
+        /* in/out */
+        pub dev: crate::__u64,
+
+        /* device name */
+        pub name: [c_char; DM_NAME_LEN],
+        /* unique identifier for the block device */
+        pub uuid: [c_char; DM_UUID_LEN],
+        /* padding or data */
+        pub data: [c_char; 7],
+    }
+
+    /*
+     * Used to specify tables.  These structures appear after the
+     * dm_ioctl.
+     */
+    pub struct dm_target_spec {
+        pub sector_start: crate::__u64,
+        pub length: crate::__u64,
+        /* used when reading from kernel only */
+        pub status: crate::__s32,
+
+        /*
+         * Location of the next dm_target_spec.
+         * - When specifying targets on a DM_TABLE_LOAD command, this value is
+         *   the number of bytes from the start of the "current" dm_target_spec
+         *   to the start of the "next" dm_target_spec.
+         * - When retrieving targets on a DM_TABLE_STATUS command, this value
+         *   is the number of bytes from the start of the first dm_target_spec
+         *   (that follows the dm_ioctl struct) to the start of the "next"
+         *   dm_target_spec.
+         */
+        pub next: crate::__u32,
+
+        pub target_type: [c_char; DM_MAX_TYPE_NAME],
+        /*
+         * Parameter string starts immediately after this object.
+         * Be careful to add padding after string to ensure correct
+         * alignment of subsequent dm_target_spec.
+         */
+    }
+
+    /*
+     * Used to retrieve the target dependencies.
+     */
+    pub struct dm_target_deps {
+        /* Array size */
+        pub count: crate::__u32,
+        /* unused */
+        padding: Padding<crate::__u32>,
+        /* out */
+        pub dev: [crate::__u64; 0],
+    }
+
+    /*
+     * Used to get a list of all dm devices.
+     */
+    pub struct dm_name_list {
+        pub dev: crate::__u64,
+        /* offset to the next record from the _start_ of this */
+        pub next: crate::__u32,
+        pub name: [c_char; 0],
+        /*
+         * The following members can be accessed by taking a pointer that
+         * points immediately after the terminating zero character in "name"
+         * and aligning this pointer to next 8-byte boundary.
+         * Uuid is present if the flag DM_NAME_LIST_FLAG_HAS_UUID is set.
+         *
+         * __u32 event_nr;
+         * __u32 flags;
+         * char uuid[0];
+         */
+    }
+}
+
+pub const DM_NAME_LIST_FLAG_HAS_UUID: c_uint = 1;
+pub const DM_NAME_LIST_FLAG_DOESNT_HAVE_UUID: c_uint = 2;
+
+s! {
+    /*
+     * Used to retrieve the target versions
+     */
+    pub struct dm_target_versions {
+        pub next: crate::__u32,
+        pub version: [crate::__u32; 3],
+        pub name: [c_char; 0],
+    }
+
+    /*
+     * Used to pass message to a target
+     */
+    pub struct dm_target_msg {
+        /* Device sector */
+        pub sector: crate::__u64,
+        pub message: [c_char; 0],
+    }
+}
+
+/*
+ * If you change this make sure you make the corresponding change
+ * to dm-ioctl.c:lookup_ioctl()
+ */
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        /* Top level cmds */
+        pub DM_VERSION_CMD = 0,
+        pub DM_REMOVE_ALL_CMD,
+        pub DM_LIST_DEVICES_CMD,
+
+        /* device level cmds */
+        pub DM_DEV_CREATE_CMD,
+        pub DM_DEV_REMOVE_CMD,
+        pub DM_DEV_RENAME_CMD,
+        pub DM_DEV_SUSPEND_CMD,
+        pub DM_DEV_STATUS_CMD,
+        pub DM_DEV_WAIT_CMD,
+
+        /* Table level cmds */
+        pub DM_TABLE_LOAD_CMD,
+        pub DM_TABLE_CLEAR_CMD,
+        pub DM_TABLE_DEPS_CMD,
+        pub DM_TABLE_STATUS_CMD,
+
+        /* Added later */
+        pub DM_LIST_VERSIONS_CMD,
+        pub DM_TARGET_MSG_CMD,
+        pub DM_DEV_SET_GEOMETRY_CMD,
+        pub DM_DEV_ARM_POLL_CMD,
+        pub DM_GET_TARGET_VERSION_CMD,
+    }
+}
+
+pub const DM_IOCTL: c_uint = 0xfd;
+
+const fn dm_iowr(cmd: c_int) -> Ioctl {
+    _IOWR::<dm_ioctl>(DM_IOCTL, cmd as c_uint)
+}
+
+/* Top level cmds */
+pub const DM_VERSION: Ioctl = dm_iowr(DM_VERSION_CMD);
+pub const DM_REMOVE_ALL: Ioctl = dm_iowr(DM_REMOVE_ALL_CMD);
+pub const DM_LIST_DEVICES: Ioctl = dm_iowr(DM_LIST_DEVICES_CMD);
+
+/* device level cmds */
+pub const DM_DEV_CREATE: Ioctl = dm_iowr(DM_DEV_CREATE_CMD);
+pub const DM_DEV_REMOVE: Ioctl = dm_iowr(DM_DEV_REMOVE_CMD);
+pub const DM_DEV_RENAME: Ioctl = dm_iowr(DM_DEV_RENAME_CMD);
+pub const DM_DEV_SUSPEND: Ioctl = dm_iowr(DM_DEV_SUSPEND_CMD);
+pub const DM_DEV_STATUS: Ioctl = dm_iowr(DM_DEV_STATUS_CMD);
+pub const DM_DEV_WAIT: Ioctl = dm_iowr(DM_DEV_WAIT_CMD);
+pub const DM_DEV_ARM_POLL: Ioctl = dm_iowr(DM_DEV_ARM_POLL_CMD);
+
+/* Table level cmds */
+pub const DM_TABLE_LOAD: Ioctl = dm_iowr(DM_TABLE_LOAD_CMD);
+pub const DM_TABLE_CLEAR: Ioctl = dm_iowr(DM_TABLE_CLEAR_CMD);
+pub const DM_TABLE_DEPS: Ioctl = dm_iowr(DM_TABLE_DEPS_CMD);
+pub const DM_TABLE_STATUS: Ioctl = dm_iowr(DM_TABLE_STATUS_CMD);
+
+/* Added later */
+pub const DM_LIST_VERSIONS: Ioctl = dm_iowr(DM_LIST_VERSIONS_CMD);
+pub const DM_GET_TARGET_VERSION: Ioctl = dm_iowr(DM_GET_TARGET_VERSION_CMD);
+
+pub const DM_TARGET_MSG: Ioctl = dm_iowr(DM_TARGET_MSG_CMD);
+pub const DM_DEV_SET_GEOMETRY: Ioctl = dm_iowr(DM_DEV_SET_GEOMETRY_CMD);
+
+pub const DM_VERSION_MAJOR: c_int = 4;
+pub const DM_VERSION_MINOR: c_int = 48;
+pub const DM_VERSION_PATCHLEVEL: c_int = 0;
+pub const DM_VERSION_EXTRA: &[u8] = b"-ioctl (2023-03-01)\0";
+
+/* Status bits */
+/* In/Out */
+pub const DM_READONLY_FLAG: c_uint = 1 << 0;
+/* In/Out */
+pub const DM_SUSPEND_FLAG: c_uint = 1 << 1;
+/* In */
+pub const DM_PERSISTENT_DEV_FLAG: c_uint = 1 << 3;
+
+/*
+ * Flag passed into ioctl STATUS command to get table information
+ * rather than current status.
+ */
+/* In */
+pub const DM_STATUS_TABLE_FLAG: c_uint = 1 << 4;
+
+/*
+ * Flags that indicate whether a table is present in either of
+ * the two table slots that a device has.
+ */
+/* Out */
+pub const DM_ACTIVE_PRESENT_FLAG: c_uint = 1 << 5;
+/* Out */
+pub const DM_INACTIVE_PRESENT_FLAG: c_uint = 1 << 6;
+
+/*
+ * Indicates that the buffer passed in wasn't big enough for the
+ * results.
+ */
+/* Out */
+pub const DM_BUFFER_FULL_FLAG: c_uint = 1 << 8;
+
+/*
+ * This flag is now ignored.
+ */
+/* In */
+pub const DM_SKIP_BDGET_FLAG: c_uint = 1 << 9;
+
+/*
+ * Set this to avoid attempting to freeze any filesystem when suspending.
+ */
+/* In */
+pub const DM_SKIP_LOCKFS_FLAG: c_uint = 1 << 10;
+
+/*
+ * Set this to suspend without flushing queued ios.
+ * Also disables flushing uncommitted changes in the thin target before
+ * generating statistics for DM_TABLE_STATUS and DM_DEV_WAIT.
+ */
+/* In */
+pub const DM_NOFLUSH_FLAG: c_uint = 1 << 11;
+
+/*
+ * If set, any table information returned will relate to the inactive
+ * table instead of the live one.  Always check DM_INACTIVE_PRESENT_FLAG
+ * is set before using the data returned.
+ */
+/* In */
+pub const DM_QUERY_INACTIVE_TABLE_FLAG: c_uint = 1 << 12;
+
+/*
+ * If set, a uevent was generated for which the caller may need to wait.
+ */
+/* Out */
+pub const DM_UEVENT_GENERATED_FLAG: c_uint = 1 << 13;
+
+/*
+ * If set, rename changes the uuid not the name.  Only permitted
+ * if no uuid was previously supplied: an existing uuid cannot be changed.
+ */
+/* In */
+pub const DM_UUID_FLAG: c_uint = 1 << 14;
+
+/*
+ * If set, all buffers are wiped after use. Use when sending
+ * or requesting sensitive data such as an encryption key.
+ */
+/* In */
+pub const DM_SECURE_DATA_FLAG: c_uint = 1 << 15;
+
+/*
+ * If set, a message generated output data.
+ */
+/* Out */
+pub const DM_DATA_OUT_FLAG: c_uint = 1 << 16;
+
+/*
+ * If set with DM_DEV_REMOVE or DM_REMOVE_ALL this indicates that if
+ * the device cannot be removed immediately because it is still in use
+ * it should instead be scheduled for removal when it gets closed.
+ *
+ * On return from DM_DEV_REMOVE, DM_DEV_STATUS or other ioctls, this
+ * flag indicates that the device is scheduled to be removed when it
+ * gets closed.
+ */
+/* In/Out */
+pub const DM_DEFERRED_REMOVE: c_uint = 1 << 17;
+
+/*
+ * If set, the device is suspended internally.
+ */
+/* Out */
+pub const DM_INTERNAL_SUSPEND_FLAG: c_uint = 1 << 18;
+
+/*
+ * If set, returns in the in buffer passed by UM, the raw table information
+ * that would be measured by IMA subsystem on device state change.
+ */
+/* In */
+pub const DM_IMA_MEASUREMENT_FLAG: c_uint = 1 << 19;
@@ -0,0 +1,861 @@
+//! Header: `uapi/linux/ethtool.h`
+
+use crate::prelude::*;
+
+s! {
+    /// Link control and status (deprecated, use [`ethtool_link_settings`]).
+    pub struct ethtool_cmd {
+        pub cmd: crate::__u32,
+        pub supported: crate::__u32,
+        pub advertising: crate::__u32,
+        pub speed: crate::__u16,
+        pub duplex: crate::__u8,
+        pub port: crate::__u8,
+        pub phy_address: crate::__u8,
+        pub transceiver: crate::__u8,
+        pub autoneg: crate::__u8,
+        pub mdio_support: crate::__u8,
+        pub maxtxpkt: crate::__u32,
+        pub maxrxpkt: crate::__u32,
+        pub speed_hi: crate::__u16,
+        pub eth_tp_mdix: crate::__u8,
+        pub eth_tp_mdix_ctrl: crate::__u8,
+        pub lp_advertising: crate::__u32,
+        reserved: Padding<[crate::__u32; 2]>,
+    }
+}
+
+f! {
+    pub fn ethtool_cmd_speed_set(ep: *mut ethtool_cmd, speed: crate::__u32) -> () {
+        (*ep).speed = speed as crate::__u16;
+        (*ep).speed_hi = (speed >> 16) as crate::__u16;
+    }
+
+    pub fn ethtool_cmd_speed(ep: *const ethtool_cmd) -> crate::__u32 {
+        ((*ep).speed_hi as crate::__u32) << 16 | (*ep).speed as crate::__u32
+    }
+}
+
+pub const ETH_MDIO_SUPPORTS_C22: c_int = 1;
+pub const ETH_MDIO_SUPPORTS_C45: c_int = 2;
+pub const ETHTOOL_FWVERS_LEN: c_int = 32;
+pub const ETHTOOL_BUSINFO_LEN: c_int = 32;
+pub const ETHTOOL_EROMVERS_LEN: c_int = 32;
+
+s! {
+    pub struct ethtool_drvinfo {
+        pub cmd: crate::__u32,
+        pub driver: [c_char; 32],
+        pub version: [c_char; 32],
+        pub fw_version: [c_char; ETHTOOL_FWVERS_LEN as usize],
+        pub bus_info: [c_char; ETHTOOL_BUSINFO_LEN as usize],
+        pub erom_version: [c_char; ETHTOOL_EROMVERS_LEN as usize],
+        reserved2: Padding<[c_char; 12]>,
+        pub n_priv_flags: crate::__u32,
+        pub n_stats: crate::__u32,
+        pub testinfo_len: crate::__u32,
+        pub eedump_len: crate::__u32,
+        pub regdump_len: crate::__u32,
+    }
+}
+
+pub const SOPASS_MAX: c_int = 6;
+
+s! {
+    pub struct ethtool_wolinfo {
+        pub cmd: crate::__u32,
+        pub supported: crate::__u32,
+        pub wolopts: crate::__u32,
+        pub sopass: [crate::__u8; SOPASS_MAX as usize],
+    }
+
+    pub struct ethtool_value {
+        pub cmd: crate::__u32,
+        pub data: crate::__u32,
+    }
+}
+
+pub const PFC_STORM_PREVENTION_AUTO: c_int = 0xffff;
+pub const PFC_STORM_PREVENTION_DISABLE: c_int = 0;
+
+c_enum! {
+    pub enum tunable_id {
+        pub ETHTOOL_ID_UNSPEC,
+        pub ETHTOOL_RX_COPYBREAK,
+        pub ETHTOOL_TX_COPYBREAK,
+        pub ETHTOOL_PFC_PREVENTION_TOUT,
+        pub ETHTOOL_TX_COPYBREAK_BUF_SIZE,
+    }
+}
+
+c_enum! {
+    pub enum tunable_type_id {
+        pub ETHTOOL_TUNABLE_UNSPEC,
+        pub ETHTOOL_TUNABLE_U8,
+        pub ETHTOOL_TUNABLE_U16,
+        pub ETHTOOL_TUNABLE_U32,
+        pub ETHTOOL_TUNABLE_U64,
+        pub ETHTOOL_TUNABLE_STRING,
+        pub ETHTOOL_TUNABLE_S8,
+        pub ETHTOOL_TUNABLE_S16,
+        pub ETHTOOL_TUNABLE_S32,
+        pub ETHTOOL_TUNABLE_S64,
+    }
+}
+
+s! {
+    pub struct ethtool_tunable {
+        pub cmd: crate::__u32,
+        pub id: crate::__u32,
+        pub type_id: crate::__u32,
+        pub len: crate::__u32,
+        pub data: [*mut c_void; 0],
+    }
+}
+
+pub const DOWNSHIFT_DEV_DEFAULT_COUNT: c_int = 0xff;
+pub const DOWNSHIFT_DEV_DISABLE: c_int = 0;
+pub const ETHTOOL_PHY_FAST_LINK_DOWN_ON: c_int = 0;
+pub const ETHTOOL_PHY_FAST_LINK_DOWN_OFF: c_int = 0xff;
+pub const ETHTOOL_PHY_EDPD_DFLT_TX_MSECS: c_int = 0xffff;
+pub const ETHTOOL_PHY_EDPD_NO_TX: c_int = 0xfffe;
+pub const ETHTOOL_PHY_EDPD_DISABLE: c_int = 0;
+
+c_enum! {
+    pub enum phy_tunable_id {
+        pub ETHTOOL_PHY_ID_UNSPEC,
+        pub ETHTOOL_PHY_DOWNSHIFT,
+        pub ETHTOOL_PHY_FAST_LINK_DOWN,
+        pub ETHTOOL_PHY_EDPD,
+    }
+}
+
+s! {
+    pub struct ethtool_regs {
+        pub cmd: crate::__u32,
+        pub version: crate::__u32,
+        pub len: crate::__u32,
+        pub data: [crate::__u8; 0],
+    }
+
+    pub struct ethtool_eeprom {
+        pub cmd: crate::__u32,
+        pub magic: crate::__u32,
+        pub offset: crate::__u32,
+        pub len: crate::__u32,
+        pub data: [crate::__u8; 0],
+    }
+
+    pub struct ethtool_eee {
+        pub cmd: crate::__u32,
+        pub supported: crate::__u32,
+        pub advertised: crate::__u32,
+        pub lp_advertised: crate::__u32,
+        pub eee_active: crate::__u32,
+        pub eee_enabled: crate::__u32,
+        pub tx_lpi_enabled: crate::__u32,
+        pub tx_lpi_timer: crate::__u32,
+        reserved: Padding<[crate::__u32; 2]>,
+    }
+
+    pub struct ethtool_modinfo {
+        pub cmd: crate::__u32,
+        pub type_: crate::__u32,
+        pub eeprom_len: crate::__u32,
+        reserved: Padding<[crate::__u32; 8]>,
+    }
+
+    pub struct ethtool_coalesce {
+        pub cmd: crate::__u32,
+        pub rx_coalesce_usecs: crate::__u32,
+        pub rx_max_coalesced_frames: crate::__u32,
+        pub rx_coalesce_usecs_irq: crate::__u32,
+        pub rx_max_coalesced_frames_irq: crate::__u32,
+        pub tx_coalesce_usecs: crate::__u32,
+        pub tx_max_coalesced_frames: crate::__u32,
+        pub tx_coalesce_usecs_irq: crate::__u32,
+        pub tx_max_coalesced_frames_irq: crate::__u32,
+        pub stats_block_coalesce_usecs: crate::__u32,
+        pub use_adaptive_rx_coalesce: crate::__u32,
+        pub use_adaptive_tx_coalesce: crate::__u32,
+        pub pkt_rate_low: crate::__u32,
+        pub rx_coalesce_usecs_low: crate::__u32,
+        pub rx_max_coalesced_frames_low: crate::__u32,
+        pub tx_coalesce_usecs_low: crate::__u32,
+        pub tx_max_coalesced_frames_low: crate::__u32,
+        pub pkt_rate_high: crate::__u32,
+        pub rx_coalesce_usecs_high: crate::__u32,
+        pub rx_max_coalesced_frames_high: crate::__u32,
+        pub tx_coalesce_usecs_high: crate::__u32,
+        pub tx_max_coalesced_frames_high: crate::__u32,
+        pub rate_sample_interval: crate::__u32,
+    }
+
+    pub struct ethtool_ringparam {
+        pub cmd: crate::__u32,
+        pub rx_max_pending: crate::__u32,
+        pub rx_mini_max_pending: crate::__u32,
+        pub rx_jumbo_max_pending: crate::__u32,
+        pub tx_max_pending: crate::__u32,
+        pub rx_pending: crate::__u32,
+        pub rx_mini_pending: crate::__u32,
+        pub rx_jumbo_pending: crate::__u32,
+        pub tx_pending: crate::__u32,
+    }
+
+    pub struct ethtool_channels {
+        pub cmd: crate::__u32,
+        pub max_rx: crate::__u32,
+        pub max_tx: crate::__u32,
+        pub max_other: crate::__u32,
+        pub max_combined: crate::__u32,
+        pub rx_count: crate::__u32,
+        pub tx_count: crate::__u32,
+        pub other_count: crate::__u32,
+        pub combined_count: crate::__u32,
+    }
+
+    pub struct ethtool_pauseparam {
+        pub cmd: crate::__u32,
+        pub autoneg: crate::__u32,
+        pub rx_pause: crate::__u32,
+        pub tx_pause: crate::__u32,
+    }
+}
+
+/* Link extended state */
+c_enum! {
+    pub enum ethtool_link_ext_state {
+        pub ETHTOOL_LINK_EXT_STATE_AUTONEG,
+        pub ETHTOOL_LINK_EXT_STATE_LINK_TRAINING_FAILURE,
+        pub ETHTOOL_LINK_EXT_STATE_LINK_LOGICAL_MISMATCH,
+        pub ETHTOOL_LINK_EXT_STATE_BAD_SIGNAL_INTEGRITY,
+        pub ETHTOOL_LINK_EXT_STATE_NO_CABLE,
+        pub ETHTOOL_LINK_EXT_STATE_CABLE_ISSUE,
+        pub ETHTOOL_LINK_EXT_STATE_EEPROM_ISSUE,
+        pub ETHTOOL_LINK_EXT_STATE_CALIBRATION_FAILURE,
+        pub ETHTOOL_LINK_EXT_STATE_POWER_BUDGET_EXCEEDED,
+        pub ETHTOOL_LINK_EXT_STATE_OVERHEAT,
+        pub ETHTOOL_LINK_EXT_STATE_MODULE,
+    }
+}
+
+/* More information in addition to ETHTOOL_LINK_EXT_STATE_AUTONEG. */
+c_enum! {
+    pub enum ethtool_link_ext_substate_autoneg {
+        pub ETHTOOL_LINK_EXT_SUBSTATE_AN_NO_PARTNER_DETECTED = 1,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_AN_ACK_NOT_RECEIVED,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_AN_NEXT_PAGE_EXCHANGE_FAILED,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_AN_NO_PARTNER_DETECTED_FORCE_MODE,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_AN_FEC_MISMATCH_DURING_OVERRIDE,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_AN_NO_HCD,
+    }
+}
+
+c_enum! {
+    pub enum ethtool_link_ext_substate_link_training {
+        pub ETHTOOL_LINK_EXT_SUBSTATE_LT_KR_FRAME_LOCK_NOT_ACQUIRED = 1,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_LT_KR_LINK_INHIBIT_TIMEOUT,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_LT_KR_LINK_PARTNER_DID_NOT_SET_RECEIVER_READY,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_LT_REMOTE_FAULT,
+    }
+}
+
+c_enum! {
+    pub enum ethtool_link_ext_substate_link_logical_mismatch {
+        pub ETHTOOL_LINK_EXT_SUBSTATE_LLM_PCS_DID_NOT_ACQUIRE_BLOCK_LOCK = 1,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_LLM_PCS_DID_NOT_ACQUIRE_AM_LOCK,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_LLM_PCS_DID_NOT_GET_ALIGN_STATUS,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_LLM_FC_FEC_IS_NOT_LOCKED,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_LLM_RS_FEC_IS_NOT_LOCKED,
+    }
+}
+
+c_enum! {
+    pub enum ethtool_link_ext_substate_bad_signal_integrity {
+        pub ETHTOOL_LINK_EXT_SUBSTATE_BSI_LARGE_NUMBER_OF_PHYSICAL_ERRORS = 1,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_BSI_UNSUPPORTED_RATE,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_BSI_SERDES_REFERENCE_CLOCK_LOST,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_BSI_SERDES_ALOS,
+    }
+}
+
+/* More information in addition to ETHTOOL_LINK_EXT_STATE_CABLE_ISSUE. */
+c_enum! {
+    pub enum ethtool_link_ext_substate_cable_issue {
+        pub ETHTOOL_LINK_EXT_SUBSTATE_CI_UNSUPPORTED_CABLE = 1,
+        pub ETHTOOL_LINK_EXT_SUBSTATE_CI_CABLE_TEST_FAILURE,
+    }
+}
+
+/* More information in addition to ETHTOOL_LINK_EXT_STATE_MODULE. */
+c_enum! {
+    pub enum ethtool_link_ext_substate_module {
+        pub ETHTOOL_LINK_EXT_SUBSTATE_MODULE_CMIS_NOT_READY = 1,
+    }
+}
+
+pub const ETH_GSTRING_LEN: c_int = 32;
+
+c_enum! {
+    pub enum ethtool_stringset {
+        pub ETH_SS_TEST = 0,
+        pub ETH_SS_STATS,
+        pub ETH_SS_PRIV_FLAGS,
+        pub ETH_SS_NTUPLE_FILTERS,
+        pub ETH_SS_FEATURES,
+        pub ETH_SS_RSS_HASH_FUNCS,
+        pub ETH_SS_TUNABLES,
+        pub ETH_SS_PHY_STATS,
+        pub ETH_SS_PHY_TUNABLES,
+        pub ETH_SS_LINK_MODES,
+        pub ETH_SS_MSG_CLASSES,
+        pub ETH_SS_WOL_MODES,
+        pub ETH_SS_SOF_TIMESTAMPING,
+        pub ETH_SS_TS_TX_TYPES,
+        pub ETH_SS_TS_RX_FILTERS,
+        pub ETH_SS_UDP_TUNNEL_TYPES,
+        pub ETH_SS_STATS_STD,
+        pub ETH_SS_STATS_ETH_PHY,
+        pub ETH_SS_STATS_ETH_MAC,
+        pub ETH_SS_STATS_ETH_CTRL,
+        pub ETH_SS_STATS_RMON,
+    }
+}
+
+c_enum! {
+    pub enum ethtool_module_power_mode_policy {
+        pub ETHTOOL_MODULE_POWER_MODE_POLICY_HIGH = 1,
+        pub ETHTOOL_MODULE_POWER_MODE_POLICY_AUTO,
+    }
+}
+
+c_enum! {
+    pub enum ethtool_module_power_mode {
+        pub ETHTOOL_MODULE_POWER_MODE_LOW = 1,
+        pub ETHTOOL_MODULE_POWER_MODE_HIGH,
+    }
+}
+
+c_enum! {
+    pub enum ethtool_podl_pse_admin_state {
+        pub ETHTOOL_PODL_PSE_ADMIN_STATE_UNKNOWN = 1,
+        pub ETHTOOL_PODL_PSE_ADMIN_STATE_DISABLED,
+        pub ETHTOOL_PODL_PSE_ADMIN_STATE_ENABLED,
+    }
+}
+
+c_enum! {
+    pub enum ethtool_podl_pse_pw_d_status {
+        pub ETHTOOL_PODL_PSE_PW_D_STATUS_UNKNOWN = 1,
+        pub ETHTOOL_PODL_PSE_PW_D_STATUS_DISABLED,
+        pub ETHTOOL_PODL_PSE_PW_D_STATUS_SEARCHING,
+        pub ETHTOOL_PODL_PSE_PW_D_STATUS_DELIVERING,
+        pub ETHTOOL_PODL_PSE_PW_D_STATUS_SLEEP,
+        pub ETHTOOL_PODL_PSE_PW_D_STATUS_IDLE,
+        pub ETHTOOL_PODL_PSE_PW_D_STATUS_ERROR,
+    }
+}
+
+s! {
+    pub struct ethtool_gstrings {
+        pub cmd: crate::__u32,
+        pub string_set: crate::__u32,
+        pub len: crate::__u32,
+        pub data: [crate::__u8; 0],
+    }
+
+    pub struct ethtool_sset_info {
+        pub cmd: crate::__u32,
+        reserved: Padding<crate::__u32>,
+        pub sset_mask: crate::__u64,
+        pub data: [crate::__u32; 0],
+    }
+}
+
+c_enum! {
+    pub enum ethtool_test_flags {
+        pub ETH_TEST_FL_OFFLINE = 1 << 0,
+        pub ETH_TEST_FL_FAILED = 1 << 1,
+        pub ETH_TEST_FL_EXTERNAL_LB = 1 << 2,
+        pub ETH_TEST_FL_EXTERNAL_LB_DONE = 1 << 3,
+    }
+}
+
+s! {
+    pub struct ethtool_test {
+        pub cmd: crate::__u32,
+        pub flags: crate::__u32,
+        reserved: Padding<crate::__u32>,
+        pub len: crate::__u32,
+        pub data: [crate::__u64; 0],
+    }
+
+    pub struct ethtool_stats {
+        pub cmd: crate::__u32,
+        pub n_stats: crate::__u32,
+        pub data: [crate::__u64; 0],
+    }
+
+    pub struct ethtool_perm_addr {
+        pub cmd: crate::__u32,
+        pub size: crate::__u32,
+        pub data: [crate::__u8; 0],
+    }
+}
+
+c_enum! {
+    pub enum ethtool_flags {
+        pub ETH_FLAG_TXVLAN = 1 << 7,
+        pub ETH_FLAG_RXVLAN = 1 << 8,
+        pub ETH_FLAG_LRO = 1 << 15,
+        pub ETH_FLAG_NTUPLE = 1 << 27,
+        pub ETH_FLAG_RXHASH = 1 << 28,
+    }
+}
+
+pub const ETHTOOL_FLASH_MAX_FILENAME: c_int = 128;
+
+c_enum! {
+    pub enum ethtool_flash_op_type {
+        pub ETHTOOL_FLASH_ALL_REGIONS = 0,
+    }
+}
+
+s! {
+    pub struct ethtool_flash {
+        pub cmd: crate::__u32,
+        pub region: crate::__u32,
+        pub data: [c_char; ETHTOOL_FLASH_MAX_FILENAME as usize],
+    }
+
+    pub struct ethtool_dump {
+        pub cmd: crate::__u32,
+        pub version: crate::__u32,
+        pub flag: crate::__u32,
+        pub len: crate::__u32,
+        pub data: [crate::__u8; 0],
+    }
+}
+
+pub const ETH_FW_DUMP_DISABLE: c_int = 0;
+
+s! {
+    pub struct ethtool_ts_info {
+        pub cmd: crate::__u32,
+        pub so_timestamping: crate::__u32,
+        pub phc_index: crate::__s32,
+        pub tx_types: crate::__u32,
+        tx_reserved: Padding<[crate::__u32; 3]>,
+        pub rx_filters: crate::__u32,
+        rx_reserved: Padding<[crate::__u32; 3]>,
+    }
+
+    pub struct ethtool_fecparam {
+        pub cmd: crate::__u32,
+        pub active_fec: crate::__u32,
+        pub fec: crate::__u32,
+        reserved: Padding<crate::__u32>,
+    }
+}
+
+c_enum! {
+    pub enum ethtool_fec_config_bits {
+        pub ETHTOOL_FEC_NONE_BIT,
+        pub ETHTOOL_FEC_AUTO_BIT,
+        pub ETHTOOL_FEC_OFF_BIT,
+        pub ETHTOOL_FEC_RS_BIT,
+        pub ETHTOOL_FEC_BASER_BIT,
+        pub ETHTOOL_FEC_LLRS_BIT,
+    }
+}
+
+pub const ETHTOOL_FEC_NONE: c_int = 1 << ETHTOOL_FEC_NONE_BIT;
+pub const ETHTOOL_FEC_AUTO: c_int = 1 << ETHTOOL_FEC_AUTO_BIT;
+pub const ETHTOOL_FEC_OFF: c_int = 1 << ETHTOOL_FEC_OFF_BIT;
+pub const ETHTOOL_FEC_RS: c_int = 1 << ETHTOOL_FEC_RS_BIT;
+pub const ETHTOOL_FEC_BASER: c_int = 1 << ETHTOOL_FEC_BASER_BIT;
+pub const ETHTOOL_FEC_LLRS: c_int = 1 << ETHTOOL_FEC_LLRS_BIT;
+
+/* CMDs currently supported */
+pub const ETHTOOL_GSET: c_int = 0x00000001;
+pub const ETHTOOL_SSET: c_int = 0x00000002;
+pub const ETHTOOL_GDRVINFO: c_int = 0x00000003;
+pub const ETHTOOL_GREGS: c_int = 0x00000004;
+pub const ETHTOOL_GWOL: c_int = 0x00000005;
+pub const ETHTOOL_SWOL: c_int = 0x00000006;
+pub const ETHTOOL_GMSGLVL: c_int = 0x00000007;
+pub const ETHTOOL_SMSGLVL: c_int = 0x00000008;
+pub const ETHTOOL_NWAY_RST: c_int = 0x00000009;
+pub const ETHTOOL_GLINK: c_int = 0x0000000a;
+pub const ETHTOOL_GEEPROM: c_int = 0x0000000b;
+pub const ETHTOOL_SEEPROM: c_int = 0x0000000c;
+pub const ETHTOOL_GCOALESCE: c_int = 0x0000000e;
+pub const ETHTOOL_SCOALESCE: c_int = 0x0000000f;
+pub const ETHTOOL_GRINGPARAM: c_int = 0x00000010;
+pub const ETHTOOL_SRINGPARAM: c_int = 0x00000011;
+pub const ETHTOOL_GPAUSEPARAM: c_int = 0x00000012;
+pub const ETHTOOL_SPAUSEPARAM: c_int = 0x00000013;
+pub const ETHTOOL_GRXCSUM: c_int = 0x00000014;
+pub const ETHTOOL_SRXCSUM: c_int = 0x00000015;
+pub const ETHTOOL_GTXCSUM: c_int = 0x00000016;
+pub const ETHTOOL_STXCSUM: c_int = 0x00000017;
+pub const ETHTOOL_GSG: c_int = 0x00000018;
+pub const ETHTOOL_SSG: c_int = 0x00000019;
+pub const ETHTOOL_TEST: c_int = 0x0000001a;
+pub const ETHTOOL_GSTRINGS: c_int = 0x0000001b;
+pub const ETHTOOL_PHYS_ID: c_int = 0x0000001c;
+pub const ETHTOOL_GSTATS: c_int = 0x0000001d;
+pub const ETHTOOL_GTSO: c_int = 0x0000001e;
+pub const ETHTOOL_STSO: c_int = 0x0000001f;
+pub const ETHTOOL_GPERMADDR: c_int = 0x00000020;
+pub const ETHTOOL_GUFO: c_int = 0x00000021;
+pub const ETHTOOL_SUFO: c_int = 0x00000022;
+pub const ETHTOOL_GGSO: c_int = 0x00000023;
+pub const ETHTOOL_SGSO: c_int = 0x00000024;
+pub const ETHTOOL_GFLAGS: c_int = 0x00000025;
+pub const ETHTOOL_SFLAGS: c_int = 0x00000026;
+pub const ETHTOOL_GPFLAGS: c_int = 0x00000027;
+pub const ETHTOOL_SPFLAGS: c_int = 0x00000028;
+pub const ETHTOOL_GRXFH: c_int = 0x00000029;
+pub const ETHTOOL_SRXFH: c_int = 0x0000002a;
+pub const ETHTOOL_GGRO: c_int = 0x0000002b;
+pub const ETHTOOL_SGRO: c_int = 0x0000002c;
+pub const ETHTOOL_GRXRINGS: c_int = 0x0000002d;
+pub const ETHTOOL_GRXCLSRLCNT: c_int = 0x0000002e;
+pub const ETHTOOL_GRXCLSRULE: c_int = 0x0000002f;
+pub const ETHTOOL_GRXCLSRLALL: c_int = 0x00000030;
+pub const ETHTOOL_SRXCLSRLDEL: c_int = 0x00000031;
+pub const ETHTOOL_SRXCLSRLINS: c_int = 0x00000032;
+pub const ETHTOOL_FLASHDEV: c_int = 0x00000033;
+pub const ETHTOOL_RESET: c_int = 0x00000034;
+pub const ETHTOOL_SRXNTUPLE: c_int = 0x00000035;
+pub const ETHTOOL_GRXNTUPLE: c_int = 0x00000036;
+pub const ETHTOOL_GSSET_INFO: c_int = 0x00000037;
+pub const ETHTOOL_GRXFHINDIR: c_int = 0x00000038;
+pub const ETHTOOL_SRXFHINDIR: c_int = 0x00000039;
+pub const ETHTOOL_GFEATURES: c_int = 0x0000003a;
+pub const ETHTOOL_SFEATURES: c_int = 0x0000003b;
+pub const ETHTOOL_GCHANNELS: c_int = 0x0000003c;
+pub const ETHTOOL_SCHANNELS: c_int = 0x0000003d;
+pub const ETHTOOL_SET_DUMP: c_int = 0x0000003e;
+pub const ETHTOOL_GET_DUMP_FLAG: c_int = 0x0000003f;
+pub const ETHTOOL_GET_DUMP_DATA: c_int = 0x00000040;
+pub const ETHTOOL_GET_TS_INFO: c_int = 0x00000041;
+pub const ETHTOOL_GMODULEINFO: c_int = 0x00000042;
+pub const ETHTOOL_GMODULEEEPROM: c_int = 0x00000043;
+pub const ETHTOOL_GEEE: c_int = 0x00000044;
+pub const ETHTOOL_SEEE: c_int = 0x00000045;
+pub const ETHTOOL_GRSSH: c_int = 0x00000046;
+pub const ETHTOOL_SRSSH: c_int = 0x00000047;
+pub const ETHTOOL_GTUNABLE: c_int = 0x00000048;
+pub const ETHTOOL_STUNABLE: c_int = 0x00000049;
+pub const ETHTOOL_GPHYSTATS: c_int = 0x0000004a;
+pub const ETHTOOL_PERQUEUE: c_int = 0x0000004b;
+pub const ETHTOOL_GLINKSETTINGS: c_int = 0x0000004c;
+pub const ETHTOOL_SLINKSETTINGS: c_int = 0x0000004d;
+pub const ETHTOOL_PHY_GTUNABLE: c_int = 0x0000004e;
+pub const ETHTOOL_PHY_STUNABLE: c_int = 0x0000004f;
+pub const ETHTOOL_GFECPARAM: c_int = 0x00000050;
+pub const ETHTOOL_SFECPARAM: c_int = 0x00000051;
+
+/* compatibility with older code */
+pub const SPARC_ETH_GSET: c_int = ETHTOOL_GSET;
+pub const SPARC_ETH_SSET: c_int = ETHTOOL_SSET;
+
+c_enum! {
+    pub enum ethtool_link_mode_bit_indices {
+        pub ETHTOOL_LINK_MODE_10baseT_Half_BIT = 0,
+        pub ETHTOOL_LINK_MODE_10baseT_Full_BIT = 1,
+        pub ETHTOOL_LINK_MODE_100baseT_Half_BIT = 2,
+        pub ETHTOOL_LINK_MODE_100baseT_Full_BIT = 3,
+        pub ETHTOOL_LINK_MODE_1000baseT_Half_BIT = 4,
+        pub ETHTOOL_LINK_MODE_1000baseT_Full_BIT = 5,
+        pub ETHTOOL_LINK_MODE_Autoneg_BIT = 6,
+        pub ETHTOOL_LINK_MODE_TP_BIT = 7,
+        pub ETHTOOL_LINK_MODE_AUI_BIT = 8,
+        pub ETHTOOL_LINK_MODE_MII_BIT = 9,
+        pub ETHTOOL_LINK_MODE_FIBRE_BIT = 10,
+        pub ETHTOOL_LINK_MODE_BNC_BIT = 11,
+        pub ETHTOOL_LINK_MODE_10000baseT_Full_BIT = 12,
+        pub ETHTOOL_LINK_MODE_Pause_BIT = 13,
+        pub ETHTOOL_LINK_MODE_Asym_Pause_BIT = 14,
+        pub ETHTOOL_LINK_MODE_2500baseX_Full_BIT = 15,
+        pub ETHTOOL_LINK_MODE_Backplane_BIT = 16,
+        pub ETHTOOL_LINK_MODE_1000baseKX_Full_BIT = 17,
+        pub ETHTOOL_LINK_MODE_10000baseKX4_Full_BIT = 18,
+        pub ETHTOOL_LINK_MODE_10000baseKR_Full_BIT = 19,
+        pub ETHTOOL_LINK_MODE_10000baseR_FEC_BIT = 20,
+        pub ETHTOOL_LINK_MODE_20000baseMLD2_Full_BIT = 21,
+        pub ETHTOOL_LINK_MODE_20000baseKR2_Full_BIT = 22,
+        pub ETHTOOL_LINK_MODE_40000baseKR4_Full_BIT = 23,
+        pub ETHTOOL_LINK_MODE_40000baseCR4_Full_BIT = 24,
+        pub ETHTOOL_LINK_MODE_40000baseSR4_Full_BIT = 25,
+        pub ETHTOOL_LINK_MODE_40000baseLR4_Full_BIT = 26,
+        pub ETHTOOL_LINK_MODE_56000baseKR4_Full_BIT = 27,
+        pub ETHTOOL_LINK_MODE_56000baseCR4_Full_BIT = 28,
+        pub ETHTOOL_LINK_MODE_56000baseSR4_Full_BIT = 29,
+        pub ETHTOOL_LINK_MODE_56000baseLR4_Full_BIT = 30,
+        pub ETHTOOL_LINK_MODE_25000baseCR_Full_BIT = 31,
+        pub ETHTOOL_LINK_MODE_25000baseKR_Full_BIT = 32,
+        pub ETHTOOL_LINK_MODE_25000baseSR_Full_BIT = 33,
+        pub ETHTOOL_LINK_MODE_50000baseCR2_Full_BIT = 34,
+        pub ETHTOOL_LINK_MODE_50000baseKR2_Full_BIT = 35,
+        pub ETHTOOL_LINK_MODE_100000baseKR4_Full_BIT = 36,
+        pub ETHTOOL_LINK_MODE_100000baseSR4_Full_BIT = 37,
+        pub ETHTOOL_LINK_MODE_100000baseCR4_Full_BIT = 38,
+        pub ETHTOOL_LINK_MODE_100000baseLR4_ER4_Full_BIT = 39,
+        pub ETHTOOL_LINK_MODE_50000baseSR2_Full_BIT = 40,
+        pub ETHTOOL_LINK_MODE_1000baseX_Full_BIT = 41,
+        pub ETHTOOL_LINK_MODE_10000baseCR_Full_BIT = 42,
+        pub ETHTOOL_LINK_MODE_10000baseSR_Full_BIT = 43,
+        pub ETHTOOL_LINK_MODE_10000baseLR_Full_BIT = 44,
+        pub ETHTOOL_LINK_MODE_10000baseLRM_Full_BIT = 45,
+        pub ETHTOOL_LINK_MODE_10000baseER_Full_BIT = 46,
+        pub ETHTOOL_LINK_MODE_2500baseT_Full_BIT = 47,
+        pub ETHTOOL_LINK_MODE_5000baseT_Full_BIT = 48,
+        pub ETHTOOL_LINK_MODE_FEC_NONE_BIT = 49,
+        pub ETHTOOL_LINK_MODE_FEC_RS_BIT = 50,
+        pub ETHTOOL_LINK_MODE_FEC_BASER_BIT = 51,
+        pub ETHTOOL_LINK_MODE_50000baseKR_Full_BIT = 52,
+        pub ETHTOOL_LINK_MODE_50000baseSR_Full_BIT = 53,
+        pub ETHTOOL_LINK_MODE_50000baseCR_Full_BIT = 54,
+        pub ETHTOOL_LINK_MODE_50000baseLR_ER_FR_Full_BIT = 55,
+        pub ETHTOOL_LINK_MODE_50000baseDR_Full_BIT = 56,
+        pub ETHTOOL_LINK_MODE_100000baseKR2_Full_BIT = 57,
+        pub ETHTOOL_LINK_MODE_100000baseSR2_Full_BIT = 58,
+        pub ETHTOOL_LINK_MODE_100000baseCR2_Full_BIT = 59,
+        pub ETHTOOL_LINK_MODE_100000baseLR2_ER2_FR2_Full_BIT = 60,
+        pub ETHTOOL_LINK_MODE_100000baseDR2_Full_BIT = 61,
+        pub ETHTOOL_LINK_MODE_200000baseKR4_Full_BIT = 62,
+        pub ETHTOOL_LINK_MODE_200000baseSR4_Full_BIT = 63,
+        pub ETHTOOL_LINK_MODE_200000baseLR4_ER4_FR4_Full_BIT = 64,
+        pub ETHTOOL_LINK_MODE_200000baseDR4_Full_BIT = 65,
+        pub ETHTOOL_LINK_MODE_200000baseCR4_Full_BIT = 66,
+        pub ETHTOOL_LINK_MODE_100baseT1_Full_BIT = 67,
+        pub ETHTOOL_LINK_MODE_1000baseT1_Full_BIT = 68,
+        pub ETHTOOL_LINK_MODE_400000baseKR8_Full_BIT = 69,
+        pub ETHTOOL_LINK_MODE_400000baseSR8_Full_BIT = 70,
+        pub ETHTOOL_LINK_MODE_400000baseLR8_ER8_FR8_Full_BIT = 71,
+        pub ETHTOOL_LINK_MODE_400000baseDR8_Full_BIT = 72,
+        pub ETHTOOL_LINK_MODE_400000baseCR8_Full_BIT = 73,
+        pub ETHTOOL_LINK_MODE_FEC_LLRS_BIT = 74,
+        pub ETHTOOL_LINK_MODE_100000baseKR_Full_BIT = 75,
+        pub ETHTOOL_LINK_MODE_100000baseSR_Full_BIT = 76,
+        pub ETHTOOL_LINK_MODE_100000baseLR_ER_FR_Full_BIT = 77,
+        pub ETHTOOL_LINK_MODE_100000baseCR_Full_BIT = 78,
+        pub ETHTOOL_LINK_MODE_100000baseDR_Full_BIT = 79,
+        pub ETHTOOL_LINK_MODE_200000baseKR2_Full_BIT = 80,
+        pub ETHTOOL_LINK_MODE_200000baseSR2_Full_BIT = 81,
+        pub ETHTOOL_LINK_MODE_200000baseLR2_ER2_FR2_Full_BIT = 82,
+        pub ETHTOOL_LINK_MODE_200000baseDR2_Full_BIT = 83,
+        pub ETHTOOL_LINK_MODE_200000baseCR2_Full_BIT = 84,
+        pub ETHTOOL_LINK_MODE_400000baseKR4_Full_BIT = 85,
+        pub ETHTOOL_LINK_MODE_400000baseSR4_Full_BIT = 86,
+        pub ETHTOOL_LINK_MODE_400000baseLR4_ER4_FR4_Full_BIT = 87,
+        pub ETHTOOL_LINK_MODE_400000baseDR4_Full_BIT = 88,
+        pub ETHTOOL_LINK_MODE_400000baseCR4_Full_BIT = 89,
+        pub ETHTOOL_LINK_MODE_100baseFX_Half_BIT = 90,
+        pub ETHTOOL_LINK_MODE_100baseFX_Full_BIT = 91,
+        pub ETHTOOL_LINK_MODE_10baseT1L_Full_BIT = 92,
+    }
+}
+
+/* Legacy link mode masks, deprecated in favour of ETHTOOL_GLINKSETTINGS. */
+pub const SUPPORTED_10baseT_Half: c_ulong = 1 << ETHTOOL_LINK_MODE_10baseT_Half_BIT;
+pub const SUPPORTED_10baseT_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_10baseT_Full_BIT;
+pub const SUPPORTED_100baseT_Half: c_ulong = 1 << ETHTOOL_LINK_MODE_100baseT_Half_BIT;
+pub const SUPPORTED_100baseT_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_100baseT_Full_BIT;
+pub const SUPPORTED_1000baseT_Half: c_ulong = 1 << ETHTOOL_LINK_MODE_1000baseT_Half_BIT;
+pub const SUPPORTED_1000baseT_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_1000baseT_Full_BIT;
+pub const SUPPORTED_Autoneg: c_ulong = 1 << ETHTOOL_LINK_MODE_Autoneg_BIT;
+pub const SUPPORTED_TP: c_ulong = 1 << ETHTOOL_LINK_MODE_TP_BIT;
+pub const SUPPORTED_AUI: c_ulong = 1 << ETHTOOL_LINK_MODE_AUI_BIT;
+pub const SUPPORTED_MII: c_ulong = 1 << ETHTOOL_LINK_MODE_MII_BIT;
+pub const SUPPORTED_FIBRE: c_ulong = 1 << ETHTOOL_LINK_MODE_FIBRE_BIT;
+pub const SUPPORTED_BNC: c_ulong = 1 << ETHTOOL_LINK_MODE_BNC_BIT;
+pub const SUPPORTED_10000baseT_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_10000baseT_Full_BIT;
+pub const SUPPORTED_Pause: c_ulong = 1 << ETHTOOL_LINK_MODE_Pause_BIT;
+pub const SUPPORTED_Asym_Pause: c_ulong = 1 << ETHTOOL_LINK_MODE_Asym_Pause_BIT;
+pub const SUPPORTED_2500baseX_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_2500baseX_Full_BIT;
+pub const SUPPORTED_Backplane: c_ulong = 1 << ETHTOOL_LINK_MODE_Backplane_BIT;
+pub const SUPPORTED_1000baseKX_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_1000baseKX_Full_BIT;
+pub const SUPPORTED_10000baseKX4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_10000baseKX4_Full_BIT;
+pub const SUPPORTED_10000baseKR_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_10000baseKR_Full_BIT;
+pub const SUPPORTED_10000baseR_FEC: c_ulong = 1 << ETHTOOL_LINK_MODE_10000baseR_FEC_BIT;
+pub const SUPPORTED_20000baseMLD2_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_20000baseMLD2_Full_BIT;
+pub const SUPPORTED_20000baseKR2_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_20000baseKR2_Full_BIT;
+pub const SUPPORTED_40000baseKR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_40000baseKR4_Full_BIT;
+pub const SUPPORTED_40000baseCR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_40000baseCR4_Full_BIT;
+pub const SUPPORTED_40000baseSR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_40000baseSR4_Full_BIT;
+pub const SUPPORTED_40000baseLR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_40000baseLR4_Full_BIT;
+pub const SUPPORTED_56000baseKR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_56000baseKR4_Full_BIT;
+pub const SUPPORTED_56000baseCR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_56000baseCR4_Full_BIT;
+pub const SUPPORTED_56000baseSR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_56000baseSR4_Full_BIT;
+pub const SUPPORTED_56000baseLR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_56000baseLR4_Full_BIT;
+
+pub const ADVERTISED_10baseT_Half: c_ulong = 1 << ETHTOOL_LINK_MODE_10baseT_Half_BIT;
+pub const ADVERTISED_10baseT_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_10baseT_Full_BIT;
+pub const ADVERTISED_100baseT_Half: c_ulong = 1 << ETHTOOL_LINK_MODE_100baseT_Half_BIT;
+pub const ADVERTISED_100baseT_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_100baseT_Full_BIT;
+pub const ADVERTISED_1000baseT_Half: c_ulong = 1 << ETHTOOL_LINK_MODE_1000baseT_Half_BIT;
+pub const ADVERTISED_1000baseT_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_1000baseT_Full_BIT;
+pub const ADVERTISED_Autoneg: c_ulong = 1 << ETHTOOL_LINK_MODE_Autoneg_BIT;
+pub const ADVERTISED_TP: c_ulong = 1 << ETHTOOL_LINK_MODE_TP_BIT;
+pub const ADVERTISED_AUI: c_ulong = 1 << ETHTOOL_LINK_MODE_AUI_BIT;
+pub const ADVERTISED_MII: c_ulong = 1 << ETHTOOL_LINK_MODE_MII_BIT;
+pub const ADVERTISED_FIBRE: c_ulong = 1 << ETHTOOL_LINK_MODE_FIBRE_BIT;
+pub const ADVERTISED_BNC: c_ulong = 1 << ETHTOOL_LINK_MODE_BNC_BIT;
+pub const ADVERTISED_10000baseT_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_10000baseT_Full_BIT;
+pub const ADVERTISED_Pause: c_ulong = 1 << ETHTOOL_LINK_MODE_Pause_BIT;
+pub const ADVERTISED_Asym_Pause: c_ulong = 1 << ETHTOOL_LINK_MODE_Asym_Pause_BIT;
+pub const ADVERTISED_2500baseX_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_2500baseX_Full_BIT;
+pub const ADVERTISED_Backplane: c_ulong = 1 << ETHTOOL_LINK_MODE_Backplane_BIT;
+pub const ADVERTISED_1000baseKX_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_1000baseKX_Full_BIT;
+pub const ADVERTISED_10000baseKX4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_10000baseKX4_Full_BIT;
+pub const ADVERTISED_10000baseKR_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_10000baseKR_Full_BIT;
+pub const ADVERTISED_10000baseR_FEC: c_ulong = 1 << ETHTOOL_LINK_MODE_10000baseR_FEC_BIT;
+pub const ADVERTISED_20000baseMLD2_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_20000baseMLD2_Full_BIT;
+pub const ADVERTISED_20000baseKR2_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_20000baseKR2_Full_BIT;
+pub const ADVERTISED_40000baseKR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_40000baseKR4_Full_BIT;
+pub const ADVERTISED_40000baseCR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_40000baseCR4_Full_BIT;
+pub const ADVERTISED_40000baseSR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_40000baseSR4_Full_BIT;
+pub const ADVERTISED_40000baseLR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_40000baseLR4_Full_BIT;
+pub const ADVERTISED_56000baseKR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_56000baseKR4_Full_BIT;
+pub const ADVERTISED_56000baseCR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_56000baseCR4_Full_BIT;
+pub const ADVERTISED_56000baseSR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_56000baseSR4_Full_BIT;
+pub const ADVERTISED_56000baseLR4_Full: c_ulong = 1 << ETHTOOL_LINK_MODE_56000baseLR4_Full_BIT;
+
+pub const SPEED_10: c_int = 10;
+pub const SPEED_100: c_int = 100;
+pub const SPEED_1000: c_int = 1000;
+pub const SPEED_2500: c_int = 2500;
+pub const SPEED_5000: c_int = 5000;
+pub const SPEED_10000: c_int = 10000;
+pub const SPEED_14000: c_int = 14000;
+pub const SPEED_20000: c_int = 20000;
+pub const SPEED_25000: c_int = 25000;
+pub const SPEED_40000: c_int = 40000;
+pub const SPEED_50000: c_int = 50000;
+pub const SPEED_56000: c_int = 56000;
+pub const SPEED_100000: c_int = 100000;
+pub const SPEED_200000: c_int = 200000;
+pub const SPEED_400000: c_int = 400000;
+pub const SPEED_UNKNOWN: c_int = -1;
+
+/* Duplex, half or full. */
+pub const DUPLEX_HALF: c_int = 0x00;
+pub const DUPLEX_FULL: c_int = 0x01;
+pub const DUPLEX_UNKNOWN: c_int = 0xff;
+
+pub const MASTER_SLAVE_CFG_UNSUPPORTED: c_int = 0;
+pub const MASTER_SLAVE_CFG_UNKNOWN: c_int = 1;
+pub const MASTER_SLAVE_CFG_MASTER_PREFERRED: c_int = 2;
+pub const MASTER_SLAVE_CFG_SLAVE_PREFERRED: c_int = 3;
+pub const MASTER_SLAVE_CFG_MASTER_FORCE: c_int = 4;
+pub const MASTER_SLAVE_CFG_SLAVE_FORCE: c_int = 5;
+
+pub const MASTER_SLAVE_STATE_UNSUPPORTED: c_int = 0;
+pub const MASTER_SLAVE_STATE_UNKNOWN: c_int = 1;
+pub const MASTER_SLAVE_STATE_MASTER: c_int = 2;
+pub const MASTER_SLAVE_STATE_SLAVE: c_int = 3;
+pub const MASTER_SLAVE_STATE_ERR: c_int = 4;
+
+/* These are used to throttle the rate of data on the phy interface when the
+ * native speed of the interface is higher than the link speed. */
+pub const RATE_MATCH_NONE: c_int = 0;
+pub const RATE_MATCH_PAUSE: c_int = 1;
+pub const RATE_MATCH_CRS: c_int = 2;
+pub const RATE_MATCH_OPEN_LOOP: c_int = 3;
+
+/* Which connector port. */
+pub const PORT_TP: c_int = 0x00;
+pub const PORT_AUI: c_int = 0x01;
+pub const PORT_MII: c_int = 0x02;
+pub const PORT_FIBRE: c_int = 0x03;
+pub const PORT_BNC: c_int = 0x04;
+pub const PORT_DA: c_int = 0x05;
+pub const PORT_NONE: c_int = 0xef;
+pub const PORT_OTHER: c_int = 0xff;
+
+/* Which transceiver to use. */
+pub const XCVR_INTERNAL: c_int = 0x00;
+pub const XCVR_EXTERNAL: c_int = 0x01;
+pub const XCVR_DUMMY1: c_int = 0x02;
+pub const XCVR_DUMMY2: c_int = 0x03;
+pub const XCVR_DUMMY3: c_int = 0x04;
+
+/* Enable or disable autonegotiation. */
+pub const AUTONEG_DISABLE: c_int = 0x00;
+pub const AUTONEG_ENABLE: c_int = 0x01;
+
+/* MDI or MDI-X status/control */
+pub const ETH_TP_MDI_INVALID: c_int = 0x00;
+pub const ETH_TP_MDI: c_int = 0x01;
+pub const ETH_TP_MDI_X: c_int = 0x02;
+pub const ETH_TP_MDI_AUTO: c_int = 0x03;
+
+/* Wake-On-Lan options. */
+pub const WAKE_PHY: c_int = 1 << 0;
+pub const WAKE_UCAST: c_int = 1 << 1;
+pub const WAKE_MCAST: c_int = 1 << 2;
+pub const WAKE_BCAST: c_int = 1 << 3;
+pub const WAKE_ARP: c_int = 1 << 4;
+pub const WAKE_MAGIC: c_int = 1 << 5;
+pub const WAKE_MAGICSECURE: c_int = 1 << 6;
+pub const WAKE_FILTER: c_int = 1 << 7;
+
+pub const WOL_MODE_COUNT: c_int = 8;
+
+/* EEPROM Standards for plug in modules */
+pub const ETH_MODULE_SFF_8079: c_int = 0x1;
+pub const ETH_MODULE_SFF_8079_LEN: c_int = 256;
+pub const ETH_MODULE_SFF_8472: c_int = 0x2;
+pub const ETH_MODULE_SFF_8472_LEN: c_int = 512;
+pub const ETH_MODULE_SFF_8636: c_int = 0x3;
+pub const ETH_MODULE_SFF_8636_LEN: c_int = 256;
+pub const ETH_MODULE_SFF_8436: c_int = 0x4;
+pub const ETH_MODULE_SFF_8436_LEN: c_int = 256;
+
+pub const ETH_MODULE_SFF_8636_MAX_LEN: c_int = 640;
+pub const ETH_MODULE_SFF_8436_MAX_LEN: c_int = 640;
+
+/* Reset flags */
+c_enum! {
+    pub enum ethtool_reset_flags {
+        pub ETH_RESET_MGMT = 1 << 0,
+        pub ETH_RESET_IRQ = 1 << 1,
+        pub ETH_RESET_DMA = 1 << 2,
+        pub ETH_RESET_FILTER = 1 << 3,
+        pub ETH_RESET_OFFLOAD = 1 << 4,
+        pub ETH_RESET_MAC = 1 << 5,
+        pub ETH_RESET_PHY = 1 << 6,
+        pub ETH_RESET_RAM = 1 << 7,
+        pub ETH_RESET_AP = 1 << 8,
+        pub ETH_RESET_DEDICATED = 0x0000ffff,
+        pub ETH_RESET_ALL = 0xffffffff,
+    }
+}
+
+pub const ETH_RESET_SHARED_SHIFT: c_int = 16;
+
+s! {
+    pub struct ethtool_link_settings {
+        pub cmd: crate::__u32,
+        pub speed: crate::__u32,
+        pub duplex: crate::__u8,
+        pub port: crate::__u8,
+        pub phy_address: crate::__u8,
+        pub autoneg: crate::__u8,
+        pub mdio_support: crate::__u8,
+        pub eth_tp_mdix: crate::__u8,
+        pub eth_tp_mdix_ctrl: crate::__u8,
+        pub link_mode_masks_nwords: crate::__s8,
+        pub transceiver: crate::__u8,
+        pub master_slave_cfg: crate::__u8,
+        pub master_slave_state: crate::__u8,
+        pub rate_matching: crate::__u8,
+        reserved: Padding<[crate::__u32; 7]>,
+        pub link_mode_masks: [crate::__u32; 0],
+    }
+}
@@ -0,0 +1,658 @@
+//! Header: `uapi/linux/ethtool_netlink.h`
+
+use crate::prelude::*;
+
+/* message types - userspace to kernel */
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub ETHTOOL_MSG_USER_NONE,
+        pub ETHTOOL_MSG_STRSET_GET,
+        pub ETHTOOL_MSG_LINKINFO_GET,
+        pub ETHTOOL_MSG_LINKINFO_SET,
+        pub ETHTOOL_MSG_LINKMODES_GET,
+        pub ETHTOOL_MSG_LINKMODES_SET,
+        pub ETHTOOL_MSG_LINKSTATE_GET,
+        pub ETHTOOL_MSG_DEBUG_GET,
+        pub ETHTOOL_MSG_DEBUG_SET,
+        pub ETHTOOL_MSG_WOL_GET,
+        pub ETHTOOL_MSG_WOL_SET,
+        pub ETHTOOL_MSG_FEATURES_GET,
+        pub ETHTOOL_MSG_FEATURES_SET,
+        pub ETHTOOL_MSG_PRIVFLAGS_GET,
+        pub ETHTOOL_MSG_PRIVFLAGS_SET,
+        pub ETHTOOL_MSG_RINGS_GET,
+        pub ETHTOOL_MSG_RINGS_SET,
+        pub ETHTOOL_MSG_CHANNELS_GET,
+        pub ETHTOOL_MSG_CHANNELS_SET,
+        pub ETHTOOL_MSG_COALESCE_GET,
+        pub ETHTOOL_MSG_COALESCE_SET,
+        pub ETHTOOL_MSG_PAUSE_GET,
+        pub ETHTOOL_MSG_PAUSE_SET,
+        pub ETHTOOL_MSG_EEE_GET,
+        pub ETHTOOL_MSG_EEE_SET,
+        pub ETHTOOL_MSG_TSINFO_GET,
+        pub ETHTOOL_MSG_CABLE_TEST_ACT,
+        pub ETHTOOL_MSG_CABLE_TEST_TDR_ACT,
+        pub ETHTOOL_MSG_TUNNEL_INFO_GET,
+        pub ETHTOOL_MSG_FEC_GET,
+        pub ETHTOOL_MSG_FEC_SET,
+        pub ETHTOOL_MSG_MODULE_EEPROM_GET,
+        pub ETHTOOL_MSG_STATS_GET,
+        pub ETHTOOL_MSG_PHC_VCLOCKS_GET,
+        pub ETHTOOL_MSG_MODULE_GET,
+        pub ETHTOOL_MSG_MODULE_SET,
+        pub ETHTOOL_MSG_PSE_GET,
+        pub ETHTOOL_MSG_PSE_SET,
+    }
+
+    /* message types - kernel to userspace */
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub ETHTOOL_MSG_KERNEL_NONE,
+        pub ETHTOOL_MSG_STRSET_GET_REPLY,
+        pub ETHTOOL_MSG_LINKINFO_GET_REPLY,
+        pub ETHTOOL_MSG_LINKINFO_NTF,
+        pub ETHTOOL_MSG_LINKMODES_GET_REPLY,
+        pub ETHTOOL_MSG_LINKMODES_NTF,
+        pub ETHTOOL_MSG_LINKSTATE_GET_REPLY,
+        pub ETHTOOL_MSG_DEBUG_GET_REPLY,
+        pub ETHTOOL_MSG_DEBUG_NTF,
+        pub ETHTOOL_MSG_WOL_GET_REPLY,
+        pub ETHTOOL_MSG_WOL_NTF,
+        pub ETHTOOL_MSG_FEATURES_GET_REPLY,
+        pub ETHTOOL_MSG_FEATURES_SET_REPLY,
+        pub ETHTOOL_MSG_FEATURES_NTF,
+        pub ETHTOOL_MSG_PRIVFLAGS_GET_REPLY,
+        pub ETHTOOL_MSG_PRIVFLAGS_NTF,
+        pub ETHTOOL_MSG_RINGS_GET_REPLY,
+        pub ETHTOOL_MSG_RINGS_NTF,
+        pub ETHTOOL_MSG_CHANNELS_GET_REPLY,
+        pub ETHTOOL_MSG_CHANNELS_NTF,
+        pub ETHTOOL_MSG_COALESCE_GET_REPLY,
+        pub ETHTOOL_MSG_COALESCE_NTF,
+        pub ETHTOOL_MSG_PAUSE_GET_REPLY,
+        pub ETHTOOL_MSG_PAUSE_NTF,
+        pub ETHTOOL_MSG_EEE_GET_REPLY,
+        pub ETHTOOL_MSG_EEE_NTF,
+        pub ETHTOOL_MSG_TSINFO_GET_REPLY,
+        pub ETHTOOL_MSG_CABLE_TEST_NTF,
+        pub ETHTOOL_MSG_CABLE_TEST_TDR_NTF,
+        pub ETHTOOL_MSG_TUNNEL_INFO_GET_REPLY,
+        pub ETHTOOL_MSG_FEC_GET_REPLY,
+        pub ETHTOOL_MSG_FEC_NTF,
+        pub ETHTOOL_MSG_MODULE_EEPROM_GET_REPLY,
+        pub ETHTOOL_MSG_STATS_GET_REPLY,
+        pub ETHTOOL_MSG_PHC_VCLOCKS_GET_REPLY,
+        pub ETHTOOL_MSG_MODULE_GET_REPLY,
+        pub ETHTOOL_MSG_MODULE_NTF,
+        pub ETHTOOL_MSG_PSE_GET_REPLY,
+    }
+}
+
+/* request header */
+
+/* use compact bitsets in reply */
+pub const ETHTOOL_FLAG_COMPACT_BITSETS: c_int = 1 << 0;
+pub const ETHTOOL_FLAG_OMIT_REPLY: c_int = 1 << 1;
+pub const ETHTOOL_FLAG_STATS: c_int = 1 << 2;
+
+pub const ETHTOOL_FLAG_ALL: c_int =
+    ETHTOOL_FLAG_COMPACT_BITSETS | ETHTOOL_FLAG_OMIT_REPLY | ETHTOOL_FLAG_STATS;
+
+c_enum! {
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_HEADER_UNSPEC,
+        pub ETHTOOL_A_HEADER_DEV_INDEX,
+        pub ETHTOOL_A_HEADER_DEV_NAME,
+        pub ETHTOOL_A_HEADER_FLAGS,
+    }
+
+    /* bit sets */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_BITSET_BIT_UNSPEC,
+        pub ETHTOOL_A_BITSET_BIT_INDEX,
+        pub ETHTOOL_A_BITSET_BIT_NAME,
+        pub ETHTOOL_A_BITSET_BIT_VALUE,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_BITSET_BITS_UNSPEC,
+        pub ETHTOOL_A_BITSET_BITS_BIT,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_BITSET_UNSPEC,
+        pub ETHTOOL_A_BITSET_NOMASK,
+        pub ETHTOOL_A_BITSET_SIZE,
+        pub ETHTOOL_A_BITSET_BITS,
+        pub ETHTOOL_A_BITSET_VALUE,
+        pub ETHTOOL_A_BITSET_MASK,
+    }
+
+    /* string sets */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_STRING_UNSPEC,
+        pub ETHTOOL_A_STRING_INDEX,
+        pub ETHTOOL_A_STRING_VALUE,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_STRINGS_UNSPEC,
+        pub ETHTOOL_A_STRINGS_STRING,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_STRINGSET_UNSPEC,
+        pub ETHTOOL_A_STRINGSET_ID,
+        pub ETHTOOL_A_STRINGSET_COUNT,
+        pub ETHTOOL_A_STRINGSET_STRINGS,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_STRINGSETS_UNSPEC,
+        pub ETHTOOL_A_STRINGSETS_STRINGSET,
+    }
+
+    /* STRSET */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_STRSET_UNSPEC,
+        pub ETHTOOL_A_STRSET_HEADER,
+        pub ETHTOOL_A_STRSET_STRINGSETS,
+        pub ETHTOOL_A_STRSET_COUNTS_ONLY,
+    }
+
+    /* LINKINFO */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_LINKINFO_UNSPEC,
+        pub ETHTOOL_A_LINKINFO_HEADER,
+        pub ETHTOOL_A_LINKINFO_PORT,
+        pub ETHTOOL_A_LINKINFO_PHYADDR,
+        pub ETHTOOL_A_LINKINFO_TP_MDIX,
+        pub ETHTOOL_A_LINKINFO_TP_MDIX_CTRL,
+        pub ETHTOOL_A_LINKINFO_TRANSCEIVER,
+    }
+
+    /* LINKMODES */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_LINKMODES_UNSPEC,
+        pub ETHTOOL_A_LINKMODES_HEADER,
+        pub ETHTOOL_A_LINKMODES_AUTONEG,
+        pub ETHTOOL_A_LINKMODES_OURS,
+        pub ETHTOOL_A_LINKMODES_PEER,
+        pub ETHTOOL_A_LINKMODES_SPEED,
+        pub ETHTOOL_A_LINKMODES_DUPLEX,
+        pub ETHTOOL_A_LINKMODES_MASTER_SLAVE_CFG,
+        pub ETHTOOL_A_LINKMODES_MASTER_SLAVE_STATE,
+        pub ETHTOOL_A_LINKMODES_LANES,
+        pub ETHTOOL_A_LINKMODES_RATE_MATCHING,
+    }
+
+    /* LINKSTATE */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_LINKSTATE_UNSPEC,
+        pub ETHTOOL_A_LINKSTATE_HEADER,
+        pub ETHTOOL_A_LINKSTATE_LINK,
+        pub ETHTOOL_A_LINKSTATE_SQI,
+        pub ETHTOOL_A_LINKSTATE_SQI_MAX,
+        pub ETHTOOL_A_LINKSTATE_EXT_STATE,
+        pub ETHTOOL_A_LINKSTATE_EXT_SUBSTATE,
+    }
+
+    /* DEBUG */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_DEBUG_UNSPEC,
+        pub ETHTOOL_A_DEBUG_HEADER,
+        pub ETHTOOL_A_DEBUG_MSGMASK,
+    }
+
+    /* WOL */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_WOL_UNSPEC,
+        pub ETHTOOL_A_WOL_HEADER,
+        pub ETHTOOL_A_WOL_MODES,
+        pub ETHTOOL_A_WOL_SOPASS,
+    }
+
+    /* FEATURES */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_FEATURES_UNSPEC,
+        pub ETHTOOL_A_FEATURES_HEADER,
+        pub ETHTOOL_A_FEATURES_HW,
+        pub ETHTOOL_A_FEATURES_WANTED,
+        pub ETHTOOL_A_FEATURES_ACTIVE,
+        pub ETHTOOL_A_FEATURES_NOCHANGE,
+    }
+
+    /* PRIVFLAGS */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_PRIVFLAGS_UNSPEC,
+        pub ETHTOOL_A_PRIVFLAGS_HEADER,
+        pub ETHTOOL_A_PRIVFLAGS_FLAGS,
+    }
+
+    /* RINGS */
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub ETHTOOL_TCP_DATA_SPLIT_UNKNOWN = 0,
+        pub ETHTOOL_TCP_DATA_SPLIT_DISABLED,
+        pub ETHTOOL_TCP_DATA_SPLIT_ENABLED,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_RINGS_UNSPEC,
+        pub ETHTOOL_A_RINGS_HEADER,
+        pub ETHTOOL_A_RINGS_RX_MAX,
+        pub ETHTOOL_A_RINGS_RX_MINI_MAX,
+        pub ETHTOOL_A_RINGS_RX_JUMBO_MAX,
+        pub ETHTOOL_A_RINGS_TX_MAX,
+        pub ETHTOOL_A_RINGS_RX,
+        pub ETHTOOL_A_RINGS_RX_MINI,
+        pub ETHTOOL_A_RINGS_RX_JUMBO,
+        pub ETHTOOL_A_RINGS_TX,
+        pub ETHTOOL_A_RINGS_RX_BUF_LEN,
+        pub ETHTOOL_A_RINGS_TCP_DATA_SPLIT,
+        pub ETHTOOL_A_RINGS_CQE_SIZE,
+        pub ETHTOOL_A_RINGS_TX_PUSH,
+    }
+
+    /* CHANNELS */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CHANNELS_UNSPEC,
+        pub ETHTOOL_A_CHANNELS_HEADER,
+        pub ETHTOOL_A_CHANNELS_RX_MAX,
+        pub ETHTOOL_A_CHANNELS_TX_MAX,
+        pub ETHTOOL_A_CHANNELS_OTHER_MAX,
+        pub ETHTOOL_A_CHANNELS_COMBINED_MAX,
+        pub ETHTOOL_A_CHANNELS_RX_COUNT,
+        pub ETHTOOL_A_CHANNELS_TX_COUNT,
+        pub ETHTOOL_A_CHANNELS_OTHER_COUNT,
+        pub ETHTOOL_A_CHANNELS_COMBINED_COUNT,
+    }
+
+    /* COALESCE */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_COALESCE_UNSPEC,
+        pub ETHTOOL_A_COALESCE_HEADER,
+        pub ETHTOOL_A_COALESCE_RX_USECS,
+        pub ETHTOOL_A_COALESCE_RX_MAX_FRAMES,
+        pub ETHTOOL_A_COALESCE_RX_USECS_IRQ,
+        pub ETHTOOL_A_COALESCE_RX_MAX_FRAMES_IRQ,
+        pub ETHTOOL_A_COALESCE_TX_USECS,
+        pub ETHTOOL_A_COALESCE_TX_MAX_FRAMES,
+        pub ETHTOOL_A_COALESCE_TX_USECS_IRQ,
+        pub ETHTOOL_A_COALESCE_TX_MAX_FRAMES_IRQ,
+        pub ETHTOOL_A_COALESCE_STATS_BLOCK_USECS,
+        pub ETHTOOL_A_COALESCE_USE_ADAPTIVE_RX,
+        pub ETHTOOL_A_COALESCE_USE_ADAPTIVE_TX,
+        pub ETHTOOL_A_COALESCE_PKT_RATE_LOW,
+        pub ETHTOOL_A_COALESCE_RX_USECS_LOW,
+        pub ETHTOOL_A_COALESCE_RX_MAX_FRAMES_LOW,
+        pub ETHTOOL_A_COALESCE_TX_USECS_LOW,
+        pub ETHTOOL_A_COALESCE_TX_MAX_FRAMES_LOW,
+        pub ETHTOOL_A_COALESCE_PKT_RATE_HIGH,
+        pub ETHTOOL_A_COALESCE_RX_USECS_HIGH,
+        pub ETHTOOL_A_COALESCE_RX_MAX_FRAMES_HIGH,
+        pub ETHTOOL_A_COALESCE_TX_USECS_HIGH,
+        pub ETHTOOL_A_COALESCE_TX_MAX_FRAMES_HIGH,
+        pub ETHTOOL_A_COALESCE_RATE_SAMPLE_INTERVAL,
+        pub ETHTOOL_A_COALESCE_USE_CQE_MODE_TX,
+        pub ETHTOOL_A_COALESCE_USE_CQE_MODE_RX,
+    }
+
+    /* PAUSE */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_PAUSE_UNSPEC,
+        pub ETHTOOL_A_PAUSE_HEADER,
+        pub ETHTOOL_A_PAUSE_AUTONEG,
+        pub ETHTOOL_A_PAUSE_RX,
+        pub ETHTOOL_A_PAUSE_TX,
+        pub ETHTOOL_A_PAUSE_STATS,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_PAUSE_STAT_UNSPEC,
+        pub ETHTOOL_A_PAUSE_STAT_PAD,
+        pub ETHTOOL_A_PAUSE_STAT_TX_FRAMES,
+        pub ETHTOOL_A_PAUSE_STAT_RX_FRAMES,
+    }
+
+    /* EEE */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_EEE_UNSPEC,
+        pub ETHTOOL_A_EEE_HEADER,
+        pub ETHTOOL_A_EEE_MODES_OURS,
+        pub ETHTOOL_A_EEE_MODES_PEER,
+        pub ETHTOOL_A_EEE_ACTIVE,
+        pub ETHTOOL_A_EEE_ENABLED,
+        pub ETHTOOL_A_EEE_TX_LPI_ENABLED,
+        pub ETHTOOL_A_EEE_TX_LPI_TIMER,
+    }
+
+    /* TSINFO */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_TSINFO_UNSPEC,
+        pub ETHTOOL_A_TSINFO_HEADER,
+        pub ETHTOOL_A_TSINFO_TIMESTAMPING,
+        pub ETHTOOL_A_TSINFO_TX_TYPES,
+        pub ETHTOOL_A_TSINFO_RX_FILTERS,
+        pub ETHTOOL_A_TSINFO_PHC_INDEX,
+    }
+
+    /* PHC VCLOCKS */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_PHC_VCLOCKS_UNSPEC,
+        pub ETHTOOL_A_PHC_VCLOCKS_HEADER,
+        pub ETHTOOL_A_PHC_VCLOCKS_NUM,
+        pub ETHTOOL_A_PHC_VCLOCKS_INDEX,
+    }
+
+    /* CABLE TEST */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_TEST_UNSPEC,
+        pub ETHTOOL_A_CABLE_TEST_HEADER,
+    }
+
+    /* CABLE TEST NOTIFY */
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_RESULT_CODE_UNSPEC,
+        pub ETHTOOL_A_CABLE_RESULT_CODE_OK,
+        pub ETHTOOL_A_CABLE_RESULT_CODE_OPEN,
+        pub ETHTOOL_A_CABLE_RESULT_CODE_SAME_SHORT,
+        pub ETHTOOL_A_CABLE_RESULT_CODE_CROSS_SHORT,
+    }
+
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_PAIR_A,
+        pub ETHTOOL_A_CABLE_PAIR_B,
+        pub ETHTOOL_A_CABLE_PAIR_C,
+        pub ETHTOOL_A_CABLE_PAIR_D,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_RESULT_UNSPEC,
+        pub ETHTOOL_A_CABLE_RESULT_PAIR,
+        pub ETHTOOL_A_CABLE_RESULT_CODE,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_FAULT_LENGTH_UNSPEC,
+        pub ETHTOOL_A_CABLE_FAULT_LENGTH_PAIR,
+        pub ETHTOOL_A_CABLE_FAULT_LENGTH_CM,
+    }
+
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_TEST_NTF_STATUS_UNSPEC,
+        pub ETHTOOL_A_CABLE_TEST_NTF_STATUS_STARTED,
+        pub ETHTOOL_A_CABLE_TEST_NTF_STATUS_COMPLETED,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_NEST_UNSPEC,
+        pub ETHTOOL_A_CABLE_NEST_RESULT,
+        pub ETHTOOL_A_CABLE_NEST_FAULT_LENGTH,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_TEST_NTF_UNSPEC,
+        pub ETHTOOL_A_CABLE_TEST_NTF_HEADER,
+        pub ETHTOOL_A_CABLE_TEST_NTF_STATUS,
+        pub ETHTOOL_A_CABLE_TEST_NTF_NEST,
+    }
+
+    /* CABLE TEST TDR */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_TEST_TDR_CFG_UNSPEC,
+        pub ETHTOOL_A_CABLE_TEST_TDR_CFG_FIRST,
+        pub ETHTOOL_A_CABLE_TEST_TDR_CFG_LAST,
+        pub ETHTOOL_A_CABLE_TEST_TDR_CFG_STEP,
+        pub ETHTOOL_A_CABLE_TEST_TDR_CFG_PAIR,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_TEST_TDR_UNSPEC,
+        pub ETHTOOL_A_CABLE_TEST_TDR_HEADER,
+        pub ETHTOOL_A_CABLE_TEST_TDR_CFG,
+    }
+
+    /* CABLE TEST TDR NOTIFY */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_AMPLITUDE_UNSPEC,
+        pub ETHTOOL_A_CABLE_AMPLITUDE_PAIR,
+        pub ETHTOOL_A_CABLE_AMPLITUDE_mV,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_PULSE_UNSPEC,
+        pub ETHTOOL_A_CABLE_PULSE_mV,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_STEP_UNSPEC,
+        pub ETHTOOL_A_CABLE_STEP_FIRST_DISTANCE,
+        pub ETHTOOL_A_CABLE_STEP_LAST_DISTANCE,
+        pub ETHTOOL_A_CABLE_STEP_STEP_DISTANCE,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_TDR_NEST_UNSPEC,
+        pub ETHTOOL_A_CABLE_TDR_NEST_STEP,
+        pub ETHTOOL_A_CABLE_TDR_NEST_AMPLITUDE,
+        pub ETHTOOL_A_CABLE_TDR_NEST_PULSE,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_CABLE_TEST_TDR_NTF_UNSPEC,
+        pub ETHTOOL_A_CABLE_TEST_TDR_NTF_HEADER,
+        pub ETHTOOL_A_CABLE_TEST_TDR_NTF_STATUS,
+        pub ETHTOOL_A_CABLE_TEST_TDR_NTF_NEST,
+    }
+
+    /* TUNNEL INFO */
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub ETHTOOL_UDP_TUNNEL_TYPE_VXLAN,
+        pub ETHTOOL_UDP_TUNNEL_TYPE_GENEVE,
+        pub ETHTOOL_UDP_TUNNEL_TYPE_VXLAN_GPE,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_TUNNEL_UDP_ENTRY_UNSPEC,
+        pub ETHTOOL_A_TUNNEL_UDP_ENTRY_PORT,
+        pub ETHTOOL_A_TUNNEL_UDP_ENTRY_TYPE,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_TUNNEL_UDP_TABLE_UNSPEC,
+        pub ETHTOOL_A_TUNNEL_UDP_TABLE_SIZE,
+        pub ETHTOOL_A_TUNNEL_UDP_TABLE_TYPES,
+        pub ETHTOOL_A_TUNNEL_UDP_TABLE_ENTRY,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_TUNNEL_UDP_UNSPEC,
+        pub ETHTOOL_A_TUNNEL_UDP_TABLE,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_TUNNEL_INFO_UNSPEC,
+        pub ETHTOOL_A_TUNNEL_INFO_HEADER,
+        pub ETHTOOL_A_TUNNEL_INFO_UDP_PORTS,
+    }
+
+    /* FEC */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_FEC_UNSPEC,
+        pub ETHTOOL_A_FEC_HEADER,
+        pub ETHTOOL_A_FEC_MODES,
+        pub ETHTOOL_A_FEC_AUTO,
+        pub ETHTOOL_A_FEC_ACTIVE,
+        pub ETHTOOL_A_FEC_STATS,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_FEC_STAT_UNSPEC,
+        pub ETHTOOL_A_FEC_STAT_PAD,
+        pub ETHTOOL_A_FEC_STAT_CORRECTED,
+        pub ETHTOOL_A_FEC_STAT_UNCORR,
+        pub ETHTOOL_A_FEC_STAT_CORR_BITS,
+    }
+
+    /* MODULE EEPROM */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_MODULE_EEPROM_UNSPEC,
+        pub ETHTOOL_A_MODULE_EEPROM_HEADER,
+        pub ETHTOOL_A_MODULE_EEPROM_OFFSET,
+        pub ETHTOOL_A_MODULE_EEPROM_LENGTH,
+        pub ETHTOOL_A_MODULE_EEPROM_PAGE,
+        pub ETHTOOL_A_MODULE_EEPROM_BANK,
+        pub ETHTOOL_A_MODULE_EEPROM_I2C_ADDRESS,
+        pub ETHTOOL_A_MODULE_EEPROM_DATA,
+    }
+
+    /* STATS */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_STATS_UNSPEC,
+        pub ETHTOOL_A_STATS_PAD,
+        pub ETHTOOL_A_STATS_HEADER,
+        pub ETHTOOL_A_STATS_GROUPS,
+        pub ETHTOOL_A_STATS_GRP,
+    }
+
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub ETHTOOL_STATS_ETH_PHY,
+        pub ETHTOOL_STATS_ETH_MAC,
+        pub ETHTOOL_STATS_ETH_CTRL,
+        pub ETHTOOL_STATS_RMON,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_STATS_GRP_UNSPEC,
+        pub ETHTOOL_A_STATS_GRP_PAD,
+        pub ETHTOOL_A_STATS_GRP_ID,
+        pub ETHTOOL_A_STATS_GRP_SS_ID,
+        pub ETHTOOL_A_STATS_GRP_STAT,
+        pub ETHTOOL_A_STATS_GRP_HIST_RX,
+        pub ETHTOOL_A_STATS_GRP_HIST_TX,
+        pub ETHTOOL_A_STATS_GRP_HIST_BKT_LOW,
+        pub ETHTOOL_A_STATS_GRP_HIST_BKT_HI,
+        pub ETHTOOL_A_STATS_GRP_HIST_VAL,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_STATS_ETH_PHY_5_SYM_ERR,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_STATS_ETH_MAC_2_TX_PKT,
+        pub ETHTOOL_A_STATS_ETH_MAC_3_SINGLE_COL,
+        pub ETHTOOL_A_STATS_ETH_MAC_4_MULTI_COL,
+        pub ETHTOOL_A_STATS_ETH_MAC_5_RX_PKT,
+        pub ETHTOOL_A_STATS_ETH_MAC_6_FCS_ERR,
+        pub ETHTOOL_A_STATS_ETH_MAC_7_ALIGN_ERR,
+        pub ETHTOOL_A_STATS_ETH_MAC_8_TX_BYTES,
+        pub ETHTOOL_A_STATS_ETH_MAC_9_TX_DEFER,
+        pub ETHTOOL_A_STATS_ETH_MAC_10_LATE_COL,
+        pub ETHTOOL_A_STATS_ETH_MAC_11_XS_COL,
+        pub ETHTOOL_A_STATS_ETH_MAC_12_TX_INT_ERR,
+        pub ETHTOOL_A_STATS_ETH_MAC_13_CS_ERR,
+        pub ETHTOOL_A_STATS_ETH_MAC_14_RX_BYTES,
+        pub ETHTOOL_A_STATS_ETH_MAC_15_RX_INT_ERR,
+        pub ETHTOOL_A_STATS_ETH_MAC_18_TX_MCAST,
+        pub ETHTOOL_A_STATS_ETH_MAC_19_TX_BCAST,
+        pub ETHTOOL_A_STATS_ETH_MAC_20_XS_DEFER,
+        pub ETHTOOL_A_STATS_ETH_MAC_21_RX_MCAST,
+        pub ETHTOOL_A_STATS_ETH_MAC_22_RX_BCAST,
+        pub ETHTOOL_A_STATS_ETH_MAC_23_IR_LEN_ERR,
+        pub ETHTOOL_A_STATS_ETH_MAC_24_OOR_LEN,
+        pub ETHTOOL_A_STATS_ETH_MAC_25_TOO_LONG_ERR,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_STATS_ETH_CTRL_3_TX,
+        pub ETHTOOL_A_STATS_ETH_CTRL_4_RX,
+        pub ETHTOOL_A_STATS_ETH_CTRL_5_RX_UNSUP,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_STATS_RMON_UNDERSIZE,
+        pub ETHTOOL_A_STATS_RMON_OVERSIZE,
+        pub ETHTOOL_A_STATS_RMON_FRAG,
+        pub ETHTOOL_A_STATS_RMON_JABBER,
+    }
+
+    /* MODULE */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_MODULE_UNSPEC,
+        pub ETHTOOL_A_MODULE_HEADER,
+        pub ETHTOOL_A_MODULE_POWER_MODE_POLICY,
+        pub ETHTOOL_A_MODULE_POWER_MODE,
+    }
+
+    /* Power Sourcing Equipment */
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub ETHTOOL_A_PSE_UNSPEC,
+        pub ETHTOOL_A_PSE_HEADER,
+        pub ETHTOOL_A_PODL_PSE_ADMIN_STATE,
+        pub ETHTOOL_A_PODL_PSE_ADMIN_CONTROL,
+        pub ETHTOOL_A_PODL_PSE_PW_D_STATUS,
+    }
+}
+
+/* generic netlink info */
+pub const ETHTOOL_GENL_VERSION: c_int = 1;
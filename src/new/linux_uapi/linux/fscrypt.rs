@@ -0,0 +1,201 @@
+//! Header: `uapi/linux/fscrypt.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IOR,
+    _IOW,
+    _IOWR,
+};
+
+/* Encryption policy flags */
+pub const FSCRYPT_POLICY_FLAGS_PAD_4: c_uint = 0x00;
+pub const FSCRYPT_POLICY_FLAGS_PAD_8: c_uint = 0x01;
+pub const FSCRYPT_POLICY_FLAGS_PAD_16: c_uint = 0x02;
+pub const FSCRYPT_POLICY_FLAGS_PAD_32: c_uint = 0x03;
+pub const FSCRYPT_POLICY_FLAGS_PAD_MASK: c_uint = 0x03;
+pub const FSCRYPT_POLICY_FLAG_DIRECT_KEY: c_uint = 0x04;
+pub const FSCRYPT_POLICY_FLAG_IV_INO_LBLK_64: c_uint = 0x08;
+pub const FSCRYPT_POLICY_FLAG_IV_INO_LBLK_32: c_uint = 0x10;
+
+/* Encryption algorithms */
+pub const FSCRYPT_MODE_AES_256_XTS: c_uint = 1;
+pub const FSCRYPT_MODE_AES_256_CTS: c_uint = 4;
+pub const FSCRYPT_MODE_AES_128_CBC: c_uint = 5;
+pub const FSCRYPT_MODE_AES_128_CTS: c_uint = 6;
+pub const FSCRYPT_MODE_SM4_XTS: c_uint = 7;
+pub const FSCRYPT_MODE_SM4_CTS: c_uint = 8;
+pub const FSCRYPT_MODE_ADIANTUM: c_uint = 9;
+pub const FSCRYPT_MODE_AES_256_HCTR2: c_uint = 10;
+
+/*
+ * Legacy policy version; ad-hoc KDF and no key verification.
+ * For new encrypted directories, use fscrypt_policy_v2 instead.
+ *
+ * Careful: the .version field for this is actually 0, not 1.
+ */
+pub const FSCRYPT_POLICY_V1: c_uint = 0;
+pub const FSCRYPT_KEY_DESCRIPTOR_SIZE: usize = 8;
+
+s! {
+    pub struct fscrypt_policy_v1 {
+        pub version: crate::__u8,
+        pub contents_encryption_mode: crate::__u8,
+        pub filenames_encryption_mode: crate::__u8,
+        pub flags: crate::__u8,
+        pub master_key_descriptor: [crate::__u8; FSCRYPT_KEY_DESCRIPTOR_SIZE],
+    }
+}
+
+/*
+ * Process-subscribed "logon" key description prefix and payload format.
+ * Deprecated; prefer FS_IOC_ADD_ENCRYPTION_KEY instead.
+ */
+pub const FSCRYPT_KEY_DESC_PREFIX: &[u8] = b"fscrypt:\0";
+pub const FSCRYPT_KEY_DESC_PREFIX_SIZE: c_int = 8;
+pub const FSCRYPT_MAX_KEY_SIZE: usize = 64;
+
+s! {
+    pub struct fscrypt_key {
+        pub mode: crate::__u32,
+        pub raw: [crate::__u8; FSCRYPT_MAX_KEY_SIZE],
+        pub size: crate::__u32,
+    }
+}
+
+/*
+ * New policy version with HKDF and key verification (recommended).
+ */
+pub const FSCRYPT_POLICY_V2: c_uint = 2;
+pub const FSCRYPT_KEY_IDENTIFIER_SIZE: usize = 16;
+
+s! {
+    pub struct fscrypt_policy_v2 {
+        pub version: crate::__u8,
+        pub contents_encryption_mode: crate::__u8,
+        pub filenames_encryption_mode: crate::__u8,
+        pub flags: crate::__u8,
+        pub log2_data_unit_size: crate::__u8,
+        __reserved: Padding<[crate::__u8; 3]>,
+        pub master_key_identifier: [crate::__u8; FSCRYPT_KEY_IDENTIFIER_SIZE],
+    }
+}
+
+s_no_extra_traits! {
+    /* Struct passed to FS_IOC_GET_ENCRYPTION_POLICY_EX */
+    pub struct fscrypt_get_policy_ex_arg {
+        /* input/output */
+        pub policy_size: crate::__u64,
+        /* output */
+        pub policy: __c_anonymous_fscrypt_get_policy_ex_arg_policy,
+    }
+
+    pub union __c_anonymous_fscrypt_get_policy_ex_arg_policy {
+        pub version: crate::__u8,
+        pub v1: fscrypt_policy_v1,
+        pub v2: fscrypt_policy_v2,
+    }
+}
+
+/*
+ * v1 policy keys are specified by an arbitrary 8-byte key "descriptor",
+ * matching fscrypt_policy_v1::master_key_descriptor.
+ */
+pub const FSCRYPT_KEY_SPEC_TYPE_DESCRIPTOR: c_uint = 1;
+
+/*
+ * v2 policy keys are specified by a 16-byte key "identifier" which the kernel
+ * calculates as a cryptographic hash of the key itself,
+ * matching fscrypt_policy_v2::master_key_identifier.
+ */
+pub const FSCRYPT_KEY_SPEC_TYPE_IDENTIFIER: c_uint = 2;
+
+s_no_extra_traits! {
+    /*
+     * Specifies a key, either for v1 or v2 policies.  This doesn't contain the
+     * actual key itself; this is just the "name" of the key.
+     */
+    pub struct fscrypt_key_specifier {
+        /* one of FSCRYPT_KEY_SPEC_TYPE_* */
+        pub type_: crate::__u32,
+        __reserved: Padding<crate::__u32>,
+        pub u: __c_anonymous_fscrypt_key_specifier_u,
+    }
+
+    pub union __c_anonymous_fscrypt_key_specifier_u {
+        /* reserve some extra space */
+        __reserved: Padding<[crate::__u8; 32]>,
+        pub descriptor: [crate::__u8; FSCRYPT_KEY_DESCRIPTOR_SIZE],
+        pub identifier: [crate::__u8; FSCRYPT_KEY_IDENTIFIER_SIZE],
+    }
+}
+
+s! {
+    /*
+     * Payload of Linux keyring key of type "fscrypt-provisioning", referenced by
+     * fscrypt_add_key_arg::key_id as an alternative to fscrypt_add_key_arg::raw.
+     */
+    pub struct fscrypt_provisioning_key_payload {
+        pub type_: crate::__u32,
+        __reserved: Padding<crate::__u32>,
+        pub raw: [crate::__u8; 0],
+    }
+}
+
+s_no_extra_traits! {
+    /* Struct passed to FS_IOC_ADD_ENCRYPTION_KEY */
+    pub struct fscrypt_add_key_arg {
+        pub key_spec: fscrypt_key_specifier,
+        pub raw_size: crate::__u32,
+        pub key_id: crate::__u32,
+        __reserved: Padding<[crate::__u32; 8]>,
+        pub raw: [crate::__u8; 0],
+    }
+
+    /* Struct passed to FS_IOC_REMOVE_ENCRYPTION_KEY */
+    pub struct fscrypt_remove_key_arg {
+        pub key_spec: fscrypt_key_specifier,
+        /* output */
+        pub removal_status_flags: crate::__u32,
+        __reserved: Padding<[crate::__u32; 5]>,
+    }
+}
+
+pub const FSCRYPT_KEY_REMOVAL_STATUS_FLAG_FILES_BUSY: c_uint = 0x00000001;
+pub const FSCRYPT_KEY_REMOVAL_STATUS_FLAG_OTHER_USERS: c_uint = 0x00000002;
+
+s_no_extra_traits! {
+    /* Struct passed to FS_IOC_GET_ENCRYPTION_KEY_STATUS */
+    pub struct fscrypt_get_key_status_arg {
+        /* input */
+        pub key_spec: fscrypt_key_specifier,
+        __reserved: Padding<[crate::__u32; 6]>,
+
+        /* output */
+        pub status: crate::__u32,
+        pub status_flags: crate::__u32,
+        pub user_count: crate::__u32,
+        __out_reserved: Padding<[crate::__u32; 13]>,
+    }
+}
+
+pub const FSCRYPT_KEY_STATUS_ABSENT: c_uint = 1;
+pub const FSCRYPT_KEY_STATUS_PRESENT: c_uint = 2;
+pub const FSCRYPT_KEY_STATUS_INCOMPLETELY_REMOVED: c_uint = 3;
+pub const FSCRYPT_KEY_STATUS_FLAG_ADDED_BY_SELF: c_uint = 0x00000001;
+
+const FSCRYPT_IOC_MAGIC: c_uint = b'f' as c_uint;
+
+pub const FS_IOC_SET_ENCRYPTION_POLICY: Ioctl = _IOR::<fscrypt_policy_v1>(FSCRYPT_IOC_MAGIC, 19);
+pub const FS_IOC_GET_ENCRYPTION_PWSALT: Ioctl = _IOW::<[crate::__u8; 16]>(FSCRYPT_IOC_MAGIC, 20);
+pub const FS_IOC_GET_ENCRYPTION_POLICY: Ioctl = _IOW::<fscrypt_policy_v1>(FSCRYPT_IOC_MAGIC, 21);
+/* size + version */
+pub const FS_IOC_GET_ENCRYPTION_POLICY_EX: Ioctl = _IOWR::<[crate::__u8; 9]>(FSCRYPT_IOC_MAGIC, 22);
+pub const FS_IOC_ADD_ENCRYPTION_KEY: Ioctl = _IOWR::<fscrypt_add_key_arg>(FSCRYPT_IOC_MAGIC, 23);
+pub const FS_IOC_REMOVE_ENCRYPTION_KEY: Ioctl =
+    _IOWR::<fscrypt_remove_key_arg>(FSCRYPT_IOC_MAGIC, 24);
+pub const FS_IOC_REMOVE_ENCRYPTION_KEY_ALL_USERS: Ioctl =
+    _IOWR::<fscrypt_remove_key_arg>(FSCRYPT_IOC_MAGIC, 25);
+pub const FS_IOC_GET_ENCRYPTION_KEY_STATUS: Ioctl =
+    _IOWR::<fscrypt_get_key_status_arg>(FSCRYPT_IOC_MAGIC, 26);
+pub const FS_IOC_GET_ENCRYPTION_NONCE: Ioctl = _IOR::<[crate::__u8; 16]>(FSCRYPT_IOC_MAGIC, 27);
@@ -0,0 +1,103 @@
+//! Header: `uapi/linux/fsverity.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IOW,
+    _IOWR,
+};
+
+pub const FS_VERITY_HASH_ALG_SHA256: c_uint = 1;
+pub const FS_VERITY_HASH_ALG_SHA512: c_uint = 2;
+
+s! {
+    pub struct fsverity_enable_arg {
+        pub version: crate::__u32,
+        pub hash_algorithm: crate::__u32,
+        pub block_size: crate::__u32,
+        pub salt_size: crate::__u32,
+        pub salt_ptr: crate::__u64,
+        pub sig_size: crate::__u32,
+        __reserved1: Padding<crate::__u32>,
+        pub sig_ptr: crate::__u64,
+        __reserved2: Padding<[crate::__u64; 11]>,
+    }
+
+    pub struct fsverity_digest {
+        pub digest_algorithm: crate::__u16,
+        /* input/output */
+        pub digest_size: crate::__u16,
+        pub digest: [crate::__u8; 0],
+    }
+
+    /*
+     * Struct containing a file's Merkle tree properties.  The fs-verity file digest
+     * is the hash of this struct.  A userspace program needs this struct only if it
+     * needs to compute fs-verity file digests itself, e.g. in order to sign files.
+     * It isn't needed just to enable fs-verity on a file.
+     *
+     * Note: when computing the file digest, 'sig_size' and 'signature' must be left
+     * zero and empty, respectively.  These fields are present only because some
+     * filesystems reuse this struct as part of their on-disk format.
+     */
+    pub struct fsverity_descriptor {
+        /* must be 1 */
+        pub version: crate::__u8,
+        /* Merkle tree hash algorithm */
+        pub hash_algorithm: crate::__u8,
+        /* log2 of size of data and tree blocks */
+        pub log_blocksize: crate::__u8,
+        /* size of salt in bytes; 0 if none */
+        pub salt_size: crate::__u8,
+        /* must be 0 */
+        __reserved_0x04: Padding<crate::__u32>,
+        /* size of file the Merkle tree is built over */
+        pub data_size: crate::__u64,
+        /* Merkle tree root hash */
+        pub root_hash: [crate::__u8; 64],
+        /* salt prepended to each hashed block */
+        pub salt: [crate::__u8; 32],
+        /* must be 0's */
+        __reserved: Padding<[crate::__u8; 144]>,
+    }
+
+    /*
+     * Format in which fs-verity file digests are signed in built-in signatures.
+     * This is the same as 'struct fsverity_digest', except here some magic bytes
+     * are prepended to provide some context about what is being signed in case the
+     * same key is used for non-fsverity purposes, and here the fields have fixed
+     * endianness.
+     *
+     * This struct is specific to the built-in signature verification support, which
+     * is optional.  fs-verity users may also verify signatures in userspace, in
+     * which case userspace is responsible for deciding on what bytes are signed.
+     * This struct may still be used, but it doesn't have to be.  For example,
+     * userspace could instead use a string like "sha256:$digest_as_hex_string".
+     */
+    pub struct fsverity_formatted_digest {
+        /* must be "FSVerity" */
+        pub magic: [c_char; 8],
+        pub digest_algorithm: crate::__u16,
+        pub digest_size: crate::__u16,
+        pub digest: [crate::__u8; 0],
+    }
+}
+
+pub const FS_VERITY_METADATA_TYPE_MERKLE_TREE: c_uint = 1;
+pub const FS_VERITY_METADATA_TYPE_DESCRIPTOR: c_uint = 2;
+pub const FS_VERITY_METADATA_TYPE_SIGNATURE: c_uint = 3;
+
+s! {
+    pub struct fsverity_read_metadata_arg {
+        pub metadata_type: crate::__u64,
+        pub offset: crate::__u64,
+        pub length: crate::__u64,
+        pub buf_ptr: crate::__u64,
+        __reserved: Padding<crate::__u64>,
+    }
+}
+
+pub const FS_IOC_ENABLE_VERITY: Ioctl = _IOW::<fsverity_enable_arg>(b'f' as c_uint, 133);
+pub const FS_IOC_MEASURE_VERITY: Ioctl = _IOWR::<fsverity_digest>(b'f' as c_uint, 134);
+pub const FS_IOC_READ_VERITY_METADATA: Ioctl =
+    _IOWR::<fsverity_read_metadata_arg>(b'f' as c_uint, 135);
@@ -0,0 +1,75 @@
+//! Header: `uapi/linux/if_addr.h`
+
+use crate::prelude::*;
+
+s! {
+    pub struct ifaddrmsg {
+        pub ifa_family: crate::__u8,
+        pub ifa_prefixlen: crate::__u8,
+        pub ifa_flags: crate::__u8,
+        pub ifa_scope: crate::__u8,
+        pub ifa_index: crate::__u32,
+    }
+}
+
+c_enum! {
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub IFA_UNSPEC,
+        pub IFA_ADDRESS,
+        pub IFA_LOCAL,
+        pub IFA_LABEL,
+        pub IFA_BROADCAST,
+        pub IFA_ANYCAST,
+        pub IFA_CACHEINFO,
+        pub IFA_MULTICAST,
+        pub IFA_FLAGS,
+        pub IFA_RT_PRIORITY,
+        pub IFA_TARGET_NETNSID,
+        pub IFA_PROTO,
+    }
+}
+
+/* ifa_flags */
+pub const IFA_F_SECONDARY: u32 = 0x01;
+pub const IFA_F_TEMPORARY: u32 = IFA_F_SECONDARY;
+
+pub const IFA_F_NODAD: u32 = 0x02;
+pub const IFA_F_OPTIMISTIC: u32 = 0x04;
+pub const IFA_F_DADFAILED: u32 = 0x08;
+pub const IFA_F_HOMEADDRESS: u32 = 0x10;
+pub const IFA_F_DEPRECATED: u32 = 0x20;
+pub const IFA_F_TENTATIVE: u32 = 0x40;
+pub const IFA_F_PERMANENT: u32 = 0x80;
+pub const IFA_F_MANAGETEMPADDR: u32 = 0x100;
+pub const IFA_F_NOPREFIXROUTE: u32 = 0x200;
+pub const IFA_F_MCAUTOJOIN: u32 = 0x400;
+pub const IFA_F_STABLE_PRIVACY: u32 = 0x800;
+
+s! {
+    pub struct ifa_cacheinfo {
+        pub ifa_prefered: crate::__u32,
+        pub ifa_valid: crate::__u32,
+        pub cstamp: crate::__u32,
+        pub tstamp: crate::__u32,
+    }
+}
+
+/* backwards compatibility for userspace */
+f! {
+    pub fn IFA_RTA(r: *const ifaddrmsg) -> *mut crate::rtattr {
+        r.cast::<c_char>()
+            .offset(crate::NLMSG_ALIGN(size_of::<ifaddrmsg>() as c_int) as isize)
+            as *mut crate::rtattr
+    }
+
+    pub fn IFA_PAYLOAD(n: *const crate::nlmsghdr) -> c_int {
+        crate::NLMSG_PAYLOAD(n, size_of::<ifaddrmsg>() as c_int)
+    }
+}
+
+/* ifa_proto */
+pub const IFAPROT_UNSPEC: u8 = 0;
+pub const IFAPROT_KERNEL_LO: u8 = 1;
+pub const IFAPROT_KERNEL_RA: u8 = 2;
+pub const IFAPROT_KERNEL_LL: u8 = 3;
@@ -0,0 +1,200 @@
+//! Header: `uapi/linux/input.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IOC,
+    _IOC_READ,
+    _IOR,
+    _IOW,
+};
+
+/*
+ * Protocol version.
+ */
+pub const EV_VERSION: c_int = 0x010001;
+
+pub const INPUT_KEYMAP_BY_INDEX: crate::__u8 = 1 << 0;
+
+const EVIOC_MAGIC: c_uint = b'E' as c_uint;
+
+/*
+ * IOCTLs (0x00 - 0x7f)
+ */
+pub const EVIOCGVERSION: Ioctl = _IOR::<c_int>(EVIOC_MAGIC, 0x01); /* get driver version */
+pub const EVIOCGID: Ioctl = _IOR::<crate::input_id>(EVIOC_MAGIC, 0x02); /* get device ID */
+pub const EVIOCGREP: Ioctl = _IOR::<[c_uint; 2]>(EVIOC_MAGIC, 0x03); /* get repeat settings */
+pub const EVIOCSREP: Ioctl = _IOW::<[c_uint; 2]>(EVIOC_MAGIC, 0x03); /* set repeat settings */
+
+pub const EVIOCGKEYCODE: Ioctl = _IOR::<[c_uint; 2]>(EVIOC_MAGIC, 0x04); /* get keycode */
+pub const EVIOCGKEYCODE_V2: Ioctl = _IOR::<crate::input_keymap_entry>(EVIOC_MAGIC, 0x04);
+pub const EVIOCSKEYCODE: Ioctl = _IOW::<[c_uint; 2]>(EVIOC_MAGIC, 0x04); /* set keycode */
+pub const EVIOCSKEYCODE_V2: Ioctl = _IOW::<crate::input_keymap_entry>(EVIOC_MAGIC, 0x04);
+
+safe_f! {
+    /* get device name */
+    pub const fn EVIOCGNAME(len: usize) -> Ioctl {
+        _IOC(_IOC_READ, EVIOC_MAGIC, 0x06, len)
+    }
+
+    /* get physical location */
+    pub const fn EVIOCGPHYS(len: usize) -> Ioctl {
+        _IOC(_IOC_READ, EVIOC_MAGIC, 0x07, len)
+    }
+
+    /* get unique identifier */
+    pub const fn EVIOCGUNIQ(len: usize) -> Ioctl {
+        _IOC(_IOC_READ, EVIOC_MAGIC, 0x08, len)
+    }
+
+    /* get device properties */
+    pub const fn EVIOCGPROP(len: usize) -> Ioctl {
+        _IOC(_IOC_READ, EVIOC_MAGIC, 0x09, len)
+    }
+
+    /* get MT slot values */
+    pub const fn EVIOCGMTSLOTS(len: usize) -> Ioctl {
+        _IOC(_IOC_READ, EVIOC_MAGIC, 0x0a, len)
+    }
+
+    /* get global key state */
+    pub const fn EVIOCGKEY(len: usize) -> Ioctl {
+        _IOC(_IOC_READ, EVIOC_MAGIC, 0x18, len)
+    }
+
+    /* get all LEDs */
+    pub const fn EVIOCGLED(len: usize) -> Ioctl {
+        _IOC(_IOC_READ, EVIOC_MAGIC, 0x19, len)
+    }
+
+    /* get all sounds status */
+    pub const fn EVIOCGSND(len: usize) -> Ioctl {
+        _IOC(_IOC_READ, EVIOC_MAGIC, 0x1a, len)
+    }
+
+    /* get all switch states */
+    pub const fn EVIOCGSW(len: usize) -> Ioctl {
+        _IOC(_IOC_READ, EVIOC_MAGIC, 0x1b, len)
+    }
+
+    /* get event bits */
+    pub const fn EVIOCGBIT(ev: crate::__u16, len: usize) -> Ioctl {
+        _IOC(_IOC_READ, EVIOC_MAGIC, 0x20 + ev as c_uint, len)
+    }
+
+    /* get abs value/limits */
+    pub const fn EVIOCGABS(abs: crate::__u16) -> Ioctl {
+        _IOR::<crate::input_absinfo>(EVIOC_MAGIC, 0x40 + abs as c_uint)
+    }
+
+    /* set abs value/limits */
+    pub const fn EVIOCSABS(abs: crate::__u16) -> Ioctl {
+        _IOW::<crate::input_absinfo>(EVIOC_MAGIC, 0xc0 + abs as c_uint)
+    }
+}
+
+/* send a force effect to a force feedback device */
+pub const EVIOCSFF: Ioctl = _IOW::<crate::ff_effect>(EVIOC_MAGIC, 0x80);
+pub const EVIOCRMFF: Ioctl = _IOW::<c_int>(EVIOC_MAGIC, 0x81); /* Erase a force effect */
+/* Report number of effects playable at the same time */
+pub const EVIOCGEFFECTS: Ioctl = _IOR::<c_int>(EVIOC_MAGIC, 0x84);
+
+pub const EVIOCGRAB: Ioctl = _IOW::<c_int>(EVIOC_MAGIC, 0x90); /* Grab/Release device */
+pub const EVIOCREVOKE: Ioctl = _IOW::<c_int>(EVIOC_MAGIC, 0x91); /* Revoke device access */
+
+pub const EVIOCGMASK: Ioctl = _IOR::<crate::input_mask>(EVIOC_MAGIC, 0x92); /* Get event-masks */
+pub const EVIOCSMASK: Ioctl = _IOW::<crate::input_mask>(EVIOC_MAGIC, 0x93); /* Set event-masks */
+
+/* Set clockid to be used for timestamps */
+pub const EVIOCSCLOCKID: Ioctl = _IOW::<c_int>(EVIOC_MAGIC, 0xa0);
+
+/*
+ * IDs.
+ */
+pub const ID_BUS: c_int = 0;
+pub const ID_VENDOR: c_int = 1;
+pub const ID_PRODUCT: c_int = 2;
+pub const ID_VERSION: c_int = 3;
+
+pub const BUS_PCI: crate::__u16 = 0x01;
+pub const BUS_ISAPNP: crate::__u16 = 0x02;
+pub const BUS_USB: crate::__u16 = 0x03;
+pub const BUS_HIL: crate::__u16 = 0x04;
+pub const BUS_BLUETOOTH: crate::__u16 = 0x05;
+pub const BUS_VIRTUAL: crate::__u16 = 0x06;
+
+pub const BUS_ISA: crate::__u16 = 0x10;
+pub const BUS_I8042: crate::__u16 = 0x11;
+pub const BUS_XTKBD: crate::__u16 = 0x12;
+pub const BUS_RS232: crate::__u16 = 0x13;
+pub const BUS_GAMEPORT: crate::__u16 = 0x14;
+pub const BUS_PARPORT: crate::__u16 = 0x15;
+pub const BUS_AMIGA: crate::__u16 = 0x16;
+pub const BUS_ADB: crate::__u16 = 0x17;
+pub const BUS_I2C: crate::__u16 = 0x18;
+pub const BUS_HOST: crate::__u16 = 0x19;
+pub const BUS_GSC: crate::__u16 = 0x1A;
+pub const BUS_ATARI: crate::__u16 = 0x1B;
+pub const BUS_SPI: crate::__u16 = 0x1C;
+pub const BUS_RMI: crate::__u16 = 0x1D;
+pub const BUS_CEC: crate::__u16 = 0x1E;
+pub const BUS_INTEL_ISHTP: crate::__u16 = 0x1F;
+pub const BUS_AMD_SFH: crate::__u16 = 0x20;
+
+/*
+ * MT_TOOL types
+ */
+pub const MT_TOOL_FINGER: c_int = 0x00;
+pub const MT_TOOL_PEN: c_int = 0x01;
+pub const MT_TOOL_PALM: c_int = 0x02;
+pub const MT_TOOL_DIAL: c_int = 0x0a;
+pub const MT_TOOL_MAX: c_int = 0x0f;
+
+/*
+ * Values describing the status of a force-feedback effect
+ */
+pub const FF_STATUS_STOPPED: crate::__u16 = 0x00;
+pub const FF_STATUS_PLAYING: crate::__u16 = 0x01;
+pub const FF_STATUS_MAX: crate::__u16 = 0x01;
+
+/*
+ * Force feedback effect types
+ */
+pub const FF_RUMBLE: crate::__u16 = 0x50;
+pub const FF_PERIODIC: crate::__u16 = 0x51;
+pub const FF_CONSTANT: crate::__u16 = 0x52;
+pub const FF_SPRING: crate::__u16 = 0x53;
+pub const FF_FRICTION: crate::__u16 = 0x54;
+pub const FF_DAMPER: crate::__u16 = 0x55;
+pub const FF_INERTIA: crate::__u16 = 0x56;
+pub const FF_RAMP: crate::__u16 = 0x57;
+
+pub const FF_EFFECT_MIN: crate::__u16 = FF_RUMBLE;
+pub const FF_EFFECT_MAX: crate::__u16 = FF_RAMP;
+
+/*
+ * Force feedback periodic effect types
+ */
+pub const FF_SQUARE: crate::__u16 = 0x58;
+pub const FF_TRIANGLE: crate::__u16 = 0x59;
+pub const FF_SINE: crate::__u16 = 0x5a;
+pub const FF_SAW_UP: crate::__u16 = 0x5b;
+pub const FF_SAW_DOWN: crate::__u16 = 0x5c;
+pub const FF_CUSTOM: crate::__u16 = 0x5d;
+
+pub const FF_WAVEFORM_MIN: crate::__u16 = FF_SQUARE;
+pub const FF_WAVEFORM_MAX: crate::__u16 = FF_CUSTOM;
+
+/*
+ * Set ff device properties
+ */
+pub const FF_GAIN: crate::__u16 = 0x60;
+pub const FF_AUTOCENTER: crate::__u16 = 0x61;
+
+/*
+ * ff->playback(effect_id = FF_GAIN) is the first effect_id to
+ * cause a collision with another ff method, in this case ff->set_gain().
+ * Therefore the greatest safe value for effect_id is FF_GAIN - 1,
+ * and thus the total number of effects should never exceed FF_GAIN.
+ */
+pub const FF_MAX_EFFECTS: crate::__u16 = FF_GAIN;
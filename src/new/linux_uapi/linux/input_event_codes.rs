@@ -0,0 +1,898 @@
+//! Header: `uapi/linux/input-event-codes.h`
+
+/*
+ * Event types
+ */
+
+pub const EV_SYN: crate::__u16 = 0x00;
+pub const EV_KEY: crate::__u16 = 0x01;
+pub const EV_REL: crate::__u16 = 0x02;
+pub const EV_ABS: crate::__u16 = 0x03;
+pub const EV_MSC: crate::__u16 = 0x04;
+pub const EV_SW: crate::__u16 = 0x05;
+pub const EV_LED: crate::__u16 = 0x11;
+pub const EV_SND: crate::__u16 = 0x12;
+pub const EV_REP: crate::__u16 = 0x14;
+pub const EV_FF: crate::__u16 = 0x15;
+pub const EV_PWR: crate::__u16 = 0x16;
+pub const EV_FF_STATUS: crate::__u16 = 0x17;
+
+/*
+ * Synchronization events.
+ */
+
+pub const SYN_REPORT: crate::__u16 = 0;
+pub const SYN_CONFIG: crate::__u16 = 1;
+pub const SYN_MT_REPORT: crate::__u16 = 2;
+pub const SYN_DROPPED: crate::__u16 = 3;
+
+/*
+ * Keys and buttons
+ *
+ * Most of the keys/buttons are modeled after USB HUT 1.12
+ * (see http://www.usb.org/developers/hidpage).
+ * Abbreviations in the comments:
+ * AC - Application Control
+ * AL - Application Launch Button
+ * SC - System Control
+ */
+
+pub const KEY_RESERVED: crate::__u16 = 0;
+pub const KEY_ESC: crate::__u16 = 1;
+pub const KEY_1: crate::__u16 = 2;
+pub const KEY_2: crate::__u16 = 3;
+pub const KEY_3: crate::__u16 = 4;
+pub const KEY_4: crate::__u16 = 5;
+pub const KEY_5: crate::__u16 = 6;
+pub const KEY_6: crate::__u16 = 7;
+pub const KEY_7: crate::__u16 = 8;
+pub const KEY_8: crate::__u16 = 9;
+pub const KEY_9: crate::__u16 = 10;
+pub const KEY_0: crate::__u16 = 11;
+pub const KEY_MINUS: crate::__u16 = 12;
+pub const KEY_EQUAL: crate::__u16 = 13;
+pub const KEY_BACKSPACE: crate::__u16 = 14;
+pub const KEY_TAB: crate::__u16 = 15;
+pub const KEY_Q: crate::__u16 = 16;
+pub const KEY_W: crate::__u16 = 17;
+pub const KEY_E: crate::__u16 = 18;
+pub const KEY_R: crate::__u16 = 19;
+pub const KEY_T: crate::__u16 = 20;
+pub const KEY_Y: crate::__u16 = 21;
+pub const KEY_U: crate::__u16 = 22;
+pub const KEY_I: crate::__u16 = 23;
+pub const KEY_O: crate::__u16 = 24;
+pub const KEY_P: crate::__u16 = 25;
+pub const KEY_LEFTBRACE: crate::__u16 = 26;
+pub const KEY_RIGHTBRACE: crate::__u16 = 27;
+pub const KEY_ENTER: crate::__u16 = 28;
+pub const KEY_LEFTCTRL: crate::__u16 = 29;
+pub const KEY_A: crate::__u16 = 30;
+pub const KEY_S: crate::__u16 = 31;
+pub const KEY_D: crate::__u16 = 32;
+pub const KEY_F: crate::__u16 = 33;
+pub const KEY_G: crate::__u16 = 34;
+pub const KEY_H: crate::__u16 = 35;
+pub const KEY_J: crate::__u16 = 36;
+pub const KEY_K: crate::__u16 = 37;
+pub const KEY_L: crate::__u16 = 38;
+pub const KEY_SEMICOLON: crate::__u16 = 39;
+pub const KEY_APOSTROPHE: crate::__u16 = 40;
+pub const KEY_GRAVE: crate::__u16 = 41;
+pub const KEY_LEFTSHIFT: crate::__u16 = 42;
+pub const KEY_BACKSLASH: crate::__u16 = 43;
+pub const KEY_Z: crate::__u16 = 44;
+pub const KEY_X: crate::__u16 = 45;
+pub const KEY_C: crate::__u16 = 46;
+pub const KEY_V: crate::__u16 = 47;
+pub const KEY_B: crate::__u16 = 48;
+pub const KEY_N: crate::__u16 = 49;
+pub const KEY_M: crate::__u16 = 50;
+pub const KEY_COMMA: crate::__u16 = 51;
+pub const KEY_DOT: crate::__u16 = 52;
+pub const KEY_SLASH: crate::__u16 = 53;
+pub const KEY_RIGHTSHIFT: crate::__u16 = 54;
+pub const KEY_KPASTERISK: crate::__u16 = 55;
+pub const KEY_LEFTALT: crate::__u16 = 56;
+pub const KEY_SPACE: crate::__u16 = 57;
+pub const KEY_CAPSLOCK: crate::__u16 = 58;
+pub const KEY_F1: crate::__u16 = 59;
+pub const KEY_F2: crate::__u16 = 60;
+pub const KEY_F3: crate::__u16 = 61;
+pub const KEY_F4: crate::__u16 = 62;
+pub const KEY_F5: crate::__u16 = 63;
+pub const KEY_F6: crate::__u16 = 64;
+pub const KEY_F7: crate::__u16 = 65;
+pub const KEY_F8: crate::__u16 = 66;
+pub const KEY_F9: crate::__u16 = 67;
+pub const KEY_F10: crate::__u16 = 68;
+pub const KEY_NUMLOCK: crate::__u16 = 69;
+pub const KEY_SCROLLLOCK: crate::__u16 = 70;
+pub const KEY_KP7: crate::__u16 = 71;
+pub const KEY_KP8: crate::__u16 = 72;
+pub const KEY_KP9: crate::__u16 = 73;
+pub const KEY_KPMINUS: crate::__u16 = 74;
+pub const KEY_KP4: crate::__u16 = 75;
+pub const KEY_KP5: crate::__u16 = 76;
+pub const KEY_KP6: crate::__u16 = 77;
+pub const KEY_KPPLUS: crate::__u16 = 78;
+pub const KEY_KP1: crate::__u16 = 79;
+pub const KEY_KP2: crate::__u16 = 80;
+pub const KEY_KP3: crate::__u16 = 81;
+pub const KEY_KP0: crate::__u16 = 82;
+pub const KEY_KPDOT: crate::__u16 = 83;
+
+pub const KEY_ZENKAKUHANKAKU: crate::__u16 = 85;
+pub const KEY_102ND: crate::__u16 = 86;
+pub const KEY_F11: crate::__u16 = 87;
+pub const KEY_F12: crate::__u16 = 88;
+pub const KEY_RO: crate::__u16 = 89;
+pub const KEY_KATAKANA: crate::__u16 = 90;
+pub const KEY_HIRAGANA: crate::__u16 = 91;
+pub const KEY_HENKAN: crate::__u16 = 92;
+pub const KEY_KATAKANAHIRAGANA: crate::__u16 = 93;
+pub const KEY_MUHENKAN: crate::__u16 = 94;
+pub const KEY_KPJPCOMMA: crate::__u16 = 95;
+pub const KEY_KPENTER: crate::__u16 = 96;
+pub const KEY_RIGHTCTRL: crate::__u16 = 97;
+pub const KEY_KPSLASH: crate::__u16 = 98;
+pub const KEY_SYSRQ: crate::__u16 = 99;
+pub const KEY_RIGHTALT: crate::__u16 = 100;
+pub const KEY_LINEFEED: crate::__u16 = 101;
+pub const KEY_HOME: crate::__u16 = 102;
+pub const KEY_UP: crate::__u16 = 103;
+pub const KEY_PAGEUP: crate::__u16 = 104;
+pub const KEY_LEFT: crate::__u16 = 105;
+pub const KEY_RIGHT: crate::__u16 = 106;
+pub const KEY_END: crate::__u16 = 107;
+pub const KEY_DOWN: crate::__u16 = 108;
+pub const KEY_PAGEDOWN: crate::__u16 = 109;
+pub const KEY_INSERT: crate::__u16 = 110;
+pub const KEY_DELETE: crate::__u16 = 111;
+pub const KEY_MACRO: crate::__u16 = 112;
+pub const KEY_MUTE: crate::__u16 = 113;
+pub const KEY_VOLUMEDOWN: crate::__u16 = 114;
+pub const KEY_VOLUMEUP: crate::__u16 = 115;
+pub const KEY_POWER: crate::__u16 = 116; /* SC System Power Down */
+pub const KEY_KPEQUAL: crate::__u16 = 117;
+pub const KEY_KPPLUSMINUS: crate::__u16 = 118;
+pub const KEY_PAUSE: crate::__u16 = 119;
+pub const KEY_SCALE: crate::__u16 = 120; /* AL Compiz Scale (Expose) */
+
+pub const KEY_KPCOMMA: crate::__u16 = 121;
+pub const KEY_HANGEUL: crate::__u16 = 122;
+pub const KEY_HANGUEL: crate::__u16 = KEY_HANGEUL;
+pub const KEY_HANJA: crate::__u16 = 123;
+pub const KEY_YEN: crate::__u16 = 124;
+pub const KEY_LEFTMETA: crate::__u16 = 125;
+pub const KEY_RIGHTMETA: crate::__u16 = 126;
+pub const KEY_COMPOSE: crate::__u16 = 127;
+
+pub const KEY_STOP: crate::__u16 = 128; /* AC Stop */
+pub const KEY_AGAIN: crate::__u16 = 129;
+pub const KEY_PROPS: crate::__u16 = 130; /* AC Properties */
+pub const KEY_UNDO: crate::__u16 = 131; /* AC Undo */
+pub const KEY_FRONT: crate::__u16 = 132;
+pub const KEY_COPY: crate::__u16 = 133; /* AC Copy */
+pub const KEY_OPEN: crate::__u16 = 134; /* AC Open */
+pub const KEY_PASTE: crate::__u16 = 135; /* AC Paste */
+pub const KEY_FIND: crate::__u16 = 136; /* AC Search */
+pub const KEY_CUT: crate::__u16 = 137; /* AC Cut */
+pub const KEY_HELP: crate::__u16 = 138; /* AL Integrated Help Center */
+pub const KEY_MENU: crate::__u16 = 139; /* Menu (show menu) */
+pub const KEY_CALC: crate::__u16 = 140; /* AL Calculator */
+pub const KEY_SETUP: crate::__u16 = 141;
+pub const KEY_SLEEP: crate::__u16 = 142; /* SC System Sleep */
+pub const KEY_WAKEUP: crate::__u16 = 143; /* System Wake Up */
+pub const KEY_FILE: crate::__u16 = 144; /* AL Local Machine Browser */
+pub const KEY_SENDFILE: crate::__u16 = 145;
+pub const KEY_DELETEFILE: crate::__u16 = 146;
+pub const KEY_XFER: crate::__u16 = 147;
+pub const KEY_PROG1: crate::__u16 = 148;
+pub const KEY_PROG2: crate::__u16 = 149;
+pub const KEY_WWW: crate::__u16 = 150; /* AL Internet Browser */
+pub const KEY_MSDOS: crate::__u16 = 151;
+pub const KEY_COFFEE: crate::__u16 = 152; /* AL Terminal Lock/Screensaver */
+pub const KEY_SCREENLOCK: crate::__u16 = KEY_COFFEE;
+pub const KEY_ROTATE_DISPLAY: crate::__u16 = 153; /* Display orientation for e.g. tablets */
+pub const KEY_DIRECTION: crate::__u16 = KEY_ROTATE_DISPLAY;
+pub const KEY_CYCLEWINDOWS: crate::__u16 = 154;
+pub const KEY_MAIL: crate::__u16 = 155;
+pub const KEY_BOOKMARKS: crate::__u16 = 156; /* AC Bookmarks */
+pub const KEY_COMPUTER: crate::__u16 = 157;
+pub const KEY_BACK: crate::__u16 = 158; /* AC Back */
+pub const KEY_FORWARD: crate::__u16 = 159; /* AC Forward */
+pub const KEY_CLOSECD: crate::__u16 = 160;
+pub const KEY_EJECTCD: crate::__u16 = 161;
+pub const KEY_EJECTCLOSECD: crate::__u16 = 162;
+pub const KEY_NEXTSONG: crate::__u16 = 163;
+pub const KEY_PLAYPAUSE: crate::__u16 = 164;
+pub const KEY_PREVIOUSSONG: crate::__u16 = 165;
+pub const KEY_STOPCD: crate::__u16 = 166;
+pub const KEY_RECORD: crate::__u16 = 167;
+pub const KEY_REWIND: crate::__u16 = 168;
+pub const KEY_PHONE: crate::__u16 = 169; /* Media Select Telephone */
+pub const KEY_ISO: crate::__u16 = 170;
+pub const KEY_CONFIG: crate::__u16 = 171; /* AL Consumer Control Configuration */
+pub const KEY_HOMEPAGE: crate::__u16 = 172; /* AC Home */
+pub const KEY_REFRESH: crate::__u16 = 173; /* AC Refresh */
+pub const KEY_EXIT: crate::__u16 = 174; /* AC Exit */
+pub const KEY_MOVE: crate::__u16 = 175;
+pub const KEY_EDIT: crate::__u16 = 176;
+pub const KEY_SCROLLUP: crate::__u16 = 177;
+pub const KEY_SCROLLDOWN: crate::__u16 = 178;
+pub const KEY_KPLEFTPAREN: crate::__u16 = 179;
+pub const KEY_KPRIGHTPAREN: crate::__u16 = 180;
+pub const KEY_NEW: crate::__u16 = 181; /* AC New */
+pub const KEY_REDO: crate::__u16 = 182; /* AC Redo/Repeat */
+
+pub const KEY_F13: crate::__u16 = 183;
+pub const KEY_F14: crate::__u16 = 184;
+pub const KEY_F15: crate::__u16 = 185;
+pub const KEY_F16: crate::__u16 = 186;
+pub const KEY_F17: crate::__u16 = 187;
+pub const KEY_F18: crate::__u16 = 188;
+pub const KEY_F19: crate::__u16 = 189;
+pub const KEY_F20: crate::__u16 = 190;
+pub const KEY_F21: crate::__u16 = 191;
+pub const KEY_F22: crate::__u16 = 192;
+pub const KEY_F23: crate::__u16 = 193;
+pub const KEY_F24: crate::__u16 = 194;
+
+pub const KEY_PLAYCD: crate::__u16 = 200;
+pub const KEY_PAUSECD: crate::__u16 = 201;
+pub const KEY_PROG3: crate::__u16 = 202;
+pub const KEY_PROG4: crate::__u16 = 203;
+pub const KEY_ALL_APPLICATIONS: crate::__u16 = 204; /* AC Desktop Show All Applications */
+pub const KEY_DASHBOARD: crate::__u16 = KEY_ALL_APPLICATIONS;
+pub const KEY_SUSPEND: crate::__u16 = 205;
+pub const KEY_CLOSE: crate::__u16 = 206; /* AC Close */
+pub const KEY_PLAY: crate::__u16 = 207;
+pub const KEY_FASTFORWARD: crate::__u16 = 208;
+pub const KEY_BASSBOOST: crate::__u16 = 209;
+pub const KEY_PRINT: crate::__u16 = 210; /* AC Print */
+pub const KEY_HP: crate::__u16 = 211;
+pub const KEY_CAMERA: crate::__u16 = 212;
+pub const KEY_SOUND: crate::__u16 = 213;
+pub const KEY_QUESTION: crate::__u16 = 214;
+pub const KEY_EMAIL: crate::__u16 = 215;
+pub const KEY_CHAT: crate::__u16 = 216;
+pub const KEY_SEARCH: crate::__u16 = 217;
+pub const KEY_CONNECT: crate::__u16 = 218;
+pub const KEY_FINANCE: crate::__u16 = 219; /* AL Checkbook/Finance */
+pub const KEY_SPORT: crate::__u16 = 220;
+pub const KEY_SHOP: crate::__u16 = 221;
+pub const KEY_ALTERASE: crate::__u16 = 222;
+pub const KEY_CANCEL: crate::__u16 = 223; /* AC Cancel */
+pub const KEY_BRIGHTNESSDOWN: crate::__u16 = 224;
+pub const KEY_BRIGHTNESSUP: crate::__u16 = 225;
+pub const KEY_MEDIA: crate::__u16 = 226;
+
+/* Cycle between available video outputs (Monitor/LCD/TV-out/etc) */
+pub const KEY_SWITCHVIDEOMODE: crate::__u16 = 227;
+pub const KEY_KBDILLUMTOGGLE: crate::__u16 = 228;
+pub const KEY_KBDILLUMDOWN: crate::__u16 = 229;
+pub const KEY_KBDILLUMUP: crate::__u16 = 230;
+
+pub const KEY_SEND: crate::__u16 = 231; /* AC Send */
+pub const KEY_REPLY: crate::__u16 = 232; /* AC Reply */
+pub const KEY_FORWARDMAIL: crate::__u16 = 233; /* AC Forward Msg */
+pub const KEY_SAVE: crate::__u16 = 234; /* AC Save */
+pub const KEY_DOCUMENTS: crate::__u16 = 235;
+
+pub const KEY_BATTERY: crate::__u16 = 236;
+
+pub const KEY_BLUETOOTH: crate::__u16 = 237;
+pub const KEY_WLAN: crate::__u16 = 238;
+pub const KEY_UWB: crate::__u16 = 239;
+
+pub const KEY_UNKNOWN: crate::__u16 = 240;
+
+pub const KEY_VIDEO_NEXT: crate::__u16 = 241; /* drive next video source */
+pub const KEY_VIDEO_PREV: crate::__u16 = 242; /* drive previous video source */
+pub const KEY_BRIGHTNESS_CYCLE: crate::__u16 = 243; /* brightness up, after max is min */
+/* Set Auto Brightness: manual brightness control is off, rely on ambient */
+pub const KEY_BRIGHTNESS_AUTO: crate::__u16 = 244;
+pub const KEY_BRIGHTNESS_ZERO: crate::__u16 = KEY_BRIGHTNESS_AUTO;
+pub const KEY_DISPLAY_OFF: crate::__u16 = 245; /* display device to off state */
+
+pub const KEY_WWAN: crate::__u16 = 246; /* Wireless WAN (LTE, UMTS, GSM, etc.) */
+pub const KEY_WIMAX: crate::__u16 = KEY_WWAN;
+pub const KEY_RFKILL: crate::__u16 = 247; /* Key that controls all radios */
+
+pub const KEY_MICMUTE: crate::__u16 = 248; /* Mute / unmute the microphone */
+
+/* Code 255 is reserved for special needs of AT keyboard driver */
+
+pub const BTN_MISC: crate::__u16 = 0x100;
+pub const BTN_0: crate::__u16 = 0x100;
+pub const BTN_1: crate::__u16 = 0x101;
+pub const BTN_2: crate::__u16 = 0x102;
+pub const BTN_3: crate::__u16 = 0x103;
+pub const BTN_4: crate::__u16 = 0x104;
+pub const BTN_5: crate::__u16 = 0x105;
+pub const BTN_6: crate::__u16 = 0x106;
+pub const BTN_7: crate::__u16 = 0x107;
+pub const BTN_8: crate::__u16 = 0x108;
+pub const BTN_9: crate::__u16 = 0x109;
+
+pub const BTN_MOUSE: crate::__u16 = 0x110;
+pub const BTN_LEFT: crate::__u16 = 0x110;
+pub const BTN_RIGHT: crate::__u16 = 0x111;
+pub const BTN_MIDDLE: crate::__u16 = 0x112;
+pub const BTN_SIDE: crate::__u16 = 0x113;
+pub const BTN_EXTRA: crate::__u16 = 0x114;
+pub const BTN_FORWARD: crate::__u16 = 0x115;
+pub const BTN_BACK: crate::__u16 = 0x116;
+pub const BTN_TASK: crate::__u16 = 0x117;
+
+pub const BTN_JOYSTICK: crate::__u16 = 0x120;
+pub const BTN_TRIGGER: crate::__u16 = 0x120;
+pub const BTN_THUMB: crate::__u16 = 0x121;
+pub const BTN_THUMB2: crate::__u16 = 0x122;
+pub const BTN_TOP: crate::__u16 = 0x123;
+pub const BTN_TOP2: crate::__u16 = 0x124;
+pub const BTN_PINKIE: crate::__u16 = 0x125;
+pub const BTN_BASE: crate::__u16 = 0x126;
+pub const BTN_BASE2: crate::__u16 = 0x127;
+pub const BTN_BASE3: crate::__u16 = 0x128;
+pub const BTN_BASE4: crate::__u16 = 0x129;
+pub const BTN_BASE5: crate::__u16 = 0x12a;
+pub const BTN_BASE6: crate::__u16 = 0x12b;
+pub const BTN_DEAD: crate::__u16 = 0x12f;
+
+pub const BTN_GAMEPAD: crate::__u16 = 0x130;
+pub const BTN_SOUTH: crate::__u16 = 0x130;
+pub const BTN_A: crate::__u16 = BTN_SOUTH;
+pub const BTN_EAST: crate::__u16 = 0x131;
+pub const BTN_B: crate::__u16 = BTN_EAST;
+pub const BTN_C: crate::__u16 = 0x132;
+pub const BTN_NORTH: crate::__u16 = 0x133;
+pub const BTN_X: crate::__u16 = BTN_NORTH;
+pub const BTN_WEST: crate::__u16 = 0x134;
+pub const BTN_Y: crate::__u16 = BTN_WEST;
+pub const BTN_Z: crate::__u16 = 0x135;
+pub const BTN_TL: crate::__u16 = 0x136;
+pub const BTN_TR: crate::__u16 = 0x137;
+pub const BTN_TL2: crate::__u16 = 0x138;
+pub const BTN_TR2: crate::__u16 = 0x139;
+pub const BTN_SELECT: crate::__u16 = 0x13a;
+pub const BTN_START: crate::__u16 = 0x13b;
+pub const BTN_MODE: crate::__u16 = 0x13c;
+pub const BTN_THUMBL: crate::__u16 = 0x13d;
+pub const BTN_THUMBR: crate::__u16 = 0x13e;
+
+pub const BTN_DIGI: crate::__u16 = 0x140;
+pub const BTN_TOOL_PEN: crate::__u16 = 0x140;
+pub const BTN_TOOL_RUBBER: crate::__u16 = 0x141;
+pub const BTN_TOOL_BRUSH: crate::__u16 = 0x142;
+pub const BTN_TOOL_PENCIL: crate::__u16 = 0x143;
+pub const BTN_TOOL_AIRBRUSH: crate::__u16 = 0x144;
+pub const BTN_TOOL_FINGER: crate::__u16 = 0x145;
+pub const BTN_TOOL_MOUSE: crate::__u16 = 0x146;
+pub const BTN_TOOL_LENS: crate::__u16 = 0x147;
+pub const BTN_TOOL_QUINTTAP: crate::__u16 = 0x148; /* Five fingers on trackpad */
+pub const BTN_STYLUS3: crate::__u16 = 0x149;
+pub const BTN_TOUCH: crate::__u16 = 0x14a;
+pub const BTN_STYLUS: crate::__u16 = 0x14b;
+pub const BTN_STYLUS2: crate::__u16 = 0x14c;
+pub const BTN_TOOL_DOUBLETAP: crate::__u16 = 0x14d;
+pub const BTN_TOOL_TRIPLETAP: crate::__u16 = 0x14e;
+pub const BTN_TOOL_QUADTAP: crate::__u16 = 0x14f; /* Four fingers on trackpad */
+
+pub const BTN_WHEEL: crate::__u16 = 0x150;
+pub const BTN_GEAR_DOWN: crate::__u16 = 0x150;
+pub const BTN_GEAR_UP: crate::__u16 = 0x151;
+
+pub const KEY_OK: crate::__u16 = 0x160;
+pub const KEY_SELECT: crate::__u16 = 0x161;
+pub const KEY_GOTO: crate::__u16 = 0x162;
+pub const KEY_CLEAR: crate::__u16 = 0x163;
+pub const KEY_POWER2: crate::__u16 = 0x164;
+pub const KEY_OPTION: crate::__u16 = 0x165;
+pub const KEY_INFO: crate::__u16 = 0x166; /* AL OEM Features/Tips/Tutorial */
+pub const KEY_TIME: crate::__u16 = 0x167;
+pub const KEY_VENDOR: crate::__u16 = 0x168;
+pub const KEY_ARCHIVE: crate::__u16 = 0x169;
+pub const KEY_PROGRAM: crate::__u16 = 0x16a; /* Media Select Program Guide */
+pub const KEY_CHANNEL: crate::__u16 = 0x16b;
+pub const KEY_FAVORITES: crate::__u16 = 0x16c;
+pub const KEY_EPG: crate::__u16 = 0x16d;
+pub const KEY_PVR: crate::__u16 = 0x16e; /* Media Select Home */
+pub const KEY_MHP: crate::__u16 = 0x16f;
+pub const KEY_LANGUAGE: crate::__u16 = 0x170;
+pub const KEY_TITLE: crate::__u16 = 0x171;
+pub const KEY_SUBTITLE: crate::__u16 = 0x172;
+pub const KEY_ANGLE: crate::__u16 = 0x173;
+pub const KEY_FULL_SCREEN: crate::__u16 = 0x174; /* AC View Toggle */
+pub const KEY_ZOOM: crate::__u16 = KEY_FULL_SCREEN;
+pub const KEY_MODE: crate::__u16 = 0x175;
+pub const KEY_KEYBOARD: crate::__u16 = 0x176;
+pub const KEY_ASPECT_RATIO: crate::__u16 = 0x177; /* HUTRR37: Aspect */
+pub const KEY_SCREEN: crate::__u16 = KEY_ASPECT_RATIO;
+pub const KEY_PC: crate::__u16 = 0x178; /* Media Select Computer */
+pub const KEY_TV: crate::__u16 = 0x179; /* Media Select TV */
+pub const KEY_TV2: crate::__u16 = 0x17a; /* Media Select Cable */
+pub const KEY_VCR: crate::__u16 = 0x17b; /* Media Select VCR */
+pub const KEY_VCR2: crate::__u16 = 0x17c; /* VCR Plus */
+pub const KEY_SAT: crate::__u16 = 0x17d; /* Media Select Satellite */
+pub const KEY_SAT2: crate::__u16 = 0x17e;
+pub const KEY_CD: crate::__u16 = 0x17f; /* Media Select CD */
+pub const KEY_TAPE: crate::__u16 = 0x180; /* Media Select Tape */
+pub const KEY_RADIO: crate::__u16 = 0x181;
+pub const KEY_TUNER: crate::__u16 = 0x182; /* Media Select Tuner */
+pub const KEY_PLAYER: crate::__u16 = 0x183;
+pub const KEY_TEXT: crate::__u16 = 0x184;
+pub const KEY_DVD: crate::__u16 = 0x185; /* Media Select DVD */
+pub const KEY_AUX: crate::__u16 = 0x186;
+pub const KEY_MP3: crate::__u16 = 0x187;
+pub const KEY_AUDIO: crate::__u16 = 0x188; /* AL Audio Browser */
+pub const KEY_VIDEO: crate::__u16 = 0x189; /* AL Movie Browser */
+pub const KEY_DIRECTORY: crate::__u16 = 0x18a;
+pub const KEY_LIST: crate::__u16 = 0x18b;
+pub const KEY_MEMO: crate::__u16 = 0x18c; /* Media Select Messages */
+pub const KEY_CALENDAR: crate::__u16 = 0x18d;
+pub const KEY_RED: crate::__u16 = 0x18e;
+pub const KEY_GREEN: crate::__u16 = 0x18f;
+pub const KEY_YELLOW: crate::__u16 = 0x190;
+pub const KEY_BLUE: crate::__u16 = 0x191;
+pub const KEY_CHANNELUP: crate::__u16 = 0x192; /* Channel Increment */
+pub const KEY_CHANNELDOWN: crate::__u16 = 0x193; /* Channel Decrement */
+pub const KEY_FIRST: crate::__u16 = 0x194;
+pub const KEY_LAST: crate::__u16 = 0x195; /* Recall Last */
+pub const KEY_AB: crate::__u16 = 0x196;
+pub const KEY_NEXT: crate::__u16 = 0x197;
+pub const KEY_RESTART: crate::__u16 = 0x198;
+pub const KEY_SLOW: crate::__u16 = 0x199;
+pub const KEY_SHUFFLE: crate::__u16 = 0x19a;
+pub const KEY_BREAK: crate::__u16 = 0x19b;
+pub const KEY_PREVIOUS: crate::__u16 = 0x19c;
+pub const KEY_DIGITS: crate::__u16 = 0x19d;
+pub const KEY_TEEN: crate::__u16 = 0x19e;
+pub const KEY_TWEN: crate::__u16 = 0x19f;
+pub const KEY_VIDEOPHONE: crate::__u16 = 0x1a0; /* Media Select Video Phone */
+pub const KEY_GAMES: crate::__u16 = 0x1a1; /* Media Select Games */
+pub const KEY_ZOOMIN: crate::__u16 = 0x1a2; /* AC Zoom In */
+pub const KEY_ZOOMOUT: crate::__u16 = 0x1a3; /* AC Zoom Out */
+pub const KEY_ZOOMRESET: crate::__u16 = 0x1a4; /* AC Zoom */
+pub const KEY_WORDPROCESSOR: crate::__u16 = 0x1a5; /* AL Word Processor */
+pub const KEY_EDITOR: crate::__u16 = 0x1a6; /* AL Text Editor */
+pub const KEY_SPREADSHEET: crate::__u16 = 0x1a7; /* AL Spreadsheet */
+pub const KEY_GRAPHICSEDITOR: crate::__u16 = 0x1a8; /* AL Graphics Editor */
+pub const KEY_PRESENTATION: crate::__u16 = 0x1a9; /* AL Presentation App */
+pub const KEY_DATABASE: crate::__u16 = 0x1aa; /* AL Database App */
+pub const KEY_NEWS: crate::__u16 = 0x1ab; /* AL Newsreader */
+pub const KEY_VOICEMAIL: crate::__u16 = 0x1ac; /* AL Voicemail */
+pub const KEY_ADDRESSBOOK: crate::__u16 = 0x1ad; /* AL Contacts/Address Book */
+pub const KEY_MESSENGER: crate::__u16 = 0x1ae; /* AL Instant Messaging */
+pub const KEY_DISPLAYTOGGLE: crate::__u16 = 0x1af; /* Turn display (LCD) on and off */
+pub const KEY_BRIGHTNESS_TOGGLE: crate::__u16 = KEY_DISPLAYTOGGLE;
+pub const KEY_SPELLCHECK: crate::__u16 = 0x1b0; /* AL Spell Check */
+pub const KEY_LOGOFF: crate::__u16 = 0x1b1; /* AL Logoff */
+
+pub const KEY_DOLLAR: crate::__u16 = 0x1b2;
+pub const KEY_EURO: crate::__u16 = 0x1b3;
+
+pub const KEY_FRAMEBACK: crate::__u16 = 0x1b4; /* Consumer - transport controls */
+pub const KEY_FRAMEFORWARD: crate::__u16 = 0x1b5;
+pub const KEY_CONTEXT_MENU: crate::__u16 = 0x1b6; /* GenDesc - system context menu */
+pub const KEY_MEDIA_REPEAT: crate::__u16 = 0x1b7; /* Consumer - transport control */
+pub const KEY_10CHANNELSUP: crate::__u16 = 0x1b8; /* 10 channels up (10+) */
+pub const KEY_10CHANNELSDOWN: crate::__u16 = 0x1b9; /* 10 channels down (10-) */
+pub const KEY_IMAGES: crate::__u16 = 0x1ba; /* AL Image Browser */
+pub const KEY_NOTIFICATION_CENTER: crate::__u16 = 0x1bc; /* Show/hide the notification center */
+pub const KEY_PICKUP_PHONE: crate::__u16 = 0x1bd; /* Answer incoming call */
+pub const KEY_HANGUP_PHONE: crate::__u16 = 0x1be; /* Decline incoming call */
+pub const KEY_LINK_PHONE: crate::__u16 = 0x1bf; /* AL Phone Syncing */
+
+pub const KEY_DEL_EOL: crate::__u16 = 0x1c0;
+pub const KEY_DEL_EOS: crate::__u16 = 0x1c1;
+pub const KEY_INS_LINE: crate::__u16 = 0x1c2;
+pub const KEY_DEL_LINE: crate::__u16 = 0x1c3;
+
+pub const KEY_FN: crate::__u16 = 0x1d0;
+pub const KEY_FN_ESC: crate::__u16 = 0x1d1;
+pub const KEY_FN_F1: crate::__u16 = 0x1d2;
+pub const KEY_FN_F2: crate::__u16 = 0x1d3;
+pub const KEY_FN_F3: crate::__u16 = 0x1d4;
+pub const KEY_FN_F4: crate::__u16 = 0x1d5;
+pub const KEY_FN_F5: crate::__u16 = 0x1d6;
+pub const KEY_FN_F6: crate::__u16 = 0x1d7;
+pub const KEY_FN_F7: crate::__u16 = 0x1d8;
+pub const KEY_FN_F8: crate::__u16 = 0x1d9;
+pub const KEY_FN_F9: crate::__u16 = 0x1da;
+pub const KEY_FN_F10: crate::__u16 = 0x1db;
+pub const KEY_FN_F11: crate::__u16 = 0x1dc;
+pub const KEY_FN_F12: crate::__u16 = 0x1dd;
+pub const KEY_FN_1: crate::__u16 = 0x1de;
+pub const KEY_FN_2: crate::__u16 = 0x1df;
+pub const KEY_FN_D: crate::__u16 = 0x1e0;
+pub const KEY_FN_E: crate::__u16 = 0x1e1;
+pub const KEY_FN_F: crate::__u16 = 0x1e2;
+pub const KEY_FN_S: crate::__u16 = 0x1e3;
+pub const KEY_FN_B: crate::__u16 = 0x1e4;
+pub const KEY_FN_RIGHT_SHIFT: crate::__u16 = 0x1e5;
+
+pub const KEY_BRL_DOT1: crate::__u16 = 0x1f1;
+pub const KEY_BRL_DOT2: crate::__u16 = 0x1f2;
+pub const KEY_BRL_DOT3: crate::__u16 = 0x1f3;
+pub const KEY_BRL_DOT4: crate::__u16 = 0x1f4;
+pub const KEY_BRL_DOT5: crate::__u16 = 0x1f5;
+pub const KEY_BRL_DOT6: crate::__u16 = 0x1f6;
+pub const KEY_BRL_DOT7: crate::__u16 = 0x1f7;
+pub const KEY_BRL_DOT8: crate::__u16 = 0x1f8;
+pub const KEY_BRL_DOT9: crate::__u16 = 0x1f9;
+pub const KEY_BRL_DOT10: crate::__u16 = 0x1fa;
+
+pub const KEY_NUMERIC_0: crate::__u16 = 0x200; /* used by phones, remote controls, */
+pub const KEY_NUMERIC_1: crate::__u16 = 0x201; /* and other keypads */
+pub const KEY_NUMERIC_2: crate::__u16 = 0x202;
+pub const KEY_NUMERIC_3: crate::__u16 = 0x203;
+pub const KEY_NUMERIC_4: crate::__u16 = 0x204;
+pub const KEY_NUMERIC_5: crate::__u16 = 0x205;
+pub const KEY_NUMERIC_6: crate::__u16 = 0x206;
+pub const KEY_NUMERIC_7: crate::__u16 = 0x207;
+pub const KEY_NUMERIC_8: crate::__u16 = 0x208;
+pub const KEY_NUMERIC_9: crate::__u16 = 0x209;
+pub const KEY_NUMERIC_STAR: crate::__u16 = 0x20a;
+pub const KEY_NUMERIC_POUND: crate::__u16 = 0x20b;
+pub const KEY_NUMERIC_A: crate::__u16 = 0x20c; /* Phone key A - HUT Telephony 0xb9 */
+pub const KEY_NUMERIC_B: crate::__u16 = 0x20d;
+pub const KEY_NUMERIC_C: crate::__u16 = 0x20e;
+pub const KEY_NUMERIC_D: crate::__u16 = 0x20f;
+
+pub const KEY_CAMERA_FOCUS: crate::__u16 = 0x210;
+pub const KEY_WPS_BUTTON: crate::__u16 = 0x211; /* WiFi Protected Setup key */
+
+pub const KEY_TOUCHPAD_TOGGLE: crate::__u16 = 0x212; /* Request switch touchpad on or off */
+pub const KEY_TOUCHPAD_ON: crate::__u16 = 0x213;
+pub const KEY_TOUCHPAD_OFF: crate::__u16 = 0x214;
+
+pub const KEY_CAMERA_ZOOMIN: crate::__u16 = 0x215;
+pub const KEY_CAMERA_ZOOMOUT: crate::__u16 = 0x216;
+pub const KEY_CAMERA_UP: crate::__u16 = 0x217;
+pub const KEY_CAMERA_DOWN: crate::__u16 = 0x218;
+pub const KEY_CAMERA_LEFT: crate::__u16 = 0x219;
+pub const KEY_CAMERA_RIGHT: crate::__u16 = 0x21a;
+
+pub const KEY_ATTENDANT_ON: crate::__u16 = 0x21b;
+pub const KEY_ATTENDANT_OFF: crate::__u16 = 0x21c;
+pub const KEY_ATTENDANT_TOGGLE: crate::__u16 = 0x21d; /* Attendant call on or off */
+pub const KEY_LIGHTS_TOGGLE: crate::__u16 = 0x21e; /* Reading light on or off */
+
+pub const BTN_DPAD_UP: crate::__u16 = 0x220;
+pub const BTN_DPAD_DOWN: crate::__u16 = 0x221;
+pub const BTN_DPAD_LEFT: crate::__u16 = 0x222;
+pub const BTN_DPAD_RIGHT: crate::__u16 = 0x223;
+
+pub const KEY_ALS_TOGGLE: crate::__u16 = 0x230; /* Ambient light sensor */
+pub const KEY_ROTATE_LOCK_TOGGLE: crate::__u16 = 0x231; /* Display rotation lock */
+pub const KEY_REFRESH_RATE_TOGGLE: crate::__u16 = 0x232; /* Display refresh rate toggle */
+
+pub const KEY_BUTTONCONFIG: crate::__u16 = 0x240; /* AL Button Configuration */
+pub const KEY_TASKMANAGER: crate::__u16 = 0x241; /* AL Task/Project Manager */
+pub const KEY_JOURNAL: crate::__u16 = 0x242; /* AL Log/Journal/Timecard */
+pub const KEY_CONTROLPANEL: crate::__u16 = 0x243; /* AL Control Panel */
+pub const KEY_APPSELECT: crate::__u16 = 0x244; /* AL Select Task/Application */
+pub const KEY_SCREENSAVER: crate::__u16 = 0x245; /* AL Screen Saver */
+pub const KEY_VOICECOMMAND: crate::__u16 = 0x246; /* Listening Voice Command */
+pub const KEY_ASSISTANT: crate::__u16 = 0x247; /* AL Context-aware desktop assistant */
+pub const KEY_KBD_LAYOUT_NEXT: crate::__u16 = 0x248; /* AC Next Keyboard Layout Select */
+pub const KEY_EMOJI_PICKER: crate::__u16 = 0x249; /* Show/hide emoji picker (HUTRR101) */
+pub const KEY_DICTATE: crate::__u16 = 0x24a; /* Start or Stop Voice Dictation Session (HUTRR99) */
+
+pub const KEY_BRIGHTNESS_MIN: crate::__u16 = 0x250; /* Set Brightness to Minimum */
+pub const KEY_BRIGHTNESS_MAX: crate::__u16 = 0x251; /* Set Brightness to Maximum */
+
+pub const KEY_KBDINPUTASSIST_PREV: crate::__u16 = 0x260;
+pub const KEY_KBDINPUTASSIST_NEXT: crate::__u16 = 0x261;
+pub const KEY_KBDINPUTASSIST_PREVGROUP: crate::__u16 = 0x262;
+pub const KEY_KBDINPUTASSIST_NEXTGROUP: crate::__u16 = 0x263;
+pub const KEY_KBDINPUTASSIST_ACCEPT: crate::__u16 = 0x264;
+pub const KEY_KBDINPUTASSIST_CANCEL: crate::__u16 = 0x265;
+
+/* Diagonal movement keys */
+pub const KEY_RIGHT_UP: crate::__u16 = 0x266;
+pub const KEY_RIGHT_DOWN: crate::__u16 = 0x267;
+pub const KEY_LEFT_UP: crate::__u16 = 0x268;
+pub const KEY_LEFT_DOWN: crate::__u16 = 0x269;
+
+pub const KEY_ROOT_MENU: crate::__u16 = 0x26a; /* Show Device's Root Menu */
+/* Show Top Menu of the Media (e.g. DVD) */
+pub const KEY_MEDIA_TOP_MENU: crate::__u16 = 0x26b;
+pub const KEY_NUMERIC_11: crate::__u16 = 0x26c;
+pub const KEY_NUMERIC_12: crate::__u16 = 0x26d;
+/*
+ * Toggle Audio Description: refers to an audio service that helps blind and
+ * visually impaired consumers understand the action in a program. Note: in
+ * some countries this is referred to as "Video Description".
+ */
+pub const KEY_AUDIO_DESC: crate::__u16 = 0x26e;
+pub const KEY_3D_MODE: crate::__u16 = 0x26f;
+pub const KEY_NEXT_FAVORITE: crate::__u16 = 0x270;
+pub const KEY_STOP_RECORD: crate::__u16 = 0x271;
+pub const KEY_PAUSE_RECORD: crate::__u16 = 0x272;
+pub const KEY_VOD: crate::__u16 = 0x273; /* Video on Demand */
+pub const KEY_UNMUTE: crate::__u16 = 0x274;
+pub const KEY_FASTREVERSE: crate::__u16 = 0x275;
+pub const KEY_SLOWREVERSE: crate::__u16 = 0x276;
+/*
+ * Control a data application associated with the currently viewed channel,
+ * e.g. teletext or data broadcast application (MHEG, MHP, HbbTV, etc.)
+ */
+pub const KEY_DATA: crate::__u16 = 0x277;
+pub const KEY_ONSCREEN_KEYBOARD: crate::__u16 = 0x278;
+/* Electronic privacy screen control */
+pub const KEY_PRIVACY_SCREEN_TOGGLE: crate::__u16 = 0x279;
+
+/* Select an area of screen to be copied */
+pub const KEY_SELECTIVE_SCREENSHOT: crate::__u16 = 0x27a;
+
+/* Move the focus to the next or previous user controllable element within a UI container */
+pub const KEY_NEXT_ELEMENT: crate::__u16 = 0x27b;
+pub const KEY_PREVIOUS_ELEMENT: crate::__u16 = 0x27c;
+
+/* Toggle Autopilot engagement */
+pub const KEY_AUTOPILOT_ENGAGE_TOGGLE: crate::__u16 = 0x27d;
+
+/* Shortcut Keys */
+pub const KEY_MARK_WAYPOINT: crate::__u16 = 0x27e;
+pub const KEY_SOS: crate::__u16 = 0x27f;
+pub const KEY_NAV_CHART: crate::__u16 = 0x280;
+pub const KEY_FISHING_CHART: crate::__u16 = 0x281;
+pub const KEY_SINGLE_RANGE_RADAR: crate::__u16 = 0x282;
+pub const KEY_DUAL_RANGE_RADAR: crate::__u16 = 0x283;
+pub const KEY_RADAR_OVERLAY: crate::__u16 = 0x284;
+pub const KEY_TRADITIONAL_SONAR: crate::__u16 = 0x285;
+pub const KEY_CLEARVU_SONAR: crate::__u16 = 0x286;
+pub const KEY_SIDEVU_SONAR: crate::__u16 = 0x287;
+pub const KEY_NAV_INFO: crate::__u16 = 0x288;
+pub const KEY_BRIGHTNESS_MENU: crate::__u16 = 0x289;
+
+/*
+ * Keys without a defined meaning, intended to be bound to macros by the user.
+ * They must not be used for keys whose marking indicates a defined purpose.
+ */
+pub const KEY_MACRO1: crate::__u16 = 0x290;
+pub const KEY_MACRO2: crate::__u16 = 0x291;
+pub const KEY_MACRO3: crate::__u16 = 0x292;
+pub const KEY_MACRO4: crate::__u16 = 0x293;
+pub const KEY_MACRO5: crate::__u16 = 0x294;
+pub const KEY_MACRO6: crate::__u16 = 0x295;
+pub const KEY_MACRO7: crate::__u16 = 0x296;
+pub const KEY_MACRO8: crate::__u16 = 0x297;
+pub const KEY_MACRO9: crate::__u16 = 0x298;
+pub const KEY_MACRO10: crate::__u16 = 0x299;
+pub const KEY_MACRO11: crate::__u16 = 0x29a;
+pub const KEY_MACRO12: crate::__u16 = 0x29b;
+pub const KEY_MACRO13: crate::__u16 = 0x29c;
+pub const KEY_MACRO14: crate::__u16 = 0x29d;
+pub const KEY_MACRO15: crate::__u16 = 0x29e;
+pub const KEY_MACRO16: crate::__u16 = 0x29f;
+pub const KEY_MACRO17: crate::__u16 = 0x2a0;
+pub const KEY_MACRO18: crate::__u16 = 0x2a1;
+pub const KEY_MACRO19: crate::__u16 = 0x2a2;
+pub const KEY_MACRO20: crate::__u16 = 0x2a3;
+pub const KEY_MACRO21: crate::__u16 = 0x2a4;
+pub const KEY_MACRO22: crate::__u16 = 0x2a5;
+pub const KEY_MACRO23: crate::__u16 = 0x2a6;
+pub const KEY_MACRO24: crate::__u16 = 0x2a7;
+pub const KEY_MACRO25: crate::__u16 = 0x2a8;
+pub const KEY_MACRO26: crate::__u16 = 0x2a9;
+pub const KEY_MACRO27: crate::__u16 = 0x2aa;
+pub const KEY_MACRO28: crate::__u16 = 0x2ab;
+pub const KEY_MACRO29: crate::__u16 = 0x2ac;
+pub const KEY_MACRO30: crate::__u16 = 0x2ad;
+
+/*
+ * Extra keys controlling the host-side macro handling: recording start/stop
+ * and switching between macro presets.
+ */
+pub const KEY_MACRO_RECORD_START: crate::__u16 = 0x2b0;
+pub const KEY_MACRO_RECORD_STOP: crate::__u16 = 0x2b1;
+pub const KEY_MACRO_PRESET_CYCLE: crate::__u16 = 0x2b2;
+pub const KEY_MACRO_PRESET1: crate::__u16 = 0x2b3;
+pub const KEY_MACRO_PRESET2: crate::__u16 = 0x2b4;
+pub const KEY_MACRO_PRESET3: crate::__u16 = 0x2b5;
+
+/* Unlabeled keys for controlling a menu shown on a keyboard's LCD panel */
+pub const KEY_KBD_LCD_MENU1: crate::__u16 = 0x2b8;
+pub const KEY_KBD_LCD_MENU2: crate::__u16 = 0x2b9;
+pub const KEY_KBD_LCD_MENU3: crate::__u16 = 0x2ba;
+pub const KEY_KBD_LCD_MENU4: crate::__u16 = 0x2bb;
+pub const KEY_KBD_LCD_MENU5: crate::__u16 = 0x2bc;
+
+pub const BTN_TRIGGER_HAPPY: crate::__u16 = 0x2c0;
+pub const BTN_TRIGGER_HAPPY1: crate::__u16 = 0x2c0;
+pub const BTN_TRIGGER_HAPPY2: crate::__u16 = 0x2c1;
+pub const BTN_TRIGGER_HAPPY3: crate::__u16 = 0x2c2;
+pub const BTN_TRIGGER_HAPPY4: crate::__u16 = 0x2c3;
+pub const BTN_TRIGGER_HAPPY5: crate::__u16 = 0x2c4;
+pub const BTN_TRIGGER_HAPPY6: crate::__u16 = 0x2c5;
+pub const BTN_TRIGGER_HAPPY7: crate::__u16 = 0x2c6;
+pub const BTN_TRIGGER_HAPPY8: crate::__u16 = 0x2c7;
+pub const BTN_TRIGGER_HAPPY9: crate::__u16 = 0x2c8;
+pub const BTN_TRIGGER_HAPPY10: crate::__u16 = 0x2c9;
+pub const BTN_TRIGGER_HAPPY11: crate::__u16 = 0x2ca;
+pub const BTN_TRIGGER_HAPPY12: crate::__u16 = 0x2cb;
+pub const BTN_TRIGGER_HAPPY13: crate::__u16 = 0x2cc;
+pub const BTN_TRIGGER_HAPPY14: crate::__u16 = 0x2cd;
+pub const BTN_TRIGGER_HAPPY15: crate::__u16 = 0x2ce;
+pub const BTN_TRIGGER_HAPPY16: crate::__u16 = 0x2cf;
+pub const BTN_TRIGGER_HAPPY17: crate::__u16 = 0x2d0;
+pub const BTN_TRIGGER_HAPPY18: crate::__u16 = 0x2d1;
+pub const BTN_TRIGGER_HAPPY19: crate::__u16 = 0x2d2;
+pub const BTN_TRIGGER_HAPPY20: crate::__u16 = 0x2d3;
+pub const BTN_TRIGGER_HAPPY21: crate::__u16 = 0x2d4;
+pub const BTN_TRIGGER_HAPPY22: crate::__u16 = 0x2d5;
+pub const BTN_TRIGGER_HAPPY23: crate::__u16 = 0x2d6;
+pub const BTN_TRIGGER_HAPPY24: crate::__u16 = 0x2d7;
+pub const BTN_TRIGGER_HAPPY25: crate::__u16 = 0x2d8;
+pub const BTN_TRIGGER_HAPPY26: crate::__u16 = 0x2d9;
+pub const BTN_TRIGGER_HAPPY27: crate::__u16 = 0x2da;
+pub const BTN_TRIGGER_HAPPY28: crate::__u16 = 0x2db;
+pub const BTN_TRIGGER_HAPPY29: crate::__u16 = 0x2dc;
+pub const BTN_TRIGGER_HAPPY30: crate::__u16 = 0x2dd;
+pub const BTN_TRIGGER_HAPPY31: crate::__u16 = 0x2de;
+pub const BTN_TRIGGER_HAPPY32: crate::__u16 = 0x2df;
+pub const BTN_TRIGGER_HAPPY33: crate::__u16 = 0x2e0;
+pub const BTN_TRIGGER_HAPPY34: crate::__u16 = 0x2e1;
+pub const BTN_TRIGGER_HAPPY35: crate::__u16 = 0x2e2;
+pub const BTN_TRIGGER_HAPPY36: crate::__u16 = 0x2e3;
+pub const BTN_TRIGGER_HAPPY37: crate::__u16 = 0x2e4;
+pub const BTN_TRIGGER_HAPPY38: crate::__u16 = 0x2e5;
+pub const BTN_TRIGGER_HAPPY39: crate::__u16 = 0x2e6;
+pub const BTN_TRIGGER_HAPPY40: crate::__u16 = 0x2e7;
+
+/* We avoid low common keys in module aliases so they don't get huge. */
+pub const KEY_MIN_INTERESTING: crate::__u16 = KEY_MUTE;
+
+/*
+ * Relative axes
+ */
+
+pub const REL_X: crate::__u16 = 0x00;
+pub const REL_Y: crate::__u16 = 0x01;
+pub const REL_Z: crate::__u16 = 0x02;
+pub const REL_RX: crate::__u16 = 0x03;
+pub const REL_RY: crate::__u16 = 0x04;
+pub const REL_RZ: crate::__u16 = 0x05;
+pub const REL_HWHEEL: crate::__u16 = 0x06;
+pub const REL_DIAL: crate::__u16 = 0x07;
+pub const REL_WHEEL: crate::__u16 = 0x08;
+pub const REL_MISC: crate::__u16 = 0x09;
+/*
+ * 0x0a is reserved and should not be used in input drivers.
+ * It was used by HID as REL_MISC+1 and userspace needs to detect if
+ * the next REL_* event is correct or is just REL_MISC + n.
+ * We define here REL_RESERVED so userspace can rely on it and detect
+ * the situation described above.
+ */
+pub const REL_RESERVED: crate::__u16 = 0x0a;
+pub const REL_WHEEL_HI_RES: crate::__u16 = 0x0b;
+pub const REL_HWHEEL_HI_RES: crate::__u16 = 0x0c;
+
+/*
+ * Absolute axes
+ */
+
+pub const ABS_X: crate::__u16 = 0x00;
+pub const ABS_Y: crate::__u16 = 0x01;
+pub const ABS_Z: crate::__u16 = 0x02;
+pub const ABS_RX: crate::__u16 = 0x03;
+pub const ABS_RY: crate::__u16 = 0x04;
+pub const ABS_RZ: crate::__u16 = 0x05;
+pub const ABS_THROTTLE: crate::__u16 = 0x06;
+pub const ABS_RUDDER: crate::__u16 = 0x07;
+pub const ABS_WHEEL: crate::__u16 = 0x08;
+pub const ABS_GAS: crate::__u16 = 0x09;
+pub const ABS_BRAKE: crate::__u16 = 0x0a;
+pub const ABS_HAT0X: crate::__u16 = 0x10;
+pub const ABS_HAT0Y: crate::__u16 = 0x11;
+pub const ABS_HAT1X: crate::__u16 = 0x12;
+pub const ABS_HAT1Y: crate::__u16 = 0x13;
+pub const ABS_HAT2X: crate::__u16 = 0x14;
+pub const ABS_HAT2Y: crate::__u16 = 0x15;
+pub const ABS_HAT3X: crate::__u16 = 0x16;
+pub const ABS_HAT3Y: crate::__u16 = 0x17;
+pub const ABS_PRESSURE: crate::__u16 = 0x18;
+pub const ABS_DISTANCE: crate::__u16 = 0x19;
+pub const ABS_TILT_X: crate::__u16 = 0x1a;
+pub const ABS_TILT_Y: crate::__u16 = 0x1b;
+pub const ABS_TOOL_WIDTH: crate::__u16 = 0x1c;
+
+pub const ABS_VOLUME: crate::__u16 = 0x20;
+pub const ABS_PROFILE: crate::__u16 = 0x21;
+
+pub const ABS_MISC: crate::__u16 = 0x28;
+
+/*
+ * 0x2e is reserved and should not be used in input drivers.
+ * It was used by HID as ABS_MISC+6 and userspace needs to detect if
+ * the next ABS_* event is correct or is just ABS_MISC + n.
+ * We define here ABS_RESERVED so userspace can rely on it and detect
+ * the situation described above.
+ */
+pub const ABS_RESERVED: crate::__u16 = 0x2e;
+
+pub const ABS_MT_SLOT: crate::__u16 = 0x2f; /* MT slot being modified */
+pub const ABS_MT_TOUCH_MAJOR: crate::__u16 = 0x30; /* Major axis of touching ellipse */
+pub const ABS_MT_TOUCH_MINOR: crate::__u16 = 0x31; /* Minor axis (omit if circular) */
+pub const ABS_MT_WIDTH_MAJOR: crate::__u16 = 0x32; /* Major axis of approaching ellipse */
+pub const ABS_MT_WIDTH_MINOR: crate::__u16 = 0x33; /* Minor axis (omit if circular) */
+pub const ABS_MT_ORIENTATION: crate::__u16 = 0x34; /* Ellipse orientation */
+pub const ABS_MT_POSITION_X: crate::__u16 = 0x35; /* Center X touch position */
+pub const ABS_MT_POSITION_Y: crate::__u16 = 0x36; /* Center Y touch position */
+pub const ABS_MT_TOOL_TYPE: crate::__u16 = 0x37; /* Type of touching device */
+pub const ABS_MT_BLOB_ID: crate::__u16 = 0x38; /* Group a set of packets as a blob */
+pub const ABS_MT_TRACKING_ID: crate::__u16 = 0x39; /* Unique ID of initiated contact */
+pub const ABS_MT_PRESSURE: crate::__u16 = 0x3a; /* Pressure on contact area */
+pub const ABS_MT_DISTANCE: crate::__u16 = 0x3b; /* Contact hover distance */
+pub const ABS_MT_TOOL_X: crate::__u16 = 0x3c; /* Center X tool position */
+pub const ABS_MT_TOOL_Y: crate::__u16 = 0x3d; /* Center Y tool position */
+
+/*
+ * Switch events
+ */
+
+pub const SW_LID: crate::__u16 = 0x00; /* set = lid shut */
+pub const SW_TABLET_MODE: crate::__u16 = 0x01; /* set = tablet mode */
+pub const SW_HEADPHONE_INSERT: crate::__u16 = 0x02; /* set = inserted */
+/* rfkill master switch, type "any" set = radio enabled */
+pub const SW_RFKILL_ALL: crate::__u16 = 0x03;
+pub const SW_RADIO: crate::__u16 = SW_RFKILL_ALL; /* deprecated */
+pub const SW_MICROPHONE_INSERT: crate::__u16 = 0x04; /* set = inserted */
+pub const SW_DOCK: crate::__u16 = 0x05; /* set = plugged into dock */
+pub const SW_LINEOUT_INSERT: crate::__u16 = 0x06; /* set = inserted */
+pub const SW_JACK_PHYSICAL_INSERT: crate::__u16 = 0x07; /* set = mechanical switch set */
+pub const SW_VIDEOOUT_INSERT: crate::__u16 = 0x08; /* set = inserted */
+pub const SW_CAMERA_LENS_COVER: crate::__u16 = 0x09; /* set = lens covered */
+pub const SW_KEYPAD_SLIDE: crate::__u16 = 0x0a; /* set = keypad slide out */
+pub const SW_FRONT_PROXIMITY: crate::__u16 = 0x0b; /* set = front proximity sensor active */
+pub const SW_ROTATE_LOCK: crate::__u16 = 0x0c; /* set = rotate locked/disabled */
+pub const SW_LINEIN_INSERT: crate::__u16 = 0x0d; /* set = inserted */
+pub const SW_MUTE_DEVICE: crate::__u16 = 0x0e; /* set = device disabled */
+pub const SW_PEN_INSERTED: crate::__u16 = 0x0f; /* set = pen inserted */
+pub const SW_MACHINE_COVER: crate::__u16 = 0x10; /* set = cover closed */
+
+/*
+ * Misc events
+ */
+
+pub const MSC_SERIAL: crate::__u16 = 0x00;
+pub const MSC_PULSELED: crate::__u16 = 0x01;
+pub const MSC_GESTURE: crate::__u16 = 0x02;
+pub const MSC_RAW: crate::__u16 = 0x03;
+pub const MSC_SCAN: crate::__u16 = 0x04;
+pub const MSC_TIMESTAMP: crate::__u16 = 0x05;
+
+/*
+ * LEDs
+ */
+
+pub const LED_NUML: crate::__u16 = 0x00;
+pub const LED_CAPSL: crate::__u16 = 0x01;
+pub const LED_SCROLLL: crate::__u16 = 0x02;
+pub const LED_COMPOSE: crate::__u16 = 0x03;
+pub const LED_KANA: crate::__u16 = 0x04;
+pub const LED_SLEEP: crate::__u16 = 0x05;
+pub const LED_SUSPEND: crate::__u16 = 0x06;
+pub const LED_MUTE: crate::__u16 = 0x07;
+pub const LED_MISC: crate::__u16 = 0x08;
+pub const LED_MAIL: crate::__u16 = 0x09;
+pub const LED_CHARGING: crate::__u16 = 0x0a;
+
+/*
+ * Autorepeat values
+ */
+
+pub const REP_DELAY: crate::__u16 = 0x00;
+pub const REP_PERIOD: crate::__u16 = 0x01;
+
+/*
+ * Sounds
+ */
+
+pub const SND_CLICK: crate::__u16 = 0x00;
+pub const SND_BELL: crate::__u16 = 0x01;
+pub const SND_TONE: crate::__u16 = 0x02;
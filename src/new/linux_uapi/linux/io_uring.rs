@@ -0,0 +1,573 @@
+//! Header: `uapi/linux/io_uring.h`
+
+use crate::prelude::*;
+
+s_no_extra_traits! {
+    pub struct io_uring_sqe {
+        pub opcode: crate::__u8,
+        pub flags: crate::__u8,
+        pub ioprio: crate::__u16,
+        pub fd: crate::__s32,
+        pub anonymous_1: __c_anonymous_io_uring_sqe_1,
+        pub anonymous_2: __c_anonymous_io_uring_sqe_2,
+        pub len: crate::__u32,
+        pub anonymous_3: __c_anonymous_io_uring_sqe_3,
+        pub user_data: crate::__u64,
+        pub anonymous_4: __c_anonymous_io_uring_sqe_4,
+        pub personality: crate::__u16,
+        pub anonymous_5: __c_anonymous_io_uring_sqe_5,
+        pub anonymous_6: __c_anonymous_io_uring_sqe_6,
+    }
+
+    pub union __c_anonymous_io_uring_sqe_1 {
+        pub off: crate::__u64,
+        pub addr2: crate::__u64,
+        pub cmd_op: __c_anonymous_io_uring_sqe_cmd_op,
+    }
+
+    pub union __c_anonymous_io_uring_sqe_2 {
+        pub addr: crate::__u64,
+        pub splice_off_in: crate::__u64,
+        pub sockopt: __c_anonymous_io_uring_sqe_sockopt,
+    }
+
+    pub union __c_anonymous_io_uring_sqe_3 {
+        // `__kernel_rwf_t`
+        pub rw_flags: c_int,
+        pub fsync_flags: crate::__u32,
+        pub poll_events: crate::__u16,
+        pub poll32_events: crate::__u32,
+        pub sync_range_flags: crate::__u32,
+        pub msg_flags: crate::__u32,
+        pub timeout_flags: crate::__u32,
+        pub accept_flags: crate::__u32,
+        pub cancel_flags: crate::__u32,
+        pub open_flags: crate::__u32,
+        pub statx_flags: crate::__u32,
+        pub fadvise_advice: crate::__u32,
+        pub splice_flags: crate::__u32,
+        pub rename_flags: crate::__u32,
+        pub unlink_flags: crate::__u32,
+        pub hardlink_flags: crate::__u32,
+        pub xattr_flags: crate::__u32,
+        pub msg_ring_flags: crate::__u32,
+        pub uring_cmd_flags: crate::__u32,
+        pub waitid_flags: crate::__u32,
+        pub futex_flags: crate::__u32,
+        pub install_fd_flags: crate::__u32,
+        pub nop_flags: crate::__u32,
+    }
+
+    pub union __c_anonymous_io_uring_sqe_4 {
+        pub buf_index: crate::__u16,
+        pub buf_group: crate::__u16,
+    }
+
+    pub union __c_anonymous_io_uring_sqe_5 {
+        pub splice_fd_in: crate::__s32,
+        pub file_index: crate::__u32,
+        pub optlen: crate::__u32,
+        pub addr_len: __c_anonymous_io_uring_sqe_addr_len,
+    }
+
+    pub union __c_anonymous_io_uring_sqe_6 {
+        pub addr3: __c_anonymous_io_uring_sqe_addr3,
+        pub optval: crate::__u64,
+        pub cmd: [crate::__u8; 0],
+    }
+
+    pub struct __c_anonymous_io_uring_sqe_cmd_op {
+        pub cmd_op: crate::__u32,
+        __pad1: Padding<crate::__u32>,
+    }
+
+    pub struct __c_anonymous_io_uring_sqe_sockopt {
+        pub level: crate::__u32,
+        pub optname: crate::__u32,
+    }
+
+    pub struct __c_anonymous_io_uring_sqe_addr_len {
+        pub addr_len: crate::__u16,
+        __pad3: Padding<[crate::__u16; 1]>,
+    }
+
+    pub struct __c_anonymous_io_uring_sqe_addr3 {
+        pub addr3: crate::__u64,
+        __pad2: Padding<[crate::__u64; 1]>,
+    }
+}
+
+pub const IORING_FILE_INDEX_ALLOC: c_uint = !0;
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub IOSQE_FIXED_FILE_BIT,
+        pub IOSQE_IO_DRAIN_BIT,
+        pub IOSQE_IO_LINK_BIT,
+        pub IOSQE_IO_HARDLINK_BIT,
+        pub IOSQE_ASYNC_BIT,
+        pub IOSQE_BUFFER_SELECT_BIT,
+        pub IOSQE_CQE_SKIP_SUCCESS_BIT,
+    }
+}
+
+pub const IOSQE_FIXED_FILE: c_uint = 1 << IOSQE_FIXED_FILE_BIT;
+pub const IOSQE_IO_DRAIN: c_uint = 1 << IOSQE_IO_DRAIN_BIT;
+pub const IOSQE_IO_LINK: c_uint = 1 << IOSQE_IO_LINK_BIT;
+pub const IOSQE_IO_HARDLINK: c_uint = 1 << IOSQE_IO_HARDLINK_BIT;
+pub const IOSQE_ASYNC: c_uint = 1 << IOSQE_ASYNC_BIT;
+pub const IOSQE_BUFFER_SELECT: c_uint = 1 << IOSQE_BUFFER_SELECT_BIT;
+pub const IOSQE_CQE_SKIP_SUCCESS: c_uint = 1 << IOSQE_CQE_SKIP_SUCCESS_BIT;
+
+pub const IORING_SETUP_IOPOLL: c_uint = 1 << 0;
+pub const IORING_SETUP_SQPOLL: c_uint = 1 << 1;
+pub const IORING_SETUP_SQ_AFF: c_uint = 1 << 2;
+pub const IORING_SETUP_CQSIZE: c_uint = 1 << 3;
+pub const IORING_SETUP_CLAMP: c_uint = 1 << 4;
+pub const IORING_SETUP_ATTACH_WQ: c_uint = 1 << 5;
+pub const IORING_SETUP_R_DISABLED: c_uint = 1 << 6;
+pub const IORING_SETUP_SUBMIT_ALL: c_uint = 1 << 7;
+pub const IORING_SETUP_COOP_TASKRUN: c_uint = 1 << 8;
+pub const IORING_SETUP_TASKRUN_FLAG: c_uint = 1 << 9;
+pub const IORING_SETUP_SQE128: c_uint = 1 << 10;
+pub const IORING_SETUP_CQE32: c_uint = 1 << 11;
+pub const IORING_SETUP_SINGLE_ISSUER: c_uint = 1 << 12;
+pub const IORING_SETUP_DEFER_TASKRUN: c_uint = 1 << 13;
+pub const IORING_SETUP_NO_MMAP: c_uint = 1 << 14;
+pub const IORING_SETUP_REGISTERED_FD_ONLY: c_uint = 1 << 15;
+pub const IORING_SETUP_NO_SQARRAY: c_uint = 1 << 16;
+pub const IORING_SETUP_HYBRID_IOPOLL: c_uint = 1 << 17;
+
+c_enum! {
+    pub enum io_uring_op {
+        pub IORING_OP_NOP,
+        pub IORING_OP_READV,
+        pub IORING_OP_WRITEV,
+        pub IORING_OP_FSYNC,
+        pub IORING_OP_READ_FIXED,
+        pub IORING_OP_WRITE_FIXED,
+        pub IORING_OP_POLL_ADD,
+        pub IORING_OP_POLL_REMOVE,
+        pub IORING_OP_SYNC_FILE_RANGE,
+        pub IORING_OP_SENDMSG,
+        pub IORING_OP_RECVMSG,
+        pub IORING_OP_TIMEOUT,
+        pub IORING_OP_TIMEOUT_REMOVE,
+        pub IORING_OP_ACCEPT,
+        pub IORING_OP_ASYNC_CANCEL,
+        pub IORING_OP_LINK_TIMEOUT,
+        pub IORING_OP_CONNECT,
+        pub IORING_OP_FALLOCATE,
+        pub IORING_OP_OPENAT,
+        pub IORING_OP_CLOSE,
+        pub IORING_OP_FILES_UPDATE,
+        pub IORING_OP_STATX,
+        pub IORING_OP_READ,
+        pub IORING_OP_WRITE,
+        pub IORING_OP_FADVISE,
+        pub IORING_OP_MADVISE,
+        pub IORING_OP_SEND,
+        pub IORING_OP_RECV,
+        pub IORING_OP_OPENAT2,
+        pub IORING_OP_EPOLL_CTL,
+        pub IORING_OP_SPLICE,
+        pub IORING_OP_PROVIDE_BUFFERS,
+        pub IORING_OP_REMOVE_BUFFERS,
+        pub IORING_OP_TEE,
+        pub IORING_OP_SHUTDOWN,
+        pub IORING_OP_RENAMEAT,
+        pub IORING_OP_UNLINKAT,
+        pub IORING_OP_MKDIRAT,
+        pub IORING_OP_SYMLINKAT,
+        pub IORING_OP_LINKAT,
+        pub IORING_OP_MSG_RING,
+        pub IORING_OP_FSETXATTR,
+        pub IORING_OP_SETXATTR,
+        pub IORING_OP_FGETXATTR,
+        pub IORING_OP_GETXATTR,
+        pub IORING_OP_SOCKET,
+        pub IORING_OP_URING_CMD,
+        pub IORING_OP_SEND_ZC,
+        pub IORING_OP_SENDMSG_ZC,
+        pub IORING_OP_READ_MULTISHOT,
+        pub IORING_OP_WAITID,
+        pub IORING_OP_FUTEX_WAIT,
+        pub IORING_OP_FUTEX_WAKE,
+        pub IORING_OP_FUTEX_WAITV,
+        pub IORING_OP_FIXED_FD_INSTALL,
+        pub IORING_OP_FTRUNCATE,
+        pub IORING_OP_BIND,
+        pub IORING_OP_LISTEN,
+    }
+}
+
+pub const IORING_URING_CMD_FIXED: c_uint = 1 << 0;
+
+pub const IORING_FSYNC_DATASYNC: c_uint = 1 << 0;
+
+pub const IORING_TIMEOUT_ABS: c_uint = 1 << 0;
+pub const IORING_TIMEOUT_UPDATE: c_uint = 1 << 1;
+pub const IORING_TIMEOUT_BOOTTIME: c_uint = 1 << 2;
+pub const IORING_TIMEOUT_REALTIME: c_uint = 1 << 3;
+pub const IORING_LINK_TIMEOUT_UPDATE: c_uint = 1 << 4;
+pub const IORING_TIMEOUT_ETIME_SUCCESS: c_uint = 1 << 5;
+pub const IORING_TIMEOUT_MULTISHOT: c_uint = 1 << 6;
+pub const IORING_TIMEOUT_CLOCK_MASK: c_uint = IORING_TIMEOUT_BOOTTIME | IORING_TIMEOUT_REALTIME;
+pub const IORING_TIMEOUT_UPDATE_MASK: c_uint = IORING_TIMEOUT_UPDATE | IORING_LINK_TIMEOUT_UPDATE;
+
+pub const SPLICE_F_FD_IN_FIXED: c_uint = 1 << 31;
+
+pub const IORING_POLL_ADD_MULTI: c_uint = 1 << 0;
+pub const IORING_POLL_UPDATE_EVENTS: c_uint = 1 << 1;
+pub const IORING_POLL_UPDATE_USER_DATA: c_uint = 1 << 2;
+pub const IORING_POLL_ADD_LEVEL: c_uint = 1 << 3;
+
+pub const IORING_ASYNC_CANCEL_ALL: c_uint = 1 << 0;
+pub const IORING_ASYNC_CANCEL_FD: c_uint = 1 << 1;
+pub const IORING_ASYNC_CANCEL_ANY: c_uint = 1 << 2;
+pub const IORING_ASYNC_CANCEL_FD_FIXED: c_uint = 1 << 3;
+pub const IORING_ASYNC_CANCEL_USERDATA: c_uint = 1 << 4;
+pub const IORING_ASYNC_CANCEL_OP: c_uint = 1 << 5;
+
+pub const IORING_RECVSEND_POLL_FIRST: c_uint = 1 << 0;
+pub const IORING_RECV_MULTISHOT: c_uint = 1 << 1;
+pub const IORING_RECVSEND_FIXED_BUF: c_uint = 1 << 2;
+pub const IORING_SEND_ZC_REPORT_USAGE: c_uint = 1 << 3;
+pub const IORING_RECVSEND_BUNDLE: c_uint = 1 << 4;
+
+pub const IORING_NOTIF_USAGE_ZC_COPIED: c_uint = 1 << 31;
+
+pub const IORING_ACCEPT_MULTISHOT: c_uint = 1 << 0;
+pub const IORING_ACCEPT_DONTWAIT: c_uint = 1 << 1;
+pub const IORING_ACCEPT_POLL_FIRST: c_uint = 1 << 2;
+
+c_enum! {
+    pub enum io_uring_msg_ring_flags {
+        pub IORING_MSG_DATA,
+        pub IORING_MSG_SEND_FD,
+    }
+}
+
+pub const IORING_MSG_RING_CQE_SKIP: c_uint = 1 << 0;
+pub const IORING_MSG_RING_FLAGS_PASS: c_uint = 1 << 1;
+
+pub const IORING_FIXED_FD_NO_CLOEXEC: c_uint = 1 << 0;
+
+s! {
+    pub struct io_uring_cqe {
+        pub user_data: crate::__u64,
+        pub res: crate::__s32,
+        pub flags: crate::__u32,
+        pub big_cqe: [crate::__u64; 0],
+    }
+}
+
+pub const IORING_CQE_F_BUFFER: c_uint = 1 << 0;
+pub const IORING_CQE_F_MORE: c_uint = 1 << 1;
+pub const IORING_CQE_F_SOCK_NONEMPTY: c_uint = 1 << 2;
+pub const IORING_CQE_F_NOTIF: c_uint = 1 << 3;
+pub const IORING_CQE_F_BUF_MORE: c_uint = 1 << 4;
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub IORING_CQE_BUFFER_SHIFT = 16,
+    }
+}
+
+pub const IORING_OFF_SQ_RING: u64 = 0;
+pub const IORING_OFF_CQ_RING: u64 = 0x8000000;
+pub const IORING_OFF_SQES: u64 = 0x10000000;
+pub const IORING_OFF_PBUF_RING: u64 = 0x80000000;
+pub const IORING_OFF_PBUF_SHIFT: c_int = 16;
+pub const IORING_OFF_MMAP_MASK: u64 = 0xf8000000;
+
+s! {
+    pub struct io_sqring_offsets {
+        pub head: crate::__u32,
+        pub tail: crate::__u32,
+        pub ring_mask: crate::__u32,
+        pub ring_entries: crate::__u32,
+        pub flags: crate::__u32,
+        pub dropped: crate::__u32,
+        pub array: crate::__u32,
+        pub resv1: crate::__u32,
+        pub user_addr: crate::__u64,
+    }
+}
+
+pub const IORING_SQ_NEED_WAKEUP: c_uint = 1 << 0;
+pub const IORING_SQ_CQ_OVERFLOW: c_uint = 1 << 1;
+pub const IORING_SQ_TASKRUN: c_uint = 1 << 2;
+
+s! {
+    pub struct io_cqring_offsets {
+        pub head: crate::__u32,
+        pub tail: crate::__u32,
+        pub ring_mask: crate::__u32,
+        pub ring_entries: crate::__u32,
+        pub overflow: crate::__u32,
+        pub cqes: crate::__u32,
+        pub flags: crate::__u32,
+        pub resv1: crate::__u32,
+        pub user_addr: crate::__u64,
+    }
+}
+
+pub const IORING_CQ_EVENTFD_DISABLED: c_uint = 1 << 0;
+
+pub const IORING_ENTER_GETEVENTS: c_uint = 1 << 0;
+pub const IORING_ENTER_SQ_WAKEUP: c_uint = 1 << 1;
+pub const IORING_ENTER_SQ_WAIT: c_uint = 1 << 2;
+pub const IORING_ENTER_EXT_ARG: c_uint = 1 << 3;
+pub const IORING_ENTER_REGISTERED_RING: c_uint = 1 << 4;
+pub const IORING_ENTER_ABS_TIMER: c_uint = 1 << 5;
+
+s! {
+    pub struct io_uring_params {
+        pub sq_entries: crate::__u32,
+        pub cq_entries: crate::__u32,
+        pub flags: crate::__u32,
+        pub sq_thread_cpu: crate::__u32,
+        pub sq_thread_idle: crate::__u32,
+        pub features: crate::__u32,
+        pub wq_fd: crate::__u32,
+        pub resv: [crate::__u32; 3],
+        pub sq_off: io_sqring_offsets,
+        pub cq_off: io_cqring_offsets,
+    }
+}
+
+pub const IORING_FEAT_SINGLE_MMAP: c_uint = 1 << 0;
+pub const IORING_FEAT_NODROP: c_uint = 1 << 1;
+pub const IORING_FEAT_SUBMIT_STABLE: c_uint = 1 << 2;
+pub const IORING_FEAT_RW_CUR_POS: c_uint = 1 << 3;
+pub const IORING_FEAT_CUR_PERSONALITY: c_uint = 1 << 4;
+pub const IORING_FEAT_FAST_POLL: c_uint = 1 << 5;
+pub const IORING_FEAT_POLL_32BITS: c_uint = 1 << 6;
+pub const IORING_FEAT_SQPOLL_NONFIXED: c_uint = 1 << 7;
+pub const IORING_FEAT_EXT_ARG: c_uint = 1 << 8;
+pub const IORING_FEAT_NATIVE_WORKERS: c_uint = 1 << 9;
+pub const IORING_FEAT_RSRC_TAGS: c_uint = 1 << 10;
+pub const IORING_FEAT_CQE_SKIP: c_uint = 1 << 11;
+pub const IORING_FEAT_LINKED_FILE: c_uint = 1 << 12;
+pub const IORING_FEAT_REG_REG_RING: c_uint = 1 << 13;
+pub const IORING_FEAT_RECVSEND_BUNDLE: c_uint = 1 << 14;
+pub const IORING_FEAT_MIN_TIMEOUT: c_uint = 1 << 15;
+
+c_enum! {
+    pub enum io_uring_register_op {
+        pub IORING_REGISTER_BUFFERS = 0,
+        pub IORING_UNREGISTER_BUFFERS = 1,
+        pub IORING_REGISTER_FILES = 2,
+        pub IORING_UNREGISTER_FILES = 3,
+        pub IORING_REGISTER_EVENTFD = 4,
+        pub IORING_UNREGISTER_EVENTFD = 5,
+        pub IORING_REGISTER_FILES_UPDATE = 6,
+        pub IORING_REGISTER_EVENTFD_ASYNC = 7,
+        pub IORING_REGISTER_PROBE = 8,
+        pub IORING_REGISTER_PERSONALITY = 9,
+        pub IORING_UNREGISTER_PERSONALITY = 10,
+        pub IORING_REGISTER_RESTRICTIONS = 11,
+        pub IORING_REGISTER_ENABLE_RINGS = 12,
+        pub IORING_REGISTER_FILES2 = 13,
+        pub IORING_REGISTER_FILES_UPDATE2 = 14,
+        pub IORING_REGISTER_BUFFERS2 = 15,
+        pub IORING_REGISTER_BUFFERS_UPDATE = 16,
+        pub IORING_REGISTER_IOWQ_AFF = 17,
+        pub IORING_UNREGISTER_IOWQ_AFF = 18,
+        pub IORING_REGISTER_IOWQ_MAX_WORKERS = 19,
+        pub IORING_REGISTER_RING_FDS = 20,
+        pub IORING_UNREGISTER_RING_FDS = 21,
+        pub IORING_REGISTER_PBUF_RING = 22,
+        pub IORING_UNREGISTER_PBUF_RING = 23,
+        pub IORING_REGISTER_SYNC_CANCEL = 24,
+        pub IORING_REGISTER_FILE_ALLOC_RANGE = 25,
+        pub IORING_REGISTER_PBUF_STATUS = 26,
+        pub IORING_REGISTER_NAPI = 27,
+        pub IORING_UNREGISTER_NAPI = 28,
+        pub IORING_REGISTER_CLOCK = 29,
+        pub IORING_REGISTER_CLONE_BUFFERS = 30,
+
+        pub IORING_REGISTER_USE_REGISTERED_RING = 1 << 31,
+    }
+}
+
+c_enum! {
+    pub enum io_wq_type {
+        pub IO_WQ_BOUND,
+        pub IO_WQ_UNBOUND,
+    }
+}
+
+s! {
+    #[repr(align(8))]
+    pub struct io_uring_files_update {
+        pub offset: crate::__u32,
+        pub resv: crate::__u32,
+        pub fds: crate::__u64,
+    }
+}
+
+pub const IORING_RSRC_REGISTER_SPARSE: c_uint = 1 << 0;
+
+s! {
+    #[repr(align(8))]
+    pub struct io_uring_rsrc_register {
+        pub nr: crate::__u32,
+        pub flags: crate::__u32,
+        pub resv2: crate::__u64,
+        pub data: crate::__u64,
+        pub tags: crate::__u64,
+    }
+
+    #[repr(align(8))]
+    pub struct io_uring_rsrc_update {
+        pub offset: crate::__u32,
+        pub resv: crate::__u32,
+        pub data: crate::__u64,
+    }
+
+    #[repr(align(8))]
+    pub struct io_uring_rsrc_update2 {
+        pub offset: crate::__u32,
+        pub resv: crate::__u32,
+        pub data: crate::__u64,
+        pub tags: crate::__u64,
+        pub nr: crate::__u32,
+        pub resv2: crate::__u32,
+    }
+}
+
+pub const IORING_REGISTER_FILES_SKIP: c_int = -2;
+
+pub const IO_URING_OP_SUPPORTED: c_uint = 1 << 0;
+
+s! {
+    pub struct io_uring_probe_op {
+        pub op: crate::__u8,
+        pub resv: crate::__u8,
+        pub flags: crate::__u16,
+        pub resv2: crate::__u32,
+    }
+
+    pub struct io_uring_probe {
+        pub last_op: crate::__u8,
+        pub ops_len: crate::__u8,
+        pub resv: crate::__u16,
+        pub resv2: [crate::__u32; 3],
+        pub ops: [io_uring_probe_op; 0],
+    }
+}
+
+s_no_extra_traits! {
+    pub struct io_uring_restriction {
+        pub opcode: crate::__u16,
+        pub anonymous_1: __c_anonymous_io_uring_restriction_1,
+        pub resv: crate::__u8,
+        pub resv2: [crate::__u32; 3],
+    }
+
+    pub union __c_anonymous_io_uring_restriction_1 {
+        pub register_op: crate::__u8,
+        pub sqe_op: crate::__u8,
+        pub sqe_flags: crate::__u8,
+    }
+}
+
+s! {
+    pub struct io_uring_buf {
+        pub addr: crate::__u64,
+        pub len: crate::__u32,
+        pub bid: crate::__u16,
+        pub resv: crate::__u16,
+    }
+}
+
+s_no_extra_traits! {
+    pub struct io_uring_buf_ring {
+        pub anonymous_1: __c_anonymous_io_uring_buf_ring_1,
+    }
+
+    pub union __c_anonymous_io_uring_buf_ring_1 {
+        pub tail: __c_anonymous_io_uring_buf_ring_tail,
+        pub bufs: [io_uring_buf; 0],
+    }
+
+    pub struct __c_anonymous_io_uring_buf_ring_tail {
+        pub resv1: crate::__u64,
+        pub resv2: crate::__u32,
+        pub resv3: crate::__u16,
+        pub tail: crate::__u16,
+    }
+}
+
+pub const IOU_PBUF_RING_MMAP: c_uint = 1;
+pub const IOU_PBUF_RING_INC: c_uint = 2;
+
+s! {
+    pub struct io_uring_buf_reg {
+        pub ring_addr: crate::__u64,
+        pub ring_entries: crate::__u32,
+        pub bgid: crate::__u16,
+        pub flags: crate::__u16,
+        pub resv: [crate::__u64; 3],
+    }
+
+    pub struct io_uring_buf_status {
+        pub buf_group: crate::__u32,
+        pub head: crate::__u32,
+        pub resv: [crate::__u32; 8],
+    }
+}
+
+c_enum! {
+    pub enum io_uring_register_restriction_op {
+        pub IORING_RESTRICTION_REGISTER_OP = 0,
+        pub IORING_RESTRICTION_SQE_OP = 1,
+        pub IORING_RESTRICTION_SQE_FLAGS_ALLOWED = 2,
+        pub IORING_RESTRICTION_SQE_FLAGS_REQUIRED = 3,
+    }
+}
+
+s! {
+    pub struct io_uring_getevents_arg {
+        pub sigmask: crate::__u64,
+        pub sigmask_sz: crate::__u32,
+        pub min_wait_usec: crate::__u32,
+        pub ts: crate::__u64,
+    }
+
+    pub struct io_uring_sync_cancel_reg {
+        pub addr: crate::__u64,
+        pub fd: crate::__s32,
+        pub flags: crate::__u32,
+        pub timeout: crate::__kernel_timespec,
+        pub opcode: crate::__u8,
+        pub pad: [crate::__u8; 7],
+        pub pad2: [crate::__u64; 3],
+    }
+
+    pub struct io_uring_file_index_range {
+        pub off: crate::__u32,
+        pub len: crate::__u32,
+        pub resv: crate::__u64,
+    }
+
+    pub struct io_uring_recvmsg_out {
+        pub namelen: crate::__u32,
+        pub controllen: crate::__u32,
+        pub payloadlen: crate::__u32,
+        pub flags: crate::__u32,
+    }
+}
+
+c_enum! {
+    pub enum io_uring_socket_op {
+        pub SOCKET_URING_OP_SIOCINQ = 0,
+        pub SOCKET_URING_OP_SIOCOUTQ,
+        pub SOCKET_URING_OP_GETSOCKOPT,
+        pub SOCKET_URING_OP_SETSOCKOPT,
+    }
+}
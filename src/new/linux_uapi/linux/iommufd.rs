@@ -0,0 +1,219 @@
+//! Header: `uapi/linux/iommufd.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IO,
+};
+
+pub const IOMMUFD_TYPE: c_uint = b';' as c_uint;
+
+/*
+ * General iommufd ioctl commands. Each command uses the ioctl interface with
+ * the same number as IOMMUFD_CMD_* and the first __u32 of the argument
+ * struct is the size of the struct, to allow it to be extended.
+ */
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub IOMMUFD_CMD_BASE = 0x80,
+        pub IOMMUFD_CMD_DESTROY = IOMMUFD_CMD_BASE,
+        pub IOMMUFD_CMD_IOAS_ALLOC,
+        pub IOMMUFD_CMD_IOAS_ALLOW_IOVAS,
+        pub IOMMUFD_CMD_IOAS_COPY,
+        pub IOMMUFD_CMD_IOAS_IOVA_RANGES,
+        pub IOMMUFD_CMD_IOAS_MAP,
+        pub IOMMUFD_CMD_IOAS_UNMAP,
+        pub IOMMUFD_CMD_OPTION,
+        pub IOMMUFD_CMD_VFIO_IOAS,
+    }
+}
+
+s! {
+    /*
+     * struct iommu_destroy - ioctl(IOMMU_DESTROY)
+     *
+     * Destroy any object held within iommufd.
+     */
+    pub struct iommu_destroy {
+        pub size: crate::__u32,
+        pub id: crate::__u32,
+    }
+
+    /*
+     * struct iommu_ioas_alloc - ioctl(IOMMU_IOAS_ALLOC)
+     *
+     * Allocate an IO Address Space (IOAS) which holds an IO Virtual Address
+     * (IOVA) to memory mapping.
+     */
+    pub struct iommu_ioas_alloc {
+        pub size: crate::__u32,
+        pub flags: crate::__u32,
+        pub out_ioas_id: crate::__u32,
+    }
+
+    /*
+     * struct iommu_iova_range - ioctl(IOMMU_IOVA_RANGE)
+     *
+     * An interval in IOVA space.
+     */
+    #[repr(align(8))]
+    pub struct iommu_iova_range {
+        pub start: crate::__u64,
+        pub last: crate::__u64,
+    }
+
+    /*
+     * struct iommu_ioas_iova_ranges - ioctl(IOMMU_IOAS_IOVA_RANGES)
+     *
+     * Query an IOAS for ranges of allowed IOVAs. Mapping IOVA outside these
+     * ranges is not allowed. `allowed_iovas` points to an array of
+     * `num_iovas` `struct iommu_iova_range`.
+     */
+    #[repr(align(8))]
+    pub struct iommu_ioas_iova_ranges {
+        pub size: crate::__u32,
+        pub ioas_id: crate::__u32,
+        pub num_iovas: crate::__u32,
+        pub __reserved: crate::__u32,
+        pub allowed_iovas: crate::__u64,
+        pub out_iova_alignment: crate::__u64,
+    }
+
+    /*
+     * struct iommu_ioas_allow_iovas - ioctl(IOMMU_IOAS_ALLOW_IOVAS)
+     *
+     * Ensure a range of IOVAs are always available for allocation.
+     */
+    #[repr(align(8))]
+    pub struct iommu_ioas_allow_iovas {
+        pub size: crate::__u32,
+        pub ioas_id: crate::__u32,
+        pub num_iovas: crate::__u32,
+        pub __reserved: crate::__u32,
+        pub allowed_iovas: crate::__u64,
+    }
+}
+
+c_enum! {
+    pub enum iommufd_ioas_map_flags {
+        pub IOMMU_IOAS_MAP_FIXED_IOVA = 1 << 0,
+        pub IOMMU_IOAS_MAP_WRITEABLE = 1 << 1,
+        pub IOMMU_IOAS_MAP_READABLE = 1 << 2,
+    }
+}
+
+s! {
+    /*
+     * struct iommu_ioas_map - ioctl(IOMMU_IOAS_MAP)
+     *
+     * Set an IOVA mapping from a user pointer.
+     */
+    #[repr(align(8))]
+    pub struct iommu_ioas_map {
+        pub size: crate::__u32,
+        pub flags: crate::__u32,
+        pub ioas_id: crate::__u32,
+        pub __reserved: crate::__u32,
+        pub user_va: crate::__u64,
+        pub length: crate::__u64,
+        pub iova: crate::__u64,
+    }
+
+    /*
+     * struct iommu_ioas_copy - ioctl(IOMMU_IOAS_COPY)
+     *
+     * Copy an already existing mapping from `src_ioas_id` and establish it
+     * in `dst_ioas_id`.
+     */
+    #[repr(align(8))]
+    pub struct iommu_ioas_copy {
+        pub size: crate::__u32,
+        pub flags: crate::__u32,
+        pub dst_ioas_id: crate::__u32,
+        pub src_ioas_id: crate::__u32,
+        pub length: crate::__u64,
+        pub dst_iova: crate::__u64,
+        pub src_iova: crate::__u64,
+    }
+
+    /*
+     * struct iommu_ioas_unmap - ioctl(IOMMU_IOAS_UNMAP)
+     *
+     * Unmap an IOVA range. The iova/length must be a superset of a previously
+     * mapped range used with IOMMU_IOAS_MAP or IOMMU_IOAS_COPY.
+     */
+    #[repr(align(8))]
+    pub struct iommu_ioas_unmap {
+        pub size: crate::__u32,
+        pub ioas_id: crate::__u32,
+        pub iova: crate::__u64,
+        pub length: crate::__u64,
+    }
+}
+
+c_enum! {
+    pub enum iommufd_option {
+        pub IOMMU_OPTION_RLIMIT_MODE = 0,
+        pub IOMMU_OPTION_HUGE_PAGES = 1,
+    }
+
+    pub enum iommufd_option_ops {
+        pub IOMMU_OPTION_OP_SET = 0,
+        pub IOMMU_OPTION_OP_GET = 1,
+    }
+}
+
+s! {
+    /*
+     * struct iommu_option - iommu option multiplexer
+     *
+     * Change a simple option value. This multiplexor allows controlling
+     * options on objects. IOMMU_OPTION_OP_SET will load an option and
+     * IOMMU_OPTION_OP_GET will return the current value.
+     */
+    #[repr(align(8))]
+    pub struct iommu_option {
+        pub size: crate::__u32,
+        pub option_id: crate::__u32,
+        pub op: crate::__u16,
+        pub __reserved: crate::__u16,
+        pub object_id: crate::__u32,
+        pub val64: crate::__u64,
+    }
+}
+
+c_enum! {
+    pub enum iommufd_vfio_ioas_op {
+        pub IOMMU_VFIO_IOAS_GET,
+        pub IOMMU_VFIO_IOAS_SET,
+        pub IOMMU_VFIO_IOAS_CLEAR,
+    }
+}
+
+s! {
+    /*
+     * struct iommu_vfio_ioas - ioctl(IOMMU_VFIO_IOAS)
+     *
+     * The VFIO compatibility support uses a single ioas because VFIO APIs do
+     * not support the ID field. Set or Get the IOAS that VFIO compatibility
+     * will use. When VFIO_GROUP_SET_CONTAINER is used on an iommufd it will
+     * select the compatibility ioas.
+     */
+    pub struct iommu_vfio_ioas {
+        pub size: crate::__u32,
+        pub ioas_id: crate::__u32,
+        pub op: crate::__u16,
+        pub __reserved: crate::__u16,
+    }
+}
+
+pub const IOMMU_DESTROY: Ioctl = _IO(IOMMUFD_TYPE, IOMMUFD_CMD_DESTROY);
+pub const IOMMU_IOAS_ALLOC: Ioctl = _IO(IOMMUFD_TYPE, IOMMUFD_CMD_IOAS_ALLOC);
+pub const IOMMU_IOAS_ALLOW_IOVAS: Ioctl = _IO(IOMMUFD_TYPE, IOMMUFD_CMD_IOAS_ALLOW_IOVAS);
+pub const IOMMU_IOAS_COPY: Ioctl = _IO(IOMMUFD_TYPE, IOMMUFD_CMD_IOAS_COPY);
+pub const IOMMU_IOAS_IOVA_RANGES: Ioctl = _IO(IOMMUFD_TYPE, IOMMUFD_CMD_IOAS_IOVA_RANGES);
+pub const IOMMU_IOAS_MAP: Ioctl = _IO(IOMMUFD_TYPE, IOMMUFD_CMD_IOAS_MAP);
+pub const IOMMU_IOAS_UNMAP: Ioctl = _IO(IOMMUFD_TYPE, IOMMUFD_CMD_IOAS_UNMAP);
+pub const IOMMU_OPTION: Ioctl = _IO(IOMMUFD_TYPE, IOMMUFD_CMD_OPTION);
+pub const IOMMU_VFIO_IOAS: Ioctl = _IO(IOMMUFD_TYPE, IOMMUFD_CMD_VFIO_IOAS);
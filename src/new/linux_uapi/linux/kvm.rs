@@ -0,0 +1,1234 @@
+//! Header: `uapi/linux/kvm.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IO,
+    _IOR,
+    _IOW,
+    _IOWR,
+};
+
+cfg_if! {
+    if #[cfg(target_arch = "x86_64")] {
+        mod x86;
+        pub use self::x86::*;
+    } else if #[cfg(target_arch = "aarch64")] {
+        mod arm64;
+        pub use self::arm64::*;
+    } else if #[cfg(target_arch = "riscv64")] {
+        mod riscv;
+        pub use self::riscv::*;
+    } else if #[cfg(target_arch = "s390x")] {
+        mod s390;
+        pub use self::s390::*;
+    }
+}
+
+pub const KVM_API_VERSION: c_int = 12;
+
+s! {
+    /* for KVM_SET_USER_MEMORY_REGION */
+    pub struct kvm_userspace_memory_region {
+        pub slot: crate::__u32,
+        pub flags: crate::__u32,
+        pub guest_phys_addr: crate::__u64,
+        /* bytes */
+        pub memory_size: crate::__u64,
+        /* start of the userspace allocated memory */
+        pub userspace_addr: crate::__u64,
+    }
+
+    /* for KVM_SET_USER_MEMORY_REGION2 */
+    pub struct kvm_userspace_memory_region2 {
+        pub slot: crate::__u32,
+        pub flags: crate::__u32,
+        pub guest_phys_addr: crate::__u64,
+        pub memory_size: crate::__u64,
+        pub userspace_addr: crate::__u64,
+        pub guest_memfd_offset: crate::__u64,
+        pub guest_memfd: crate::__u32,
+        pub pad1: crate::__u32,
+        pub pad2: [crate::__u64; 14],
+    }
+}
+
+/*
+ * The bit 0 ~ bit 15 of kvm_userspace_memory_region::flags are visible for
+ * userspace, other bits are reserved for kvm internal use which are defined
+ * in include/linux/kvm_host.h.
+ */
+pub const KVM_MEM_LOG_DIRTY_PAGES: c_ulong = 1 << 0;
+pub const KVM_MEM_READONLY: c_ulong = 1 << 1;
+pub const KVM_MEM_GUEST_MEMFD: c_ulong = 1 << 2;
+
+s_no_extra_traits! {
+    pub union __c_anonymous_kvm_irq_level_1 {
+        pub irq: crate::__u32,
+        pub status: crate::__s32,
+    }
+
+    /* for KVM_IRQ_LINE */
+    pub struct kvm_irq_level {
+        /*
+         * ACPI gsi notion of irq.
+         * For IA-64 (APIC model) IOAPIC0: irq 0-23; IOAPIC1: irq 24-47..
+         * For X86 (standard AT mode) PIC0/1: irq 0-15. IOAPIC0: 0-23..
+         * For ARM: See Documentation/virt/kvm/api.rst
+         */
+        pub anonymous_1: __c_anonymous_kvm_irq_level_1,
+        pub level: crate::__u32,
+    }
+}
+
+pub const KVM_EXIT_HYPERV_SYNIC: c_int = 1;
+pub const KVM_EXIT_HYPERV_HCALL: c_int = 2;
+pub const KVM_EXIT_HYPERV_SYNDBG: c_int = 3;
+
+s! {
+    pub struct __c_anonymous_kvm_hyperv_exit_synic {
+        pub msr: crate::__u32,
+        pub pad2: crate::__u32,
+        pub control: crate::__u64,
+        pub evt_page: crate::__u64,
+        pub msg_page: crate::__u64,
+    }
+
+    pub struct __c_anonymous_kvm_hyperv_exit_hcall {
+        pub input: crate::__u64,
+        pub result: crate::__u64,
+        pub params: [crate::__u64; 2],
+    }
+
+    pub struct __c_anonymous_kvm_hyperv_exit_syndbg {
+        pub msr: crate::__u32,
+        pub pad2: crate::__u32,
+        pub control: crate::__u64,
+        pub status: crate::__u64,
+        pub send_page: crate::__u64,
+        pub recv_page: crate::__u64,
+        pub pending_page: crate::__u64,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_kvm_hyperv_exit_u {
+        pub synic: __c_anonymous_kvm_hyperv_exit_synic,
+        pub hcall: __c_anonymous_kvm_hyperv_exit_hcall,
+        pub syndbg: __c_anonymous_kvm_hyperv_exit_syndbg,
+    }
+
+    pub struct kvm_hyperv_exit {
+        pub type_: crate::__u32,
+        pub pad1: crate::__u32,
+        pub u: __c_anonymous_kvm_hyperv_exit_u,
+    }
+}
+
+pub const KVM_EXIT_XEN_HCALL: c_int = 1;
+
+s! {
+    pub struct __c_anonymous_kvm_xen_exit_hcall {
+        pub longmode: crate::__u32,
+        pub cpl: crate::__u32,
+        pub input: crate::__u64,
+        pub result: crate::__u64,
+        pub params: [crate::__u64; 6],
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_kvm_xen_exit_u {
+        pub hcall: __c_anonymous_kvm_xen_exit_hcall,
+    }
+
+    pub struct kvm_xen_exit {
+        pub type_: crate::__u32,
+        pub u: __c_anonymous_kvm_xen_exit_u,
+    }
+}
+
+pub const KVM_EXIT_UNKNOWN: c_int = 0;
+pub const KVM_EXIT_EXCEPTION: c_int = 1;
+pub const KVM_EXIT_IO: c_int = 2;
+pub const KVM_EXIT_HYPERCALL: c_int = 3;
+pub const KVM_EXIT_DEBUG: c_int = 4;
+pub const KVM_EXIT_HLT: c_int = 5;
+pub const KVM_EXIT_MMIO: c_int = 6;
+pub const KVM_EXIT_IRQ_WINDOW_OPEN: c_int = 7;
+pub const KVM_EXIT_SHUTDOWN: c_int = 8;
+pub const KVM_EXIT_FAIL_ENTRY: c_int = 9;
+pub const KVM_EXIT_INTR: c_int = 10;
+pub const KVM_EXIT_SET_TPR: c_int = 11;
+pub const KVM_EXIT_TPR_ACCESS: c_int = 12;
+pub const KVM_EXIT_S390_SIEIC: c_int = 13;
+pub const KVM_EXIT_S390_RESET: c_int = 14;
+/* deprecated */
+pub const KVM_EXIT_DCR: c_int = 15;
+pub const KVM_EXIT_NMI: c_int = 16;
+pub const KVM_EXIT_INTERNAL_ERROR: c_int = 17;
+pub const KVM_EXIT_OSI: c_int = 18;
+pub const KVM_EXIT_PAPR_HCALL: c_int = 19;
+pub const KVM_EXIT_S390_UCONTROL: c_int = 20;
+pub const KVM_EXIT_WATCHDOG: c_int = 21;
+pub const KVM_EXIT_S390_TSCH: c_int = 22;
+pub const KVM_EXIT_EPR: c_int = 23;
+pub const KVM_EXIT_SYSTEM_EVENT: c_int = 24;
+pub const KVM_EXIT_S390_STSI: c_int = 25;
+pub const KVM_EXIT_IOAPIC_EOI: c_int = 26;
+pub const KVM_EXIT_HYPERV: c_int = 27;
+pub const KVM_EXIT_ARM_NISV: c_int = 28;
+pub const KVM_EXIT_X86_RDMSR: c_int = 29;
+pub const KVM_EXIT_X86_WRMSR: c_int = 30;
+pub const KVM_EXIT_DIRTY_RING_FULL: c_int = 31;
+pub const KVM_EXIT_AP_RESET_HOLD: c_int = 32;
+pub const KVM_EXIT_X86_BUS_LOCK: c_int = 33;
+pub const KVM_EXIT_XEN: c_int = 34;
+pub const KVM_EXIT_RISCV_SBI: c_int = 35;
+pub const KVM_EXIT_RISCV_CSR: c_int = 36;
+pub const KVM_EXIT_NOTIFY: c_int = 37;
+
+/* For KVM_EXIT_INTERNAL_ERROR */
+/* Emulate instruction failed. */
+pub const KVM_INTERNAL_ERROR_EMULATION: c_int = 1;
+/* Encounter unexpected simultaneous exceptions. */
+pub const KVM_INTERNAL_ERROR_SIMUL_EX: c_int = 2;
+/* Encounter unexpected vm-exit due to delivery event. */
+pub const KVM_INTERNAL_ERROR_DELIVERY_EV: c_int = 3;
+/* Encounter unexpected vm-exit reason */
+pub const KVM_INTERNAL_ERROR_UNEXPECTED_EXIT_REASON: c_int = 4;
+
+/* Flags that describe what fields in emulation_failure hold valid data. */
+pub const KVM_INTERNAL_ERROR_EMULATION_FLAG_INSTRUCTION_BYTES: c_ulonglong = 1 << 0;
+
+/* KVM_EXIT_IO */
+pub const KVM_EXIT_IO_IN: c_int = 0;
+pub const KVM_EXIT_IO_OUT: c_int = 1;
+
+/* KVM_EXIT_S390_RESET */
+pub const KVM_S390_RESET_POR: c_int = 1;
+pub const KVM_S390_RESET_CLEAR: c_int = 2;
+pub const KVM_S390_RESET_SUBSYSTEM: c_int = 4;
+pub const KVM_S390_RESET_CPU_INIT: c_int = 8;
+pub const KVM_S390_RESET_IPL: c_int = 16;
+
+/* KVM_EXIT_SYSTEM_EVENT */
+pub const KVM_SYSTEM_EVENT_SHUTDOWN: c_int = 1;
+pub const KVM_SYSTEM_EVENT_RESET: c_int = 2;
+pub const KVM_SYSTEM_EVENT_CRASH: c_int = 3;
+pub const KVM_SYSTEM_EVENT_WAKEUP: c_int = 4;
+pub const KVM_SYSTEM_EVENT_SUSPEND: c_int = 5;
+pub const KVM_SYSTEM_EVENT_SEV_TERM: c_int = 6;
+
+/* KVM_EXIT_X86_RDMSR / KVM_EXIT_X86_WRMSR */
+pub const KVM_MSR_EXIT_REASON_INVAL: c_int = 1 << 0;
+pub const KVM_MSR_EXIT_REASON_UNKNOWN: c_int = 1 << 1;
+pub const KVM_MSR_EXIT_REASON_FILTER: c_int = 1 << 2;
+
+/* KVM_EXIT_NOTIFY */
+pub const KVM_NOTIFY_CONTEXT_INVALID: c_int = 1 << 0;
+
+/*
+ * 2048 is the size of the char array used to bound/pad the size of the
+ * union that holds sync regs.
+ */
+pub const SYNC_REGS_SIZE_BYTES: usize = 2048;
+
+s! {
+    /* KVM_EXIT_UNKNOWN */
+    pub struct __c_anonymous_kvm_run_hw {
+        pub hardware_exit_reason: crate::__u64,
+    }
+
+    /* KVM_EXIT_FAIL_ENTRY */
+    pub struct __c_anonymous_kvm_run_fail_entry {
+        pub hardware_entry_failure_reason: crate::__u64,
+        pub cpu: crate::__u32,
+    }
+
+    /* KVM_EXIT_EXCEPTION */
+    pub struct __c_anonymous_kvm_run_ex {
+        pub exception: crate::__u32,
+        pub error_code: crate::__u32,
+    }
+
+    /* KVM_EXIT_IO */
+    pub struct __c_anonymous_kvm_run_io {
+        pub direction: crate::__u8,
+        /* bytes */
+        pub size: crate::__u8,
+        pub port: crate::__u16,
+        pub count: crate::__u32,
+        /* relative to kvm_run start */
+        pub data_offset: crate::__u64,
+    }
+
+    /* KVM_EXIT_DEBUG */
+    pub struct __c_anonymous_kvm_run_debug {
+        pub arch: kvm_debug_exit_arch,
+    }
+
+    /* KVM_EXIT_MMIO */
+    pub struct __c_anonymous_kvm_run_mmio {
+        pub phys_addr: crate::__u64,
+        pub data: [crate::__u8; 8],
+        pub len: crate::__u32,
+        pub is_write: crate::__u8,
+    }
+
+    /* KVM_EXIT_HYPERCALL */
+    pub struct __c_anonymous_kvm_run_hypercall {
+        pub nr: crate::__u64,
+        pub args: [crate::__u64; 6],
+        pub ret: crate::__u64,
+        pub longmode: crate::__u32,
+        pub pad: crate::__u32,
+    }
+
+    /* KVM_EXIT_TPR_ACCESS */
+    pub struct __c_anonymous_kvm_run_tpr_access {
+        pub rip: crate::__u64,
+        pub is_write: crate::__u32,
+        pub pad: crate::__u32,
+    }
+
+    /* KVM_EXIT_S390_SIEIC */
+    pub struct __c_anonymous_kvm_run_s390_sieic {
+        pub icptcode: crate::__u8,
+        pub ipa: crate::__u16,
+        pub ipb: crate::__u32,
+    }
+
+    /* KVM_EXIT_S390_UCONTROL */
+    pub struct __c_anonymous_kvm_run_s390_ucontrol {
+        pub trans_exc_code: crate::__u64,
+        pub pgm_code: crate::__u32,
+    }
+
+    /* KVM_EXIT_DCR (deprecated) */
+    pub struct __c_anonymous_kvm_run_dcr {
+        pub dcrn: crate::__u32,
+        pub data: crate::__u32,
+        pub is_write: crate::__u8,
+    }
+
+    /* KVM_EXIT_INTERNAL_ERROR */
+    pub struct __c_anonymous_kvm_run_internal {
+        pub suberror: crate::__u32,
+        /* Available with KVM_CAP_INTERNAL_ERROR_DATA: */
+        pub ndata: crate::__u32,
+        pub data: [crate::__u64; 16],
+    }
+
+    /*
+     * KVM_INTERNAL_ERROR_EMULATION
+     *
+     * "struct emulation_failure" is an overlay of "struct internal" that is
+     * used for the KVM_INTERNAL_ERROR_EMULATION sub-type of
+     * KVM_EXIT_INTERNAL_ERROR. The instruction bytes live in a single-member
+     * anonymous union in C, which is flattened here.
+     */
+    pub struct __c_anonymous_kvm_run_emulation_failure {
+        pub suberror: crate::__u32,
+        pub ndata: crate::__u32,
+        pub flags: crate::__u64,
+        pub insn_size: crate::__u8,
+        pub insn_bytes: [crate::__u8; 15],
+    }
+
+    /* KVM_EXIT_OSI */
+    pub struct __c_anonymous_kvm_run_osi {
+        pub gprs: [crate::__u64; 32],
+    }
+
+    /* KVM_EXIT_PAPR_HCALL */
+    pub struct __c_anonymous_kvm_run_papr_hcall {
+        pub nr: crate::__u64,
+        pub ret: crate::__u64,
+        pub args: [crate::__u64; 9],
+    }
+
+    /* KVM_EXIT_S390_TSCH */
+    pub struct __c_anonymous_kvm_run_s390_tsch {
+        pub subchannel_id: crate::__u16,
+        pub subchannel_nr: crate::__u16,
+        pub io_int_parm: crate::__u32,
+        pub io_int_word: crate::__u32,
+        pub ipb: crate::__u32,
+        pub dequeued: crate::__u8,
+    }
+
+    /* KVM_EXIT_EPR */
+    pub struct __c_anonymous_kvm_run_epr {
+        pub epr: crate::__u32,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_kvm_run_system_event_1 {
+        pub flags: crate::__u64,
+        pub data: [crate::__u64; 16],
+    }
+
+    /* KVM_EXIT_SYSTEM_EVENT */
+    pub struct __c_anonymous_kvm_run_system_event {
+        pub type_: crate::__u32,
+        pub ndata: crate::__u32,
+        pub anonymous_1: __c_anonymous_kvm_run_system_event_1,
+    }
+}
+
+s! {
+    /* KVM_EXIT_S390_STSI */
+    pub struct __c_anonymous_kvm_run_s390_stsi {
+        pub addr: crate::__u64,
+        pub ar: crate::__u8,
+        pub reserved: crate::__u8,
+        pub fc: crate::__u8,
+        pub sel1: crate::__u8,
+        pub sel2: crate::__u16,
+    }
+
+    /* KVM_EXIT_IOAPIC_EOI */
+    pub struct __c_anonymous_kvm_run_eoi {
+        pub vector: crate::__u8,
+    }
+
+    /* KVM_EXIT_ARM_NISV */
+    pub struct __c_anonymous_kvm_run_arm_nisv {
+        pub esr_iss: crate::__u64,
+        pub fault_ipa: crate::__u64,
+    }
+
+    /* KVM_EXIT_X86_RDMSR / KVM_EXIT_X86_WRMSR */
+    pub struct __c_anonymous_kvm_run_msr {
+        /* user -> kernel */
+        pub error: crate::__u8,
+        pub pad: [crate::__u8; 7],
+        /* kernel -> user */
+        pub reason: crate::__u32,
+        /* kernel -> user */
+        pub index: crate::__u32,
+        /* kernel <-> user */
+        pub data: crate::__u64,
+    }
+
+    /* KVM_EXIT_RISCV_SBI */
+    pub struct __c_anonymous_kvm_run_riscv_sbi {
+        pub extension_id: c_ulong,
+        pub function_id: c_ulong,
+        pub args: [c_ulong; 6],
+        pub ret: [c_ulong; 2],
+    }
+
+    /* KVM_EXIT_RISCV_CSR */
+    pub struct __c_anonymous_kvm_run_riscv_csr {
+        pub csr_num: c_ulong,
+        pub new_value: c_ulong,
+        pub write_mask: c_ulong,
+        pub ret_value: c_ulong,
+    }
+
+    /* KVM_EXIT_NOTIFY */
+    pub struct __c_anonymous_kvm_run_notify {
+        pub flags: crate::__u32,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_kvm_run_1 {
+        pub hw: __c_anonymous_kvm_run_hw,
+        pub fail_entry: __c_anonymous_kvm_run_fail_entry,
+        pub ex: __c_anonymous_kvm_run_ex,
+        pub io: __c_anonymous_kvm_run_io,
+        pub debug: __c_anonymous_kvm_run_debug,
+        pub mmio: __c_anonymous_kvm_run_mmio,
+        pub hypercall: __c_anonymous_kvm_run_hypercall,
+        pub tpr_access: __c_anonymous_kvm_run_tpr_access,
+        pub s390_sieic: __c_anonymous_kvm_run_s390_sieic,
+        /* KVM_EXIT_S390_RESET */
+        pub s390_reset_flags: crate::__u64,
+        pub s390_ucontrol: __c_anonymous_kvm_run_s390_ucontrol,
+        pub dcr: __c_anonymous_kvm_run_dcr,
+        pub internal: __c_anonymous_kvm_run_internal,
+        pub emulation_failure: __c_anonymous_kvm_run_emulation_failure,
+        pub osi: __c_anonymous_kvm_run_osi,
+        pub papr_hcall: __c_anonymous_kvm_run_papr_hcall,
+        pub s390_tsch: __c_anonymous_kvm_run_s390_tsch,
+        pub epr: __c_anonymous_kvm_run_epr,
+        pub system_event: __c_anonymous_kvm_run_system_event,
+        pub s390_stsi: __c_anonymous_kvm_run_s390_stsi,
+        pub eoi: __c_anonymous_kvm_run_eoi,
+        /* KVM_EXIT_HYPERV */
+        pub hyperv: kvm_hyperv_exit,
+        pub arm_nisv: __c_anonymous_kvm_run_arm_nisv,
+        pub msr: __c_anonymous_kvm_run_msr,
+        /* KVM_EXIT_XEN */
+        pub xen: kvm_xen_exit,
+        pub riscv_sbi: __c_anonymous_kvm_run_riscv_sbi,
+        pub riscv_csr: __c_anonymous_kvm_run_riscv_csr,
+        pub notify: __c_anonymous_kvm_run_notify,
+        /* Fix the size of the union. */
+        pub padding: [c_char; 256],
+    }
+
+    /*
+     * The s390 register sync area is not described here; only its padding
+     * member is available on that architecture.
+     */
+    pub union __c_anonymous_kvm_run_s {
+        #[cfg(not(target_arch = "s390x"))]
+        pub regs: kvm_sync_regs,
+        pub padding: [c_char; SYNC_REGS_SIZE_BYTES],
+    }
+
+    /* for KVM_RUN, returned by mmap(vcpu_fd, offset=0) */
+    pub struct kvm_run {
+        /* in */
+        pub request_interrupt_window: crate::__u8,
+        pub immediate_exit: crate::__u8,
+        pub padding1: [crate::__u8; 6],
+
+        /* out */
+        pub exit_reason: crate::__u32,
+        pub ready_for_interrupt_injection: crate::__u8,
+        pub if_flag: crate::__u8,
+        pub flags: crate::__u16,
+
+        /* in (pre_kvm_run), out (post_kvm_run) */
+        pub cr8: crate::__u64,
+        pub apic_base: crate::__u64,
+
+        /* the processor status word for s390 */
+        #[cfg(target_arch = "s390x")]
+        pub psw_mask: crate::__u64,
+        #[cfg(target_arch = "s390x")]
+        pub psw_addr: crate::__u64,
+        pub anonymous_1: __c_anonymous_kvm_run_1,
+
+        /*
+         * shared registers between kvm and userspace.
+         * kvm_valid_regs specifies the register classes set by the host
+         * kvm_dirty_regs specified the register classes dirtied by userspace
+         * struct kvm_sync_regs is architecture specific, as well as the
+         * bits for kvm_valid_regs and kvm_dirty_regs
+         */
+        pub kvm_valid_regs: crate::__u64,
+        pub kvm_dirty_regs: crate::__u64,
+        pub s: __c_anonymous_kvm_run_s,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_kvm_coalesced_mmio_1 {
+        pub pad: crate::__u32,
+        pub pio: crate::__u32,
+    }
+
+    /* for KVM_REGISTER_COALESCED_MMIO / KVM_UNREGISTER_COALESCED_MMIO */
+    pub struct kvm_coalesced_mmio_zone {
+        pub addr: crate::__u64,
+        pub size: crate::__u32,
+        pub anonymous_1: __c_anonymous_kvm_coalesced_mmio_1,
+    }
+
+    pub struct kvm_coalesced_mmio {
+        pub phys_addr: crate::__u64,
+        pub len: crate::__u32,
+        pub anonymous_1: __c_anonymous_kvm_coalesced_mmio_1,
+        pub data: [crate::__u8; 8],
+    }
+
+    pub struct kvm_coalesced_mmio_ring {
+        pub first: crate::__u32,
+        pub last: crate::__u32,
+        pub coalesced_mmio: [kvm_coalesced_mmio; 0],
+    }
+}
+
+s! {
+    /* for KVM_TRANSLATE */
+    pub struct kvm_translation {
+        /* in */
+        pub linear_address: crate::__u64,
+
+        /* out */
+        pub physical_address: crate::__u64,
+        pub valid: crate::__u8,
+        pub writeable: crate::__u8,
+        pub usermode: crate::__u8,
+        pub pad: [crate::__u8; 5],
+    }
+
+    /* for KVM_INTERRUPT */
+    pub struct kvm_interrupt {
+        /* in */
+        pub irq: crate::__u32,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_kvm_dirty_log_1 {
+        /* one bit per page */
+        pub dirty_bitmap: *mut c_void,
+        pub padding2: crate::__u64,
+    }
+
+    /* for KVM_GET_DIRTY_LOG */
+    pub struct kvm_dirty_log {
+        pub slot: crate::__u32,
+        pub padding1: crate::__u32,
+        pub anonymous_1: __c_anonymous_kvm_dirty_log_1,
+    }
+
+    /* for KVM_CLEAR_DIRTY_LOG */
+    pub struct kvm_clear_dirty_log {
+        pub slot: crate::__u32,
+        pub num_pages: crate::__u32,
+        pub first_page: crate::__u64,
+        pub anonymous_1: __c_anonymous_kvm_dirty_log_1,
+    }
+}
+
+s! {
+    /* for KVM_SET_SIGNAL_MASK */
+    pub struct kvm_signal_mask {
+        pub len: crate::__u32,
+        pub sigset: [crate::__u8; 0],
+    }
+}
+
+/* for KVM_SET_MP_STATE */
+
+/* not all states are valid on all architectures */
+pub const KVM_MP_STATE_RUNNABLE: c_int = 0;
+pub const KVM_MP_STATE_UNINITIALIZED: c_int = 1;
+pub const KVM_MP_STATE_INIT_RECEIVED: c_int = 2;
+pub const KVM_MP_STATE_HALTED: c_int = 3;
+pub const KVM_MP_STATE_SIPI_RECEIVED: c_int = 4;
+pub const KVM_MP_STATE_STOPPED: c_int = 5;
+pub const KVM_MP_STATE_CHECK_STOP: c_int = 6;
+pub const KVM_MP_STATE_OPERATING: c_int = 7;
+pub const KVM_MP_STATE_LOAD: c_int = 8;
+pub const KVM_MP_STATE_AP_RESET_HOLD: c_int = 9;
+pub const KVM_MP_STATE_SUSPENDED: c_int = 10;
+
+s! {
+    pub struct kvm_mp_state {
+        pub mp_state: crate::__u32,
+    }
+}
+
+/* for KVM_SET_GUEST_DEBUG */
+
+pub const KVM_GUESTDBG_ENABLE: c_int = 0x00000001;
+pub const KVM_GUESTDBG_SINGLESTEP: c_int = 0x00000002;
+
+s! {
+    pub struct kvm_guest_debug {
+        pub control: crate::__u32,
+        pub pad: crate::__u32,
+        pub arch: kvm_guest_debug_arch,
+    }
+}
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub kvm_ioeventfd_flag_nr_datamatch,
+        pub kvm_ioeventfd_flag_nr_pio,
+        pub kvm_ioeventfd_flag_nr_deassign,
+        pub kvm_ioeventfd_flag_nr_virtio_ccw_notify,
+        pub kvm_ioeventfd_flag_nr_fast_mmio,
+        pub kvm_ioeventfd_flag_nr_max,
+    }
+}
+
+pub const KVM_IOEVENTFD_FLAG_DATAMATCH: c_int = 1 << kvm_ioeventfd_flag_nr_datamatch;
+pub const KVM_IOEVENTFD_FLAG_PIO: c_int = 1 << kvm_ioeventfd_flag_nr_pio;
+pub const KVM_IOEVENTFD_FLAG_DEASSIGN: c_int = 1 << kvm_ioeventfd_flag_nr_deassign;
+pub const KVM_IOEVENTFD_FLAG_VIRTIO_CCW_NOTIFY: c_int =
+    1 << kvm_ioeventfd_flag_nr_virtio_ccw_notify;
+
+pub const KVM_IOEVENTFD_VALID_FLAG_MASK: c_int = (1 << kvm_ioeventfd_flag_nr_max) - 1;
+
+s! {
+    pub struct kvm_ioeventfd {
+        pub datamatch: crate::__u64,
+        /* legal pio/mmio address */
+        pub addr: crate::__u64,
+        /* 1, 2, 4, or 8 bytes; or 0 to ignore length */
+        pub len: crate::__u32,
+        pub fd: crate::__s32,
+        pub flags: crate::__u32,
+        pub pad: [crate::__u8; 36],
+    }
+
+    /* for KVM_ENABLE_CAP */
+    pub struct kvm_enable_cap {
+        /* in */
+        pub cap: crate::__u32,
+        pub flags: crate::__u32,
+        pub args: [crate::__u64; 4],
+        pub pad: [crate::__u8; 64],
+    }
+}
+
+pub const KVMIO: c_uint = 0xAE;
+
+/* extension capability list. */
+pub const KVM_CAP_IRQCHIP: c_int = 0;
+pub const KVM_CAP_HLT: c_int = 1;
+pub const KVM_CAP_MMU_SHADOW_CACHE_CONTROL: c_int = 2;
+pub const KVM_CAP_USER_MEMORY: c_int = 3;
+pub const KVM_CAP_SET_TSS_ADDR: c_int = 4;
+pub const KVM_CAP_VAPIC: c_int = 6;
+pub const KVM_CAP_EXT_CPUID: c_int = 7;
+pub const KVM_CAP_CLOCKSOURCE: c_int = 8;
+/* returns recommended max vcpus per vm */
+pub const KVM_CAP_NR_VCPUS: c_int = 9;
+/* returns max memory slots per vm */
+pub const KVM_CAP_NR_MEMSLOTS: c_int = 10;
+pub const KVM_CAP_PIT: c_int = 11;
+pub const KVM_CAP_NOP_IO_DELAY: c_int = 12;
+pub const KVM_CAP_PV_MMU: c_int = 13;
+pub const KVM_CAP_MP_STATE: c_int = 14;
+pub const KVM_CAP_COALESCED_MMIO: c_int = 15;
+/* Changes to host mmap are reflected in guest */
+pub const KVM_CAP_SYNC_MMU: c_int = 16;
+pub const KVM_CAP_IOMMU: c_int = 18;
+/* Bug in KVM_SET_USER_MEMORY_REGION fixed: */
+pub const KVM_CAP_DESTROY_MEMORY_REGION_WORKS: c_int = 21;
+pub const KVM_CAP_USER_NMI: c_int = 22;
+#[cfg(not(target_arch = "riscv64"))]
+pub const KVM_CAP_SET_GUEST_DEBUG: c_int = 23;
+#[cfg(target_arch = "x86_64")]
+pub const KVM_CAP_REINJECT_CONTROL: c_int = 24;
+pub const KVM_CAP_IRQ_ROUTING: c_int = 25;
+pub const KVM_CAP_IRQ_INJECT_STATUS: c_int = 26;
+pub const KVM_CAP_ASSIGN_DEV_IRQ: c_int = 29;
+/* Another bug in KVM_SET_USER_MEMORY_REGION fixed: */
+pub const KVM_CAP_JOIN_MEMORY_REGIONS_WORKS: c_int = 30;
+#[cfg(target_arch = "x86_64")]
+pub const KVM_CAP_MCE: c_int = 31;
+pub const KVM_CAP_IRQFD: c_int = 32;
+#[cfg(target_arch = "x86_64")]
+pub const KVM_CAP_PIT2: c_int = 33;
+pub const KVM_CAP_SET_BOOT_CPU_ID: c_int = 34;
+#[cfg(target_arch = "x86_64")]
+pub const KVM_CAP_PIT_STATE2: c_int = 35;
+pub const KVM_CAP_IOEVENTFD: c_int = 36;
+pub const KVM_CAP_SET_IDENTITY_MAP_ADDR: c_int = 37;
+#[cfg(target_arch = "x86_64")]
+pub const KVM_CAP_XEN_HVM: c_int = 38;
+pub const KVM_CAP_ADJUST_CLOCK: c_int = 39;
+pub const KVM_CAP_INTERNAL_ERROR_DATA: c_int = 40;
+#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+pub const KVM_CAP_VCPU_EVENTS: c_int = 41;
+pub const KVM_CAP_S390_PSW: c_int = 42;
+pub const KVM_CAP_PPC_SEGSTATE: c_int = 43;
+pub const KVM_CAP_HYPERV: c_int = 44;
+pub const KVM_CAP_HYPERV_VAPIC: c_int = 45;
+pub const KVM_CAP_HYPERV_SPIN: c_int = 46;
+pub const KVM_CAP_PCI_SEGMENT: c_int = 47;
+pub const KVM_CAP_PPC_PAIRED_SINGLES: c_int = 48;
+pub const KVM_CAP_INTR_SHADOW: c_int = 49;
+#[cfg(target_arch = "x86_64")]
+pub const KVM_CAP_DEBUGREGS: c_int = 50;
+pub const KVM_CAP_X86_ROBUST_SINGLESTEP: c_int = 51;
+pub const KVM_CAP_PPC_OSI: c_int = 52;
+pub const KVM_CAP_PPC_UNSET_IRQ: c_int = 53;
+pub const KVM_CAP_ENABLE_CAP: c_int = 54;
+#[cfg(target_arch = "x86_64")]
+pub const KVM_CAP_XSAVE: c_int = 55;
+#[cfg(target_arch = "x86_64")]
+pub const KVM_CAP_XCRS: c_int = 56;
+pub const KVM_CAP_PPC_GET_PVINFO: c_int = 57;
+pub const KVM_CAP_PPC_IRQ_LEVEL: c_int = 58;
+pub const KVM_CAP_ASYNC_PF: c_int = 59;
+pub const KVM_CAP_TSC_CONTROL: c_int = 60;
+pub const KVM_CAP_GET_TSC_KHZ: c_int = 61;
+pub const KVM_CAP_PPC_BOOKE_SREGS: c_int = 62;
+pub const KVM_CAP_SPAPR_TCE: c_int = 63;
+pub const KVM_CAP_PPC_SMT: c_int = 64;
+pub const KVM_CAP_PPC_RMA: c_int = 65;
+/* returns max vcpus per vm */
+pub const KVM_CAP_MAX_VCPUS: c_int = 66;
+pub const KVM_CAP_PPC_HIOR: c_int = 67;
+pub const KVM_CAP_PPC_PAPR: c_int = 68;
+pub const KVM_CAP_SW_TLB: c_int = 69;
+pub const KVM_CAP_ONE_REG: c_int = 70;
+pub const KVM_CAP_S390_GMAP: c_int = 71;
+pub const KVM_CAP_TSC_DEADLINE_TIMER: c_int = 72;
+pub const KVM_CAP_S390_UCONTROL: c_int = 73;
+pub const KVM_CAP_SYNC_REGS: c_int = 74;
+pub const KVM_CAP_PCI_2_3: c_int = 75;
+pub const KVM_CAP_KVMCLOCK_CTRL: c_int = 76;
+pub const KVM_CAP_SIGNAL_MSI: c_int = 77;
+pub const KVM_CAP_PPC_GET_SMMU_INFO: c_int = 78;
+pub const KVM_CAP_S390_COW: c_int = 79;
+pub const KVM_CAP_PPC_ALLOC_HTAB: c_int = 80;
+pub const KVM_CAP_READONLY_MEM: c_int = 81;
+pub const KVM_CAP_IRQFD_RESAMPLE: c_int = 82;
+pub const KVM_CAP_PPC_BOOKE_WATCHDOG: c_int = 83;
+pub const KVM_CAP_PPC_HTAB_FD: c_int = 84;
+pub const KVM_CAP_S390_CSS_SUPPORT: c_int = 85;
+pub const KVM_CAP_PPC_EPR: c_int = 86;
+pub const KVM_CAP_ARM_PSCI: c_int = 87;
+pub const KVM_CAP_ARM_SET_DEVICE_ADDR: c_int = 88;
+pub const KVM_CAP_DEVICE_CTRL: c_int = 89;
+pub const KVM_CAP_IRQ_MPIC: c_int = 90;
+pub const KVM_CAP_PPC_RTAS: c_int = 91;
+pub const KVM_CAP_IRQ_XICS: c_int = 92;
+pub const KVM_CAP_ARM_EL1_32BIT: c_int = 93;
+pub const KVM_CAP_SPAPR_MULTITCE: c_int = 94;
+pub const KVM_CAP_EXT_EMUL_CPUID: c_int = 95;
+pub const KVM_CAP_HYPERV_TIME: c_int = 96;
+pub const KVM_CAP_IOAPIC_POLARITY_IGNORED: c_int = 97;
+pub const KVM_CAP_ENABLE_CAP_VM: c_int = 98;
+pub const KVM_CAP_S390_IRQCHIP: c_int = 99;
+pub const KVM_CAP_IOEVENTFD_NO_LENGTH: c_int = 100;
+pub const KVM_CAP_VM_ATTRIBUTES: c_int = 101;
+pub const KVM_CAP_ARM_PSCI_0_2: c_int = 102;
+pub const KVM_CAP_PPC_FIXUP_HCALL: c_int = 103;
+pub const KVM_CAP_PPC_ENABLE_HCALL: c_int = 104;
+pub const KVM_CAP_CHECK_EXTENSION_VM: c_int = 105;
+pub const KVM_CAP_S390_USER_SIGP: c_int = 106;
+pub const KVM_CAP_S390_VECTOR_REGISTERS: c_int = 107;
+pub const KVM_CAP_S390_MEM_OP: c_int = 108;
+pub const KVM_CAP_S390_USER_STSI: c_int = 109;
+pub const KVM_CAP_S390_SKEYS: c_int = 110;
+pub const KVM_CAP_MIPS_FPU: c_int = 111;
+pub const KVM_CAP_MIPS_MSA: c_int = 112;
+pub const KVM_CAP_S390_INJECT_IRQ: c_int = 113;
+pub const KVM_CAP_S390_IRQ_STATE: c_int = 114;
+pub const KVM_CAP_PPC_HWRNG: c_int = 115;
+pub const KVM_CAP_DISABLE_QUIRKS: c_int = 116;
+pub const KVM_CAP_X86_SMM: c_int = 117;
+pub const KVM_CAP_MULTI_ADDRESS_SPACE: c_int = 118;
+pub const KVM_CAP_GUEST_DEBUG_HW_BPS: c_int = 119;
+pub const KVM_CAP_GUEST_DEBUG_HW_WPS: c_int = 120;
+pub const KVM_CAP_SPLIT_IRQCHIP: c_int = 121;
+pub const KVM_CAP_IOEVENTFD_ANY_LENGTH: c_int = 122;
+pub const KVM_CAP_HYPERV_SYNIC: c_int = 123;
+pub const KVM_CAP_S390_RI: c_int = 124;
+pub const KVM_CAP_SPAPR_TCE_64: c_int = 125;
+pub const KVM_CAP_ARM_PMU_V3: c_int = 126;
+pub const KVM_CAP_VCPU_ATTRIBUTES: c_int = 127;
+pub const KVM_CAP_MAX_VCPU_ID: c_int = 128;
+pub const KVM_CAP_X2APIC_API: c_int = 129;
+pub const KVM_CAP_S390_USER_INSTR0: c_int = 130;
+pub const KVM_CAP_MSI_DEVID: c_int = 131;
+pub const KVM_CAP_PPC_HTM: c_int = 132;
+pub const KVM_CAP_SPAPR_RESIZE_HPT: c_int = 133;
+pub const KVM_CAP_PPC_MMU_RADIX: c_int = 134;
+pub const KVM_CAP_PPC_MMU_HASH_V3: c_int = 135;
+pub const KVM_CAP_IMMEDIATE_EXIT: c_int = 136;
+pub const KVM_CAP_MIPS_VZ: c_int = 137;
+pub const KVM_CAP_MIPS_TE: c_int = 138;
+pub const KVM_CAP_MIPS_64BIT: c_int = 139;
+pub const KVM_CAP_S390_GS: c_int = 140;
+pub const KVM_CAP_S390_AIS: c_int = 141;
+pub const KVM_CAP_SPAPR_TCE_VFIO: c_int = 142;
+pub const KVM_CAP_X86_DISABLE_EXITS: c_int = 143;
+pub const KVM_CAP_ARM_USER_IRQ: c_int = 144;
+pub const KVM_CAP_S390_CMMA_MIGRATION: c_int = 145;
+pub const KVM_CAP_PPC_FWNMI: c_int = 146;
+pub const KVM_CAP_PPC_SMT_POSSIBLE: c_int = 147;
+pub const KVM_CAP_HYPERV_SYNIC2: c_int = 148;
+pub const KVM_CAP_HYPERV_VP_INDEX: c_int = 149;
+pub const KVM_CAP_S390_AIS_MIGRATION: c_int = 150;
+pub const KVM_CAP_PPC_GET_CPU_CHAR: c_int = 151;
+pub const KVM_CAP_S390_BPB: c_int = 152;
+pub const KVM_CAP_GET_MSR_FEATURES: c_int = 153;
+pub const KVM_CAP_HYPERV_EVENTFD: c_int = 154;
+pub const KVM_CAP_HYPERV_TLBFLUSH: c_int = 155;
+pub const KVM_CAP_S390_HPAGE_1M: c_int = 156;
+pub const KVM_CAP_NESTED_STATE: c_int = 157;
+pub const KVM_CAP_ARM_INJECT_SERROR_ESR: c_int = 158;
+pub const KVM_CAP_MSR_PLATFORM_INFO: c_int = 159;
+pub const KVM_CAP_PPC_NESTED_HV: c_int = 160;
+pub const KVM_CAP_HYPERV_SEND_IPI: c_int = 161;
+pub const KVM_CAP_COALESCED_PIO: c_int = 162;
+pub const KVM_CAP_HYPERV_ENLIGHTENED_VMCS: c_int = 163;
+pub const KVM_CAP_EXCEPTION_PAYLOAD: c_int = 164;
+pub const KVM_CAP_ARM_VM_IPA_SIZE: c_int = 165;
+/* Obsolete */
+pub const KVM_CAP_MANUAL_DIRTY_LOG_PROTECT: c_int = 166;
+pub const KVM_CAP_HYPERV_CPUID: c_int = 167;
+pub const KVM_CAP_MANUAL_DIRTY_LOG_PROTECT2: c_int = 168;
+pub const KVM_CAP_PPC_IRQ_XIVE: c_int = 169;
+pub const KVM_CAP_ARM_SVE: c_int = 170;
+pub const KVM_CAP_ARM_PTRAUTH_ADDRESS: c_int = 171;
+pub const KVM_CAP_ARM_PTRAUTH_GENERIC: c_int = 172;
+pub const KVM_CAP_PMU_EVENT_FILTER: c_int = 173;
+pub const KVM_CAP_ARM_IRQ_LINE_LAYOUT_2: c_int = 174;
+pub const KVM_CAP_HYPERV_DIRECT_TLBFLUSH: c_int = 175;
+pub const KVM_CAP_PPC_GUEST_DEBUG_SSTEP: c_int = 176;
+pub const KVM_CAP_ARM_NISV_TO_USER: c_int = 177;
+pub const KVM_CAP_ARM_INJECT_EXT_DABT: c_int = 178;
+pub const KVM_CAP_S390_VCPU_RESETS: c_int = 179;
+pub const KVM_CAP_S390_PROTECTED: c_int = 180;
+pub const KVM_CAP_PPC_SECURE_GUEST: c_int = 181;
+pub const KVM_CAP_HALT_POLL: c_int = 182;
+pub const KVM_CAP_ASYNC_PF_INT: c_int = 183;
+pub const KVM_CAP_LAST_CPU: c_int = 184;
+pub const KVM_CAP_SMALLER_MAXPHYADDR: c_int = 185;
+pub const KVM_CAP_S390_DIAG318: c_int = 186;
+pub const KVM_CAP_STEAL_TIME: c_int = 187;
+pub const KVM_CAP_X86_USER_SPACE_MSR: c_int = 188;
+pub const KVM_CAP_X86_MSR_FILTER: c_int = 189;
+pub const KVM_CAP_ENFORCE_PV_FEATURE_CPUID: c_int = 190;
+pub const KVM_CAP_SYS_HYPERV_CPUID: c_int = 191;
+pub const KVM_CAP_DIRTY_LOG_RING: c_int = 192;
+pub const KVM_CAP_X86_BUS_LOCK_EXIT: c_int = 193;
+pub const KVM_CAP_PPC_DAWR1: c_int = 194;
+pub const KVM_CAP_SET_GUEST_DEBUG2: c_int = 195;
+pub const KVM_CAP_SGX_ATTRIBUTE: c_int = 196;
+pub const KVM_CAP_VM_COPY_ENC_CONTEXT_FROM: c_int = 197;
+pub const KVM_CAP_PTP_KVM: c_int = 198;
+pub const KVM_CAP_HYPERV_ENFORCE_CPUID: c_int = 199;
+pub const KVM_CAP_SREGS2: c_int = 200;
+pub const KVM_CAP_EXIT_HYPERCALL: c_int = 201;
+pub const KVM_CAP_PPC_RPT_INVALIDATE: c_int = 202;
+pub const KVM_CAP_BINARY_STATS_FD: c_int = 203;
+pub const KVM_CAP_EXIT_ON_EMULATION_FAILURE: c_int = 204;
+pub const KVM_CAP_ARM_MTE: c_int = 205;
+pub const KVM_CAP_VM_MOVE_ENC_CONTEXT_FROM: c_int = 206;
+pub const KVM_CAP_VM_GPA_BITS: c_int = 207;
+pub const KVM_CAP_XSAVE2: c_int = 208;
+pub const KVM_CAP_SYS_ATTRIBUTES: c_int = 209;
+pub const KVM_CAP_PPC_AIL_MODE_3: c_int = 210;
+pub const KVM_CAP_S390_MEM_OP_EXTENSION: c_int = 211;
+pub const KVM_CAP_PMU_CAPABILITY: c_int = 212;
+pub const KVM_CAP_DISABLE_QUIRKS2: c_int = 213;
+pub const KVM_CAP_VM_TSC_CONTROL: c_int = 214;
+pub const KVM_CAP_SYSTEM_EVENT_DATA: c_int = 215;
+pub const KVM_CAP_ARM_SYSTEM_SUSPEND: c_int = 216;
+pub const KVM_CAP_S390_PROTECTED_DUMP: c_int = 217;
+pub const KVM_CAP_X86_TRIPLE_FAULT_EVENT: c_int = 218;
+pub const KVM_CAP_X86_NOTIFY_VMEXIT: c_int = 219;
+pub const KVM_CAP_VM_DISABLE_NX_HUGE_PAGES: c_int = 220;
+pub const KVM_CAP_S390_ZPCI_OP: c_int = 221;
+pub const KVM_CAP_S390_CPU_TOPOLOGY: c_int = 222;
+pub const KVM_CAP_DIRTY_LOG_RING_ACQ_REL: c_int = 223;
+
+pub const KVM_CAP_USER_MEMORY2: c_int = 231;
+
+s! {
+    pub struct kvm_irq_routing_irqchip {
+        pub irqchip: crate::__u32,
+        pub pin: crate::__u32,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_kvm_irq_routing_msi_1 {
+        pub pad: crate::__u32,
+        pub devid: crate::__u32,
+    }
+
+    pub struct kvm_irq_routing_msi {
+        pub address_lo: crate::__u32,
+        pub address_hi: crate::__u32,
+        pub data: crate::__u32,
+        pub anonymous_1: __c_anonymous_kvm_irq_routing_msi_1,
+    }
+}
+
+s! {
+    pub struct kvm_irq_routing_s390_adapter {
+        pub ind_addr: crate::__u64,
+        pub summary_addr: crate::__u64,
+        pub ind_offset: crate::__u64,
+        pub summary_offset: crate::__u32,
+        pub adapter_id: crate::__u32,
+    }
+
+    pub struct kvm_irq_routing_hv_sint {
+        pub vcpu: crate::__u32,
+        pub sint: crate::__u32,
+    }
+
+    pub struct kvm_irq_routing_xen_evtchn {
+        pub port: crate::__u32,
+        pub vcpu: crate::__u32,
+        pub priority: crate::__u32,
+    }
+}
+
+pub const KVM_IRQ_ROUTING_XEN_EVTCHN_PRIO_2LEVEL: crate::__u32 = u32::MAX;
+
+/* gsi routing entry types */
+pub const KVM_IRQ_ROUTING_IRQCHIP: c_int = 1;
+pub const KVM_IRQ_ROUTING_MSI: c_int = 2;
+pub const KVM_IRQ_ROUTING_S390_ADAPTER: c_int = 3;
+pub const KVM_IRQ_ROUTING_HV_SINT: c_int = 4;
+pub const KVM_IRQ_ROUTING_XEN_EVTCHN: c_int = 5;
+
+s_no_extra_traits! {
+    pub union __c_anonymous_kvm_irq_routing_entry_u {
+        pub irqchip: kvm_irq_routing_irqchip,
+        pub msi: kvm_irq_routing_msi,
+        pub adapter: kvm_irq_routing_s390_adapter,
+        pub hv_sint: kvm_irq_routing_hv_sint,
+        pub xen_evtchn: kvm_irq_routing_xen_evtchn,
+        pub pad: [crate::__u32; 8],
+    }
+
+    pub struct kvm_irq_routing_entry {
+        pub gsi: crate::__u32,
+        pub type_: crate::__u32,
+        pub flags: crate::__u32,
+        pub pad: crate::__u32,
+        pub u: __c_anonymous_kvm_irq_routing_entry_u,
+    }
+
+    pub struct kvm_irq_routing {
+        pub nr: crate::__u32,
+        pub flags: crate::__u32,
+        pub entries: [kvm_irq_routing_entry; 0],
+    }
+}
+
+pub const KVM_IRQFD_FLAG_DEASSIGN: c_int = 1 << 0;
+/*
+ * Available with KVM_CAP_IRQFD_RESAMPLE
+ *
+ * KVM_IRQFD_FLAG_RESAMPLE indicates resamplefd is valid and specifies
+ * the irqfd to operate in resampling mode for level triggered interrupt
+ * emulation.  See Documentation/virt/kvm/api.rst.
+ */
+pub const KVM_IRQFD_FLAG_RESAMPLE: c_int = 1 << 1;
+
+s! {
+    pub struct kvm_irqfd {
+        pub fd: crate::__u32,
+        pub gsi: crate::__u32,
+        pub flags: crate::__u32,
+        pub resamplefd: crate::__u32,
+        pub pad: [crate::__u8; 16],
+    }
+}
+
+/* Available with KVM_CAP_ONE_REG */
+
+pub const KVM_REG_ARCH_MASK: c_ulonglong = 0xff00000000000000;
+pub const KVM_REG_GENERIC: c_ulonglong = 0x0000000000000000;
+
+/*
+ * Architecture specific registers are to be defined in arch headers and
+ * ORed with the arch identifier.
+ */
+pub const KVM_REG_PPC: c_ulonglong = 0x1000000000000000;
+pub const KVM_REG_X86: c_ulonglong = 0x2000000000000000;
+pub const KVM_REG_IA64: c_ulonglong = 0x3000000000000000;
+pub const KVM_REG_ARM: c_ulonglong = 0x4000000000000000;
+pub const KVM_REG_S390: c_ulonglong = 0x5000000000000000;
+pub const KVM_REG_ARM64: c_ulonglong = 0x6000000000000000;
+pub const KVM_REG_MIPS: c_ulonglong = 0x7000000000000000;
+pub const KVM_REG_RISCV: c_ulonglong = 0x8000000000000000;
+
+pub const KVM_REG_SIZE_SHIFT: c_int = 52;
+pub const KVM_REG_SIZE_MASK: c_ulonglong = 0x00f0000000000000;
+pub const KVM_REG_SIZE_U8: c_ulonglong = 0x0000000000000000;
+pub const KVM_REG_SIZE_U16: c_ulonglong = 0x0010000000000000;
+pub const KVM_REG_SIZE_U32: c_ulonglong = 0x0020000000000000;
+pub const KVM_REG_SIZE_U64: c_ulonglong = 0x0030000000000000;
+pub const KVM_REG_SIZE_U128: c_ulonglong = 0x0040000000000000;
+pub const KVM_REG_SIZE_U256: c_ulonglong = 0x0050000000000000;
+pub const KVM_REG_SIZE_U512: c_ulonglong = 0x0060000000000000;
+pub const KVM_REG_SIZE_U1024: c_ulonglong = 0x0070000000000000;
+pub const KVM_REG_SIZE_U2048: c_ulonglong = 0x0080000000000000;
+
+s! {
+    pub struct kvm_reg_list {
+        /* number of regs */
+        pub n: crate::__u64,
+        pub reg: [crate::__u64; 0],
+    }
+
+    pub struct kvm_one_reg {
+        pub id: crate::__u64,
+        pub addr: crate::__u64,
+    }
+}
+
+pub const KVM_MSI_VALID_DEVID: c_uint = 1 << 0;
+
+s! {
+    pub struct kvm_msi {
+        pub address_lo: crate::__u32,
+        pub address_hi: crate::__u32,
+        pub data: crate::__u32,
+        pub flags: crate::__u32,
+        pub devid: crate::__u32,
+        pub pad: [crate::__u8; 12],
+    }
+}
+
+/*
+ * Device control API, available with KVM_CAP_DEVICE_CTRL
+ */
+pub const KVM_CREATE_DEVICE_TEST: c_int = 1;
+
+s! {
+    pub struct kvm_create_device {
+        /* in: KVM_DEV_TYPE_xxx */
+        pub type_: crate::__u32,
+        /* out: device handle */
+        pub fd: crate::__u32,
+        /* in: KVM_CREATE_DEVICE_xxx */
+        pub flags: crate::__u32,
+    }
+
+    pub struct kvm_device_attr {
+        /* no flags currently defined */
+        pub flags: crate::__u32,
+        /* device-defined */
+        pub group: crate::__u32,
+        /* group-defined */
+        pub attr: crate::__u64,
+        /* userspace address of attr data */
+        pub addr: crate::__u64,
+    }
+}
+
+c_enum! {
+    pub enum kvm_device_type {
+        pub KVM_DEV_TYPE_FSL_MPIC_20 = 1,
+        pub KVM_DEV_TYPE_FSL_MPIC_42,
+        pub KVM_DEV_TYPE_XICS,
+        pub KVM_DEV_TYPE_VFIO,
+        pub KVM_DEV_TYPE_ARM_VGIC_V2,
+        pub KVM_DEV_TYPE_FLIC,
+        pub KVM_DEV_TYPE_ARM_VGIC_V3,
+        pub KVM_DEV_TYPE_ARM_VGIC_ITS,
+        pub KVM_DEV_TYPE_XIVE,
+        pub KVM_DEV_TYPE_ARM_PV_TIME,
+    }
+}
+
+/*
+ * ioctls for /dev/kvm fds:
+ */
+pub const KVM_GET_API_VERSION: Ioctl = _IO(KVMIO, 0x00);
+/* returns a VM fd */
+pub const KVM_CREATE_VM: Ioctl = _IO(KVMIO, 0x01);
+/*
+ * Check if a kvm extension is available.  Argument is extension number,
+ * return is 1 (yes) or 0 (no, sorry).
+ */
+pub const KVM_CHECK_EXTENSION: Ioctl = _IO(KVMIO, 0x03);
+/*
+ * Get size for mmap(vcpu_fd)
+ */
+/* in bytes */
+pub const KVM_GET_VCPU_MMAP_SIZE: Ioctl = _IO(KVMIO, 0x04);
+
+/*
+ * ioctls for VM fds
+ */
+/*
+ * KVM_CREATE_VCPU receives as a parameter the vcpu slot, and returns
+ * a vcpu fd.
+ */
+pub const KVM_CREATE_VCPU: Ioctl = _IO(KVMIO, 0x41);
+pub const KVM_GET_DIRTY_LOG: Ioctl = _IOW::<kvm_dirty_log>(KVMIO, 0x42);
+pub const KVM_SET_NR_MMU_PAGES: Ioctl = _IO(KVMIO, 0x44);
+pub const KVM_GET_NR_MMU_PAGES: Ioctl = _IO(KVMIO, 0x45);
+pub const KVM_SET_USER_MEMORY_REGION: Ioctl = _IOW::<kvm_userspace_memory_region>(KVMIO, 0x46);
+pub const KVM_SET_TSS_ADDR: Ioctl = _IO(KVMIO, 0x47);
+pub const KVM_SET_IDENTITY_MAP_ADDR: Ioctl = _IOW::<crate::__u64>(KVMIO, 0x48);
+pub const KVM_SET_USER_MEMORY_REGION2: Ioctl = _IOW::<kvm_userspace_memory_region2>(KVMIO, 0x49);
+
+/* Device model IOC */
+pub const KVM_CREATE_IRQCHIP: Ioctl = _IO(KVMIO, 0x60);
+pub const KVM_IRQ_LINE: Ioctl = _IOW::<kvm_irq_level>(KVMIO, 0x61);
+pub const KVM_IRQ_LINE_STATUS: Ioctl = _IOWR::<kvm_irq_level>(KVMIO, 0x67);
+pub const KVM_REGISTER_COALESCED_MMIO: Ioctl = _IOW::<kvm_coalesced_mmio_zone>(KVMIO, 0x67);
+pub const KVM_UNREGISTER_COALESCED_MMIO: Ioctl = _IOW::<kvm_coalesced_mmio_zone>(KVMIO, 0x68);
+pub const KVM_SET_GSI_ROUTING: Ioctl = _IOW::<kvm_irq_routing>(KVMIO, 0x6a);
+pub const KVM_IRQFD: Ioctl = _IOW::<kvm_irqfd>(KVMIO, 0x76);
+pub const KVM_SET_BOOT_CPU_ID: Ioctl = _IO(KVMIO, 0x78);
+pub const KVM_IOEVENTFD: Ioctl = _IOW::<kvm_ioeventfd>(KVMIO, 0x79);
+/*
+ * Available with KVM_CAP_TSC_CONTROL for a vCPU, or with
+ * KVM_CAP_VM_TSC_CONTROL to set defaults for a VM
+ */
+pub const KVM_SET_TSC_KHZ: Ioctl = _IO(KVMIO, 0xa2);
+pub const KVM_GET_TSC_KHZ: Ioctl = _IO(KVMIO, 0xa3);
+/* Available with KVM_CAP_SIGNAL_MSI */
+pub const KVM_SIGNAL_MSI: Ioctl = _IOW::<kvm_msi>(KVMIO, 0xa5);
+
+/* ioctl for vm fd */
+pub const KVM_CREATE_DEVICE: Ioctl = _IOWR::<kvm_create_device>(KVMIO, 0xe0);
+
+/* ioctls for fds returned by KVM_CREATE_DEVICE */
+pub const KVM_SET_DEVICE_ATTR: Ioctl = _IOW::<kvm_device_attr>(KVMIO, 0xe1);
+pub const KVM_GET_DEVICE_ATTR: Ioctl = _IOW::<kvm_device_attr>(KVMIO, 0xe2);
+pub const KVM_HAS_DEVICE_ATTR: Ioctl = _IOW::<kvm_device_attr>(KVMIO, 0xe3);
+
+/*
+ * ioctls for vcpu fds
+ */
+pub const KVM_RUN: Ioctl = _IO(KVMIO, 0x80);
+pub const KVM_GET_REGS: Ioctl = _IOR::<kvm_regs>(KVMIO, 0x81);
+pub const KVM_SET_REGS: Ioctl = _IOW::<kvm_regs>(KVMIO, 0x82);
+pub const KVM_GET_SREGS: Ioctl = _IOR::<kvm_sregs>(KVMIO, 0x83);
+pub const KVM_SET_SREGS: Ioctl = _IOW::<kvm_sregs>(KVMIO, 0x84);
+pub const KVM_TRANSLATE: Ioctl = _IOWR::<kvm_translation>(KVMIO, 0x85);
+pub const KVM_INTERRUPT: Ioctl = _IOW::<kvm_interrupt>(KVMIO, 0x86);
+pub const KVM_SET_SIGNAL_MASK: Ioctl = _IOW::<kvm_signal_mask>(KVMIO, 0x8b);
+pub const KVM_GET_FPU: Ioctl = _IOR::<kvm_fpu>(KVMIO, 0x8c);
+pub const KVM_SET_FPU: Ioctl = _IOW::<kvm_fpu>(KVMIO, 0x8d);
+pub const KVM_GET_MP_STATE: Ioctl = _IOR::<kvm_mp_state>(KVMIO, 0x98);
+pub const KVM_SET_MP_STATE: Ioctl = _IOW::<kvm_mp_state>(KVMIO, 0x99);
+/* Available with KVM_CAP_USER_NMI */
+pub const KVM_NMI: Ioctl = _IO(KVMIO, 0x9a);
+/* Available with KVM_CAP_SET_GUEST_DEBUG */
+#[cfg(not(target_arch = "riscv64"))]
+pub const KVM_SET_GUEST_DEBUG: Ioctl = _IOW::<kvm_guest_debug>(KVMIO, 0x9b);
+/*
+ * vcpu version available with KVM_ENABLE_CAP
+ * vm version available with KVM_CAP_ENABLE_CAP_VM
+ */
+pub const KVM_ENABLE_CAP: Ioctl = _IOW::<kvm_enable_cap>(KVMIO, 0xa3);
+/* Available with KVM_CAP_ONE_REG */
+pub const KVM_GET_ONE_REG: Ioctl = _IOW::<kvm_one_reg>(KVMIO, 0xab);
+pub const KVM_SET_ONE_REG: Ioctl = _IOW::<kvm_one_reg>(KVMIO, 0xac);
+/* VM is being stopped by host */
+pub const KVM_KVMCLOCK_CTRL: Ioctl = _IO(KVMIO, 0xad);
+pub const KVM_GET_REG_LIST: Ioctl = _IOWR::<kvm_reg_list>(KVMIO, 0xb0);
+/* Available with KVM_CAP_MANUAL_DIRTY_LOG_PROTECT_2 */
+pub const KVM_CLEAR_DIRTY_LOG: Ioctl = _IOWR::<kvm_clear_dirty_log>(KVMIO, 0xc0);
+/* Available with KVM_CAP_ARM_SVE */
+pub const KVM_ARM_VCPU_FINALIZE: Ioctl = _IOW::<c_int>(KVMIO, 0xc2);
+
+cfg_if! {
+    if #[cfg(target_arch = "x86_64")] {
+        pub const KVM_GET_MSR_INDEX_LIST: Ioctl = _IOWR::<kvm_msr_list>(KVMIO, 0x02);
+        pub const KVM_GET_SUPPORTED_CPUID: Ioctl = _IOWR::<kvm_cpuid2>(KVMIO, 0x05);
+        pub const KVM_GET_EMULATED_CPUID: Ioctl = _IOWR::<kvm_cpuid2>(KVMIO, 0x09);
+        pub const KVM_GET_MSR_FEATURE_INDEX_LIST: Ioctl = _IOWR::<kvm_msr_list>(KVMIO, 0x0a);
+
+        pub const KVM_CREATE_PIT2: Ioctl = _IOW::<kvm_pit_config>(KVMIO, 0x77);
+        pub const KVM_SET_CLOCK: Ioctl = _IOW::<kvm_clock_data>(KVMIO, 0x7b);
+        pub const KVM_GET_CLOCK: Ioctl = _IOR::<kvm_clock_data>(KVMIO, 0x7c);
+
+        pub const KVM_GET_MSRS: Ioctl = _IOWR::<kvm_msrs>(KVMIO, 0x88);
+        pub const KVM_SET_MSRS: Ioctl = _IOW::<kvm_msrs>(KVMIO, 0x89);
+        pub const KVM_GET_LAPIC: Ioctl = _IOR::<kvm_lapic_state>(KVMIO, 0x8e);
+        pub const KVM_SET_LAPIC: Ioctl = _IOW::<kvm_lapic_state>(KVMIO, 0x8f);
+        pub const KVM_SET_CPUID2: Ioctl = _IOW::<kvm_cpuid2>(KVMIO, 0x90);
+        pub const KVM_GET_CPUID2: Ioctl = _IOWR::<kvm_cpuid2>(KVMIO, 0x91);
+        /* Available with KVM_CAP_VCPU_EVENTS */
+        pub const KVM_GET_VCPU_EVENTS: Ioctl = _IOR::<kvm_vcpu_events>(KVMIO, 0x9f);
+        pub const KVM_SET_VCPU_EVENTS: Ioctl = _IOW::<kvm_vcpu_events>(KVMIO, 0xa0);
+        /* Available with KVM_CAP_X86_SMM */
+        pub const KVM_SMI: Ioctl = _IO(KVMIO, 0xb7);
+    } else if #[cfg(target_arch = "aarch64")] {
+        pub const KVM_ARM_VCPU_INIT: Ioctl = _IOW::<kvm_vcpu_init>(KVMIO, 0xae);
+        pub const KVM_ARM_PREFERRED_TARGET: Ioctl = _IOR::<kvm_vcpu_init>(KVMIO, 0xaf);
+    }
+}
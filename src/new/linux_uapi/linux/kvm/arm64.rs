@@ -0,0 +1,88 @@
+//! Header: `arch/arm64/include/uapi/asm/kvm.h`
+
+use crate::prelude::*;
+
+pub const KVM_COALESCED_MMIO_PAGE_OFFSET: c_int = 1;
+pub const KVM_DIRTY_LOG_PAGE_OFFSET: c_int = 64;
+
+pub const KVM_NR_SPSR: usize = 5;
+
+/* from asm/ptrace.h */
+s! {
+    pub struct user_pt_regs {
+        pub regs: [crate::__u64; 31],
+        pub sp: crate::__u64,
+        pub pc: crate::__u64,
+        pub pstate: crate::__u64,
+    }
+
+    pub struct user_fpsimd_state {
+        pub vregs: [u128; 32],
+        pub fpsr: crate::__u32,
+        pub fpcr: crate::__u32,
+        __reserved: Padding<[crate::__u32; 2]>,
+    }
+}
+
+s! {
+    pub struct kvm_regs {
+        /* sp = sp_el0 */
+        pub regs: user_pt_regs,
+        pub sp_el1: crate::__u64,
+        pub elr_el1: crate::__u64,
+        pub spsr: [crate::__u64; KVM_NR_SPSR],
+        pub fp_regs: user_fpsimd_state,
+    }
+
+    pub struct kvm_vcpu_init {
+        pub target: crate::__u32,
+        pub features: [crate::__u32; 7],
+    }
+
+    pub struct kvm_sregs {}
+
+    pub struct kvm_fpu {}
+}
+
+pub const KVM_ARM_VCPU_POWER_OFF: c_int = 0;
+pub const KVM_ARM_VCPU_EL1_32BIT: c_int = 1;
+pub const KVM_ARM_VCPU_PSCI_0_2: c_int = 2;
+pub const KVM_ARM_VCPU_PMU_V3: c_int = 3;
+pub const KVM_ARM_VCPU_SVE: c_int = 4;
+pub const KVM_ARM_VCPU_PTRAUTH_ADDRESS: c_int = 5;
+pub const KVM_ARM_VCPU_PTRAUTH_GENERIC: c_int = 6;
+
+/*
+ * See v8 ARM ARM D7.3: Debug Registers
+ *
+ * The architectural limit is 16 debug registers of each type although
+ * in practice there are usually less (see ID_AA64DFR0_EL1).
+ */
+pub const KVM_ARM_MAX_DBG_REGS: usize = 16;
+
+s! {
+    pub struct kvm_guest_debug_arch {
+        pub dbg_bcr: [crate::__u64; KVM_ARM_MAX_DBG_REGS],
+        pub dbg_bvr: [crate::__u64; KVM_ARM_MAX_DBG_REGS],
+        pub dbg_wcr: [crate::__u64; KVM_ARM_MAX_DBG_REGS],
+        pub dbg_wvr: [crate::__u64; KVM_ARM_MAX_DBG_REGS],
+    }
+
+    pub struct kvm_debug_exit_arch {
+        pub hsr: crate::__u32,
+        /* ESR_EL2[61:32] */
+        pub hsr_high: crate::__u32,
+        /* used for watchpoints */
+        pub far: crate::__u64,
+    }
+}
+
+pub const KVM_GUESTDBG_USE_SW_BP: c_int = 1 << 16;
+pub const KVM_GUESTDBG_USE_HW: c_int = 1 << 17;
+
+s! {
+    pub struct kvm_sync_regs {
+        /* Used with KVM_CAP_ARM_USER_IRQ */
+        pub device_irq_level: crate::__u64,
+    }
+}
@@ -0,0 +1,25 @@
+//! Header: `arch/riscv/include/uapi/asm/kvm.h`
+
+use crate::prelude::*;
+
+pub const KVM_COALESCED_MMIO_PAGE_OFFSET: c_int = 1;
+
+s! {
+    /* for KVM_GET_REGS and KVM_SET_REGS */
+    pub struct kvm_regs {}
+
+    /* for KVM_GET_FPU and KVM_SET_FPU */
+    pub struct kvm_fpu {}
+
+    /* KVM Debug exit structure */
+    pub struct kvm_debug_exit_arch {}
+
+    /* for KVM_SET_GUEST_DEBUG */
+    pub struct kvm_guest_debug_arch {}
+
+    /* definition of registers in kvm_run */
+    pub struct kvm_sync_regs {}
+
+    /* for KVM_GET_SREGS and KVM_SET_SREGS */
+    pub struct kvm_sregs {}
+}
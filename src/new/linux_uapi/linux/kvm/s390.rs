@@ -0,0 +1,44 @@
+//! Header: `arch/s390/include/uapi/asm/kvm.h`
+
+s! {
+    /* for KVM_GET_REGS and KVM_SET_REGS */
+    pub struct kvm_regs {
+        /* general purpose regs for s390 */
+        pub gprs: [crate::__u64; 16],
+    }
+
+    /* for KVM_GET_SREGS and KVM_SET_SREGS */
+    pub struct kvm_sregs {
+        pub acrs: [crate::__u32; 16],
+        pub crs: [crate::__u64; 16],
+    }
+
+    /* for KVM_GET_FPU and KVM_SET_FPU */
+    pub struct kvm_fpu {
+        pub fpc: crate::__u32,
+        pub fprs: [crate::__u64; 16],
+    }
+
+    pub struct kvm_hw_breakpoint {
+        pub addr: crate::__u64,
+        pub phys_addr: crate::__u64,
+        pub len: crate::__u64,
+        pub type_: crate::__u8,
+        pub pad: [crate::__u8; 7],
+    }
+
+    /* for KVM_SET_GUEST_DEBUG */
+    pub struct kvm_guest_debug_arch {
+        pub nr_hw_bp: crate::__u32,
+        pub pad: crate::__u32,
+        pub hw_bp: *mut kvm_hw_breakpoint,
+    }
+
+    /* for KVM_DEBUG_EXIT */
+    pub struct kvm_debug_exit_arch {
+        pub addr: crate::__u64,
+        pub type_: crate::__u8,
+        /* Should be set to 0 */
+        pub pad: [crate::__u8; 7],
+    }
+}
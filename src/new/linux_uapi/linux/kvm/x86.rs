@@ -0,0 +1,278 @@
+//! Header: `arch/x86/include/uapi/asm/kvm.h`
+
+use crate::prelude::*;
+
+pub const KVM_PIO_PAGE_OFFSET: c_int = 1;
+pub const KVM_COALESCED_MMIO_PAGE_OFFSET: c_int = 2;
+pub const KVM_DIRTY_LOG_PAGE_OFFSET: c_int = 64;
+
+/* Architectural interrupt line count. */
+pub const KVM_NR_INTERRUPTS: usize = 256;
+
+pub const KVM_IRQCHIP_PIC_MASTER: c_int = 0;
+pub const KVM_IRQCHIP_PIC_SLAVE: c_int = 1;
+pub const KVM_IRQCHIP_IOAPIC: c_int = 2;
+pub const KVM_NR_IRQCHIPS: c_int = 3;
+
+pub const KVM_RUN_X86_SMM: c_int = 1 << 0;
+pub const KVM_RUN_X86_BUS_LOCK: c_int = 1 << 1;
+
+/* for KVM_GET_LAPIC and KVM_SET_LAPIC */
+pub const KVM_APIC_REG_SIZE: usize = 0x400;
+
+s! {
+    /* for KVM_GET_REGS and KVM_SET_REGS */
+    pub struct kvm_regs {
+        /* out (KVM_GET_REGS) / in (KVM_SET_REGS) */
+        pub rax: crate::__u64,
+        pub rbx: crate::__u64,
+        pub rcx: crate::__u64,
+        pub rdx: crate::__u64,
+        pub rsi: crate::__u64,
+        pub rdi: crate::__u64,
+        pub rsp: crate::__u64,
+        pub rbp: crate::__u64,
+        pub r8: crate::__u64,
+        pub r9: crate::__u64,
+        pub r10: crate::__u64,
+        pub r11: crate::__u64,
+        pub r12: crate::__u64,
+        pub r13: crate::__u64,
+        pub r14: crate::__u64,
+        pub r15: crate::__u64,
+        pub rip: crate::__u64,
+        pub rflags: crate::__u64,
+    }
+
+    pub struct kvm_lapic_state {
+        pub regs: [c_char; KVM_APIC_REG_SIZE],
+    }
+
+    pub struct kvm_segment {
+        pub base: crate::__u64,
+        pub limit: crate::__u32,
+        pub selector: crate::__u16,
+        pub type_: crate::__u8,
+        pub present: crate::__u8,
+        pub dpl: crate::__u8,
+        pub db: crate::__u8,
+        pub s: crate::__u8,
+        pub l: crate::__u8,
+        pub g: crate::__u8,
+        pub avl: crate::__u8,
+        pub unusable: crate::__u8,
+        pub padding: crate::__u8,
+    }
+
+    pub struct kvm_dtable {
+        pub base: crate::__u64,
+        pub limit: crate::__u16,
+        pub padding: [crate::__u16; 3],
+    }
+
+    /* for KVM_GET_SREGS and KVM_SET_SREGS */
+    pub struct kvm_sregs {
+        /* out (KVM_GET_SREGS) / in (KVM_SET_SREGS) */
+        pub cs: kvm_segment,
+        pub ds: kvm_segment,
+        pub es: kvm_segment,
+        pub fs: kvm_segment,
+        pub gs: kvm_segment,
+        pub ss: kvm_segment,
+        pub tr: kvm_segment,
+        pub ldt: kvm_segment,
+        pub gdt: kvm_dtable,
+        pub idt: kvm_dtable,
+        pub cr0: crate::__u64,
+        pub cr2: crate::__u64,
+        pub cr3: crate::__u64,
+        pub cr4: crate::__u64,
+        pub cr8: crate::__u64,
+        pub efer: crate::__u64,
+        pub apic_base: crate::__u64,
+        pub interrupt_bitmap: [crate::__u64; (KVM_NR_INTERRUPTS + 63) / 64],
+    }
+
+    /* for KVM_GET_FPU and KVM_SET_FPU */
+    pub struct kvm_fpu {
+        pub fpr: [[crate::__u8; 16]; 8],
+        pub fcw: crate::__u16,
+        pub fsw: crate::__u16,
+        /* in fxsave format */
+        pub ftwx: crate::__u8,
+        pub pad1: crate::__u8,
+        pub last_opcode: crate::__u16,
+        pub last_ip: crate::__u64,
+        pub last_dp: crate::__u64,
+        pub xmm: [[crate::__u8; 16]; 16],
+        pub mxcsr: crate::__u32,
+        pub pad2: crate::__u32,
+    }
+
+    pub struct kvm_msr_entry {
+        pub index: crate::__u32,
+        pub reserved: crate::__u32,
+        pub data: crate::__u64,
+    }
+
+    /* for KVM_GET_MSRS and KVM_SET_MSRS */
+    pub struct kvm_msrs {
+        /* number of msrs in entries */
+        pub nmsrs: crate::__u32,
+        pub pad: crate::__u32,
+        pub entries: [kvm_msr_entry; 0],
+    }
+
+    /* for KVM_GET_MSR_INDEX_LIST */
+    pub struct kvm_msr_list {
+        /* number of msrs in entries */
+        pub nmsrs: crate::__u32,
+        pub indices: [crate::__u32; 0],
+    }
+
+    pub struct kvm_cpuid_entry2 {
+        pub function: crate::__u32,
+        pub index: crate::__u32,
+        pub flags: crate::__u32,
+        pub eax: crate::__u32,
+        pub ebx: crate::__u32,
+        pub ecx: crate::__u32,
+        pub edx: crate::__u32,
+        pub padding: [crate::__u32; 3],
+    }
+}
+
+pub const KVM_CPUID_FLAG_SIGNIFCANT_INDEX: c_int = 1 << 0;
+pub const KVM_CPUID_FLAG_STATEFUL_FUNC: c_int = 1 << 1;
+pub const KVM_CPUID_FLAG_STATE_READ_NEXT: c_int = 1 << 2;
+
+s! {
+    /* for KVM_SET_CPUID2 */
+    pub struct kvm_cpuid2 {
+        pub nent: crate::__u32,
+        pub padding: crate::__u32,
+        pub entries: [kvm_cpuid_entry2; 0],
+    }
+
+    pub struct kvm_debug_exit_arch {
+        pub exception: crate::__u32,
+        pub pad: crate::__u32,
+        pub pc: crate::__u64,
+        pub dr6: crate::__u64,
+        pub dr7: crate::__u64,
+    }
+}
+
+pub const KVM_GUESTDBG_USE_SW_BP: c_int = 0x00010000;
+pub const KVM_GUESTDBG_USE_HW_BP: c_int = 0x00020000;
+pub const KVM_GUESTDBG_INJECT_DB: c_int = 0x00040000;
+pub const KVM_GUESTDBG_INJECT_BP: c_int = 0x00080000;
+pub const KVM_GUESTDBG_BLOCKIRQ: c_int = 0x00100000;
+
+s! {
+    /* for KVM_SET_GUEST_DEBUG */
+    pub struct kvm_guest_debug_arch {
+        pub debugreg: [crate::__u64; 8],
+    }
+}
+
+/* When set in flags, include corresponding fields on KVM_SET_VCPU_EVENTS */
+pub const KVM_VCPUEVENT_VALID_NMI_PENDING: c_int = 0x00000001;
+pub const KVM_VCPUEVENT_VALID_SIPI_VECTOR: c_int = 0x00000002;
+pub const KVM_VCPUEVENT_VALID_SHADOW: c_int = 0x00000004;
+pub const KVM_VCPUEVENT_VALID_SMM: c_int = 0x00000008;
+pub const KVM_VCPUEVENT_VALID_PAYLOAD: c_int = 0x00000010;
+pub const KVM_VCPUEVENT_VALID_TRIPLE_FAULT: c_int = 0x00000020;
+
+/* Interrupt shadow states */
+pub const KVM_X86_SHADOW_INT_MOV_SS: c_int = 0x01;
+pub const KVM_X86_SHADOW_INT_STI: c_int = 0x02;
+
+s! {
+    pub struct __c_anonymous_kvm_vcpu_events_exception {
+        pub injected: crate::__u8,
+        pub nr: crate::__u8,
+        pub has_error_code: crate::__u8,
+        pub pending: crate::__u8,
+        pub error_code: crate::__u32,
+    }
+
+    pub struct __c_anonymous_kvm_vcpu_events_interrupt {
+        pub injected: crate::__u8,
+        pub nr: crate::__u8,
+        pub soft: crate::__u8,
+        pub shadow: crate::__u8,
+    }
+
+    pub struct __c_anonymous_kvm_vcpu_events_nmi {
+        pub injected: crate::__u8,
+        pub pending: crate::__u8,
+        pub masked: crate::__u8,
+        pub pad: crate::__u8,
+    }
+
+    pub struct __c_anonymous_kvm_vcpu_events_smi {
+        pub smm: crate::__u8,
+        pub pending: crate::__u8,
+        pub smm_inside_nmi: crate::__u8,
+        pub latched_init: crate::__u8,
+    }
+
+    pub struct __c_anonymous_kvm_vcpu_events_triple_fault {
+        pub pending: crate::__u8,
+    }
+
+    /* for KVM_GET/SET_VCPU_EVENTS */
+    pub struct kvm_vcpu_events {
+        pub exception: __c_anonymous_kvm_vcpu_events_exception,
+        pub interrupt: __c_anonymous_kvm_vcpu_events_interrupt,
+        pub nmi: __c_anonymous_kvm_vcpu_events_nmi,
+        pub sipi_vector: crate::__u32,
+        pub flags: crate::__u32,
+        pub smi: __c_anonymous_kvm_vcpu_events_smi,
+        pub triple_fault: __c_anonymous_kvm_vcpu_events_triple_fault,
+        pub reserved: [crate::__u8; 26],
+        pub exception_has_payload: crate::__u8,
+        pub exception_payload: crate::__u64,
+    }
+}
+
+pub const KVM_SYNC_X86_REGS: c_ulong = 1 << 0;
+pub const KVM_SYNC_X86_SREGS: c_ulong = 1 << 1;
+pub const KVM_SYNC_X86_EVENTS: c_ulong = 1 << 2;
+
+pub const KVM_SYNC_X86_VALID_FIELDS: c_ulong =
+    KVM_SYNC_X86_REGS | KVM_SYNC_X86_SREGS | KVM_SYNC_X86_EVENTS;
+
+s! {
+    /* kvm_sync_regs struct included by kvm_run struct */
+    pub struct kvm_sync_regs {
+        pub regs: kvm_regs,
+        pub sregs: kvm_sregs,
+        pub events: kvm_vcpu_events,
+    }
+
+    /* for KVM_CREATE_PIT2 */
+    pub struct kvm_pit_config {
+        pub flags: crate::__u32,
+        pub pad: [crate::__u32; 15],
+    }
+}
+
+pub const KVM_PIT_SPEAKER_DUMMY: c_int = 1;
+
+/* Available with KVM_CAP_ADJUST_CLOCK */
+pub const KVM_CLOCK_TSC_STABLE: c_int = 2;
+pub const KVM_CLOCK_REALTIME: c_int = 1 << 2;
+pub const KVM_CLOCK_HOST_TSC: c_int = 1 << 3;
+
+s! {
+    pub struct kvm_clock_data {
+        pub clock: crate::__u64,
+        pub flags: crate::__u32,
+        pub pad0: crate::__u32,
+        pub realtime: crate::__u64,
+        pub host_tsc: crate::__u64,
+        pub pad: [crate::__u32; 4],
+    }
+}
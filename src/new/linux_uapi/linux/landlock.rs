@@ -0,0 +1,54 @@
+//! Header: `uapi/linux/landlock.h`
+
+use crate::prelude::*;
+
+s! {
+    pub struct landlock_ruleset_attr {
+        pub handled_access_fs: crate::__u64,
+        pub handled_access_net: crate::__u64,
+        pub scoped: crate::__u64,
+    }
+
+    #[repr(packed)]
+    pub struct landlock_path_beneath_attr {
+        pub allowed_access: crate::__u64,
+        pub parent_fd: crate::__s32,
+    }
+
+    pub struct landlock_net_port_attr {
+        pub allowed_access: crate::__u64,
+        pub port: crate::__u64,
+    }
+}
+
+pub const LANDLOCK_CREATE_RULESET_VERSION: c_uint = 1 << 0;
+
+c_enum! {
+    pub enum landlock_rule_type {
+        pub LANDLOCK_RULE_PATH_BENEATH = 1,
+        pub LANDLOCK_RULE_NET_PORT,
+    }
+}
+
+pub const LANDLOCK_ACCESS_FS_EXECUTE: crate::__u64 = 1 << 0;
+pub const LANDLOCK_ACCESS_FS_WRITE_FILE: crate::__u64 = 1 << 1;
+pub const LANDLOCK_ACCESS_FS_READ_FILE: crate::__u64 = 1 << 2;
+pub const LANDLOCK_ACCESS_FS_READ_DIR: crate::__u64 = 1 << 3;
+pub const LANDLOCK_ACCESS_FS_REMOVE_DIR: crate::__u64 = 1 << 4;
+pub const LANDLOCK_ACCESS_FS_REMOVE_FILE: crate::__u64 = 1 << 5;
+pub const LANDLOCK_ACCESS_FS_MAKE_CHAR: crate::__u64 = 1 << 6;
+pub const LANDLOCK_ACCESS_FS_MAKE_DIR: crate::__u64 = 1 << 7;
+pub const LANDLOCK_ACCESS_FS_MAKE_REG: crate::__u64 = 1 << 8;
+pub const LANDLOCK_ACCESS_FS_MAKE_SOCK: crate::__u64 = 1 << 9;
+pub const LANDLOCK_ACCESS_FS_MAKE_FIFO: crate::__u64 = 1 << 10;
+pub const LANDLOCK_ACCESS_FS_MAKE_BLOCK: crate::__u64 = 1 << 11;
+pub const LANDLOCK_ACCESS_FS_MAKE_SYM: crate::__u64 = 1 << 12;
+pub const LANDLOCK_ACCESS_FS_REFER: crate::__u64 = 1 << 13;
+pub const LANDLOCK_ACCESS_FS_TRUNCATE: crate::__u64 = 1 << 14;
+pub const LANDLOCK_ACCESS_FS_IOCTL_DEV: crate::__u64 = 1 << 15;
+
+pub const LANDLOCK_ACCESS_NET_BIND_TCP: crate::__u64 = 1 << 0;
+pub const LANDLOCK_ACCESS_NET_CONNECT_TCP: crate::__u64 = 1 << 1;
+
+pub const LANDLOCK_SCOPE_ABSTRACT_UNIX_SOCKET: crate::__u64 = 1 << 0;
+pub const LANDLOCK_SCOPE_SIGNAL: crate::__u64 = 1 << 1;
@@ -0,0 +1,148 @@
+//! Header: `uapi/linux/loop.h`
+
+use crate::prelude::*;
+use crate::Ioctl;
+
+pub const LO_NAME_SIZE: usize = 64;
+pub const LO_KEY_SIZE: usize = 32;
+
+/*
+ * Loop flags
+ */
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        pub LO_FLAGS_READ_ONLY = 1,
+        pub LO_FLAGS_AUTOCLEAR = 4,
+        pub LO_FLAGS_PARTSCAN = 8,
+        pub LO_FLAGS_DIRECT_IO = 16,
+    }
+}
+
+/* LO_FLAGS that can be set using LOOP_SET_STATUS(64) */
+pub const LOOP_SET_STATUS_SETTABLE_FLAGS: c_int = LO_FLAGS_AUTOCLEAR | LO_FLAGS_PARTSCAN;
+
+/* LO_FLAGS that can be cleared using LOOP_SET_STATUS(64) */
+pub const LOOP_SET_STATUS_CLEARABLE_FLAGS: c_int = LO_FLAGS_AUTOCLEAR;
+
+/* LO_FLAGS that can be set using LOOP_CONFIGURE */
+pub const LOOP_CONFIGURE_SETTABLE_FLAGS: c_int =
+    LO_FLAGS_READ_ONLY | LO_FLAGS_AUTOCLEAR | LO_FLAGS_PARTSCAN | LO_FLAGS_DIRECT_IO;
+
+/* from asm/posix_types.h */
+cfg_if! {
+    if #[cfg(any(target_arch = "x86_64", target_arch = "powerpc64"))] {
+        pub type __kernel_old_dev_t = c_ulong;
+    } else if #[cfg(any(
+        target_arch = "x86",
+        target_arch = "arm",
+        target_arch = "m68k",
+        target_arch = "s390x",
+        target_arch = "sparc"
+    ))] {
+        pub type __kernel_old_dev_t = c_ushort;
+    } else {
+        pub type __kernel_old_dev_t = c_uint;
+    }
+}
+
+s! {
+    /* Backwards compatibility version */
+    pub struct loop_info {
+        /* ioctl r/o */
+        pub lo_number: c_int,
+        /* ioctl r/o */
+        pub lo_device: __kernel_old_dev_t,
+        /* ioctl r/o */
+        pub lo_inode: c_ulong,
+        /* ioctl r/o */
+        pub lo_rdevice: __kernel_old_dev_t,
+        pub lo_offset: c_int,
+        /* obsolete, ignored */
+        pub lo_encrypt_type: c_int,
+        /* ioctl w/o */
+        pub lo_encrypt_key_size: c_int,
+        pub lo_flags: c_int,
+        pub lo_name: [c_char; LO_NAME_SIZE],
+        /* ioctl w/o */
+        pub lo_encrypt_key: [c_uchar; LO_KEY_SIZE],
+        pub lo_init: [c_ulong; 2],
+        reserved: Padding<[c_char; 4]>,
+    }
+
+    pub struct loop_info64 {
+        /* ioctl r/o */
+        pub lo_device: crate::__u64,
+        /* ioctl r/o */
+        pub lo_inode: crate::__u64,
+        /* ioctl r/o */
+        pub lo_rdevice: crate::__u64,
+        pub lo_offset: crate::__u64,
+        /* bytes, 0 == max available */
+        pub lo_sizelimit: crate::__u64,
+        /* ioctl r/o */
+        pub lo_number: crate::__u32,
+        /* obsolete, ignored */
+        pub lo_encrypt_type: crate::__u32,
+        /* ioctl w/o */
+        pub lo_encrypt_key_size: crate::__u32,
+        pub lo_flags: crate::__u32,
+        pub lo_file_name: [crate::__u8; LO_NAME_SIZE],
+        pub lo_crypt_name: [crate::__u8; LO_NAME_SIZE],
+        /* ioctl w/o */
+        pub lo_encrypt_key: [crate::__u8; LO_KEY_SIZE],
+        pub lo_init: [crate::__u64; 2],
+    }
+
+    /*
+     * struct loop_config - Complete configuration for a loop device.
+     * @fd: fd of the file to be used as a backing file for the loop device.
+     * @block_size: block size to use; ignored if 0.
+     * @info: struct loop_info64 to configure the loop device with.
+     *
+     * This structure is used with the LOOP_CONFIGURE ioctl, and can be used to
+     * atomically setup and configure all loop device parameters at once.
+     */
+    pub struct loop_config {
+        pub fd: crate::__u32,
+        pub block_size: crate::__u32,
+        pub info: loop_info64,
+        __reserved: Padding<[crate::__u64; 8]>,
+    }
+}
+
+/*
+ * Loop filter types
+ */
+pub const LO_CRYPT_NONE: c_int = 0;
+pub const LO_CRYPT_XOR: c_int = 1;
+pub const LO_CRYPT_DES: c_int = 2;
+/* Twofish encryption */
+pub const LO_CRYPT_FISH2: c_int = 3;
+pub const LO_CRYPT_BLOW: c_int = 4;
+pub const LO_CRYPT_CAST128: c_int = 5;
+pub const LO_CRYPT_IDEA: c_int = 6;
+pub const LO_CRYPT_DUMMY: c_int = 9;
+pub const LO_CRYPT_SKIPJACK: c_int = 10;
+pub const LO_CRYPT_CRYPTOAPI: c_int = 18;
+pub const MAX_LO_CRYPT: c_int = 20;
+
+/*
+ * IOCTL commands --- we will commandeer 0x4C ('L')
+ */
+pub const LOOP_SET_FD: Ioctl = 0x4C00;
+pub const LOOP_CLR_FD: Ioctl = 0x4C01;
+pub const LOOP_SET_STATUS: Ioctl = 0x4C02;
+pub const LOOP_GET_STATUS: Ioctl = 0x4C03;
+pub const LOOP_SET_STATUS64: Ioctl = 0x4C04;
+pub const LOOP_GET_STATUS64: Ioctl = 0x4C05;
+pub const LOOP_CHANGE_FD: Ioctl = 0x4C06;
+pub const LOOP_SET_CAPACITY: Ioctl = 0x4C07;
+pub const LOOP_SET_DIRECT_IO: Ioctl = 0x4C08;
+pub const LOOP_SET_BLOCK_SIZE: Ioctl = 0x4C09;
+pub const LOOP_CONFIGURE: Ioctl = 0x4C0A;
+
+/* /dev/loop-control interface */
+pub const LOOP_CTL_ADD: Ioctl = 0x4C80;
+pub const LOOP_CTL_REMOVE: Ioctl = 0x4C81;
+pub const LOOP_CTL_GET_FREE: Ioctl = 0x4C82;
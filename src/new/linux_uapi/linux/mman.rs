@@ -0,0 +1,16 @@
+//! Header: `uapi/linux/mman.h`
+
+s! {
+    pub struct cachestat_range {
+        pub off: crate::__u64,
+        pub len: crate::__u64,
+    }
+
+    pub struct cachestat {
+        pub nr_cache: crate::__u64,
+        pub nr_dirty: crate::__u64,
+        pub nr_writeback: crate::__u64,
+        pub nr_evicted: crate::__u64,
+        pub nr_recently_evicted: crate::__u64,
+    }
+}
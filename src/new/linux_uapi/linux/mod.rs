@@ -2,12 +2,56 @@
 //!
 //! <https://github.com/torvalds/linux/tree/master/include/uapi/linux>
 
+pub(crate) mod aio_abi;
+pub(crate) mod audit;
+pub(crate) mod bpf;
+pub(crate) mod bpf_common;
 pub(crate) mod can;
+pub(crate) mod capability;
+pub(crate) mod cgroupstats;
+pub(crate) mod cn_proc;
+pub(crate) mod connector;
+pub(crate) mod devlink;
+pub(crate) mod dm_ioctl;
+pub(crate) mod ethtool;
+pub(crate) mod ethtool_netlink;
+pub(crate) mod fscrypt;
+pub(crate) mod fsverity;
 pub(crate) mod futex;
+pub(crate) mod if_addr;
 pub(crate) mod if_link;
 pub(crate) mod if_packet;
+pub(crate) mod input;
+pub(crate) mod input_event_codes;
+pub(crate) mod io_uring;
+pub(crate) mod iommufd;
 pub(crate) mod keyctl;
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "s390x",
+    target_arch = "x86_64"
+))]
+pub(crate) mod kvm;
+pub(crate) mod landlock;
+pub(crate) mod r#loop;
 pub(crate) mod membarrier;
+pub(crate) mod mman;
 pub(crate) mod mount;
+pub(crate) mod neighbour;
 pub(crate) mod netlink;
+pub(crate) mod nl80211;
+pub(crate) mod perf_event;
 pub(crate) mod pidfd;
+pub(crate) mod rseq;
+pub(crate) mod rtnetlink;
+pub(crate) mod sched;
+pub(crate) mod seccomp;
+pub(crate) mod securebits;
+pub(crate) mod taskstats;
+pub(crate) mod time_types;
+pub(crate) mod uinput;
+pub(crate) mod userfaultfd;
+pub(crate) mod v4l2_common;
+pub(crate) mod vfio;
+pub(crate) mod videodev2;
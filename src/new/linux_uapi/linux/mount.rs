@@ -66,3 +66,72 @@ s! {
 }
 
 pub const MOUNT_ATTR_SIZE_VER0: c_int = 32;
+
+s! {
+    /// Result of `statmount(2)`.
+    ///
+    /// The `[str]` fields (`fs_type`, `mnt_root`, `mnt_point`, ...) are offsets into the
+    /// variable-length string table that starts at `str`, so the caller must pass a buffer
+    /// larger than `size_of::<statmount>()`.
+    pub struct statmount {
+        pub size: crate::__u32,
+        pub mnt_opts: crate::__u32,
+        pub mask: crate::__u64,
+        pub sb_dev_major: crate::__u32,
+        pub sb_dev_minor: crate::__u32,
+        pub sb_magic: crate::__u64,
+        pub sb_flags: crate::__u32,
+        pub fs_type: crate::__u32,
+        pub mnt_id: crate::__u64,
+        pub mnt_parent_id: crate::__u64,
+        pub mnt_id_old: crate::__u32,
+        pub mnt_parent_id_old: crate::__u32,
+        pub mnt_attr: crate::__u64,
+        pub mnt_propagation: crate::__u64,
+        pub mnt_peer_group: crate::__u64,
+        pub mnt_master: crate::__u64,
+        pub propagate_from: crate::__u64,
+        pub mnt_root: crate::__u32,
+        pub mnt_point: crate::__u32,
+        pub mnt_ns_id: crate::__u64,
+        pub fs_subtype: crate::__u32,
+        pub sb_source: crate::__u32,
+        pub opt_num: crate::__u32,
+        pub opt_array: crate::__u32,
+        pub opt_sec_num: crate::__u32,
+        pub opt_sec_array: crate::__u32,
+        __spare2: Padding<[crate::__u64; 46]>,
+        pub str: [c_char; 0],
+    }
+
+    /* For statmount(2) `param` is the request mask, for listmount(2) the last listed mount id. */
+    pub struct mnt_id_req {
+        pub size: crate::__u32,
+        spare: Padding<crate::__u32>,
+        pub mnt_id: crate::__u64,
+        pub param: crate::__u64,
+        pub mnt_ns_id: crate::__u64,
+    }
+}
+
+/* List of all mnt_id_req versions. */
+pub const MNT_ID_REQ_SIZE_VER0: c_int = 24;
+pub const MNT_ID_REQ_SIZE_VER1: c_int = 32;
+
+/* @mask bits for statmount(2) */
+pub const STATMOUNT_SB_BASIC: c_uint = 0x00000001;
+pub const STATMOUNT_MNT_BASIC: c_uint = 0x00000002;
+pub const STATMOUNT_PROPAGATE_FROM: c_uint = 0x00000004;
+pub const STATMOUNT_MNT_ROOT: c_uint = 0x00000008;
+pub const STATMOUNT_MNT_POINT: c_uint = 0x00000010;
+pub const STATMOUNT_FS_TYPE: c_uint = 0x00000020;
+pub const STATMOUNT_MNT_NS_ID: c_uint = 0x00000040;
+pub const STATMOUNT_MNT_OPTS: c_uint = 0x00000080;
+pub const STATMOUNT_FS_SUBTYPE: c_uint = 0x00000100;
+pub const STATMOUNT_SB_SOURCE: c_uint = 0x00000200;
+pub const STATMOUNT_OPT_ARRAY: c_uint = 0x00000400;
+pub const STATMOUNT_OPT_SEC_ARRAY: c_uint = 0x00000800;
+
+/* Special @mnt_id values that can be passed to listmount */
+pub const LSMT_ROOT: crate::__u64 = 0xffffffffffffffff;
+pub const LISTMOUNT_REVERSE: c_uint = 1 << 0;
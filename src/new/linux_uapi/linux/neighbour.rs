@@ -0,0 +1,170 @@
+//! Header: `uapi/linux/neighbour.h`
+
+use crate::prelude::*;
+
+s! {
+    pub struct ndmsg {
+        pub ndm_family: crate::__u8,
+        ndm_pad1: Padding<crate::__u8>,
+        ndm_pad2: Padding<crate::__u16>,
+        pub ndm_ifindex: crate::__s32,
+        pub ndm_state: crate::__u16,
+        pub ndm_flags: crate::__u8,
+        pub ndm_type: crate::__u8,
+    }
+}
+
+c_enum! {
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub NDA_UNSPEC,
+        pub NDA_DST,
+        pub NDA_LLADDR,
+        pub NDA_CACHEINFO,
+        pub NDA_PROBES,
+        pub NDA_VLAN,
+        pub NDA_PORT,
+        pub NDA_VNI,
+        pub NDA_IFINDEX,
+        pub NDA_MASTER,
+        pub NDA_LINK_NETNSID,
+        pub NDA_SRC_VNI,
+        pub NDA_PROTOCOL,
+        pub NDA_NH_ID,
+        pub NDA_FDB_EXT_ATTRS,
+        pub NDA_FLAGS_EXT,
+        pub NDA_NDM_STATE_MASK,
+        pub NDA_NDM_FLAGS_MASK,
+    }
+}
+
+/* Neighbor Cache Entry Flags */
+pub const NTF_USE: u8 = 1 << 0;
+pub const NTF_SELF: u8 = 1 << 1;
+pub const NTF_MASTER: u8 = 1 << 2;
+pub const NTF_PROXY: u8 = 1 << 3;
+pub const NTF_EXT_LEARNED: u8 = 1 << 4;
+pub const NTF_OFFLOADED: u8 = 1 << 5;
+pub const NTF_STICKY: u8 = 1 << 6;
+pub const NTF_ROUTER: u8 = 1 << 7;
+
+/* Extended flags under NDA_FLAGS_EXT: */
+pub const NTF_EXT_MANAGED: u32 = 1 << 0;
+
+/* Neighbor Cache Entry States. */
+pub const NUD_INCOMPLETE: u16 = 0x01;
+pub const NUD_REACHABLE: u16 = 0x02;
+pub const NUD_STALE: u16 = 0x04;
+pub const NUD_DELAY: u16 = 0x08;
+pub const NUD_PROBE: u16 = 0x10;
+pub const NUD_FAILED: u16 = 0x20;
+
+/* Dummy states */
+pub const NUD_NOARP: u16 = 0x40;
+pub const NUD_PERMANENT: u16 = 0x80;
+pub const NUD_NONE: u16 = 0x00;
+
+s! {
+    pub struct nda_cacheinfo {
+        pub ndm_confirmed: crate::__u32,
+        pub ndm_used: crate::__u32,
+        pub ndm_updated: crate::__u32,
+        pub ndm_refcnt: crate::__u32,
+    }
+
+    /* Neighbour tables specific messages. */
+    pub struct ndt_stats {
+        pub ndts_allocs: crate::__u64,
+        pub ndts_destroys: crate::__u64,
+        pub ndts_hash_grows: crate::__u64,
+        pub ndts_res_failed: crate::__u64,
+        pub ndts_lookups: crate::__u64,
+        pub ndts_hits: crate::__u64,
+        pub ndts_rcv_probes_mcast: crate::__u64,
+        pub ndts_rcv_probes_ucast: crate::__u64,
+        pub ndts_periodic_gc_runs: crate::__u64,
+        pub ndts_forced_gc_runs: crate::__u64,
+        pub ndts_table_fulls: crate::__u64,
+    }
+}
+
+c_enum! {
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub NDTPA_UNSPEC,
+        pub NDTPA_IFINDEX,
+        pub NDTPA_REFCNT,
+        pub NDTPA_REACHABLE_TIME,
+        pub NDTPA_BASE_REACHABLE_TIME,
+        pub NDTPA_RETRANS_TIME,
+        pub NDTPA_GC_STALETIME,
+        pub NDTPA_DELAY_PROBE_TIME,
+        pub NDTPA_QUEUE_LEN,
+        pub NDTPA_APP_PROBES,
+        pub NDTPA_UCAST_PROBES,
+        pub NDTPA_MCAST_PROBES,
+        pub NDTPA_ANYCAST_DELAY,
+        pub NDTPA_PROXY_DELAY,
+        pub NDTPA_PROXY_QLEN,
+        pub NDTPA_LOCKTIME,
+        pub NDTPA_QUEUE_LENBYTES,
+        pub NDTPA_MCAST_REPROBES,
+        pub NDTPA_PAD,
+        pub NDTPA_INTERVAL_PROBE_TIME_MS,
+    }
+}
+
+s! {
+    pub struct ndtmsg {
+        pub ndtm_family: crate::__u8,
+        ndtm_pad1: Padding<crate::__u8>,
+        ndtm_pad2: Padding<crate::__u16>,
+    }
+
+    pub struct ndt_config {
+        pub ndtc_key_len: crate::__u16,
+        pub ndtc_entry_size: crate::__u16,
+        pub ndtc_entries: crate::__u32,
+        pub ndtc_last_flush: crate::__u32,
+        pub ndtc_last_rand: crate::__u32,
+        pub ndtc_hash_rnd: crate::__u32,
+        pub ndtc_hash_mask: crate::__u32,
+        pub ndtc_hash_chain_gc: crate::__u32,
+        pub ndtc_proxy_qlen: crate::__u32,
+    }
+}
+
+c_enum! {
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub NDTA_UNSPEC,
+        pub NDTA_NAME,
+        pub NDTA_THRESH1,
+        pub NDTA_THRESH2,
+        pub NDTA_THRESH3,
+        pub NDTA_CONFIG,
+        pub NDTA_PARMS,
+        pub NDTA_STATS,
+        pub NDTA_GC_INTERVAL,
+        pub NDTA_PAD,
+    }
+}
+
+/* FDB activity notification bits used in NFEA_ACTIVITY_NOTIFY */
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub FDB_NOTIFY_BIT = 1 << 0,
+        pub FDB_NOTIFY_INACTIVE_BIT = 1 << 1,
+    }
+}
+
+/* embedded into NDA_FDB_EXT_ATTRS */
+c_enum! {
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub NFEA_UNSPEC,
+        pub NFEA_ACTIVITY_NOTIFY,
+        pub NFEA_DONT_REFRESH,
+    }
+}
@@ -67,6 +67,46 @@ pub const NLM_F_NONREC: c_int = 0x100;
 pub const NLM_F_CAPPED: c_int = 0x100;
 pub const NLM_F_ACK_TLVS: c_int = 0x200;
 
+pub const NLMSG_ALIGNTO: c_int = 4;
+
+safe_f! {
+    pub const fn NLMSG_ALIGN(len: c_int) -> c_int {
+        (len + NLMSG_ALIGNTO - 1) & !(NLMSG_ALIGNTO - 1)
+    }
+
+    pub const fn NLMSG_LENGTH(len: c_int) -> c_int {
+        len + NLMSG_HDRLEN
+    }
+
+    pub const fn NLMSG_SPACE(len: c_int) -> c_int {
+        NLMSG_ALIGN(NLMSG_LENGTH(len))
+    }
+}
+
+pub const NLMSG_HDRLEN: c_int = NLMSG_ALIGN(size_of::<nlmsghdr>() as c_int);
+
+f! {
+    pub fn NLMSG_DATA(nlh: *const nlmsghdr) -> *mut c_void {
+        nlh.cast::<c_char>().offset(NLMSG_HDRLEN as isize) as *mut c_void
+    }
+
+    pub fn NLMSG_NEXT(nlh: *const nlmsghdr, len: *mut c_int) -> *mut nlmsghdr {
+        let aligned = NLMSG_ALIGN((*nlh).nlmsg_len as c_int);
+        *len -= aligned;
+        nlh.cast::<c_char>().offset(aligned as isize) as *mut nlmsghdr
+    }
+
+    pub fn NLMSG_OK(nlh: *const nlmsghdr, len: c_int) -> bool {
+        len >= size_of::<nlmsghdr>() as c_int
+            && (*nlh).nlmsg_len as usize >= size_of::<nlmsghdr>()
+            && (*nlh).nlmsg_len as c_int <= len
+    }
+
+    pub fn NLMSG_PAYLOAD(nlh: *const nlmsghdr, len: c_int) -> c_int {
+        (*nlh).nlmsg_len as c_int - NLMSG_SPACE(len)
+    }
+}
+
 pub const NLMSG_NOOP: c_int = 0x1;
 pub const NLMSG_ERROR: c_int = 0x2;
 pub const NLMSG_DONE: c_int = 0x3;
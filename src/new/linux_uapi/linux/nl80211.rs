@@ -0,0 +1,829 @@
+//! Header: `uapi/linux/nl80211.h`
+
+use crate::prelude::*;
+
+pub const NL80211_GENL_NAME: &[u8] = b"nl80211\0";
+
+pub const NL80211_MULTICAST_GROUP_CONFIG: &[u8] = b"config\0";
+pub const NL80211_MULTICAST_GROUP_SCAN: &[u8] = b"scan\0";
+pub const NL80211_MULTICAST_GROUP_REG: &[u8] = b"regulatory\0";
+pub const NL80211_MULTICAST_GROUP_MLME: &[u8] = b"mlme\0";
+pub const NL80211_MULTICAST_GROUP_VENDOR: &[u8] = b"vendor\0";
+pub const NL80211_MULTICAST_GROUP_NAN: &[u8] = b"nan\0";
+pub const NL80211_MULTICAST_GROUP_TESTMODE: &[u8] = b"testmode\0";
+
+c_enum! {
+    pub enum nl80211_commands {
+        pub NL80211_CMD_UNSPEC,
+        pub NL80211_CMD_GET_WIPHY,
+        pub NL80211_CMD_SET_WIPHY,
+        pub NL80211_CMD_NEW_WIPHY,
+        pub NL80211_CMD_DEL_WIPHY,
+        pub NL80211_CMD_GET_INTERFACE,
+        pub NL80211_CMD_SET_INTERFACE,
+        pub NL80211_CMD_NEW_INTERFACE,
+        pub NL80211_CMD_DEL_INTERFACE,
+        pub NL80211_CMD_GET_KEY,
+        pub NL80211_CMD_SET_KEY,
+        pub NL80211_CMD_NEW_KEY,
+        pub NL80211_CMD_DEL_KEY,
+        pub NL80211_CMD_GET_BEACON,
+        pub NL80211_CMD_SET_BEACON,
+        pub NL80211_CMD_START_AP,
+        pub NL80211_CMD_NEW_BEACON = NL80211_CMD_START_AP,
+        pub NL80211_CMD_STOP_AP,
+        pub NL80211_CMD_DEL_BEACON = NL80211_CMD_STOP_AP,
+        pub NL80211_CMD_GET_STATION,
+        pub NL80211_CMD_SET_STATION,
+        pub NL80211_CMD_NEW_STATION,
+        pub NL80211_CMD_DEL_STATION,
+        pub NL80211_CMD_GET_MPATH,
+        pub NL80211_CMD_SET_MPATH,
+        pub NL80211_CMD_NEW_MPATH,
+        pub NL80211_CMD_DEL_MPATH,
+        pub NL80211_CMD_SET_BSS,
+        pub NL80211_CMD_SET_REG,
+        pub NL80211_CMD_REQ_SET_REG,
+        pub NL80211_CMD_GET_MESH_CONFIG,
+        pub NL80211_CMD_SET_MESH_CONFIG,
+        pub NL80211_CMD_SET_MGMT_EXTRA_IE,
+        pub NL80211_CMD_GET_REG,
+        pub NL80211_CMD_GET_SCAN,
+        pub NL80211_CMD_TRIGGER_SCAN,
+        pub NL80211_CMD_NEW_SCAN_RESULTS,
+        pub NL80211_CMD_SCAN_ABORTED,
+        pub NL80211_CMD_REG_CHANGE,
+        pub NL80211_CMD_AUTHENTICATE,
+        pub NL80211_CMD_ASSOCIATE,
+        pub NL80211_CMD_DEAUTHENTICATE,
+        pub NL80211_CMD_DISASSOCIATE,
+        pub NL80211_CMD_MICHAEL_MIC_FAILURE,
+        pub NL80211_CMD_REG_BEACON_HINT,
+        pub NL80211_CMD_JOIN_IBSS,
+        pub NL80211_CMD_LEAVE_IBSS,
+        pub NL80211_CMD_TESTMODE,
+        pub NL80211_CMD_CONNECT,
+        pub NL80211_CMD_ROAM,
+        pub NL80211_CMD_DISCONNECT,
+        pub NL80211_CMD_SET_WIPHY_NETNS,
+        pub NL80211_CMD_GET_SURVEY,
+        pub NL80211_CMD_NEW_SURVEY_RESULTS,
+        pub NL80211_CMD_SET_PMKSA,
+        pub NL80211_CMD_DEL_PMKSA,
+        pub NL80211_CMD_FLUSH_PMKSA,
+        pub NL80211_CMD_REMAIN_ON_CHANNEL,
+        pub NL80211_CMD_CANCEL_REMAIN_ON_CHANNEL,
+        pub NL80211_CMD_SET_TX_BITRATE_MASK,
+        pub NL80211_CMD_REGISTER_FRAME,
+        pub NL80211_CMD_REGISTER_ACTION = NL80211_CMD_REGISTER_FRAME,
+        pub NL80211_CMD_FRAME,
+        pub NL80211_CMD_ACTION = NL80211_CMD_FRAME,
+        pub NL80211_CMD_FRAME_TX_STATUS,
+        pub NL80211_CMD_ACTION_TX_STATUS = NL80211_CMD_FRAME_TX_STATUS,
+        pub NL80211_CMD_SET_POWER_SAVE,
+        pub NL80211_CMD_GET_POWER_SAVE,
+        pub NL80211_CMD_SET_CQM,
+        pub NL80211_CMD_NOTIFY_CQM,
+        pub NL80211_CMD_SET_CHANNEL,
+        pub NL80211_CMD_SET_WDS_PEER,
+        pub NL80211_CMD_FRAME_WAIT_CANCEL,
+        pub NL80211_CMD_JOIN_MESH,
+        pub NL80211_CMD_LEAVE_MESH,
+        pub NL80211_CMD_UNPROT_DEAUTHENTICATE,
+        pub NL80211_CMD_UNPROT_DISASSOCIATE,
+        pub NL80211_CMD_NEW_PEER_CANDIDATE,
+        pub NL80211_CMD_GET_WOWLAN,
+        pub NL80211_CMD_SET_WOWLAN,
+        pub NL80211_CMD_START_SCHED_SCAN,
+        pub NL80211_CMD_STOP_SCHED_SCAN,
+        pub NL80211_CMD_SCHED_SCAN_RESULTS,
+        pub NL80211_CMD_SCHED_SCAN_STOPPED,
+        pub NL80211_CMD_SET_REKEY_OFFLOAD,
+        pub NL80211_CMD_PMKSA_CANDIDATE,
+        pub NL80211_CMD_TDLS_OPER,
+        pub NL80211_CMD_TDLS_MGMT,
+        pub NL80211_CMD_UNEXPECTED_FRAME,
+        pub NL80211_CMD_PROBE_CLIENT,
+        pub NL80211_CMD_REGISTER_BEACONS,
+        pub NL80211_CMD_UNEXPECTED_4ADDR_FRAME,
+        pub NL80211_CMD_SET_NOACK_MAP,
+        pub NL80211_CMD_CH_SWITCH_NOTIFY,
+        pub NL80211_CMD_START_P2P_DEVICE,
+        pub NL80211_CMD_STOP_P2P_DEVICE,
+        pub NL80211_CMD_CONN_FAILED,
+        pub NL80211_CMD_SET_MCAST_RATE,
+        pub NL80211_CMD_SET_MAC_ACL,
+        pub NL80211_CMD_RADAR_DETECT,
+    }
+}
+
+// Continuation of `enum nl80211_commands`, split to stay within the `c_enum!` recursion limit.
+c_enum! {
+    #[repr(nl80211_commands)]
+    pub enum #anon {
+        pub NL80211_CMD_GET_PROTOCOL_FEATURES = 95,
+        pub NL80211_CMD_UPDATE_FT_IES,
+        pub NL80211_CMD_FT_EVENT,
+        pub NL80211_CMD_CRIT_PROTOCOL_START,
+        pub NL80211_CMD_CRIT_PROTOCOL_STOP,
+        pub NL80211_CMD_GET_COALESCE,
+        pub NL80211_CMD_SET_COALESCE,
+        pub NL80211_CMD_CHANNEL_SWITCH,
+        pub NL80211_CMD_VENDOR,
+        pub NL80211_CMD_SET_QOS_MAP,
+        pub NL80211_CMD_ADD_TX_TS,
+        pub NL80211_CMD_DEL_TX_TS,
+        pub NL80211_CMD_GET_MPP,
+        pub NL80211_CMD_JOIN_OCB,
+        pub NL80211_CMD_LEAVE_OCB,
+        pub NL80211_CMD_CH_SWITCH_STARTED_NOTIFY,
+        pub NL80211_CMD_TDLS_CHANNEL_SWITCH,
+        pub NL80211_CMD_TDLS_CANCEL_CHANNEL_SWITCH,
+        pub NL80211_CMD_WIPHY_REG_CHANGE,
+        pub NL80211_CMD_ABORT_SCAN,
+        pub NL80211_CMD_START_NAN,
+        pub NL80211_CMD_STOP_NAN,
+        pub NL80211_CMD_ADD_NAN_FUNCTION,
+        pub NL80211_CMD_DEL_NAN_FUNCTION,
+        pub NL80211_CMD_CHANGE_NAN_CONFIG,
+        pub NL80211_CMD_NAN_MATCH,
+        pub NL80211_CMD_SET_MULTICAST_TO_UNICAST,
+        pub NL80211_CMD_UPDATE_CONNECT_PARAMS,
+        pub NL80211_CMD_SET_PMK,
+        pub NL80211_CMD_DEL_PMK,
+        pub NL80211_CMD_PORT_AUTHORIZED,
+        pub NL80211_CMD_RELOAD_REGDB,
+        pub NL80211_CMD_EXTERNAL_AUTH,
+        pub NL80211_CMD_STA_OPMODE_CHANGED,
+        pub NL80211_CMD_CONTROL_PORT_FRAME,
+        pub NL80211_CMD_GET_FTM_RESPONDER_STATS,
+        pub NL80211_CMD_PEER_MEASUREMENT_START,
+        pub NL80211_CMD_PEER_MEASUREMENT_RESULT,
+        pub NL80211_CMD_PEER_MEASUREMENT_COMPLETE,
+        pub NL80211_CMD_NOTIFY_RADAR,
+        pub NL80211_CMD_UPDATE_OWE_INFO,
+        pub NL80211_CMD_PROBE_MESH_LINK,
+        pub NL80211_CMD_SET_TID_CONFIG,
+        pub NL80211_CMD_UNPROT_BEACON,
+        pub NL80211_CMD_CONTROL_PORT_FRAME_TX_STATUS,
+        pub NL80211_CMD_SET_SAR_SPECS,
+        pub NL80211_CMD_OBSS_COLOR_COLLISION,
+        pub NL80211_CMD_COLOR_CHANGE_REQUEST,
+        pub NL80211_CMD_COLOR_CHANGE_STARTED,
+        pub NL80211_CMD_COLOR_CHANGE_ABORTED,
+        pub NL80211_CMD_COLOR_CHANGE_COMPLETED,
+        pub NL80211_CMD_SET_FILS_AAD,
+        pub NL80211_CMD_ASSOC_COMEBACK,
+        pub NL80211_CMD_ADD_LINK,
+        pub NL80211_CMD_REMOVE_LINK,
+        pub NL80211_CMD_ADD_LINK_STA,
+        pub NL80211_CMD_MODIFY_LINK_STA,
+        pub NL80211_CMD_REMOVE_LINK_STA,
+    }
+}
+
+/* source-level API compatibility */
+pub const NL80211_CMD_GET_MESH_PARAMS: nl80211_commands = NL80211_CMD_GET_MESH_CONFIG;
+pub const NL80211_CMD_SET_MESH_PARAMS: nl80211_commands = NL80211_CMD_SET_MESH_CONFIG;
+
+c_enum! {
+    pub enum nl80211_attrs {
+        pub NL80211_ATTR_UNSPEC,
+        pub NL80211_ATTR_WIPHY,
+        pub NL80211_ATTR_WIPHY_NAME,
+        pub NL80211_ATTR_IFINDEX,
+        pub NL80211_ATTR_IFNAME,
+        pub NL80211_ATTR_IFTYPE,
+        pub NL80211_ATTR_MAC,
+        pub NL80211_ATTR_KEY_DATA,
+        pub NL80211_ATTR_KEY_IDX,
+        pub NL80211_ATTR_KEY_CIPHER,
+        pub NL80211_ATTR_KEY_SEQ,
+        pub NL80211_ATTR_KEY_DEFAULT,
+        pub NL80211_ATTR_BEACON_INTERVAL,
+        pub NL80211_ATTR_DTIM_PERIOD,
+        pub NL80211_ATTR_BEACON_HEAD,
+        pub NL80211_ATTR_BEACON_TAIL,
+        pub NL80211_ATTR_STA_AID,
+        pub NL80211_ATTR_STA_FLAGS,
+        pub NL80211_ATTR_STA_LISTEN_INTERVAL,
+        pub NL80211_ATTR_STA_SUPPORTED_RATES,
+        pub NL80211_ATTR_STA_VLAN,
+        pub NL80211_ATTR_STA_INFO,
+        pub NL80211_ATTR_WIPHY_BANDS,
+        pub NL80211_ATTR_MNTR_FLAGS,
+        pub NL80211_ATTR_MESH_ID,
+        pub NL80211_ATTR_STA_PLINK_ACTION,
+        pub NL80211_ATTR_MPATH_NEXT_HOP,
+        pub NL80211_ATTR_MPATH_INFO,
+        pub NL80211_ATTR_BSS_CTS_PROT,
+        pub NL80211_ATTR_BSS_SHORT_PREAMBLE,
+        pub NL80211_ATTR_BSS_SHORT_SLOT_TIME,
+        pub NL80211_ATTR_HT_CAPABILITY,
+        pub NL80211_ATTR_SUPPORTED_IFTYPES,
+        pub NL80211_ATTR_REG_ALPHA2,
+        pub NL80211_ATTR_REG_RULES,
+        pub NL80211_ATTR_MESH_CONFIG,
+        pub NL80211_ATTR_BSS_BASIC_RATES,
+        pub NL80211_ATTR_WIPHY_TXQ_PARAMS,
+        pub NL80211_ATTR_WIPHY_FREQ,
+        pub NL80211_ATTR_WIPHY_CHANNEL_TYPE,
+        pub NL80211_ATTR_KEY_DEFAULT_MGMT,
+        pub NL80211_ATTR_MGMT_SUBTYPE,
+        pub NL80211_ATTR_IE,
+        pub NL80211_ATTR_MAX_NUM_SCAN_SSIDS,
+        pub NL80211_ATTR_SCAN_FREQUENCIES,
+        pub NL80211_ATTR_SCAN_SSIDS,
+        pub NL80211_ATTR_GENERATION,
+        pub NL80211_ATTR_BSS,
+        pub NL80211_ATTR_REG_INITIATOR,
+        pub NL80211_ATTR_REG_TYPE,
+        pub NL80211_ATTR_SUPPORTED_COMMANDS,
+        pub NL80211_ATTR_FRAME,
+        pub NL80211_ATTR_SSID,
+        pub NL80211_ATTR_AUTH_TYPE,
+        pub NL80211_ATTR_REASON_CODE,
+        pub NL80211_ATTR_KEY_TYPE,
+        pub NL80211_ATTR_MAX_SCAN_IE_LEN,
+        pub NL80211_ATTR_CIPHER_SUITES,
+        pub NL80211_ATTR_FREQ_BEFORE,
+        pub NL80211_ATTR_FREQ_AFTER,
+        pub NL80211_ATTR_FREQ_FIXED,
+        pub NL80211_ATTR_WIPHY_RETRY_SHORT,
+        pub NL80211_ATTR_WIPHY_RETRY_LONG,
+        pub NL80211_ATTR_WIPHY_FRAG_THRESHOLD,
+        pub NL80211_ATTR_WIPHY_RTS_THRESHOLD,
+        pub NL80211_ATTR_TIMED_OUT,
+        pub NL80211_ATTR_USE_MFP,
+        pub NL80211_ATTR_STA_FLAGS2,
+        pub NL80211_ATTR_CONTROL_PORT,
+        pub NL80211_ATTR_TESTDATA,
+        pub NL80211_ATTR_PRIVACY,
+        pub NL80211_ATTR_DISCONNECTED_BY_AP,
+        pub NL80211_ATTR_STATUS_CODE,
+        pub NL80211_ATTR_CIPHER_SUITES_PAIRWISE,
+        pub NL80211_ATTR_CIPHER_SUITE_GROUP,
+        pub NL80211_ATTR_WPA_VERSIONS,
+        pub NL80211_ATTR_AKM_SUITES,
+        pub NL80211_ATTR_REQ_IE,
+        pub NL80211_ATTR_RESP_IE,
+        pub NL80211_ATTR_PREV_BSSID,
+        pub NL80211_ATTR_KEY,
+        pub NL80211_ATTR_KEYS,
+        pub NL80211_ATTR_PID,
+        pub NL80211_ATTR_4ADDR,
+        pub NL80211_ATTR_SURVEY_INFO,
+        pub NL80211_ATTR_PMKID,
+        pub NL80211_ATTR_MAX_NUM_PMKIDS,
+        pub NL80211_ATTR_DURATION,
+        pub NL80211_ATTR_COOKIE,
+        pub NL80211_ATTR_WIPHY_COVERAGE_CLASS,
+        pub NL80211_ATTR_TX_RATES,
+        pub NL80211_ATTR_FRAME_MATCH,
+        pub NL80211_ATTR_ACK,
+        pub NL80211_ATTR_PS_STATE,
+        pub NL80211_ATTR_CQM,
+        pub NL80211_ATTR_LOCAL_STATE_CHANGE,
+        pub NL80211_ATTR_AP_ISOLATE,
+        pub NL80211_ATTR_WIPHY_TX_POWER_SETTING,
+        pub NL80211_ATTR_WIPHY_TX_POWER_LEVEL,
+        pub NL80211_ATTR_TX_FRAME_TYPES,
+    }
+}
+
+// Continuation of `enum nl80211_attrs`, split to stay within the `c_enum!` recursion limit.
+c_enum! {
+    #[repr(nl80211_attrs)]
+    pub enum #anon {
+        pub NL80211_ATTR_RX_FRAME_TYPES = 100,
+        pub NL80211_ATTR_FRAME_TYPE,
+        pub NL80211_ATTR_CONTROL_PORT_ETHERTYPE,
+        pub NL80211_ATTR_CONTROL_PORT_NO_ENCRYPT,
+        pub NL80211_ATTR_SUPPORT_IBSS_RSN,
+        pub NL80211_ATTR_WIPHY_ANTENNA_TX,
+        pub NL80211_ATTR_WIPHY_ANTENNA_RX,
+        pub NL80211_ATTR_MCAST_RATE,
+        pub NL80211_ATTR_OFFCHANNEL_TX_OK,
+        pub NL80211_ATTR_BSS_HT_OPMODE,
+        pub NL80211_ATTR_KEY_DEFAULT_TYPES,
+        pub NL80211_ATTR_MAX_REMAIN_ON_CHANNEL_DURATION,
+        pub NL80211_ATTR_MESH_SETUP,
+        pub NL80211_ATTR_WIPHY_ANTENNA_AVAIL_TX,
+        pub NL80211_ATTR_WIPHY_ANTENNA_AVAIL_RX,
+        pub NL80211_ATTR_SUPPORT_MESH_AUTH,
+        pub NL80211_ATTR_STA_PLINK_STATE,
+        pub NL80211_ATTR_WOWLAN_TRIGGERS,
+        pub NL80211_ATTR_WOWLAN_TRIGGERS_SUPPORTED,
+        pub NL80211_ATTR_SCHED_SCAN_INTERVAL,
+        pub NL80211_ATTR_INTERFACE_COMBINATIONS,
+        pub NL80211_ATTR_SOFTWARE_IFTYPES,
+        pub NL80211_ATTR_REKEY_DATA,
+        pub NL80211_ATTR_MAX_NUM_SCHED_SCAN_SSIDS,
+        pub NL80211_ATTR_MAX_SCHED_SCAN_IE_LEN,
+        pub NL80211_ATTR_SCAN_SUPP_RATES,
+        pub NL80211_ATTR_HIDDEN_SSID,
+        pub NL80211_ATTR_IE_PROBE_RESP,
+        pub NL80211_ATTR_IE_ASSOC_RESP,
+        pub NL80211_ATTR_STA_WME,
+        pub NL80211_ATTR_SUPPORT_AP_UAPSD,
+        pub NL80211_ATTR_ROAM_SUPPORT,
+        pub NL80211_ATTR_SCHED_SCAN_MATCH,
+        pub NL80211_ATTR_MAX_MATCH_SETS,
+        pub NL80211_ATTR_PMKSA_CANDIDATE,
+        pub NL80211_ATTR_TX_NO_CCK_RATE,
+        pub NL80211_ATTR_TDLS_ACTION,
+        pub NL80211_ATTR_TDLS_DIALOG_TOKEN,
+        pub NL80211_ATTR_TDLS_OPERATION,
+        pub NL80211_ATTR_TDLS_SUPPORT,
+        pub NL80211_ATTR_TDLS_EXTERNAL_SETUP,
+        pub NL80211_ATTR_DEVICE_AP_SME,
+        pub NL80211_ATTR_DONT_WAIT_FOR_ACK,
+        pub NL80211_ATTR_FEATURE_FLAGS,
+        pub NL80211_ATTR_PROBE_RESP_OFFLOAD,
+        pub NL80211_ATTR_PROBE_RESP,
+        pub NL80211_ATTR_DFS_REGION,
+        pub NL80211_ATTR_DISABLE_HT,
+        pub NL80211_ATTR_HT_CAPABILITY_MASK,
+        pub NL80211_ATTR_NOACK_MAP,
+        pub NL80211_ATTR_INACTIVITY_TIMEOUT,
+        pub NL80211_ATTR_RX_SIGNAL_DBM,
+        pub NL80211_ATTR_BG_SCAN_PERIOD,
+        pub NL80211_ATTR_WDEV,
+        pub NL80211_ATTR_USER_REG_HINT_TYPE,
+        pub NL80211_ATTR_CONN_FAILED_REASON,
+        pub NL80211_ATTR_AUTH_DATA,
+        pub NL80211_ATTR_VHT_CAPABILITY,
+        pub NL80211_ATTR_SCAN_FLAGS,
+        pub NL80211_ATTR_CHANNEL_WIDTH,
+        pub NL80211_ATTR_CENTER_FREQ1,
+        pub NL80211_ATTR_CENTER_FREQ2,
+        pub NL80211_ATTR_P2P_CTWINDOW,
+        pub NL80211_ATTR_P2P_OPPPS,
+        pub NL80211_ATTR_LOCAL_MESH_POWER_MODE,
+        pub NL80211_ATTR_ACL_POLICY,
+        pub NL80211_ATTR_MAC_ADDRS,
+        pub NL80211_ATTR_MAC_ACL_MAX,
+        pub NL80211_ATTR_RADAR_EVENT,
+        pub NL80211_ATTR_EXT_CAPA,
+        pub NL80211_ATTR_EXT_CAPA_MASK,
+        pub NL80211_ATTR_STA_CAPABILITY,
+        pub NL80211_ATTR_STA_EXT_CAPABILITY,
+        pub NL80211_ATTR_PROTOCOL_FEATURES,
+        pub NL80211_ATTR_SPLIT_WIPHY_DUMP,
+        pub NL80211_ATTR_DISABLE_VHT,
+        pub NL80211_ATTR_VHT_CAPABILITY_MASK,
+        pub NL80211_ATTR_MDID,
+        pub NL80211_ATTR_IE_RIC,
+        pub NL80211_ATTR_CRIT_PROT_ID,
+        pub NL80211_ATTR_MAX_CRIT_PROT_DURATION,
+        pub NL80211_ATTR_PEER_AID,
+        pub NL80211_ATTR_COALESCE_RULE,
+        pub NL80211_ATTR_CH_SWITCH_COUNT,
+        pub NL80211_ATTR_CH_SWITCH_BLOCK_TX,
+        pub NL80211_ATTR_CSA_IES,
+        pub NL80211_ATTR_CNTDWN_OFFS_BEACON,
+        pub NL80211_ATTR_CNTDWN_OFFS_PRESP,
+        pub NL80211_ATTR_RXMGMT_FLAGS,
+        pub NL80211_ATTR_STA_SUPPORTED_CHANNELS,
+        pub NL80211_ATTR_STA_SUPPORTED_OPER_CLASSES,
+        pub NL80211_ATTR_HANDLE_DFS,
+        pub NL80211_ATTR_SUPPORT_5_MHZ,
+        pub NL80211_ATTR_SUPPORT_10_MHZ,
+        pub NL80211_ATTR_OPMODE_NOTIF,
+        pub NL80211_ATTR_VENDOR_ID,
+        pub NL80211_ATTR_VENDOR_SUBCMD,
+        pub NL80211_ATTR_VENDOR_DATA,
+        pub NL80211_ATTR_VENDOR_EVENTS,
+        pub NL80211_ATTR_QOS_MAP,
+    }
+}
+
+// Continuation of `enum nl80211_attrs`, split to stay within the `c_enum!` recursion limit.
+c_enum! {
+    #[repr(nl80211_attrs)]
+    pub enum #anon {
+        pub NL80211_ATTR_MAC_HINT = 200,
+        pub NL80211_ATTR_WIPHY_FREQ_HINT,
+        pub NL80211_ATTR_MAX_AP_ASSOC_STA,
+        pub NL80211_ATTR_TDLS_PEER_CAPABILITY,
+        pub NL80211_ATTR_SOCKET_OWNER,
+        pub NL80211_ATTR_CSA_C_OFFSETS_TX,
+        pub NL80211_ATTR_MAX_CSA_COUNTERS,
+        pub NL80211_ATTR_TDLS_INITIATOR,
+        pub NL80211_ATTR_USE_RRM,
+        pub NL80211_ATTR_WIPHY_DYN_ACK,
+        pub NL80211_ATTR_TSID,
+        pub NL80211_ATTR_USER_PRIO,
+        pub NL80211_ATTR_ADMITTED_TIME,
+        pub NL80211_ATTR_SMPS_MODE,
+        pub NL80211_ATTR_OPER_CLASS,
+        pub NL80211_ATTR_MAC_MASK,
+        pub NL80211_ATTR_WIPHY_SELF_MANAGED_REG,
+        pub NL80211_ATTR_EXT_FEATURES,
+        pub NL80211_ATTR_SURVEY_RADIO_STATS,
+        pub NL80211_ATTR_NETNS_FD,
+        pub NL80211_ATTR_SCHED_SCAN_DELAY,
+        pub NL80211_ATTR_REG_INDOOR,
+        pub NL80211_ATTR_MAX_NUM_SCHED_SCAN_PLANS,
+        pub NL80211_ATTR_MAX_SCAN_PLAN_INTERVAL,
+        pub NL80211_ATTR_MAX_SCAN_PLAN_ITERATIONS,
+        pub NL80211_ATTR_SCHED_SCAN_PLANS,
+        pub NL80211_ATTR_PBSS,
+        pub NL80211_ATTR_BSS_SELECT,
+        pub NL80211_ATTR_STA_SUPPORT_P2P_PS,
+        pub NL80211_ATTR_PAD,
+        pub NL80211_ATTR_IFTYPE_EXT_CAPA,
+        pub NL80211_ATTR_MU_MIMO_GROUP_DATA,
+        pub NL80211_ATTR_MU_MIMO_FOLLOW_MAC_ADDR,
+        pub NL80211_ATTR_SCAN_START_TIME_TSF,
+        pub NL80211_ATTR_SCAN_START_TIME_TSF_BSSID,
+        pub NL80211_ATTR_MEASUREMENT_DURATION,
+        pub NL80211_ATTR_MEASUREMENT_DURATION_MANDATORY,
+        pub NL80211_ATTR_MESH_PEER_AID,
+        pub NL80211_ATTR_NAN_MASTER_PREF,
+        pub NL80211_ATTR_BANDS,
+        pub NL80211_ATTR_NAN_FUNC,
+        pub NL80211_ATTR_NAN_MATCH,
+        pub NL80211_ATTR_FILS_KEK,
+        pub NL80211_ATTR_FILS_NONCES,
+        pub NL80211_ATTR_MULTICAST_TO_UNICAST_ENABLED,
+        pub NL80211_ATTR_BSSID,
+        pub NL80211_ATTR_SCHED_SCAN_RELATIVE_RSSI,
+        pub NL80211_ATTR_SCHED_SCAN_RSSI_ADJUST,
+        pub NL80211_ATTR_TIMEOUT_REASON,
+        pub NL80211_ATTR_FILS_ERP_USERNAME,
+        pub NL80211_ATTR_FILS_ERP_REALM,
+        pub NL80211_ATTR_FILS_ERP_NEXT_SEQ_NUM,
+        pub NL80211_ATTR_FILS_ERP_RRK,
+        pub NL80211_ATTR_FILS_CACHE_ID,
+        pub NL80211_ATTR_PMK,
+        pub NL80211_ATTR_SCHED_SCAN_MULTI,
+        pub NL80211_ATTR_SCHED_SCAN_MAX_REQS,
+        pub NL80211_ATTR_WANT_1X_4WAY_HS,
+        pub NL80211_ATTR_PMKR0_NAME,
+        pub NL80211_ATTR_PORT_AUTHORIZED,
+        pub NL80211_ATTR_EXTERNAL_AUTH_ACTION,
+        pub NL80211_ATTR_EXTERNAL_AUTH_SUPPORT,
+        pub NL80211_ATTR_NSS,
+        pub NL80211_ATTR_ACK_SIGNAL,
+        pub NL80211_ATTR_CONTROL_PORT_OVER_NL80211,
+        pub NL80211_ATTR_TXQ_STATS,
+        pub NL80211_ATTR_TXQ_LIMIT,
+        pub NL80211_ATTR_TXQ_MEMORY_LIMIT,
+        pub NL80211_ATTR_TXQ_QUANTUM,
+        pub NL80211_ATTR_HE_CAPABILITY,
+        pub NL80211_ATTR_FTM_RESPONDER,
+        pub NL80211_ATTR_FTM_RESPONDER_STATS,
+        pub NL80211_ATTR_TIMEOUT,
+        pub NL80211_ATTR_PEER_MEASUREMENTS,
+        pub NL80211_ATTR_AIRTIME_WEIGHT,
+        pub NL80211_ATTR_STA_TX_POWER_SETTING,
+        pub NL80211_ATTR_STA_TX_POWER,
+        pub NL80211_ATTR_SAE_PASSWORD,
+        pub NL80211_ATTR_TWT_RESPONDER,
+        pub NL80211_ATTR_HE_OBSS_PD,
+        pub NL80211_ATTR_WIPHY_EDMG_CHANNELS,
+        pub NL80211_ATTR_WIPHY_EDMG_BW_CONFIG,
+        pub NL80211_ATTR_VLAN_ID,
+        pub NL80211_ATTR_HE_BSS_COLOR,
+        pub NL80211_ATTR_IFTYPE_AKM_SUITES,
+        pub NL80211_ATTR_TID_CONFIG,
+        pub NL80211_ATTR_CONTROL_PORT_NO_PREAUTH,
+        pub NL80211_ATTR_PMK_LIFETIME,
+        pub NL80211_ATTR_PMK_REAUTH_THRESHOLD,
+        pub NL80211_ATTR_RECEIVE_MULTICAST,
+        pub NL80211_ATTR_WIPHY_FREQ_OFFSET,
+        pub NL80211_ATTR_CENTER_FREQ1_OFFSET,
+        pub NL80211_ATTR_SCAN_FREQ_KHZ,
+        pub NL80211_ATTR_HE_6GHZ_CAPABILITY,
+        pub NL80211_ATTR_FILS_DISCOVERY,
+        pub NL80211_ATTR_UNSOL_BCAST_PROBE_RESP,
+        pub NL80211_ATTR_S1G_CAPABILITY,
+        pub NL80211_ATTR_S1G_CAPABILITY_MASK,
+        pub NL80211_ATTR_SAE_PWE,
+        pub NL80211_ATTR_RECONNECT_REQUESTED,
+    }
+}
+
+// Continuation of `enum nl80211_attrs`, split to stay within the `c_enum!` recursion limit.
+c_enum! {
+    #[repr(nl80211_attrs)]
+    pub enum #anon {
+        pub NL80211_ATTR_SAR_SPEC = 300,
+        pub NL80211_ATTR_DISABLE_HE,
+        pub NL80211_ATTR_OBSS_COLOR_BITMAP,
+        pub NL80211_ATTR_COLOR_CHANGE_COUNT,
+        pub NL80211_ATTR_COLOR_CHANGE_COLOR,
+        pub NL80211_ATTR_COLOR_CHANGE_ELEMS,
+        pub NL80211_ATTR_MBSSID_CONFIG,
+        pub NL80211_ATTR_MBSSID_ELEMS,
+        pub NL80211_ATTR_RADAR_BACKGROUND,
+        pub NL80211_ATTR_AP_SETTINGS_FLAGS,
+        pub NL80211_ATTR_EHT_CAPABILITY,
+        pub NL80211_ATTR_DISABLE_EHT,
+        pub NL80211_ATTR_MLO_LINKS,
+        pub NL80211_ATTR_MLO_LINK_ID,
+        pub NL80211_ATTR_MLD_ADDR,
+        pub NL80211_ATTR_MLO_SUPPORT,
+        pub NL80211_ATTR_MAX_NUM_AKM_SUITES,
+        pub NL80211_ATTR_EML_CAPABILITY,
+        pub NL80211_ATTR_MLD_CAPA_AND_OPS,
+        pub NL80211_ATTR_TX_HW_TIMESTAMP,
+        pub NL80211_ATTR_RX_HW_TIMESTAMP,
+    }
+}
+
+/* source-level API compatibility */
+pub const NL80211_ATTR_SCAN_GENERATION: nl80211_attrs = NL80211_ATTR_GENERATION;
+pub const NL80211_ATTR_MESH_PARAMS: nl80211_attrs = NL80211_ATTR_MESH_CONFIG;
+pub const NL80211_ATTR_IFACE_SOCKET_OWNER: nl80211_attrs = NL80211_ATTR_SOCKET_OWNER;
+pub const NL80211_ATTR_SAE_DATA: nl80211_attrs = NL80211_ATTR_AUTH_DATA;
+pub const NL80211_ATTR_CSA_C_OFF_BEACON: nl80211_attrs = NL80211_ATTR_CNTDWN_OFFS_BEACON;
+pub const NL80211_ATTR_CSA_C_OFF_PRESP: nl80211_attrs = NL80211_ATTR_CNTDWN_OFFS_PRESP;
+
+pub const NL80211_WIPHY_NAME_MAXLEN: c_int = 64;
+
+pub const NL80211_MAX_SUPP_RATES: c_int = 32;
+pub const NL80211_MAX_SUPP_HT_RATES: c_int = 77;
+pub const NL80211_MAX_SUPP_REG_RULES: c_int = 128;
+pub const NL80211_TKIP_DATA_OFFSET_ENCR_KEY: c_int = 0;
+pub const NL80211_TKIP_DATA_OFFSET_TX_MIC_KEY: c_int = 16;
+pub const NL80211_TKIP_DATA_OFFSET_RX_MIC_KEY: c_int = 24;
+pub const NL80211_HT_CAPABILITY_LEN: c_int = 26;
+pub const NL80211_VHT_CAPABILITY_LEN: c_int = 12;
+pub const NL80211_HE_MIN_CAPABILITY_LEN: c_int = 16;
+pub const NL80211_HE_MAX_CAPABILITY_LEN: c_int = 54;
+pub const NL80211_MAX_NR_CIPHER_SUITES: c_int = 5;
+pub const NL80211_MAX_NR_AKM_SUITES: c_int = 2;
+pub const NL80211_EHT_MIN_CAPABILITY_LEN: c_int = 13;
+pub const NL80211_EHT_MAX_CAPABILITY_LEN: c_int = 51;
+
+pub const NL80211_MIN_REMAIN_ON_CHANNEL_TIME: c_int = 10;
+
+/* default RSSI threshold for scan results if none specified. */
+pub const NL80211_SCAN_RSSI_THOLD_OFF: c_int = -300;
+
+pub const NL80211_CQM_TXE_MAX_INTVL: c_int = 1800;
+
+c_enum! {
+    pub enum nl80211_iftype {
+        pub NL80211_IFTYPE_UNSPECIFIED,
+        pub NL80211_IFTYPE_ADHOC,
+        pub NL80211_IFTYPE_STATION,
+        pub NL80211_IFTYPE_AP,
+        pub NL80211_IFTYPE_AP_VLAN,
+        pub NL80211_IFTYPE_WDS,
+        pub NL80211_IFTYPE_MONITOR,
+        pub NL80211_IFTYPE_MESH_POINT,
+        pub NL80211_IFTYPE_P2P_CLIENT,
+        pub NL80211_IFTYPE_P2P_GO,
+        pub NL80211_IFTYPE_P2P_DEVICE,
+        pub NL80211_IFTYPE_OCB,
+        pub NL80211_IFTYPE_NAN,
+    }
+}
+
+c_enum! {
+    pub enum nl80211_sta_flags {
+        pub NL80211_STA_FLAG_AUTHORIZED = 1,
+        pub NL80211_STA_FLAG_SHORT_PREAMBLE,
+        pub NL80211_STA_FLAG_WME,
+        pub NL80211_STA_FLAG_MFP,
+        pub NL80211_STA_FLAG_AUTHENTICATED,
+        pub NL80211_STA_FLAG_TDLS_PEER,
+        pub NL80211_STA_FLAG_ASSOCIATED,
+    }
+}
+
+pub const NL80211_STA_FLAG_MAX_OLD_API: nl80211_sta_flags = NL80211_STA_FLAG_TDLS_PEER;
+
+c_enum! {
+    pub enum nl80211_rate_info {
+        pub NL80211_RATE_INFO_BITRATE = 1,
+        pub NL80211_RATE_INFO_MCS,
+        pub NL80211_RATE_INFO_40_MHZ_WIDTH,
+        pub NL80211_RATE_INFO_SHORT_GI,
+        pub NL80211_RATE_INFO_BITRATE32,
+        pub NL80211_RATE_INFO_VHT_MCS,
+        pub NL80211_RATE_INFO_VHT_NSS,
+        pub NL80211_RATE_INFO_80_MHZ_WIDTH,
+        pub NL80211_RATE_INFO_80P80_MHZ_WIDTH,
+        pub NL80211_RATE_INFO_160_MHZ_WIDTH,
+        pub NL80211_RATE_INFO_10_MHZ_WIDTH,
+        pub NL80211_RATE_INFO_5_MHZ_WIDTH,
+        pub NL80211_RATE_INFO_HE_MCS,
+        pub NL80211_RATE_INFO_HE_NSS,
+        pub NL80211_RATE_INFO_HE_GI,
+        pub NL80211_RATE_INFO_HE_DCM,
+        pub NL80211_RATE_INFO_HE_RU_ALLOC,
+        pub NL80211_RATE_INFO_320_MHZ_WIDTH,
+        pub NL80211_RATE_INFO_EHT_MCS,
+        pub NL80211_RATE_INFO_EHT_NSS,
+        pub NL80211_RATE_INFO_EHT_GI,
+        pub NL80211_RATE_INFO_EHT_RU_ALLOC,
+    }
+}
+
+c_enum! {
+    pub enum nl80211_sta_bss_param {
+        pub NL80211_STA_BSS_PARAM_CTS_PROT = 1,
+        pub NL80211_STA_BSS_PARAM_SHORT_PREAMBLE,
+        pub NL80211_STA_BSS_PARAM_SHORT_SLOT_TIME,
+        pub NL80211_STA_BSS_PARAM_DTIM_PERIOD,
+        pub NL80211_STA_BSS_PARAM_BEACON_INTERVAL,
+    }
+}
+
+c_enum! {
+    pub enum nl80211_sta_info {
+        pub NL80211_STA_INFO_INACTIVE_TIME = 1,
+        pub NL80211_STA_INFO_RX_BYTES,
+        pub NL80211_STA_INFO_TX_BYTES,
+        pub NL80211_STA_INFO_LLID,
+        pub NL80211_STA_INFO_PLID,
+        pub NL80211_STA_INFO_PLINK_STATE,
+        pub NL80211_STA_INFO_SIGNAL,
+        pub NL80211_STA_INFO_TX_BITRATE,
+        pub NL80211_STA_INFO_RX_PACKETS,
+        pub NL80211_STA_INFO_TX_PACKETS,
+        pub NL80211_STA_INFO_TX_RETRIES,
+        pub NL80211_STA_INFO_TX_FAILED,
+        pub NL80211_STA_INFO_SIGNAL_AVG,
+        pub NL80211_STA_INFO_RX_BITRATE,
+        pub NL80211_STA_INFO_BSS_PARAM,
+        pub NL80211_STA_INFO_CONNECTED_TIME,
+        pub NL80211_STA_INFO_STA_FLAGS,
+        pub NL80211_STA_INFO_BEACON_LOSS,
+        pub NL80211_STA_INFO_T_OFFSET,
+        pub NL80211_STA_INFO_LOCAL_PM,
+        pub NL80211_STA_INFO_PEER_PM,
+        pub NL80211_STA_INFO_NONPEER_PM,
+        pub NL80211_STA_INFO_RX_BYTES64,
+        pub NL80211_STA_INFO_TX_BYTES64,
+        pub NL80211_STA_INFO_CHAIN_SIGNAL,
+        pub NL80211_STA_INFO_CHAIN_SIGNAL_AVG,
+        pub NL80211_STA_INFO_EXPECTED_THROUGHPUT,
+        pub NL80211_STA_INFO_RX_DROP_MISC,
+        pub NL80211_STA_INFO_BEACON_RX,
+        pub NL80211_STA_INFO_BEACON_SIGNAL_AVG,
+        pub NL80211_STA_INFO_TID_STATS,
+        pub NL80211_STA_INFO_RX_DURATION,
+        pub NL80211_STA_INFO_PAD,
+        pub NL80211_STA_INFO_ACK_SIGNAL,
+        pub NL80211_STA_INFO_ACK_SIGNAL_AVG,
+        pub NL80211_STA_INFO_RX_MPDUS,
+        pub NL80211_STA_INFO_FCS_ERROR_COUNT,
+        pub NL80211_STA_INFO_CONNECTED_TO_GATE,
+        pub NL80211_STA_INFO_TX_DURATION,
+        pub NL80211_STA_INFO_AIRTIME_WEIGHT,
+        pub NL80211_STA_INFO_AIRTIME_LINK_METRIC,
+        pub NL80211_STA_INFO_ASSOC_AT_BOOTTIME,
+        pub NL80211_STA_INFO_CONNECTED_TO_AS,
+    }
+}
+
+/* backward compatibility */
+pub const NL80211_STA_INFO_DATA_ACK_SIGNAL_AVG: nl80211_sta_info = NL80211_STA_INFO_ACK_SIGNAL_AVG;
+
+c_enum! {
+    pub enum nl80211_band_attr {
+        pub NL80211_BAND_ATTR_FREQS = 1,
+        pub NL80211_BAND_ATTR_RATES,
+        pub NL80211_BAND_ATTR_HT_MCS_SET,
+        pub NL80211_BAND_ATTR_HT_CAPA,
+        pub NL80211_BAND_ATTR_HT_AMPDU_FACTOR,
+        pub NL80211_BAND_ATTR_HT_AMPDU_DENSITY,
+        pub NL80211_BAND_ATTR_VHT_MCS_SET,
+        pub NL80211_BAND_ATTR_VHT_CAPA,
+        pub NL80211_BAND_ATTR_IFTYPE_DATA,
+        pub NL80211_BAND_ATTR_EDMG_CHANNELS,
+        pub NL80211_BAND_ATTR_EDMG_BW_CONFIG,
+    }
+}
+
+c_enum! {
+    pub enum nl80211_frequency_attr {
+        pub NL80211_FREQUENCY_ATTR_FREQ = 1,
+        pub NL80211_FREQUENCY_ATTR_DISABLED,
+        pub NL80211_FREQUENCY_ATTR_NO_IR,
+        pub NL80211_FREQUENCY_ATTR_RADAR = 5,
+        pub NL80211_FREQUENCY_ATTR_MAX_TX_POWER,
+        pub NL80211_FREQUENCY_ATTR_DFS_STATE,
+        pub NL80211_FREQUENCY_ATTR_DFS_TIME,
+        pub NL80211_FREQUENCY_ATTR_NO_HT40_MINUS,
+        pub NL80211_FREQUENCY_ATTR_NO_HT40_PLUS,
+        pub NL80211_FREQUENCY_ATTR_NO_80MHZ,
+        pub NL80211_FREQUENCY_ATTR_NO_160MHZ,
+        pub NL80211_FREQUENCY_ATTR_DFS_CAC_TIME,
+        pub NL80211_FREQUENCY_ATTR_INDOOR_ONLY,
+        pub NL80211_FREQUENCY_ATTR_IR_CONCURRENT,
+        pub NL80211_FREQUENCY_ATTR_NO_20MHZ,
+        pub NL80211_FREQUENCY_ATTR_NO_10MHZ,
+        pub NL80211_FREQUENCY_ATTR_WMM,
+        pub NL80211_FREQUENCY_ATTR_NO_HE,
+        pub NL80211_FREQUENCY_ATTR_OFFSET,
+        pub NL80211_FREQUENCY_ATTR_1MHZ,
+        pub NL80211_FREQUENCY_ATTR_2MHZ,
+        pub NL80211_FREQUENCY_ATTR_4MHZ,
+        pub NL80211_FREQUENCY_ATTR_8MHZ,
+        pub NL80211_FREQUENCY_ATTR_16MHZ,
+        pub NL80211_FREQUENCY_ATTR_NO_320MHZ,
+        pub NL80211_FREQUENCY_ATTR_NO_EHT,
+    }
+}
+
+pub const NL80211_FREQUENCY_ATTR_PASSIVE_SCAN: nl80211_frequency_attr = NL80211_FREQUENCY_ATTR_NO_IR;
+pub const NL80211_FREQUENCY_ATTR_NO_IBSS: nl80211_frequency_attr = NL80211_FREQUENCY_ATTR_NO_IR;
+pub const NL80211_FREQUENCY_ATTR_GO_CONCURRENT: nl80211_frequency_attr =
+    NL80211_FREQUENCY_ATTR_IR_CONCURRENT;
+
+c_enum! {
+    pub enum nl80211_bitrate_attr {
+        pub NL80211_BITRATE_ATTR_RATE = 1,
+        pub NL80211_BITRATE_ATTR_2GHZ_SHORTPREAMBLE,
+    }
+}
+
+c_enum! {
+    pub enum nl80211_channel_type {
+        pub NL80211_CHAN_NO_HT,
+        pub NL80211_CHAN_HT20,
+        pub NL80211_CHAN_HT40MINUS,
+        pub NL80211_CHAN_HT40PLUS,
+    }
+}
+
+c_enum! {
+    pub enum nl80211_chan_width {
+        pub NL80211_CHAN_WIDTH_20_NOHT,
+        pub NL80211_CHAN_WIDTH_20,
+        pub NL80211_CHAN_WIDTH_40,
+        pub NL80211_CHAN_WIDTH_80,
+        pub NL80211_CHAN_WIDTH_80P80,
+        pub NL80211_CHAN_WIDTH_160,
+        pub NL80211_CHAN_WIDTH_5,
+        pub NL80211_CHAN_WIDTH_10,
+        pub NL80211_CHAN_WIDTH_1,
+        pub NL80211_CHAN_WIDTH_2,
+        pub NL80211_CHAN_WIDTH_4,
+        pub NL80211_CHAN_WIDTH_8,
+        pub NL80211_CHAN_WIDTH_16,
+        pub NL80211_CHAN_WIDTH_320,
+    }
+}
+
+c_enum! {
+    pub enum nl80211_bss_scan_width {
+        pub NL80211_BSS_CHAN_WIDTH_20,
+        pub NL80211_BSS_CHAN_WIDTH_10,
+        pub NL80211_BSS_CHAN_WIDTH_5,
+        pub NL80211_BSS_CHAN_WIDTH_1,
+        pub NL80211_BSS_CHAN_WIDTH_2,
+    }
+}
+
+c_enum! {
+    pub enum nl80211_bss {
+        pub NL80211_BSS_BSSID = 1,
+        pub NL80211_BSS_FREQUENCY,
+        pub NL80211_BSS_TSF,
+        pub NL80211_BSS_BEACON_INTERVAL,
+        pub NL80211_BSS_CAPABILITY,
+        pub NL80211_BSS_INFORMATION_ELEMENTS,
+        pub NL80211_BSS_SIGNAL_MBM,
+        pub NL80211_BSS_SIGNAL_UNSPEC,
+        pub NL80211_BSS_STATUS,
+        pub NL80211_BSS_SEEN_MS_AGO,
+        pub NL80211_BSS_BEACON_IES,
+        pub NL80211_BSS_CHAN_WIDTH,
+        pub NL80211_BSS_BEACON_TSF,
+        pub NL80211_BSS_PRESP_DATA,
+        pub NL80211_BSS_LAST_SEEN_BOOTTIME,
+        pub NL80211_BSS_PAD,
+        pub NL80211_BSS_PARENT_TSF,
+        pub NL80211_BSS_PARENT_BSSID,
+        pub NL80211_BSS_CHAIN_SIGNAL,
+        pub NL80211_BSS_FREQUENCY_OFFSET,
+        pub NL80211_BSS_MLO_LINK_ID,
+        pub NL80211_BSS_MLD_ADDR,
+    }
+}
+
+c_enum! {
+    pub enum nl80211_bss_status {
+        pub NL80211_BSS_STATUS_AUTHENTICATED,
+        pub NL80211_BSS_STATUS_ASSOCIATED,
+        pub NL80211_BSS_STATUS_IBSS_JOINED,
+    }
+}
+
+c_enum! {
+    pub enum nl80211_band {
+        pub NL80211_BAND_2GHZ,
+        pub NL80211_BAND_5GHZ,
+        pub NL80211_BAND_60GHZ,
+        pub NL80211_BAND_6GHZ,
+        pub NL80211_BAND_S1GHZ,
+        pub NL80211_BAND_LC,
+    }
+}
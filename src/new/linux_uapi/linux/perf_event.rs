@@ -0,0 +1,545 @@
+//! Header: `uapi/linux/perf_event.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IO,
+    _IOR,
+    _IOW,
+    _IOWR,
+};
+
+c_enum! {
+    pub enum perf_type_id {
+        pub PERF_TYPE_HARDWARE = 0,
+        pub PERF_TYPE_SOFTWARE = 1,
+        pub PERF_TYPE_TRACEPOINT = 2,
+        pub PERF_TYPE_HW_CACHE = 3,
+        pub PERF_TYPE_RAW = 4,
+        pub PERF_TYPE_BREAKPOINT = 5,
+    }
+}
+
+pub const PERF_PMU_TYPE_SHIFT: c_int = 32;
+pub const PERF_HW_EVENT_MASK: c_uint = 0xffffffff;
+
+c_enum! {
+    pub enum perf_hw_id {
+        pub PERF_COUNT_HW_CPU_CYCLES = 0,
+        pub PERF_COUNT_HW_INSTRUCTIONS = 1,
+        pub PERF_COUNT_HW_CACHE_REFERENCES = 2,
+        pub PERF_COUNT_HW_CACHE_MISSES = 3,
+        pub PERF_COUNT_HW_BRANCH_INSTRUCTIONS = 4,
+        pub PERF_COUNT_HW_BRANCH_MISSES = 5,
+        pub PERF_COUNT_HW_BUS_CYCLES = 6,
+        pub PERF_COUNT_HW_STALLED_CYCLES_FRONTEND = 7,
+        pub PERF_COUNT_HW_STALLED_CYCLES_BACKEND = 8,
+        pub PERF_COUNT_HW_REF_CPU_CYCLES = 9,
+    }
+
+    pub enum perf_hw_cache_id {
+        pub PERF_COUNT_HW_CACHE_L1D = 0,
+        pub PERF_COUNT_HW_CACHE_L1I = 1,
+        pub PERF_COUNT_HW_CACHE_LL = 2,
+        pub PERF_COUNT_HW_CACHE_DTLB = 3,
+        pub PERF_COUNT_HW_CACHE_ITLB = 4,
+        pub PERF_COUNT_HW_CACHE_BPU = 5,
+        pub PERF_COUNT_HW_CACHE_NODE = 6,
+    }
+
+    pub enum perf_hw_cache_op_id {
+        pub PERF_COUNT_HW_CACHE_OP_READ = 0,
+        pub PERF_COUNT_HW_CACHE_OP_WRITE = 1,
+        pub PERF_COUNT_HW_CACHE_OP_PREFETCH = 2,
+    }
+
+    pub enum perf_hw_cache_op_result_id {
+        pub PERF_COUNT_HW_CACHE_RESULT_ACCESS = 0,
+        pub PERF_COUNT_HW_CACHE_RESULT_MISS = 1,
+    }
+
+    pub enum perf_sw_ids {
+        pub PERF_COUNT_SW_CPU_CLOCK = 0,
+        pub PERF_COUNT_SW_TASK_CLOCK = 1,
+        pub PERF_COUNT_SW_PAGE_FAULTS = 2,
+        pub PERF_COUNT_SW_CONTEXT_SWITCHES = 3,
+        pub PERF_COUNT_SW_CPU_MIGRATIONS = 4,
+        pub PERF_COUNT_SW_PAGE_FAULTS_MIN = 5,
+        pub PERF_COUNT_SW_PAGE_FAULTS_MAJ = 6,
+        pub PERF_COUNT_SW_ALIGNMENT_FAULTS = 7,
+        pub PERF_COUNT_SW_EMULATION_FAULTS = 8,
+        pub PERF_COUNT_SW_DUMMY = 9,
+        pub PERF_COUNT_SW_BPF_OUTPUT = 10,
+        pub PERF_COUNT_SW_CGROUP_SWITCHES = 11,
+    }
+
+    pub enum perf_event_sample_format {
+        pub PERF_SAMPLE_IP = 1 << 0,
+        pub PERF_SAMPLE_TID = 1 << 1,
+        pub PERF_SAMPLE_TIME = 1 << 2,
+        pub PERF_SAMPLE_ADDR = 1 << 3,
+        pub PERF_SAMPLE_READ = 1 << 4,
+        pub PERF_SAMPLE_CALLCHAIN = 1 << 5,
+        pub PERF_SAMPLE_ID = 1 << 6,
+        pub PERF_SAMPLE_CPU = 1 << 7,
+        pub PERF_SAMPLE_PERIOD = 1 << 8,
+        pub PERF_SAMPLE_STREAM_ID = 1 << 9,
+        pub PERF_SAMPLE_RAW = 1 << 10,
+        pub PERF_SAMPLE_BRANCH_STACK = 1 << 11,
+        pub PERF_SAMPLE_REGS_USER = 1 << 12,
+        pub PERF_SAMPLE_STACK_USER = 1 << 13,
+        pub PERF_SAMPLE_WEIGHT = 1 << 14,
+        pub PERF_SAMPLE_DATA_SRC = 1 << 15,
+        pub PERF_SAMPLE_IDENTIFIER = 1 << 16,
+        pub PERF_SAMPLE_TRANSACTION = 1 << 17,
+        pub PERF_SAMPLE_REGS_INTR = 1 << 18,
+        pub PERF_SAMPLE_PHYS_ADDR = 1 << 19,
+        pub PERF_SAMPLE_AUX = 1 << 20,
+        pub PERF_SAMPLE_CGROUP = 1 << 21,
+        pub PERF_SAMPLE_DATA_PAGE_SIZE = 1 << 22,
+        pub PERF_SAMPLE_CODE_PAGE_SIZE = 1 << 23,
+        pub PERF_SAMPLE_WEIGHT_STRUCT = 1 << 24,
+    }
+}
+
+pub const PERF_SAMPLE_WEIGHT_TYPE: perf_event_sample_format =
+    PERF_SAMPLE_WEIGHT | PERF_SAMPLE_WEIGHT_STRUCT;
+
+c_enum! {
+    pub enum perf_branch_sample_type_shift {
+        pub PERF_SAMPLE_BRANCH_USER_SHIFT = 0,
+        pub PERF_SAMPLE_BRANCH_KERNEL_SHIFT = 1,
+        pub PERF_SAMPLE_BRANCH_HV_SHIFT = 2,
+        pub PERF_SAMPLE_BRANCH_ANY_SHIFT = 3,
+        pub PERF_SAMPLE_BRANCH_ANY_CALL_SHIFT = 4,
+        pub PERF_SAMPLE_BRANCH_ANY_RETURN_SHIFT = 5,
+        pub PERF_SAMPLE_BRANCH_IND_CALL_SHIFT = 6,
+        pub PERF_SAMPLE_BRANCH_ABORT_TX_SHIFT = 7,
+        pub PERF_SAMPLE_BRANCH_IN_TX_SHIFT = 8,
+        pub PERF_SAMPLE_BRANCH_NO_TX_SHIFT = 9,
+        pub PERF_SAMPLE_BRANCH_COND_SHIFT = 10,
+        pub PERF_SAMPLE_BRANCH_CALL_STACK_SHIFT = 11,
+        pub PERF_SAMPLE_BRANCH_IND_JUMP_SHIFT = 12,
+        pub PERF_SAMPLE_BRANCH_CALL_SHIFT = 13,
+        pub PERF_SAMPLE_BRANCH_NO_FLAGS_SHIFT = 14,
+        pub PERF_SAMPLE_BRANCH_NO_CYCLES_SHIFT = 15,
+        pub PERF_SAMPLE_BRANCH_TYPE_SAVE_SHIFT = 16,
+        pub PERF_SAMPLE_BRANCH_HW_INDEX_SHIFT = 17,
+        pub PERF_SAMPLE_BRANCH_PRIV_SAVE_SHIFT = 18,
+        pub PERF_SAMPLE_BRANCH_COUNTERS_SHIFT = 19,
+    }
+
+    pub enum perf_branch_sample_type {
+        pub PERF_SAMPLE_BRANCH_USER = 1 << PERF_SAMPLE_BRANCH_USER_SHIFT,
+        pub PERF_SAMPLE_BRANCH_KERNEL = 1 << PERF_SAMPLE_BRANCH_KERNEL_SHIFT,
+        pub PERF_SAMPLE_BRANCH_HV = 1 << PERF_SAMPLE_BRANCH_HV_SHIFT,
+        pub PERF_SAMPLE_BRANCH_ANY = 1 << PERF_SAMPLE_BRANCH_ANY_SHIFT,
+        pub PERF_SAMPLE_BRANCH_ANY_CALL = 1 << PERF_SAMPLE_BRANCH_ANY_CALL_SHIFT,
+        pub PERF_SAMPLE_BRANCH_ANY_RETURN = 1 << PERF_SAMPLE_BRANCH_ANY_RETURN_SHIFT,
+        pub PERF_SAMPLE_BRANCH_IND_CALL = 1 << PERF_SAMPLE_BRANCH_IND_CALL_SHIFT,
+        pub PERF_SAMPLE_BRANCH_ABORT_TX = 1 << PERF_SAMPLE_BRANCH_ABORT_TX_SHIFT,
+        pub PERF_SAMPLE_BRANCH_IN_TX = 1 << PERF_SAMPLE_BRANCH_IN_TX_SHIFT,
+        pub PERF_SAMPLE_BRANCH_NO_TX = 1 << PERF_SAMPLE_BRANCH_NO_TX_SHIFT,
+        pub PERF_SAMPLE_BRANCH_COND = 1 << PERF_SAMPLE_BRANCH_COND_SHIFT,
+        pub PERF_SAMPLE_BRANCH_CALL_STACK = 1 << PERF_SAMPLE_BRANCH_CALL_STACK_SHIFT,
+        pub PERF_SAMPLE_BRANCH_IND_JUMP = 1 << PERF_SAMPLE_BRANCH_IND_JUMP_SHIFT,
+        pub PERF_SAMPLE_BRANCH_CALL = 1 << PERF_SAMPLE_BRANCH_CALL_SHIFT,
+        pub PERF_SAMPLE_BRANCH_NO_FLAGS = 1 << PERF_SAMPLE_BRANCH_NO_FLAGS_SHIFT,
+        pub PERF_SAMPLE_BRANCH_NO_CYCLES = 1 << PERF_SAMPLE_BRANCH_NO_CYCLES_SHIFT,
+        pub PERF_SAMPLE_BRANCH_TYPE_SAVE = 1 << PERF_SAMPLE_BRANCH_TYPE_SAVE_SHIFT,
+        pub PERF_SAMPLE_BRANCH_HW_INDEX = 1 << PERF_SAMPLE_BRANCH_HW_INDEX_SHIFT,
+        pub PERF_SAMPLE_BRANCH_PRIV_SAVE = 1 << PERF_SAMPLE_BRANCH_PRIV_SAVE_SHIFT,
+        pub PERF_SAMPLE_BRANCH_COUNTERS = 1 << PERF_SAMPLE_BRANCH_COUNTERS_SHIFT,
+    }
+}
+
+pub const PERF_SAMPLE_BRANCH_PLM_ALL: perf_branch_sample_type =
+    PERF_SAMPLE_BRANCH_USER | PERF_SAMPLE_BRANCH_KERNEL | PERF_SAMPLE_BRANCH_HV;
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub PERF_BR_UNKNOWN = 0,
+        pub PERF_BR_COND = 1,
+        pub PERF_BR_UNCOND = 2,
+        pub PERF_BR_IND = 3,
+        pub PERF_BR_CALL = 4,
+        pub PERF_BR_IND_CALL = 5,
+        pub PERF_BR_RET = 6,
+        pub PERF_BR_SYSCALL = 7,
+        pub PERF_BR_SYSRET = 8,
+        pub PERF_BR_COND_CALL = 9,
+        pub PERF_BR_COND_RET = 10,
+        pub PERF_BR_ERET = 11,
+        pub PERF_BR_IRQ = 12,
+        pub PERF_BR_SERROR = 13,
+        pub PERF_BR_NO_TX = 14,
+        pub PERF_BR_EXTEND_ABI = 15,
+    }
+
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub PERF_BR_SPEC_NA = 0,
+        pub PERF_BR_SPEC_WRONG_PATH = 1,
+        pub PERF_BR_NON_SPEC_CORRECT_PATH = 2,
+        pub PERF_BR_SPEC_CORRECT_PATH = 3,
+    }
+
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub PERF_BR_NEW_FAULT_ALGN = 0,
+        pub PERF_BR_NEW_FAULT_DATA = 1,
+        pub PERF_BR_NEW_FAULT_INST = 2,
+        pub PERF_BR_NEW_ARCH_1 = 3,
+        pub PERF_BR_NEW_ARCH_2 = 4,
+        pub PERF_BR_NEW_ARCH_3 = 5,
+        pub PERF_BR_NEW_ARCH_4 = 6,
+        pub PERF_BR_NEW_ARCH_5 = 7,
+    }
+
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub PERF_BR_PRIV_UNKNOWN = 0,
+        pub PERF_BR_PRIV_USER = 1,
+        pub PERF_BR_PRIV_KERNEL = 2,
+        pub PERF_BR_PRIV_HV = 3,
+    }
+}
+
+pub const PERF_BR_ARM64_FIQ: c_uint = PERF_BR_NEW_ARCH_1;
+pub const PERF_BR_ARM64_DEBUG_HALT: c_uint = PERF_BR_NEW_ARCH_2;
+pub const PERF_BR_ARM64_DEBUG_EXIT: c_uint = PERF_BR_NEW_ARCH_3;
+pub const PERF_BR_ARM64_DEBUG_INST: c_uint = PERF_BR_NEW_ARCH_4;
+pub const PERF_BR_ARM64_DEBUG_DATA: c_uint = PERF_BR_NEW_ARCH_5;
+
+c_enum! {
+    pub enum perf_sample_regs_abi {
+        pub PERF_SAMPLE_REGS_ABI_NONE = 0,
+        pub PERF_SAMPLE_REGS_ABI_32 = 1,
+        pub PERF_SAMPLE_REGS_ABI_64 = 2,
+    }
+}
+
+// These live in an anonymous enum that is widened to 64 bits by `PERF_TXN_ABORT_MASK`.
+pub const PERF_TXN_ELISION: crate::__u64 = 1 << 0;
+pub const PERF_TXN_TRANSACTION: crate::__u64 = 1 << 1;
+pub const PERF_TXN_SYNC: crate::__u64 = 1 << 2;
+pub const PERF_TXN_ASYNC: crate::__u64 = 1 << 3;
+pub const PERF_TXN_RETRY: crate::__u64 = 1 << 4;
+pub const PERF_TXN_CONFLICT: crate::__u64 = 1 << 5;
+pub const PERF_TXN_CAPACITY_WRITE: crate::__u64 = 1 << 6;
+pub const PERF_TXN_CAPACITY_READ: crate::__u64 = 1 << 7;
+pub const PERF_TXN_ABORT_MASK: crate::__u64 = 0xffffffff << 32;
+pub const PERF_TXN_ABORT_SHIFT: crate::__u64 = 32;
+
+c_enum! {
+    pub enum perf_event_read_format {
+        pub PERF_FORMAT_TOTAL_TIME_ENABLED = 1 << 0,
+        pub PERF_FORMAT_TOTAL_TIME_RUNNING = 1 << 1,
+        pub PERF_FORMAT_ID = 1 << 2,
+        pub PERF_FORMAT_GROUP = 1 << 3,
+        pub PERF_FORMAT_LOST = 1 << 4,
+    }
+}
+
+pub const PERF_ATTR_SIZE_VER0: c_int = 64;
+pub const PERF_ATTR_SIZE_VER1: c_int = 72;
+pub const PERF_ATTR_SIZE_VER2: c_int = 80;
+pub const PERF_ATTR_SIZE_VER3: c_int = 96;
+pub const PERF_ATTR_SIZE_VER4: c_int = 104;
+pub const PERF_ATTR_SIZE_VER5: c_int = 112;
+pub const PERF_ATTR_SIZE_VER6: c_int = 120;
+pub const PERF_ATTR_SIZE_VER7: c_int = 128;
+pub const PERF_ATTR_SIZE_VER8: c_int = 136;
+
+s_no_extra_traits! {
+    pub struct perf_event_attr {
+        pub type_: crate::__u32,
+        pub size: crate::__u32,
+        pub config: crate::__u64,
+        pub anonymous_1: __c_anonymous_perf_event_attr_1,
+        pub sample_type: crate::__u64,
+        pub read_format: crate::__u64,
+        /// Storage for the `disabled` through `sigtrap` bitfields, see the accessor methods.
+        __bitfield_1: crate::__u64,
+        pub anonymous_2: __c_anonymous_perf_event_attr_2,
+        pub bp_type: crate::__u32,
+        pub anonymous_3: __c_anonymous_perf_event_attr_3,
+        pub anonymous_4: __c_anonymous_perf_event_attr_4,
+        pub branch_sample_type: crate::__u64,
+        pub sample_regs_user: crate::__u64,
+        pub sample_stack_user: crate::__u32,
+        pub clockid: crate::__s32,
+        pub sample_regs_intr: crate::__u64,
+        pub aux_watermark: crate::__u32,
+        pub sample_max_stack: crate::__u16,
+        __reserved_2: Padding<crate::__u16>,
+        pub aux_sample_size: crate::__u32,
+        /// Also contains the `aux_start_paused`, `aux_pause` and `aux_resume` bitfields.
+        pub aux_action: crate::__u32,
+        pub sig_data: crate::__u64,
+        pub config3: crate::__u64,
+    }
+
+    pub union __c_anonymous_perf_event_attr_1 {
+        pub sample_period: crate::__u64,
+        pub sample_freq: crate::__u64,
+    }
+
+    pub union __c_anonymous_perf_event_attr_2 {
+        pub wakeup_events: crate::__u32,
+        pub wakeup_watermark: crate::__u32,
+    }
+
+    pub union __c_anonymous_perf_event_attr_3 {
+        pub bp_addr: crate::__u64,
+        pub kprobe_func: crate::__u64,
+        pub uprobe_path: crate::__u64,
+        pub config1: crate::__u64,
+    }
+
+    pub union __c_anonymous_perf_event_attr_4 {
+        pub bp_len: crate::__u64,
+        pub kprobe_addr: crate::__u64,
+        pub probe_offset: crate::__u64,
+        pub config2: crate::__u64,
+    }
+}
+
+impl perf_event_attr {
+    bitfield_accessors! {
+        __bitfield_1: crate::__u64;
+        disabled, set_disabled: 0, 1;
+        inherit, set_inherit: 1, 1;
+        pinned, set_pinned: 2, 1;
+        exclusive, set_exclusive: 3, 1;
+        exclude_user, set_exclude_user: 4, 1;
+        exclude_kernel, set_exclude_kernel: 5, 1;
+        exclude_hv, set_exclude_hv: 6, 1;
+        exclude_idle, set_exclude_idle: 7, 1;
+        mmap, set_mmap: 8, 1;
+        comm, set_comm: 9, 1;
+        freq, set_freq: 10, 1;
+        inherit_stat, set_inherit_stat: 11, 1;
+        enable_on_exec, set_enable_on_exec: 12, 1;
+        task, set_task: 13, 1;
+        watermark, set_watermark: 14, 1;
+        precise_ip, set_precise_ip: 15, 2;
+        mmap_data, set_mmap_data: 17, 1;
+        sample_id_all, set_sample_id_all: 18, 1;
+        exclude_host, set_exclude_host: 19, 1;
+        exclude_guest, set_exclude_guest: 20, 1;
+        exclude_callchain_kernel, set_exclude_callchain_kernel: 21, 1;
+        exclude_callchain_user, set_exclude_callchain_user: 22, 1;
+        mmap2, set_mmap2: 23, 1;
+        comm_exec, set_comm_exec: 24, 1;
+        use_clockid, set_use_clockid: 25, 1;
+        context_switch, set_context_switch: 26, 1;
+        write_backward, set_write_backward: 27, 1;
+        namespaces, set_namespaces: 28, 1;
+        ksymbol, set_ksymbol: 29, 1;
+        bpf_event, set_bpf_event: 30, 1;
+        aux_output, set_aux_output: 31, 1;
+        cgroup, set_cgroup: 32, 1;
+        text_poke, set_text_poke: 33, 1;
+        build_id, set_build_id: 34, 1;
+        inherit_thread, set_inherit_thread: 35, 1;
+        remove_on_exec, set_remove_on_exec: 36, 1;
+        sigtrap, set_sigtrap: 37, 1;
+    }
+
+    bitfield_accessors! {
+        aux_action: crate::__u32;
+        aux_start_paused, set_aux_start_paused: 0, 1;
+        aux_pause, set_aux_pause: 1, 1;
+        aux_resume, set_aux_resume: 2, 1;
+    }
+}
+
+s! {
+    pub struct perf_event_query_bpf {
+        pub ids_len: crate::__u32,
+        pub prog_cnt: crate::__u32,
+        pub ids: [crate::__u32; 0],
+    }
+}
+
+const PERF_EVENT_IOC_MAGIC: c_uint = b'$' as c_uint;
+
+pub const PERF_EVENT_IOC_ENABLE: Ioctl = _IO(PERF_EVENT_IOC_MAGIC, 0);
+pub const PERF_EVENT_IOC_DISABLE: Ioctl = _IO(PERF_EVENT_IOC_MAGIC, 1);
+pub const PERF_EVENT_IOC_REFRESH: Ioctl = _IO(PERF_EVENT_IOC_MAGIC, 2);
+pub const PERF_EVENT_IOC_RESET: Ioctl = _IO(PERF_EVENT_IOC_MAGIC, 3);
+pub const PERF_EVENT_IOC_PERIOD: Ioctl = _IOW::<crate::__u64>(PERF_EVENT_IOC_MAGIC, 4);
+pub const PERF_EVENT_IOC_SET_OUTPUT: Ioctl = _IO(PERF_EVENT_IOC_MAGIC, 5);
+pub const PERF_EVENT_IOC_SET_FILTER: Ioctl = _IOW::<*mut c_char>(PERF_EVENT_IOC_MAGIC, 6);
+pub const PERF_EVENT_IOC_ID: Ioctl = _IOR::<*mut crate::__u64>(PERF_EVENT_IOC_MAGIC, 7);
+pub const PERF_EVENT_IOC_SET_BPF: Ioctl = _IOW::<crate::__u32>(PERF_EVENT_IOC_MAGIC, 8);
+pub const PERF_EVENT_IOC_PAUSE_OUTPUT: Ioctl = _IOW::<crate::__u32>(PERF_EVENT_IOC_MAGIC, 9);
+pub const PERF_EVENT_IOC_QUERY_BPF: Ioctl =
+    _IOWR::<*mut perf_event_query_bpf>(PERF_EVENT_IOC_MAGIC, 10);
+pub const PERF_EVENT_IOC_MODIFY_ATTRIBUTES: Ioctl =
+    _IOW::<*mut perf_event_attr>(PERF_EVENT_IOC_MAGIC, 11);
+
+c_enum! {
+    pub enum perf_event_ioc_flags {
+        pub PERF_IOC_FLAG_GROUP = 1 << 0,
+    }
+}
+
+s! {
+    pub struct perf_event_mmap_page {
+        pub version: crate::__u32,
+        pub compat_version: crate::__u32,
+        pub lock: crate::__u32,
+        pub index: crate::__u32,
+        pub offset: crate::__s64,
+        pub time_enabled: crate::__u64,
+        pub time_running: crate::__u64,
+        /// Also contains the `cap_bit0` through `cap_user_time_short` bitfields.
+        pub capabilities: crate::__u64,
+        pub pmc_width: crate::__u16,
+        pub time_shift: crate::__u16,
+        pub time_mult: crate::__u32,
+        pub time_offset: crate::__u64,
+        pub time_zero: crate::__u64,
+        pub size: crate::__u32,
+        __reserved_1: Padding<crate::__u32>,
+        pub time_cycles: crate::__u64,
+        pub time_mask: crate::__u64,
+        __reserved: Padding<[crate::__u8; 116 * 8]>,
+        pub data_head: crate::__u64,
+        pub data_tail: crate::__u64,
+        pub data_offset: crate::__u64,
+        pub data_size: crate::__u64,
+        pub aux_head: crate::__u64,
+        pub aux_tail: crate::__u64,
+        pub aux_offset: crate::__u64,
+        pub aux_size: crate::__u64,
+    }
+}
+
+impl perf_event_mmap_page {
+    bitfield_accessors! {
+        capabilities: crate::__u64;
+        cap_bit0, set_cap_bit0: 0, 1;
+        cap_bit0_is_deprecated, set_cap_bit0_is_deprecated: 1, 1;
+        cap_user_rdpmc, set_cap_user_rdpmc: 2, 1;
+        cap_user_time, set_cap_user_time: 3, 1;
+        cap_user_time_zero, set_cap_user_time_zero: 4, 1;
+        cap_user_time_short, set_cap_user_time_short: 5, 1;
+    }
+}
+
+pub const PERF_RECORD_MISC_CPUMODE_MASK: c_int = 7 << 0;
+pub const PERF_RECORD_MISC_CPUMODE_UNKNOWN: c_int = 0 << 0;
+pub const PERF_RECORD_MISC_KERNEL: c_int = 1 << 0;
+pub const PERF_RECORD_MISC_USER: c_int = 2 << 0;
+pub const PERF_RECORD_MISC_HYPERVISOR: c_int = 3 << 0;
+pub const PERF_RECORD_MISC_GUEST_KERNEL: c_int = 4 << 0;
+pub const PERF_RECORD_MISC_GUEST_USER: c_int = 5 << 0;
+
+pub const PERF_RECORD_MISC_PROC_MAP_PARSE_TIMEOUT: c_int = 1 << 12;
+pub const PERF_RECORD_MISC_MMAP_DATA: c_int = 1 << 13;
+pub const PERF_RECORD_MISC_COMM_EXEC: c_int = 1 << 13;
+pub const PERF_RECORD_MISC_FORK_EXEC: c_int = 1 << 13;
+pub const PERF_RECORD_MISC_SWITCH_OUT: c_int = 1 << 13;
+pub const PERF_RECORD_MISC_EXACT_IP: c_int = 1 << 14;
+pub const PERF_RECORD_MISC_SWITCH_OUT_PREEMPT: c_int = 1 << 14;
+pub const PERF_RECORD_MISC_MMAP_BUILD_ID: c_int = 1 << 14;
+pub const PERF_RECORD_MISC_EXT_RESERVED: c_int = 1 << 15;
+
+s! {
+    pub struct perf_event_header {
+        pub type_: crate::__u32,
+        pub misc: crate::__u16,
+        pub size: crate::__u16,
+    }
+
+    pub struct perf_ns_link_info {
+        pub dev: crate::__u64,
+        pub ino: crate::__u64,
+    }
+}
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub NET_NS_INDEX = 0,
+        pub UTS_NS_INDEX = 1,
+        pub IPC_NS_INDEX = 2,
+        pub PID_NS_INDEX = 3,
+        pub USER_NS_INDEX = 4,
+        pub MNT_NS_INDEX = 5,
+        pub CGROUP_NS_INDEX = 6,
+        pub NR_NAMESPACES,
+    }
+}
+
+c_enum! {
+    pub enum perf_event_type {
+        pub PERF_RECORD_MMAP = 1,
+        pub PERF_RECORD_LOST = 2,
+        pub PERF_RECORD_COMM = 3,
+        pub PERF_RECORD_EXIT = 4,
+        pub PERF_RECORD_THROTTLE = 5,
+        pub PERF_RECORD_UNTHROTTLE = 6,
+        pub PERF_RECORD_FORK = 7,
+        pub PERF_RECORD_READ = 8,
+        pub PERF_RECORD_SAMPLE = 9,
+        pub PERF_RECORD_MMAP2 = 10,
+        pub PERF_RECORD_AUX = 11,
+        pub PERF_RECORD_ITRACE_START = 12,
+        pub PERF_RECORD_LOST_SAMPLES = 13,
+        pub PERF_RECORD_SWITCH = 14,
+        pub PERF_RECORD_SWITCH_CPU_WIDE = 15,
+        pub PERF_RECORD_NAMESPACES = 16,
+        pub PERF_RECORD_KSYMBOL = 17,
+        pub PERF_RECORD_BPF_EVENT = 18,
+        pub PERF_RECORD_CGROUP = 19,
+        pub PERF_RECORD_TEXT_POKE = 20,
+        pub PERF_RECORD_AUX_OUTPUT_HW_ID = 21,
+    }
+
+    pub enum perf_record_ksymbol_type {
+        pub PERF_RECORD_KSYMBOL_TYPE_UNKNOWN = 0,
+        pub PERF_RECORD_KSYMBOL_TYPE_BPF = 1,
+        pub PERF_RECORD_KSYMBOL_TYPE_OOL = 2,
+    }
+}
+
+pub const PERF_RECORD_KSYMBOL_FLAGS_UNREGISTER: c_int = 1 << 0;
+
+c_enum! {
+    pub enum perf_bpf_event_type {
+        pub PERF_BPF_EVENT_UNKNOWN = 0,
+        pub PERF_BPF_EVENT_PROG_LOAD = 1,
+        pub PERF_BPF_EVENT_PROG_UNLOAD = 2,
+    }
+}
+
+pub const PERF_MAX_STACK_DEPTH: c_int = 127;
+pub const PERF_MAX_CONTEXTS_PER_STACK: c_int = 8;
+
+// `enum perf_callchain_context` is widened to 64 bits by its (negative) values.
+pub const PERF_CONTEXT_HV: crate::__u64 = -32i64 as crate::__u64;
+pub const PERF_CONTEXT_KERNEL: crate::__u64 = -128i64 as crate::__u64;
+pub const PERF_CONTEXT_USER: crate::__u64 = -512i64 as crate::__u64;
+pub const PERF_CONTEXT_GUEST: crate::__u64 = -2048i64 as crate::__u64;
+pub const PERF_CONTEXT_GUEST_KERNEL: crate::__u64 = -2176i64 as crate::__u64;
+pub const PERF_CONTEXT_GUEST_USER: crate::__u64 = -2560i64 as crate::__u64;
+pub const PERF_CONTEXT_MAX: crate::__u64 = -4095i64 as crate::__u64;
+
+pub const PERF_AUX_FLAG_TRUNCATED: c_int = 0x01;
+pub const PERF_AUX_FLAG_OVERWRITE: c_int = 0x02;
+pub const PERF_AUX_FLAG_PARTIAL: c_int = 0x04;
+pub const PERF_AUX_FLAG_COLLISION: c_int = 0x08;
+pub const PERF_AUX_FLAG_PMU_FORMAT_TYPE_MASK: c_int = 0xff00;
+
+pub const PERF_AUX_FLAG_CORESIGHT_FORMAT_CORESIGHT: c_int = 0x0000;
+pub const PERF_AUX_FLAG_CORESIGHT_FORMAT_RAW: c_int = 0x0100;
+
+pub const PERF_FLAG_FD_NO_GROUP: c_ulong = 1 << 0;
+pub const PERF_FLAG_FD_OUTPUT: c_ulong = 1 << 1;
+pub const PERF_FLAG_PID_CGROUP: c_ulong = 1 << 2;
+pub const PERF_FLAG_FD_CLOEXEC: c_ulong = 1 << 3;
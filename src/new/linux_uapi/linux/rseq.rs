@@ -0,0 +1,49 @@
+//! Header: `uapi/linux/rseq.h`
+
+use crate::prelude::*;
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum rseq_cpu_id_state {
+        pub RSEQ_CPU_ID_UNINITIALIZED = -1,
+        pub RSEQ_CPU_ID_REGISTRATION_FAILED = -2,
+    }
+
+    pub enum rseq_flags {
+        pub RSEQ_FLAG_UNREGISTER = 1 << 0,
+    }
+
+    pub enum rseq_cs_flags_bit {
+        pub RSEQ_CS_FLAG_NO_RESTART_ON_PREEMPT_BIT = 0,
+        pub RSEQ_CS_FLAG_NO_RESTART_ON_SIGNAL_BIT = 1,
+        pub RSEQ_CS_FLAG_NO_RESTART_ON_MIGRATE_BIT = 2,
+    }
+
+    pub enum rseq_cs_flags {
+        pub RSEQ_CS_FLAG_NO_RESTART_ON_PREEMPT = 1 << RSEQ_CS_FLAG_NO_RESTART_ON_PREEMPT_BIT,
+        pub RSEQ_CS_FLAG_NO_RESTART_ON_SIGNAL = 1 << RSEQ_CS_FLAG_NO_RESTART_ON_SIGNAL_BIT,
+        pub RSEQ_CS_FLAG_NO_RESTART_ON_MIGRATE = 1 << RSEQ_CS_FLAG_NO_RESTART_ON_MIGRATE_BIT,
+    }
+}
+
+s! {
+    #[repr(align(32))]
+    pub struct rseq_cs {
+        pub version: crate::__u32,
+        pub flags: crate::__u32,
+        pub start_ip: crate::__u64,
+        pub post_commit_offset: crate::__u64,
+        pub abort_ip: crate::__u64,
+    }
+
+    #[repr(align(32))]
+    pub struct rseq {
+        pub cpu_id_start: crate::__u32,
+        pub cpu_id: crate::__u32,
+        pub rseq_cs: crate::__u64,
+        pub flags: crate::__u32,
+        pub node_id: crate::__u32,
+        pub mm_cid: crate::__u32,
+        pub end: [c_char; 0],
+    }
+}
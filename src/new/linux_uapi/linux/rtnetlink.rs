@@ -0,0 +1,620 @@
+//! Header: `uapi/linux/rtnetlink.h`
+
+use crate::prelude::*;
+
+/* rtnetlink families. Values up to 127 are reserved for real address
+ * families, values above 128 may be used arbitrarily.
+ */
+pub const RTNL_FAMILY_IPMR: c_uchar = 128;
+pub const RTNL_FAMILY_IP6MR: c_uchar = 129;
+
+/* Types of messages */
+c_enum! {
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub RTM_BASE = 16,
+        pub RTM_NEWLINK = 16,
+        pub RTM_DELLINK,
+        pub RTM_GETLINK,
+        pub RTM_SETLINK,
+        pub RTM_NEWADDR = 20,
+        pub RTM_DELADDR,
+        pub RTM_GETADDR,
+        pub RTM_NEWROUTE = 24,
+        pub RTM_DELROUTE,
+        pub RTM_GETROUTE,
+        pub RTM_NEWNEIGH = 28,
+        pub RTM_DELNEIGH,
+        pub RTM_GETNEIGH,
+        pub RTM_NEWRULE = 32,
+        pub RTM_DELRULE,
+        pub RTM_GETRULE,
+        pub RTM_NEWQDISC = 36,
+        pub RTM_DELQDISC,
+        pub RTM_GETQDISC,
+        pub RTM_NEWTCLASS = 40,
+        pub RTM_DELTCLASS,
+        pub RTM_GETTCLASS,
+        pub RTM_NEWTFILTER = 44,
+        pub RTM_DELTFILTER,
+        pub RTM_GETTFILTER,
+        pub RTM_NEWACTION = 48,
+        pub RTM_DELACTION,
+        pub RTM_GETACTION,
+        pub RTM_NEWPREFIX = 52,
+        pub RTM_GETMULTICAST = 58,
+        pub RTM_GETANYCAST = 62,
+        pub RTM_NEWNEIGHTBL = 64,
+        pub RTM_GETNEIGHTBL = 66,
+        pub RTM_SETNEIGHTBL,
+        pub RTM_NEWNDUSEROPT = 68,
+        pub RTM_NEWADDRLABEL = 72,
+        pub RTM_DELADDRLABEL,
+        pub RTM_GETADDRLABEL,
+        pub RTM_GETDCB = 78,
+        pub RTM_SETDCB,
+        pub RTM_NEWNETCONF = 80,
+        pub RTM_DELNETCONF,
+        pub RTM_GETNETCONF = 82,
+        pub RTM_NEWMDB = 84,
+        pub RTM_DELMDB = 85,
+        pub RTM_GETMDB = 86,
+        pub RTM_NEWNSID = 88,
+        pub RTM_DELNSID = 89,
+        pub RTM_GETNSID = 90,
+        pub RTM_NEWSTATS = 92,
+        pub RTM_GETSTATS = 94,
+        pub RTM_SETSTATS,
+        pub RTM_NEWCACHEREPORT = 96,
+        pub RTM_NEWCHAIN = 100,
+        pub RTM_DELCHAIN,
+        pub RTM_GETCHAIN,
+        pub RTM_NEWNEXTHOP = 104,
+        pub RTM_DELNEXTHOP,
+        pub RTM_GETNEXTHOP,
+        pub RTM_NEWLINKPROP = 108,
+        pub RTM_DELLINKPROP,
+        pub RTM_GETLINKPROP,
+        pub RTM_NEWVLAN = 112,
+        pub RTM_DELVLAN,
+        pub RTM_GETVLAN,
+        pub RTM_NEWNEXTHOPBUCKET = 116,
+        pub RTM_DELNEXTHOPBUCKET,
+        pub RTM_GETNEXTHOPBUCKET,
+        pub RTM_NEWTUNNEL = 120,
+        pub RTM_DELTUNNEL,
+        pub RTM_GETTUNNEL,
+    }
+}
+
+s! {
+    /* Generic structure for encapsulation of optional route information. */
+    pub struct rtattr {
+        pub rta_len: c_ushort,
+        pub rta_type: c_ushort,
+    }
+}
+
+/* Macros to handle rtattributes */
+pub const RTA_ALIGNTO: c_int = 4;
+
+safe_f! {
+    pub const fn RTA_ALIGN(len: c_int) -> c_int {
+        (len + RTA_ALIGNTO - 1) & !(RTA_ALIGNTO - 1)
+    }
+
+    pub const fn RTA_LENGTH(len: c_int) -> c_int {
+        RTA_ALIGN(size_of::<rtattr>() as c_int) + len
+    }
+
+    pub const fn RTA_SPACE(len: c_int) -> c_int {
+        RTA_ALIGN(RTA_LENGTH(len))
+    }
+}
+
+f! {
+    pub fn RTA_OK(rta: *const rtattr, len: c_int) -> bool {
+        len >= size_of::<rtattr>() as c_int
+            && (*rta).rta_len as usize >= size_of::<rtattr>()
+            && (*rta).rta_len as c_int <= len
+    }
+
+    pub fn RTA_NEXT(rta: *const rtattr, attrlen: *mut c_int) -> *mut rtattr {
+        let aligned = RTA_ALIGN((*rta).rta_len as c_int);
+        *attrlen -= aligned;
+        rta.cast::<c_char>().offset(aligned as isize) as *mut rtattr
+    }
+
+    pub fn RTA_DATA(rta: *const rtattr) -> *mut c_void {
+        rta.cast::<c_char>().offset(RTA_LENGTH(0) as isize) as *mut c_void
+    }
+
+    pub fn RTA_PAYLOAD(rta: *const rtattr) -> c_int {
+        (*rta).rta_len as c_int - RTA_LENGTH(0)
+    }
+}
+
+/* Definitions used in routing table administration. */
+s! {
+    pub struct rtmsg {
+        pub rtm_family: c_uchar,
+        pub rtm_dst_len: c_uchar,
+        pub rtm_src_len: c_uchar,
+        pub rtm_tos: c_uchar,
+        pub rtm_table: c_uchar,
+        pub rtm_protocol: c_uchar,
+        pub rtm_scope: c_uchar,
+        pub rtm_type: c_uchar,
+        pub rtm_flags: c_uint,
+    }
+}
+
+/* rtm_type */
+c_enum! {
+    #[repr(c_uchar)]
+    pub enum #anon {
+        pub RTN_UNSPEC,
+        pub RTN_UNICAST,
+        pub RTN_LOCAL,
+        pub RTN_BROADCAST,
+        pub RTN_ANYCAST,
+        pub RTN_MULTICAST,
+        pub RTN_BLACKHOLE,
+        pub RTN_UNREACHABLE,
+        pub RTN_PROHIBIT,
+        pub RTN_THROW,
+        pub RTN_NAT,
+        pub RTN_XRESOLVE,
+    }
+}
+
+/* rtm_protocol */
+pub const RTPROT_UNSPEC: c_uchar = 0;
+pub const RTPROT_REDIRECT: c_uchar = 1;
+pub const RTPROT_KERNEL: c_uchar = 2;
+pub const RTPROT_BOOT: c_uchar = 3;
+pub const RTPROT_STATIC: c_uchar = 4;
+
+pub const RTPROT_GATED: c_uchar = 8;
+pub const RTPROT_RA: c_uchar = 9;
+pub const RTPROT_MRT: c_uchar = 10;
+pub const RTPROT_ZEBRA: c_uchar = 11;
+pub const RTPROT_BIRD: c_uchar = 12;
+pub const RTPROT_DNROUTED: c_uchar = 13;
+pub const RTPROT_XORP: c_uchar = 14;
+pub const RTPROT_NTK: c_uchar = 15;
+pub const RTPROT_DHCP: c_uchar = 16;
+pub const RTPROT_MROUTED: c_uchar = 17;
+pub const RTPROT_KEEPALIVED: c_uchar = 18;
+pub const RTPROT_BABEL: c_uchar = 42;
+pub const RTPROT_OPENR: c_uchar = 99;
+pub const RTPROT_BGP: c_uchar = 186;
+pub const RTPROT_ISIS: c_uchar = 187;
+pub const RTPROT_OSPF: c_uchar = 188;
+pub const RTPROT_RIP: c_uchar = 189;
+pub const RTPROT_EIGRP: c_uchar = 192;
+
+/* rtm_scope */
+// enum rt_scope_t
+pub const RT_SCOPE_UNIVERSE: c_uchar = 0;
+pub const RT_SCOPE_SITE: c_uchar = 200;
+pub const RT_SCOPE_LINK: c_uchar = 253;
+pub const RT_SCOPE_HOST: c_uchar = 254;
+pub const RT_SCOPE_NOWHERE: c_uchar = 255;
+
+/* rtm_flags */
+pub const RTM_F_NOTIFY: c_uint = 0x100;
+pub const RTM_F_CLONED: c_uint = 0x200;
+pub const RTM_F_EQUALIZE: c_uint = 0x400;
+pub const RTM_F_PREFIX: c_uint = 0x800;
+pub const RTM_F_LOOKUP_TABLE: c_uint = 0x1000;
+pub const RTM_F_FIB_MATCH: c_uint = 0x2000;
+pub const RTM_F_OFFLOAD: c_uint = 0x4000;
+pub const RTM_F_TRAP: c_uint = 0x8000;
+pub const RTM_F_OFFLOAD_FAILED: c_uint = 0x20000000;
+
+/* Reserved table identifiers */
+// enum rt_class_t
+pub const RT_TABLE_UNSPEC: c_uchar = 0;
+pub const RT_TABLE_COMPAT: c_uchar = 252;
+pub const RT_TABLE_DEFAULT: c_uchar = 253;
+pub const RT_TABLE_MAIN: c_uchar = 254;
+pub const RT_TABLE_LOCAL: c_uchar = 255;
+
+/* Routing message attributes */
+// enum rtattr_type_t
+c_enum! {
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub RTA_UNSPEC,
+        pub RTA_DST,
+        pub RTA_SRC,
+        pub RTA_IIF,
+        pub RTA_OIF,
+        pub RTA_GATEWAY,
+        pub RTA_PRIORITY,
+        pub RTA_PREFSRC,
+        pub RTA_METRICS,
+        pub RTA_MULTIPATH,
+        pub RTA_PROTOINFO,
+        pub RTA_FLOW,
+        pub RTA_CACHEINFO,
+        pub RTA_SESSION,
+        pub RTA_MP_ALGO,
+        pub RTA_TABLE,
+        pub RTA_MARK,
+        pub RTA_MFC_STATS,
+        pub RTA_VIA,
+        pub RTA_NEWDST,
+        pub RTA_PREF,
+        pub RTA_ENCAP_TYPE,
+        pub RTA_ENCAP,
+        pub RTA_EXPIRES,
+        pub RTA_PAD,
+        pub RTA_UID,
+        pub RTA_TTL_PROPAGATE,
+        pub RTA_IP_PROTO,
+        pub RTA_SPORT,
+        pub RTA_DPORT,
+        pub RTA_NH_ID,
+    }
+}
+
+f! {
+    pub fn RTM_RTA(r: *const rtmsg) -> *mut rtattr {
+        r.cast::<c_char>()
+            .offset(crate::NLMSG_ALIGN(size_of::<rtmsg>() as c_int) as isize)
+            as *mut rtattr
+    }
+
+    pub fn RTM_PAYLOAD(n: *const crate::nlmsghdr) -> c_int {
+        crate::NLMSG_PAYLOAD(n, size_of::<rtmsg>() as c_int)
+    }
+}
+
+s! {
+    /* RTM_MULTIPATH --- array of struct rtnexthop. */
+    pub struct rtnexthop {
+        pub rtnh_len: c_ushort,
+        pub rtnh_flags: c_uchar,
+        pub rtnh_hops: c_uchar,
+        pub rtnh_ifindex: c_int,
+    }
+}
+
+/* rtnh_flags */
+pub const RTNH_F_DEAD: c_uchar = 1;
+pub const RTNH_F_PERVASIVE: c_uchar = 2;
+pub const RTNH_F_ONLINK: c_uchar = 4;
+pub const RTNH_F_OFFLOAD: c_uchar = 8;
+pub const RTNH_F_LINKDOWN: c_uchar = 16;
+pub const RTNH_F_UNRESOLVED: c_uchar = 32;
+pub const RTNH_F_TRAP: c_uchar = 64;
+
+pub const RTNH_COMPARE_MASK: c_uchar = RTNH_F_DEAD | RTNH_F_LINKDOWN | RTNH_F_OFFLOAD | RTNH_F_TRAP;
+
+/* Macros to handle hexthops */
+pub const RTNH_ALIGNTO: c_int = 4;
+
+safe_f! {
+    pub const fn RTNH_ALIGN(len: c_int) -> c_int {
+        (len + RTNH_ALIGNTO - 1) & !(RTNH_ALIGNTO - 1)
+    }
+
+    pub const fn RTNH_LENGTH(len: c_int) -> c_int {
+        RTNH_ALIGN(size_of::<rtnexthop>() as c_int) + len
+    }
+
+    pub const fn RTNH_SPACE(len: c_int) -> c_int {
+        RTNH_ALIGN(RTNH_LENGTH(len))
+    }
+}
+
+f! {
+    pub fn RTNH_OK(rtnh: *const rtnexthop, len: c_int) -> bool {
+        (*rtnh).rtnh_len as usize >= size_of::<rtnexthop>() && (*rtnh).rtnh_len as c_int <= len
+    }
+
+    pub fn RTNH_NEXT(rtnh: *const rtnexthop) -> *mut rtnexthop {
+        rtnh.cast::<c_char>()
+            .offset(RTNH_ALIGN((*rtnh).rtnh_len as c_int) as isize) as *mut rtnexthop
+    }
+
+    pub fn RTNH_DATA(rtnh: *const rtnexthop) -> *mut rtattr {
+        rtnh.cast::<c_char>().offset(RTNH_LENGTH(0) as isize) as *mut rtattr
+    }
+}
+
+s! {
+    /* RTA_VIA */
+    pub struct rtvia {
+        pub rtvia_family: crate::sa_family_t,
+        pub rtvia_addr: [crate::__u8; 0],
+    }
+
+    /* RTM_CACHEINFO */
+    pub struct rta_cacheinfo {
+        pub rta_clntref: crate::__u32,
+        pub rta_lastuse: crate::__u32,
+        pub rta_expires: crate::__s32,
+        pub rta_error: crate::__u32,
+        pub rta_used: crate::__u32,
+        pub rta_id: crate::__u32,
+        pub rta_ts: crate::__u32,
+        pub rta_tsage: crate::__u32,
+    }
+}
+
+pub const RTNETLINK_HAVE_PEERINFO: c_int = 1;
+
+/* RTM_METRICS --- array of struct rtattr with types of RTAX_* */
+c_enum! {
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub RTAX_UNSPEC,
+        pub RTAX_LOCK,
+        pub RTAX_MTU,
+        pub RTAX_WINDOW,
+        pub RTAX_RTT,
+        pub RTAX_RTTVAR,
+        pub RTAX_SSTHRESH,
+        pub RTAX_CWND,
+        pub RTAX_ADVMSS,
+        pub RTAX_REORDERING,
+        pub RTAX_HOPLIMIT,
+        pub RTAX_INITCWND,
+        pub RTAX_FEATURES,
+        pub RTAX_RTO_MIN,
+        pub RTAX_INITRWND,
+        pub RTAX_QUICKACK,
+        pub RTAX_CC_ALGO,
+        pub RTAX_FASTOPEN_NO_COOKIE,
+    }
+}
+
+pub const RTAX_FEATURE_ECN: c_int = 1 << 0;
+pub const RTAX_FEATURE_SACK: c_int = 1 << 1;
+pub const RTAX_FEATURE_TIMESTAMP: c_int = 1 << 2;
+pub const RTAX_FEATURE_ALLFRAG: c_int = 1 << 3;
+pub const RTAX_FEATURE_TCP_USEC_TS: c_int = 1 << 4;
+
+pub const RTAX_FEATURE_MASK: c_int = RTAX_FEATURE_ECN
+    | RTAX_FEATURE_SACK
+    | RTAX_FEATURE_TIMESTAMP
+    | RTAX_FEATURE_ALLFRAG
+    | RTAX_FEATURE_TCP_USEC_TS;
+
+s! {
+    pub struct rta_mfc_stats {
+        pub mfcs_packets: crate::__u64,
+        pub mfcs_bytes: crate::__u64,
+        pub mfcs_wrong_if: crate::__u64,
+    }
+
+    /* General form of address family dependent message. */
+    pub struct rtgenmsg {
+        pub rtgen_family: c_uchar,
+    }
+
+    /* Link layer specific messages. */
+    pub struct ifinfomsg {
+        pub ifi_family: c_uchar,
+        __ifi_pad: Padding<c_uchar>,
+        pub ifi_type: c_ushort,
+        pub ifi_index: c_int,
+        pub ifi_flags: c_uint,
+        pub ifi_change: c_uint,
+    }
+
+    /* prefix information */
+    pub struct prefixmsg {
+        pub prefix_family: c_uchar,
+        prefix_pad1: Padding<c_uchar>,
+        prefix_pad2: Padding<c_ushort>,
+        pub prefix_ifindex: c_int,
+        pub prefix_type: c_uchar,
+        pub prefix_len: c_uchar,
+        pub prefix_flags: c_uchar,
+        prefix_pad3: Padding<c_uchar>,
+    }
+}
+
+c_enum! {
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub PREFIX_UNSPEC,
+        pub PREFIX_ADDRESS,
+        pub PREFIX_CACHEINFO,
+    }
+}
+
+s! {
+    pub struct prefix_cacheinfo {
+        pub preferred_time: crate::__u32,
+        pub valid_time: crate::__u32,
+    }
+
+    /* Traffic control messages. */
+    pub struct tcmsg {
+        pub tcm_family: c_uchar,
+        tcm__pad1: Padding<c_uchar>,
+        tcm__pad2: Padding<c_ushort>,
+        pub tcm_ifindex: c_int,
+        pub tcm_handle: crate::__u32,
+        pub tcm_parent: crate::__u32,
+        pub tcm_info: crate::__u32,
+    }
+}
+
+pub const TCM_IFINDEX_MAGIC_BLOCK: c_uint = 0xFFFFFFFF;
+
+c_enum! {
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub TCA_UNSPEC,
+        pub TCA_KIND,
+        pub TCA_OPTIONS,
+        pub TCA_STATS,
+        pub TCA_XSTATS,
+        pub TCA_RATE,
+        pub TCA_FCNT,
+        pub TCA_STATS2,
+        pub TCA_STAB,
+        pub TCA_PAD,
+        pub TCA_DUMP_INVISIBLE,
+        pub TCA_CHAIN,
+        pub TCA_HW_OFFLOAD,
+        pub TCA_INGRESS_BLOCK,
+        pub TCA_EGRESS_BLOCK,
+        pub TCA_DUMP_FLAGS,
+        pub TCA_EXT_WARN_MSG,
+    }
+}
+
+pub const TCA_DUMP_FLAGS_TERSE: c_int = 1 << 0;
+
+f! {
+    pub fn TCA_RTA(r: *const tcmsg) -> *mut rtattr {
+        r.cast::<c_char>()
+            .offset(crate::NLMSG_ALIGN(size_of::<tcmsg>() as c_int) as isize)
+            as *mut rtattr
+    }
+
+    pub fn TCA_PAYLOAD(n: *const crate::nlmsghdr) -> c_int {
+        crate::NLMSG_PAYLOAD(n, size_of::<tcmsg>() as c_int)
+    }
+}
+
+s! {
+    /* Neighbor Discovery userland options */
+    pub struct nduseroptmsg {
+        pub nduseropt_family: c_uchar,
+        nduseropt_pad1: Padding<c_uchar>,
+        pub nduseropt_opts_len: c_ushort,
+        pub nduseropt_ifindex: c_int,
+        pub nduseropt_icmp_type: crate::__u8,
+        pub nduseropt_icmp_code: crate::__u8,
+        nduseropt_pad2: Padding<c_ushort>,
+        nduseropt_pad3: Padding<c_uint>,
+    }
+}
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub NDUSEROPT_UNSPEC,
+        pub NDUSEROPT_SRCADDR,
+    }
+}
+
+// userspace compat definitions for RTNLGRP_*
+pub const RTMGRP_LINK: c_int = 0x00001;
+pub const RTMGRP_NOTIFY: c_int = 0x00002;
+pub const RTMGRP_NEIGH: c_int = 0x00004;
+pub const RTMGRP_TC: c_int = 0x00008;
+pub const RTMGRP_IPV4_IFADDR: c_int = 0x00010;
+pub const RTMGRP_IPV4_MROUTE: c_int = 0x00020;
+pub const RTMGRP_IPV4_ROUTE: c_int = 0x00040;
+pub const RTMGRP_IPV4_RULE: c_int = 0x00080;
+pub const RTMGRP_IPV6_IFADDR: c_int = 0x00100;
+pub const RTMGRP_IPV6_MROUTE: c_int = 0x00200;
+pub const RTMGRP_IPV6_ROUTE: c_int = 0x00400;
+pub const RTMGRP_IPV6_IFINFO: c_int = 0x00800;
+pub const RTMGRP_DECnet_IFADDR: c_int = 0x01000;
+pub const RTMGRP_DECnet_ROUTE: c_int = 0x04000;
+pub const RTMGRP_IPV6_PREFIX: c_int = 0x20000;
+
+/* RTnetlink multicast groups */
+// enum rtnetlink_groups
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub RTNLGRP_NONE,
+        pub RTNLGRP_LINK,
+        pub RTNLGRP_NOTIFY,
+        pub RTNLGRP_NEIGH,
+        pub RTNLGRP_TC,
+        pub RTNLGRP_IPV4_IFADDR,
+        pub RTNLGRP_IPV4_MROUTE,
+        pub RTNLGRP_IPV4_ROUTE,
+        pub RTNLGRP_IPV4_RULE,
+        pub RTNLGRP_IPV6_IFADDR,
+        pub RTNLGRP_IPV6_MROUTE,
+        pub RTNLGRP_IPV6_ROUTE,
+        pub RTNLGRP_IPV6_IFINFO,
+        pub RTNLGRP_DECnet_IFADDR,
+        pub RTNLGRP_NOP2,
+        pub RTNLGRP_DECnet_ROUTE,
+        pub RTNLGRP_DECnet_RULE,
+        pub RTNLGRP_NOP4,
+        pub RTNLGRP_IPV6_PREFIX,
+        pub RTNLGRP_IPV6_RULE,
+        pub RTNLGRP_ND_USEROPT,
+        pub RTNLGRP_PHONET_IFADDR,
+        pub RTNLGRP_PHONET_ROUTE,
+        pub RTNLGRP_DCB,
+        pub RTNLGRP_IPV4_NETCONF,
+        pub RTNLGRP_IPV6_NETCONF,
+        pub RTNLGRP_MDB,
+        pub RTNLGRP_MPLS_ROUTE,
+        pub RTNLGRP_NSID,
+        pub RTNLGRP_MPLS_NETCONF,
+        pub RTNLGRP_IPV4_MROUTE_R,
+        pub RTNLGRP_IPV6_MROUTE_R,
+        pub RTNLGRP_NEXTHOP,
+        pub RTNLGRP_BRVLAN,
+        pub RTNLGRP_MCTP_IFADDR,
+        pub RTNLGRP_TUNNEL,
+        pub RTNLGRP_STATS,
+    }
+}
+
+s! {
+    /* TC action piece */
+    pub struct tcamsg {
+        pub tca_family: c_uchar,
+        tca__pad1: Padding<c_uchar>,
+        tca__pad2: Padding<c_ushort>,
+    }
+}
+
+c_enum! {
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub TCA_ROOT_UNSPEC,
+        pub TCA_ROOT_TAB,
+        pub TCA_ROOT_FLAGS,
+        pub TCA_ROOT_COUNT,
+        pub TCA_ROOT_TIME_DELTA,
+        pub TCA_ROOT_EXT_WARN_MSG,
+    }
+}
+
+pub const TCA_ACT_TAB: c_ushort = TCA_ROOT_TAB;
+
+f! {
+    pub fn TA_RTA(r: *const tcamsg) -> *mut rtattr {
+        r.cast::<c_char>()
+            .offset(crate::NLMSG_ALIGN(size_of::<tcamsg>() as c_int) as isize)
+            as *mut rtattr
+    }
+
+    pub fn TA_PAYLOAD(n: *const crate::nlmsghdr) -> c_int {
+        crate::NLMSG_PAYLOAD(n, size_of::<tcamsg>() as c_int)
+    }
+}
+
+/* tcamsg flags stored in attribute TCA_ROOT_FLAGS */
+pub const TCA_FLAG_LARGE_DUMP_ON: c_int = 1 << 0;
+pub const TCA_ACT_FLAG_LARGE_DUMP_ON: c_int = TCA_FLAG_LARGE_DUMP_ON;
+pub const TCA_ACT_FLAG_TERSE_DUMP: c_int = 1 << 1;
+
+/* New extended info filters for IFLA_EXT_MASK */
+pub const RTEXT_FILTER_VF: c_int = 1 << 0;
+pub const RTEXT_FILTER_BRVLAN: c_int = 1 << 1;
+pub const RTEXT_FILTER_BRVLAN_COMPRESSED: c_int = 1 << 2;
+pub const RTEXT_FILTER_SKIP_STATS: c_int = 1 << 3;
+pub const RTEXT_FILTER_MRP: c_int = 1 << 4;
+pub const RTEXT_FILTER_CFM_CONFIG: c_int = 1 << 5;
+pub const RTEXT_FILTER_CFM_STATUS: c_int = 1 << 6;
+pub const RTEXT_FILTER_MST: c_int = 1 << 7;
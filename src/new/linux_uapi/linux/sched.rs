@@ -0,0 +1,29 @@
+//! Header: `uapi/linux/sched.h`
+
+use crate::prelude::*;
+
+/* Flags for the clone3() syscall. */
+pub const CLONE_CLEAR_SIGHAND: c_ulonglong = 0x100000000;
+pub const CLONE_INTO_CGROUP: c_ulonglong = 0x200000000;
+
+s! {
+    // Every member is an `__aligned_u64`.
+    #[repr(align(8))]
+    pub struct clone_args {
+        pub flags: crate::__u64,
+        pub pidfd: crate::__u64,
+        pub child_tid: crate::__u64,
+        pub parent_tid: crate::__u64,
+        pub exit_signal: crate::__u64,
+        pub stack: crate::__u64,
+        pub stack_size: crate::__u64,
+        pub tls: crate::__u64,
+        pub set_tid: crate::__u64,
+        pub set_tid_size: crate::__u64,
+        pub cgroup: crate::__u64,
+    }
+}
+
+pub const CLONE_ARGS_SIZE_VER0: c_int = 64; /* sizeof first published struct */
+pub const CLONE_ARGS_SIZE_VER1: c_int = 80; /* sizeof second published struct */
+pub const CLONE_ARGS_SIZE_VER2: c_int = 88; /* sizeof third published struct */
@@ -0,0 +1,101 @@
+//! Header: `uapi/linux/seccomp.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IOW,
+    _IOWR,
+};
+
+/* Valid values for seccomp.mode and prctl(PR_SET_SECCOMP, <mode>) */
+pub const SECCOMP_MODE_DISABLED: c_uint = 0;
+pub const SECCOMP_MODE_STRICT: c_uint = 1;
+pub const SECCOMP_MODE_FILTER: c_uint = 2;
+
+/* Valid operations for seccomp syscall. */
+pub const SECCOMP_SET_MODE_STRICT: c_uint = 0;
+pub const SECCOMP_SET_MODE_FILTER: c_uint = 1;
+pub const SECCOMP_GET_ACTION_AVAIL: c_uint = 2;
+pub const SECCOMP_GET_NOTIF_SIZES: c_uint = 3;
+
+/* Valid flags for SECCOMP_SET_MODE_FILTER */
+pub const SECCOMP_FILTER_FLAG_TSYNC: c_ulong = 1 << 0;
+pub const SECCOMP_FILTER_FLAG_LOG: c_ulong = 1 << 1;
+pub const SECCOMP_FILTER_FLAG_SPEC_ALLOW: c_ulong = 1 << 2;
+pub const SECCOMP_FILTER_FLAG_NEW_LISTENER: c_ulong = 1 << 3;
+pub const SECCOMP_FILTER_FLAG_TSYNC_ESRCH: c_ulong = 1 << 4;
+/* Received notifications wait in killable state (only respond to fatal signals) */
+pub const SECCOMP_FILTER_FLAG_WAIT_KILLABLE_RECV: c_ulong = 1 << 5;
+
+pub const SECCOMP_RET_KILL_PROCESS: c_uint = 0x80000000;
+pub const SECCOMP_RET_KILL_THREAD: c_uint = 0x00000000;
+pub const SECCOMP_RET_KILL: c_uint = SECCOMP_RET_KILL_THREAD;
+pub const SECCOMP_RET_TRAP: c_uint = 0x00030000;
+pub const SECCOMP_RET_ERRNO: c_uint = 0x00050000;
+pub const SECCOMP_RET_USER_NOTIF: c_uint = 0x7fc00000;
+pub const SECCOMP_RET_TRACE: c_uint = 0x7ff00000;
+pub const SECCOMP_RET_LOG: c_uint = 0x7ffc0000;
+pub const SECCOMP_RET_ALLOW: c_uint = 0x7fff0000;
+
+/* Masks for the return value sections. */
+pub const SECCOMP_RET_ACTION_FULL: c_uint = 0xffff0000;
+pub const SECCOMP_RET_ACTION: c_uint = 0x7fff0000;
+pub const SECCOMP_RET_DATA: c_uint = 0x0000ffff;
+
+s! {
+    pub struct seccomp_data {
+        pub nr: c_int,
+        pub arch: crate::__u32,
+        pub instruction_pointer: crate::__u64,
+        pub args: [crate::__u64; 6],
+    }
+
+    pub struct seccomp_notif_sizes {
+        pub seccomp_notif: crate::__u16,
+        pub seccomp_notif_resp: crate::__u16,
+        pub seccomp_data: crate::__u16,
+    }
+
+    pub struct seccomp_notif {
+        pub id: crate::__u64,
+        pub pid: crate::__u32,
+        pub flags: crate::__u32,
+        pub data: seccomp_data,
+    }
+}
+
+pub const SECCOMP_USER_NOTIF_FLAG_CONTINUE: c_ulong = 1 << 0;
+
+s! {
+    pub struct seccomp_notif_resp {
+        pub id: crate::__u64,
+        pub val: crate::__s64,
+        pub error: crate::__s32,
+        pub flags: crate::__u32,
+    }
+}
+
+pub const SECCOMP_USER_NOTIF_FD_SYNC_WAKE_UP: c_ulong = 1 << 0;
+
+/* valid flags for seccomp_notif_addfd */
+pub const SECCOMP_ADDFD_FLAG_SETFD: c_ulong = 1 << 0;
+pub const SECCOMP_ADDFD_FLAG_SEND: c_ulong = 1 << 1;
+
+s! {
+    pub struct seccomp_notif_addfd {
+        pub id: crate::__u64,
+        pub flags: crate::__u32,
+        pub srcfd: crate::__u32,
+        pub newfd: crate::__u32,
+        pub newfd_flags: crate::__u32,
+    }
+}
+
+const SECCOMP_IOC_MAGIC: c_uint = b'!' as c_uint;
+
+/* Flags for seccomp notification fd ioctl. */
+pub const SECCOMP_IOCTL_NOTIF_RECV: Ioctl = _IOWR::<seccomp_notif>(SECCOMP_IOC_MAGIC, 0);
+pub const SECCOMP_IOCTL_NOTIF_SEND: Ioctl = _IOWR::<seccomp_notif_resp>(SECCOMP_IOC_MAGIC, 1);
+pub const SECCOMP_IOCTL_NOTIF_ID_VALID: Ioctl = _IOW::<crate::__u64>(SECCOMP_IOC_MAGIC, 2);
+pub const SECCOMP_IOCTL_NOTIF_ADDFD: Ioctl = _IOW::<seccomp_notif_addfd>(SECCOMP_IOC_MAGIC, 3);
+pub const SECCOMP_IOCTL_NOTIF_SET_FLAGS: Ioctl = _IOW::<crate::__u64>(SECCOMP_IOC_MAGIC, 4);
@@ -0,0 +1,150 @@
+//! Header: `uapi/linux/taskstats.h`
+
+use crate::prelude::*;
+
+pub const TASKSTATS_VERSION: c_int = 14;
+pub const TS_COMM_LEN: c_int = 32;
+
+s! {
+    /* Layout of the struct is versioned: each version only appends fields, so
+     * the `version` field tells which of the trailing fields were filled in.
+     * The kernel marks several fields `__attribute__((aligned(8)))`, the
+     * private padding fields reproduce that layout.
+     */
+    #[repr(align(8))]
+    pub struct taskstats {
+        pub version: crate::__u16,
+        pub ac_exitcode: crate::__u32,
+
+        pub ac_flag: crate::__u8,
+        pub ac_nice: crate::__u8,
+        __pad1: Padding<[crate::__u8; 6]>,
+
+        pub cpu_count: crate::__u64,
+        pub cpu_delay_total: crate::__u64,
+
+        pub blkio_count: crate::__u64,
+        pub blkio_delay_total: crate::__u64,
+
+        pub swapin_count: crate::__u64,
+        pub swapin_delay_total: crate::__u64,
+
+        pub cpu_run_real_total: crate::__u64,
+
+        pub cpu_run_virtual_total: crate::__u64,
+        /* version 1 ends here */
+
+        pub ac_comm: [c_char; TS_COMM_LEN as usize],
+        pub ac_sched: crate::__u8,
+        pub ac_pad: [crate::__u8; 3],
+        __pad2: Padding<[crate::__u8; 4]>,
+        pub ac_uid: crate::__u32,
+        pub ac_gid: crate::__u32,
+        pub ac_pid: crate::__u32,
+        pub ac_ppid: crate::__u32,
+        pub ac_btime: crate::__u32,
+        __pad3: Padding<[crate::__u8; 4]>,
+        pub ac_etime: crate::__u64,
+        pub ac_utime: crate::__u64,
+        pub ac_stime: crate::__u64,
+        pub ac_minflt: crate::__u64,
+        pub ac_majflt: crate::__u64,
+
+        pub coremem: crate::__u64,
+        pub virtmem: crate::__u64,
+
+        pub hiwater_rss: crate::__u64,
+        pub hiwater_vm: crate::__u64,
+
+        pub read_char: crate::__u64,
+        pub write_char: crate::__u64,
+        pub read_syscalls: crate::__u64,
+        pub write_syscalls: crate::__u64,
+
+        pub read_bytes: crate::__u64,
+        pub write_bytes: crate::__u64,
+        pub cancelled_write_bytes: crate::__u64,
+
+        pub nvcsw: crate::__u64,
+        pub nivcsw: crate::__u64,
+
+        pub ac_utimescaled: crate::__u64,
+        pub ac_stimescaled: crate::__u64,
+        pub cpu_scaled_run_real_total: crate::__u64,
+
+        pub freepages_count: crate::__u64,
+        pub freepages_delay_total: crate::__u64,
+
+        pub thrashing_count: crate::__u64,
+        pub thrashing_delay_total: crate::__u64,
+
+        /* v10: 64-bit btime to avoid overflow */
+        pub ac_btime64: crate::__u64,
+
+        /* v11: Delay waiting for memory compact */
+        pub compact_count: crate::__u64,
+        pub compact_delay_total: crate::__u64,
+
+        /* v12 begin */
+        pub ac_tgid: crate::__u32,
+        __pad4: Padding<[crate::__u8; 4]>,
+        pub ac_tgetime: crate::__u64,
+        pub ac_exe_dev: crate::__u64,
+        pub ac_exe_inode: crate::__u64,
+        /* v12 end */
+
+        /* v13: Delay waiting for write-protect copy */
+        pub wpcopy_count: crate::__u64,
+        pub wpcopy_delay_total: crate::__u64,
+
+        /* v14: Delay waiting for IRQ/SOFTIRQ */
+        pub irq_count: crate::__u64,
+        pub irq_delay_total: crate::__u64,
+    }
+}
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        /* Reserved */
+        pub TASKSTATS_CMD_UNSPEC = 0,
+        /* user->kernel request/get-response */
+        pub TASKSTATS_CMD_GET,
+        /* kernel->user event */
+        pub TASKSTATS_CMD_NEW,
+    }
+}
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        /* Reserved */
+        pub TASKSTATS_TYPE_UNSPEC = 0,
+        /* Process id */
+        pub TASKSTATS_TYPE_PID,
+        /* Thread group id */
+        pub TASKSTATS_TYPE_TGID,
+        /* taskstats structure */
+        pub TASKSTATS_TYPE_STATS,
+        /* contains pid + stats */
+        pub TASKSTATS_TYPE_AGGR_PID,
+        /* contains tgid + stats */
+        pub TASKSTATS_TYPE_AGGR_TGID,
+        /* contains nothing */
+        pub TASKSTATS_TYPE_NULL,
+    }
+}
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        pub TASKSTATS_CMD_ATTR_UNSPEC = 0,
+        pub TASKSTATS_CMD_ATTR_PID,
+        pub TASKSTATS_CMD_ATTR_TGID,
+        pub TASKSTATS_CMD_ATTR_REGISTER_CPUMASK,
+        pub TASKSTATS_CMD_ATTR_DEREGISTER_CPUMASK,
+    }
+}
+
+pub const TASKSTATS_GENL_NAME: &[u8] = b"TASKSTATS\0";
+pub const TASKSTATS_GENL_VERSION: c_int = 0x1;
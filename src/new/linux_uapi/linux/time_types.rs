@@ -0,0 +1,15 @@
+//! Header: `uapi/linux/time_types.h`
+
+use crate::prelude::*;
+
+s! {
+    pub struct __kernel_timespec {
+        pub tv_sec: c_longlong,
+        pub tv_nsec: c_longlong,
+    }
+
+    pub struct __kernel_itimerspec {
+        pub it_interval: __kernel_timespec,
+        pub it_value: __kernel_timespec,
+    }
+}
@@ -0,0 +1,84 @@
+//! Header: `uapi/linux/uinput.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IO,
+    _IOC,
+    _IOC_READ,
+    _IOR,
+    _IOW,
+    _IOWR,
+};
+
+/* ioctl */
+pub const UINPUT_IOCTL_BASE: c_uint = b'U' as c_uint;
+pub const UI_DEV_CREATE: Ioctl = _IO(UINPUT_IOCTL_BASE, 1);
+pub const UI_DEV_DESTROY: Ioctl = _IO(UINPUT_IOCTL_BASE, 2);
+
+/*
+ * UI_DEV_SETUP - Set device parameters for setup
+ *
+ * This ioctl sets parameters for the input device to be created. It
+ * supersedes the old "struct uinput_user_dev" method, which wrote this data
+ * via write().
+ */
+pub const UI_DEV_SETUP: Ioctl = _IOW::<crate::uinput_setup>(UINPUT_IOCTL_BASE, 3);
+
+/*
+ * UI_ABS_SETUP - Set absolute axis information for the device to setup
+ *
+ * This ioctl sets one absolute axis information for the input device to be
+ * created. It must be issued before UI_DEV_CREATE.
+ */
+pub const UI_ABS_SETUP: Ioctl = _IOW::<crate::uinput_abs_setup>(UINPUT_IOCTL_BASE, 4);
+
+pub const UI_SET_EVBIT: Ioctl = _IOW::<c_int>(UINPUT_IOCTL_BASE, 100);
+pub const UI_SET_KEYBIT: Ioctl = _IOW::<c_int>(UINPUT_IOCTL_BASE, 101);
+pub const UI_SET_RELBIT: Ioctl = _IOW::<c_int>(UINPUT_IOCTL_BASE, 102);
+pub const UI_SET_ABSBIT: Ioctl = _IOW::<c_int>(UINPUT_IOCTL_BASE, 103);
+pub const UI_SET_MSCBIT: Ioctl = _IOW::<c_int>(UINPUT_IOCTL_BASE, 104);
+pub const UI_SET_LEDBIT: Ioctl = _IOW::<c_int>(UINPUT_IOCTL_BASE, 105);
+pub const UI_SET_SNDBIT: Ioctl = _IOW::<c_int>(UINPUT_IOCTL_BASE, 106);
+pub const UI_SET_FFBIT: Ioctl = _IOW::<c_int>(UINPUT_IOCTL_BASE, 107);
+pub const UI_SET_PHYS: Ioctl = _IOW::<*mut c_char>(UINPUT_IOCTL_BASE, 108);
+pub const UI_SET_SWBIT: Ioctl = _IOW::<c_int>(UINPUT_IOCTL_BASE, 109);
+pub const UI_SET_PROPBIT: Ioctl = _IOW::<c_int>(UINPUT_IOCTL_BASE, 110);
+
+pub const UI_BEGIN_FF_UPLOAD: Ioctl = _IOWR::<crate::uinput_ff_upload>(UINPUT_IOCTL_BASE, 200);
+pub const UI_END_FF_UPLOAD: Ioctl = _IOW::<crate::uinput_ff_upload>(UINPUT_IOCTL_BASE, 201);
+pub const UI_BEGIN_FF_ERASE: Ioctl = _IOWR::<crate::uinput_ff_erase>(UINPUT_IOCTL_BASE, 202);
+pub const UI_END_FF_ERASE: Ioctl = _IOW::<crate::uinput_ff_erase>(UINPUT_IOCTL_BASE, 203);
+
+safe_f! {
+    /*
+     * UI_GET_SYSNAME - get the sysfs name of the created uinput device
+     *
+     * @return the sysfs name of the created virtual input device.
+     * The complete sysfs path is then /sys/devices/virtual/input/--NAME--
+     * Usually, it is in the form "inputN"
+     */
+    pub const fn UI_GET_SYSNAME(len: usize) -> Ioctl {
+        _IOC(_IOC_READ, UINPUT_IOCTL_BASE, 44, len)
+    }
+}
+
+/*
+ * UI_GET_VERSION - Return version of uinput protocol
+ *
+ * This writes uinput protocol version implemented by the kernel into
+ * the integer pointed to by the ioctl argument. The protocol version
+ * is hard-coded in the kernel and is independent of the uinput device.
+ */
+pub const UI_GET_VERSION: Ioctl = _IOR::<c_uint>(UINPUT_IOCTL_BASE, 45);
+
+/*
+ * To write a force-feedback-capable driver, the upload_effect
+ * and erase_effect callbacks in input_dev must be implemented.
+ * The uinput driver will generate a fake input event when one of
+ * these callbacks are invoked. The userspace code then uses
+ * ioctls to retrieve additional parameters and send the return code.
+ */
+pub const EV_UINPUT: crate::__u16 = 0x0101;
+pub const UI_FF_UPLOAD: crate::__u16 = 1;
+pub const UI_FF_ERASE: crate::__u16 = 2;
@@ -0,0 +1,216 @@
+//! Header: `uapi/linux/userfaultfd.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IO,
+    _IOR,
+    _IOWR,
+};
+
+/* ioctls for /dev/userfaultfd */
+pub const USERFAULTFD_IOC: c_uint = 0xAA;
+pub const USERFAULTFD_IOC_NEW: Ioctl = _IO(USERFAULTFD_IOC, 0x00);
+
+pub const UFFD_API: crate::__u64 = 0xAA;
+
+/* Valid ioctl command number range with this API is from 0x00 to 0x3F. */
+pub const _UFFDIO_REGISTER: c_uint = 0x00;
+pub const _UFFDIO_UNREGISTER: c_uint = 0x01;
+pub const _UFFDIO_WAKE: c_uint = 0x02;
+pub const _UFFDIO_COPY: c_uint = 0x03;
+pub const _UFFDIO_ZEROPAGE: c_uint = 0x04;
+pub const _UFFDIO_MOVE: c_uint = 0x05;
+pub const _UFFDIO_WRITEPROTECT: c_uint = 0x06;
+pub const _UFFDIO_CONTINUE: c_uint = 0x07;
+pub const _UFFDIO_POISON: c_uint = 0x08;
+pub const _UFFDIO_API: c_uint = 0x3F;
+
+/* userfaultfd ioctl ids */
+pub const UFFDIO: c_uint = 0xAA;
+pub const UFFDIO_API: Ioctl = _IOWR::<uffdio_api>(UFFDIO, _UFFDIO_API);
+pub const UFFDIO_REGISTER: Ioctl = _IOWR::<uffdio_register>(UFFDIO, _UFFDIO_REGISTER);
+pub const UFFDIO_UNREGISTER: Ioctl = _IOR::<uffdio_range>(UFFDIO, _UFFDIO_UNREGISTER);
+pub const UFFDIO_WAKE: Ioctl = _IOR::<uffdio_range>(UFFDIO, _UFFDIO_WAKE);
+pub const UFFDIO_COPY: Ioctl = _IOWR::<uffdio_copy>(UFFDIO, _UFFDIO_COPY);
+pub const UFFDIO_ZEROPAGE: Ioctl = _IOWR::<uffdio_zeropage>(UFFDIO, _UFFDIO_ZEROPAGE);
+pub const UFFDIO_MOVE: Ioctl = _IOWR::<uffdio_move>(UFFDIO, _UFFDIO_MOVE);
+pub const UFFDIO_WRITEPROTECT: Ioctl = _IOWR::<uffdio_writeprotect>(UFFDIO, _UFFDIO_WRITEPROTECT);
+pub const UFFDIO_CONTINUE: Ioctl = _IOWR::<uffdio_continue>(UFFDIO, _UFFDIO_CONTINUE);
+pub const UFFDIO_POISON: Ioctl = _IOWR::<uffdio_poison>(UFFDIO, _UFFDIO_POISON);
+
+s_no_extra_traits! {
+    /* read() structure */
+    #[repr(C, packed)]
+    pub struct uffd_msg {
+        pub event: crate::__u8,
+
+        reserved1: Padding<crate::__u8>,
+        reserved2: Padding<crate::__u16>,
+        reserved3: Padding<crate::__u32>,
+
+        pub arg: __c_anonymous_uffd_msg_arg,
+    }
+
+    pub union __c_anonymous_uffd_msg_arg {
+        pub pagefault: __c_anonymous_uffd_msg_pagefault,
+        pub fork: __c_anonymous_uffd_msg_fork,
+        pub remap: __c_anonymous_uffd_msg_remap,
+        pub remove: __c_anonymous_uffd_msg_remove,
+        pub reserved: __c_anonymous_uffd_msg_reserved,
+    }
+
+    pub struct __c_anonymous_uffd_msg_pagefault {
+        pub flags: crate::__u64,
+        pub address: crate::__u64,
+        pub feat: __c_anonymous_uffd_msg_pagefault_feat,
+    }
+
+    pub union __c_anonymous_uffd_msg_pagefault_feat {
+        pub ptid: crate::__u32,
+    }
+}
+
+s! {
+    pub struct __c_anonymous_uffd_msg_fork {
+        pub ufd: crate::__u32,
+    }
+
+    pub struct __c_anonymous_uffd_msg_remap {
+        pub from: crate::__u64,
+        pub to: crate::__u64,
+        pub len: crate::__u64,
+    }
+
+    pub struct __c_anonymous_uffd_msg_remove {
+        pub start: crate::__u64,
+        pub end: crate::__u64,
+    }
+
+    pub struct __c_anonymous_uffd_msg_reserved {
+        /* unused reserved fields */
+        reserved1: Padding<crate::__u64>,
+        reserved2: Padding<crate::__u64>,
+        reserved3: Padding<crate::__u64>,
+    }
+}
+
+/* Start at 0x12 and not at 0 to be more strict against bugs. */
+pub const UFFD_EVENT_PAGEFAULT: c_int = 0x12;
+pub const UFFD_EVENT_FORK: c_int = 0x13;
+pub const UFFD_EVENT_REMAP: c_int = 0x14;
+pub const UFFD_EVENT_REMOVE: c_int = 0x15;
+pub const UFFD_EVENT_UNMAP: c_int = 0x16;
+
+/* flags for UFFD_EVENT_PAGEFAULT */
+pub const UFFD_PAGEFAULT_FLAG_WRITE: c_int = 1 << 0;
+pub const UFFD_PAGEFAULT_FLAG_WP: c_int = 1 << 1;
+pub const UFFD_PAGEFAULT_FLAG_MINOR: c_int = 1 << 2;
+
+/* features for uffdio_api */
+pub const UFFD_FEATURE_PAGEFAULT_FLAG_WP: c_int = 1 << 0;
+pub const UFFD_FEATURE_EVENT_FORK: c_int = 1 << 1;
+pub const UFFD_FEATURE_EVENT_REMAP: c_int = 1 << 2;
+pub const UFFD_FEATURE_EVENT_REMOVE: c_int = 1 << 3;
+pub const UFFD_FEATURE_MISSING_HUGETLBFS: c_int = 1 << 4;
+pub const UFFD_FEATURE_MISSING_SHMEM: c_int = 1 << 5;
+pub const UFFD_FEATURE_EVENT_UNMAP: c_int = 1 << 6;
+pub const UFFD_FEATURE_SIGBUS: c_int = 1 << 7;
+pub const UFFD_FEATURE_THREAD_ID: c_int = 1 << 8;
+pub const UFFD_FEATURE_MINOR_HUGETLBFS: c_int = 1 << 9;
+pub const UFFD_FEATURE_MINOR_SHMEM: c_int = 1 << 10;
+pub const UFFD_FEATURE_EXACT_ADDRESS: c_int = 1 << 11;
+pub const UFFD_FEATURE_WP_HUGETLBFS_SHMEM: c_int = 1 << 12;
+pub const UFFD_FEATURE_WP_UNPOPULATED: c_int = 1 << 13;
+pub const UFFD_FEATURE_POISON: c_int = 1 << 14;
+pub const UFFD_FEATURE_WP_ASYNC: c_int = 1 << 15;
+pub const UFFD_FEATURE_MOVE: c_int = 1 << 16;
+
+/* modes for uffdio_register */
+pub const UFFDIO_REGISTER_MODE_MISSING: crate::__u64 = 1 << 0;
+pub const UFFDIO_REGISTER_MODE_WP: crate::__u64 = 1 << 1;
+pub const UFFDIO_REGISTER_MODE_MINOR: crate::__u64 = 1 << 2;
+
+/* modes for uffdio_copy */
+pub const UFFDIO_COPY_MODE_DONTWAKE: crate::__u64 = 1 << 0;
+pub const UFFDIO_COPY_MODE_WP: crate::__u64 = 1 << 1;
+
+/* modes for uffdio_zeropage */
+pub const UFFDIO_ZEROPAGE_MODE_DONTWAKE: crate::__u64 = 1 << 0;
+
+/* modes for uffdio_writeprotect */
+pub const UFFDIO_WRITEPROTECT_MODE_WP: crate::__u64 = 1 << 0;
+pub const UFFDIO_WRITEPROTECT_MODE_DONTWAKE: crate::__u64 = 1 << 1;
+
+/* modes for uffdio_continue */
+pub const UFFDIO_CONTINUE_MODE_DONTWAKE: crate::__u64 = 1 << 0;
+pub const UFFDIO_CONTINUE_MODE_WP: crate::__u64 = 1 << 1;
+
+/* modes for uffdio_poison */
+pub const UFFDIO_POISON_MODE_DONTWAKE: crate::__u64 = 1 << 0;
+
+/* modes for uffdio_move */
+pub const UFFDIO_MOVE_MODE_DONTWAKE: crate::__u64 = 1 << 0;
+pub const UFFDIO_MOVE_MODE_ALLOW_SRC_HOLES: crate::__u64 = 1 << 1;
+
+s! {
+    pub struct uffdio_api {
+        /* userland asks for an API number and the features to enable */
+        pub api: crate::__u64,
+        pub features: crate::__u64,
+        pub ioctls: crate::__u64,
+    }
+
+    pub struct uffdio_range {
+        pub start: crate::__u64,
+        pub len: crate::__u64,
+    }
+
+    pub struct uffdio_register {
+        pub range: uffdio_range,
+        pub mode: crate::__u64,
+        pub ioctls: crate::__u64,
+    }
+
+    pub struct uffdio_copy {
+        pub dst: crate::__u64,
+        pub src: crate::__u64,
+        pub len: crate::__u64,
+        pub mode: crate::__u64,
+        pub copy: crate::__s64,
+    }
+
+    pub struct uffdio_zeropage {
+        pub range: uffdio_range,
+        pub mode: crate::__u64,
+        pub zeropage: crate::__s64,
+    }
+
+    pub struct uffdio_writeprotect {
+        pub range: uffdio_range,
+        pub mode: crate::__u64,
+    }
+
+    pub struct uffdio_continue {
+        pub range: uffdio_range,
+        pub mode: crate::__u64,
+        pub mapped: crate::__s64,
+    }
+
+    pub struct uffdio_poison {
+        pub range: uffdio_range,
+        pub mode: crate::__u64,
+        pub updated: crate::__s64,
+    }
+
+    pub struct uffdio_move {
+        pub dst: crate::__u64,
+        pub src: crate::__u64,
+        pub len: crate::__u64,
+        pub mode: crate::__u64,
+        pub move_: crate::__s64,
+    }
+}
+
+/* Flags for the userfaultfd(2) system call itself. */
+pub const UFFD_USER_MODE_ONLY: c_int = 1;
@@ -0,0 +1,52 @@
+//! Header: `uapi/linux/v4l2-common.h`
+
+use crate::prelude::*;
+
+/*
+ * Selection interface definitions
+ */
+
+/* Current cropping area */
+pub const V4L2_SEL_TGT_CROP: c_uint = 0x0000;
+/* Default cropping area */
+pub const V4L2_SEL_TGT_CROP_DEFAULT: c_uint = 0x0001;
+/* Cropping bounds */
+pub const V4L2_SEL_TGT_CROP_BOUNDS: c_uint = 0x0002;
+/* Native frame size */
+pub const V4L2_SEL_TGT_NATIVE_SIZE: c_uint = 0x0003;
+/* Current composing area */
+pub const V4L2_SEL_TGT_COMPOSE: c_uint = 0x0100;
+/* Default composing area */
+pub const V4L2_SEL_TGT_COMPOSE_DEFAULT: c_uint = 0x0101;
+/* Composing bounds */
+pub const V4L2_SEL_TGT_COMPOSE_BOUNDS: c_uint = 0x0102;
+/* Current composing area plus all padding pixels */
+pub const V4L2_SEL_TGT_COMPOSE_PADDED: c_uint = 0x0103;
+
+/* Selection flags */
+pub const V4L2_SEL_FLAG_GE: c_uint = 1 << 0;
+pub const V4L2_SEL_FLAG_LE: c_uint = 1 << 1;
+pub const V4L2_SEL_FLAG_KEEP_CONFIG: c_uint = 1 << 2;
+
+s! {
+    pub struct v4l2_edid {
+        pub pad: crate::__u32,
+        pub start_block: crate::__u32,
+        pub blocks: crate::__u32,
+        reserved: Padding<[crate::__u32; 5]>,
+        pub edid: *mut crate::__u8,
+    }
+}
+
+/* Backward compatibility target definitions --- to be removed. */
+pub const V4L2_SEL_TGT_CROP_ACTIVE: c_uint = V4L2_SEL_TGT_CROP;
+pub const V4L2_SEL_TGT_COMPOSE_ACTIVE: c_uint = V4L2_SEL_TGT_COMPOSE;
+pub const V4L2_SUBDEV_SEL_TGT_CROP_ACTUAL: c_uint = V4L2_SEL_TGT_CROP;
+pub const V4L2_SUBDEV_SEL_TGT_COMPOSE_ACTUAL: c_uint = V4L2_SEL_TGT_COMPOSE;
+pub const V4L2_SUBDEV_SEL_TGT_CROP_BOUNDS: c_uint = V4L2_SEL_TGT_CROP_BOUNDS;
+pub const V4L2_SUBDEV_SEL_TGT_COMPOSE_BOUNDS: c_uint = V4L2_SEL_TGT_COMPOSE_BOUNDS;
+
+/* Backward compatibility flag definitions --- to be removed. */
+pub const V4L2_SUBDEV_SEL_FLAG_SIZE_GE: c_uint = V4L2_SEL_FLAG_GE;
+pub const V4L2_SUBDEV_SEL_FLAG_SIZE_LE: c_uint = V4L2_SEL_FLAG_LE;
+pub const V4L2_SUBDEV_SEL_FLAG_KEEP_CONFIG: c_uint = V4L2_SEL_FLAG_KEEP_CONFIG;
@@ -0,0 +1,2783 @@
+//! Header: `uapi/linux/videodev2.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IO,
+    _IOR,
+    _IOW,
+    _IOWR,
+};
+
+/*
+ * Common stuff for both V4L1 and V4L2
+ * Moved from videodev.h
+ */
+pub const VIDEO_MAX_FRAME: usize = 32;
+pub const VIDEO_MAX_PLANES: usize = 8;
+
+/*
+ *      M I S C E L L A N E O U S
+ */
+
+safe_f! {
+    /*  Four-character-code (FOURCC) */
+    pub const fn v4l2_fourcc(a: u8, b: u8, c: u8, d: u8) -> crate::__u32 {
+        (a as crate::__u32)
+            | ((b as crate::__u32) << 8)
+            | ((c as crate::__u32) << 16)
+            | ((d as crate::__u32) << 24)
+    }
+
+    pub const fn v4l2_fourcc_be(a: u8, b: u8, c: u8, d: u8) -> crate::__u32 {
+        v4l2_fourcc(a, b, c, d) | (1 << 31)
+    }
+}
+
+/*
+ *      E N U M S
+ */
+c_enum! {
+    pub enum v4l2_field {
+        /* driver can choose from none, top, bottom, interlaced depending on whatever it
+         * thinks is approximate ... */
+        pub V4L2_FIELD_ANY = 0,
+        /* this device has no fields ... */
+        pub V4L2_FIELD_NONE = 1,
+        /* top field only */
+        pub V4L2_FIELD_TOP = 2,
+        /* bottom field only */
+        pub V4L2_FIELD_BOTTOM = 3,
+        /* both fields interlaced */
+        pub V4L2_FIELD_INTERLACED = 4,
+        /* both fields sequential into one buffer, top-bottom order */
+        pub V4L2_FIELD_SEQ_TB = 5,
+        /* same as above + bottom-top order */
+        pub V4L2_FIELD_SEQ_BT = 6,
+        /* both fields alternating into separate buffers */
+        pub V4L2_FIELD_ALTERNATE = 7,
+        /* both fields interlaced, top field first and the top field is transmitted first */
+        pub V4L2_FIELD_INTERLACED_TB = 8,
+        /* both fields interlaced, top field first and the bottom field is transmitted first */
+        pub V4L2_FIELD_INTERLACED_BT = 9,
+    }
+
+    pub enum v4l2_buf_type {
+        pub V4L2_BUF_TYPE_VIDEO_CAPTURE = 1,
+        pub V4L2_BUF_TYPE_VIDEO_OUTPUT = 2,
+        pub V4L2_BUF_TYPE_VIDEO_OVERLAY = 3,
+        pub V4L2_BUF_TYPE_VBI_CAPTURE = 4,
+        pub V4L2_BUF_TYPE_VBI_OUTPUT = 5,
+        pub V4L2_BUF_TYPE_SLICED_VBI_CAPTURE = 6,
+        pub V4L2_BUF_TYPE_SLICED_VBI_OUTPUT = 7,
+        pub V4L2_BUF_TYPE_VIDEO_OUTPUT_OVERLAY = 8,
+        pub V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE = 9,
+        pub V4L2_BUF_TYPE_VIDEO_OUTPUT_MPLANE = 10,
+        pub V4L2_BUF_TYPE_SDR_CAPTURE = 11,
+        pub V4L2_BUF_TYPE_SDR_OUTPUT = 12,
+        pub V4L2_BUF_TYPE_META_CAPTURE = 13,
+        pub V4L2_BUF_TYPE_META_OUTPUT = 14,
+        /* Deprecated, do not use */
+        pub V4L2_BUF_TYPE_PRIVATE = 0x80,
+    }
+
+    pub enum v4l2_tuner_type {
+        pub V4L2_TUNER_RADIO = 1,
+        pub V4L2_TUNER_ANALOG_TV = 2,
+        pub V4L2_TUNER_DIGITAL_TV = 3,
+        pub V4L2_TUNER_SDR = 4,
+        pub V4L2_TUNER_RF = 5,
+    }
+}
+
+/* Deprecated, do not use */
+pub const V4L2_TUNER_ADC: v4l2_tuner_type = V4L2_TUNER_SDR;
+
+c_enum! {
+    pub enum v4l2_memory {
+        pub V4L2_MEMORY_MMAP = 1,
+        pub V4L2_MEMORY_USERPTR = 2,
+        pub V4L2_MEMORY_OVERLAY = 3,
+        pub V4L2_MEMORY_DMABUF = 4,
+    }
+
+    /* see also http://vektor.theorem.ca/graphics/ycbcr/ */
+    pub enum v4l2_colorspace {
+        /*
+         * Default colorspace, i.e. let the driver figure it out.
+         * Can only be used with video capture.
+         */
+        pub V4L2_COLORSPACE_DEFAULT = 0,
+
+        /* SMPTE 170M: used for broadcast NTSC/PAL SDTV */
+        pub V4L2_COLORSPACE_SMPTE170M = 1,
+
+        /* Obsolete pre-1998 SMPTE 240M HDTV standard, superseded by Rec 709 */
+        pub V4L2_COLORSPACE_SMPTE240M = 2,
+
+        /* Rec.709: used for HDTV */
+        pub V4L2_COLORSPACE_REC709 = 3,
+
+        /*
+         * Deprecated, do not use. No driver will ever return this. This was
+         * based on a misunderstanding of the bt878 datasheet.
+         */
+        pub V4L2_COLORSPACE_BT878 = 4,
+
+        /*
+         * NTSC 1953 colorspace. This only makes sense when dealing with
+         * really, really old NTSC recordings. Superseded by SMPTE 170M.
+         */
+        pub V4L2_COLORSPACE_470_SYSTEM_M = 5,
+
+        /*
+         * EBU Tech 3213 PAL/SECAM colorspace.
+         */
+        pub V4L2_COLORSPACE_470_SYSTEM_BG = 6,
+
+        /*
+         * Effectively shorthand for V4L2_COLORSPACE_SRGB, V4L2_YCBCR_ENC_601
+         * and V4L2_QUANTIZATION_FULL_RANGE. To be used for (Motion-)JPEG.
+         */
+        pub V4L2_COLORSPACE_JPEG = 7,
+
+        /* For RGB colorspaces such as produces by most webcams. */
+        pub V4L2_COLORSPACE_SRGB = 8,
+
+        /* opRGB colorspace */
+        pub V4L2_COLORSPACE_OPRGB = 9,
+
+        /* BT.2020 colorspace, used for UHDTV. */
+        pub V4L2_COLORSPACE_BT2020 = 10,
+
+        /* Raw colorspace: for RAW unprocessed images */
+        pub V4L2_COLORSPACE_RAW = 11,
+
+        /* DCI-P3 colorspace, used by cinema projectors */
+        pub V4L2_COLORSPACE_DCI_P3 = 12,
+    }
+
+    pub enum v4l2_xfer_func {
+        /*
+         * Mapping of V4L2_XFER_FUNC_DEFAULT to actual transfer functions
+         * for the various colorspaces:
+         *
+         * V4L2_COLORSPACE_SMPTE170M, V4L2_COLORSPACE_470_SYSTEM_M,
+         * V4L2_COLORSPACE_470_SYSTEM_BG, V4L2_COLORSPACE_REC709 and
+         * V4L2_COLORSPACE_BT2020: V4L2_XFER_FUNC_709
+         *
+         * V4L2_COLORSPACE_SRGB, V4L2_COLORSPACE_JPEG: V4L2_XFER_FUNC_SRGB
+         *
+         * V4L2_COLORSPACE_OPRGB: V4L2_XFER_FUNC_OPRGB
+         *
+         * V4L2_COLORSPACE_SMPTE240M: V4L2_XFER_FUNC_SMPTE240M
+         *
+         * V4L2_COLORSPACE_RAW: V4L2_XFER_FUNC_NONE
+         *
+         * V4L2_COLORSPACE_DCI_P3: V4L2_XFER_FUNC_DCI_P3
+         */
+        pub V4L2_XFER_FUNC_DEFAULT = 0,
+        pub V4L2_XFER_FUNC_709 = 1,
+        pub V4L2_XFER_FUNC_SRGB = 2,
+        pub V4L2_XFER_FUNC_OPRGB = 3,
+        pub V4L2_XFER_FUNC_SMPTE240M = 4,
+        pub V4L2_XFER_FUNC_NONE = 5,
+        pub V4L2_XFER_FUNC_DCI_P3 = 6,
+        pub V4L2_XFER_FUNC_SMPTE2084 = 7,
+    }
+
+    pub enum v4l2_ycbcr_encoding {
+        /*
+         * Mapping of V4L2_YCBCR_ENC_DEFAULT to actual encodings for the
+         * various colorspaces:
+         *
+         * V4L2_COLORSPACE_SMPTE170M, V4L2_COLORSPACE_470_SYSTEM_M,
+         * V4L2_COLORSPACE_470_SYSTEM_BG, V4L2_COLORSPACE_SRGB,
+         * V4L2_COLORSPACE_OPRGB and V4L2_COLORSPACE_JPEG: V4L2_YCBCR_ENC_601
+         *
+         * V4L2_COLORSPACE_REC709 and V4L2_COLORSPACE_DCI_P3: V4L2_YCBCR_ENC_709
+         *
+         * V4L2_COLORSPACE_BT2020: V4L2_YCBCR_ENC_BT2020
+         *
+         * V4L2_COLORSPACE_SMPTE240M: V4L2_YCBCR_ENC_SMPTE240M
+         */
+        pub V4L2_YCBCR_ENC_DEFAULT = 0,
+
+        /* ITU-R 601 -- SDTV */
+        pub V4L2_YCBCR_ENC_601 = 1,
+
+        /* Rec. 709 -- HDTV */
+        pub V4L2_YCBCR_ENC_709 = 2,
+
+        /* ITU-R 601/EN 61966-2-4 Extended Gamut -- SDTV */
+        pub V4L2_YCBCR_ENC_XV601 = 3,
+
+        /* Rec. 709/EN 61966-2-4 Extended Gamut -- HDTV */
+        pub V4L2_YCBCR_ENC_XV709 = 4,
+
+        /*
+         * sYCC (Y'CbCr encoding of sRGB), identical to ENC_601. It was added
+         * originally due to a misunderstanding of the sYCC standard. It should
+         * not be used, instead use V4L2_YCBCR_ENC_601.
+         */
+        pub V4L2_YCBCR_ENC_SYCC = 5,
+
+        /* BT.2020 Non-constant Luminance Y'CbCr */
+        pub V4L2_YCBCR_ENC_BT2020 = 6,
+
+        /* BT.2020 Constant Luminance Y'CbcCrc */
+        pub V4L2_YCBCR_ENC_BT2020_CONST_LUM = 7,
+
+        /* SMPTE 240M -- Obsolete HDTV */
+        pub V4L2_YCBCR_ENC_SMPTE240M = 8,
+    }
+
+    /*
+     * enum v4l2_hsv_encoding values should not collide with the ones from
+     * enum v4l2_ycbcr_encoding.
+     */
+    pub enum v4l2_hsv_encoding {
+        /* Hue mapped to 0 - 179 */
+        pub V4L2_HSV_ENC_180 = 128,
+
+        /* Hue mapped to 0-255 */
+        pub V4L2_HSV_ENC_256 = 129,
+    }
+
+    pub enum v4l2_quantization {
+        /*
+         * The default for R'G'B' quantization is always full range.
+         * For Y'CbCr the quantization is always limited range, except
+         * for COLORSPACE_JPEG: this is full range.
+         */
+        pub V4L2_QUANTIZATION_DEFAULT = 0,
+        pub V4L2_QUANTIZATION_FULL_RANGE = 1,
+        pub V4L2_QUANTIZATION_LIM_RANGE = 2,
+    }
+}
+
+/*
+ * Deprecated names for opRGB colorspace (IEC 61966-2-5)
+ *
+ * WARNING: Please don't use these deprecated defines in your code, as
+ * there is a chance we have to remove them in the future.
+ */
+pub const V4L2_COLORSPACE_ADOBERGB: v4l2_colorspace = V4L2_COLORSPACE_OPRGB;
+pub const V4L2_XFER_FUNC_ADOBERGB: v4l2_xfer_func = V4L2_XFER_FUNC_OPRGB;
+
+c_enum! {
+    pub enum v4l2_priority {
+        /* not initialized */
+        pub V4L2_PRIORITY_UNSET = 0,
+        pub V4L2_PRIORITY_BACKGROUND = 1,
+        pub V4L2_PRIORITY_INTERACTIVE = 2,
+        pub V4L2_PRIORITY_RECORD = 3,
+        pub V4L2_PRIORITY_DEFAULT = V4L2_PRIORITY_INTERACTIVE,
+    }
+}
+
+s! {
+    pub struct v4l2_rect {
+        pub left: crate::__s32,
+        pub top: crate::__s32,
+        pub width: crate::__u32,
+        pub height: crate::__u32,
+    }
+
+    pub struct v4l2_fract {
+        pub numerator: crate::__u32,
+        pub denominator: crate::__u32,
+    }
+
+    pub struct v4l2_area {
+        pub width: crate::__u32,
+        pub height: crate::__u32,
+    }
+
+    /*
+     * struct v4l2_capability - Describes V4L2 device caps returned by VIDIOC_QUERYCAP
+     *
+     * @driver:    name of the driver module (e.g. "bttv")
+     * @card:      name of the card (e.g. "Hauppauge WinTV")
+     * @bus_info:          name of the bus (e.g. "PCI:" + pci_name(pci_dev) )
+     * @version:           KERNEL_VERSION
+     * @capabilities: capabilities of the physical device as a whole
+     * @device_caps:  capabilities accessed via this particular device (node)
+     * @reserved:          reserved fields for future extensions
+     */
+    pub struct v4l2_capability {
+        pub driver: [crate::__u8; 16],
+        pub card: [crate::__u8; 32],
+        pub bus_info: [crate::__u8; 32],
+        pub version: crate::__u32,
+        pub capabilities: crate::__u32,
+        pub device_caps: crate::__u32,
+        reserved: Padding<[crate::__u32; 3]>,
+    }
+}
+
+/* Values for 'capabilities' field */
+/* Is a video capture device */
+pub const V4L2_CAP_VIDEO_CAPTURE: c_uint = 0x00000001;
+/* Is a video output device */
+pub const V4L2_CAP_VIDEO_OUTPUT: c_uint = 0x00000002;
+/* Can do video overlay */
+pub const V4L2_CAP_VIDEO_OVERLAY: c_uint = 0x00000004;
+/* Is a raw VBI capture device */
+pub const V4L2_CAP_VBI_CAPTURE: c_uint = 0x00000010;
+/* Is a raw VBI output device */
+pub const V4L2_CAP_VBI_OUTPUT: c_uint = 0x00000020;
+/* Is a sliced VBI capture device */
+pub const V4L2_CAP_SLICED_VBI_CAPTURE: c_uint = 0x00000040;
+/* Is a sliced VBI output device */
+pub const V4L2_CAP_SLICED_VBI_OUTPUT: c_uint = 0x00000080;
+/* RDS data capture */
+pub const V4L2_CAP_RDS_CAPTURE: c_uint = 0x00000100;
+/* Can do video output overlay */
+pub const V4L2_CAP_VIDEO_OUTPUT_OVERLAY: c_uint = 0x00000200;
+/* Can do hardware frequency seek */
+pub const V4L2_CAP_HW_FREQ_SEEK: c_uint = 0x00000400;
+/* Is an RDS encoder */
+pub const V4L2_CAP_RDS_OUTPUT: c_uint = 0x00000800;
+
+/* Is a video capture device that supports multiplanar formats */
+pub const V4L2_CAP_VIDEO_CAPTURE_MPLANE: c_uint = 0x00001000;
+/* Is a video output device that supports multiplanar formats */
+pub const V4L2_CAP_VIDEO_OUTPUT_MPLANE: c_uint = 0x00002000;
+/* Is a video mem-to-mem device that supports multiplanar formats */
+pub const V4L2_CAP_VIDEO_M2M_MPLANE: c_uint = 0x00004000;
+/* Is a video mem-to-mem device */
+pub const V4L2_CAP_VIDEO_M2M: c_uint = 0x00008000;
+
+/* has a tuner */
+pub const V4L2_CAP_TUNER: c_uint = 0x00010000;
+/* has audio support */
+pub const V4L2_CAP_AUDIO: c_uint = 0x00020000;
+/* is a radio device */
+pub const V4L2_CAP_RADIO: c_uint = 0x00040000;
+/* has a modulator */
+pub const V4L2_CAP_MODULATOR: c_uint = 0x00080000;
+
+/* Is a SDR capture device */
+pub const V4L2_CAP_SDR_CAPTURE: c_uint = 0x00100000;
+/* Supports the extended pixel format */
+pub const V4L2_CAP_EXT_PIX_FORMAT: c_uint = 0x00200000;
+/* Is a SDR output device */
+pub const V4L2_CAP_SDR_OUTPUT: c_uint = 0x00400000;
+/* Is a metadata capture device */
+pub const V4L2_CAP_META_CAPTURE: c_uint = 0x00800000;
+
+/* read/write systemcalls */
+pub const V4L2_CAP_READWRITE: c_uint = 0x01000000;
+/* streaming I/O ioctls */
+pub const V4L2_CAP_STREAMING: c_uint = 0x04000000;
+/* Is a metadata output device */
+pub const V4L2_CAP_META_OUTPUT: c_uint = 0x08000000;
+
+/* Is a touch device */
+pub const V4L2_CAP_TOUCH: c_uint = 0x10000000;
+
+/* Is input/output controlled by the media controller */
+pub const V4L2_CAP_IO_MC: c_uint = 0x20000000;
+
+/* sets device capabilities field */
+pub const V4L2_CAP_DEVICE_CAPS: c_uint = 0x80000000;
+
+/*
+ *      V I D E O   I M A G E   F O R M A T
+ */
+s_no_extra_traits! {
+    pub struct v4l2_pix_format {
+        pub width: crate::__u32,
+        pub height: crate::__u32,
+        pub pixelformat: crate::__u32,
+        /* enum v4l2_field */
+        pub field: crate::__u32,
+        /* for padding, zero if unused */
+        pub bytesperline: crate::__u32,
+        pub sizeimage: crate::__u32,
+        /* enum v4l2_colorspace */
+        pub colorspace: crate::__u32,
+        /* private data, depends on pixelformat */
+        pub priv_: crate::__u32,
+        /* format flags (V4L2_PIX_FMT_FLAG_*) */
+        pub flags: crate::__u32,
+        pub anonymous_1: __c_anonymous_v4l2_pix_format_1,
+        /* enum v4l2_quantization */
+        pub quantization: crate::__u32,
+        /* enum v4l2_xfer_func */
+        pub xfer_func: crate::__u32,
+    }
+
+    pub union __c_anonymous_v4l2_pix_format_1 {
+        /* enum v4l2_ycbcr_encoding */
+        pub ycbcr_enc: crate::__u32,
+        /* enum v4l2_hsv_encoding */
+        pub hsv_enc: crate::__u32,
+    }
+}
+
+/* RGB formats (1 or 2 bytes per pixel) */
+pub const V4L2_PIX_FMT_RGB332: crate::__u32 = v4l2_fourcc(b'R', b'G', b'B', b'1');
+pub const V4L2_PIX_FMT_RGB444: crate::__u32 = v4l2_fourcc(b'R', b'4', b'4', b'4');
+pub const V4L2_PIX_FMT_ARGB444: crate::__u32 = v4l2_fourcc(b'A', b'R', b'1', b'2');
+pub const V4L2_PIX_FMT_XRGB444: crate::__u32 = v4l2_fourcc(b'X', b'R', b'1', b'2');
+pub const V4L2_PIX_FMT_RGBA444: crate::__u32 = v4l2_fourcc(b'R', b'A', b'1', b'2');
+pub const V4L2_PIX_FMT_RGBX444: crate::__u32 = v4l2_fourcc(b'R', b'X', b'1', b'2');
+pub const V4L2_PIX_FMT_ABGR444: crate::__u32 = v4l2_fourcc(b'A', b'B', b'1', b'2');
+pub const V4L2_PIX_FMT_XBGR444: crate::__u32 = v4l2_fourcc(b'X', b'B', b'1', b'2');
+pub const V4L2_PIX_FMT_BGRA444: crate::__u32 = v4l2_fourcc(b'G', b'A', b'1', b'2');
+pub const V4L2_PIX_FMT_BGRX444: crate::__u32 = v4l2_fourcc(b'B', b'X', b'1', b'2');
+pub const V4L2_PIX_FMT_RGB555: crate::__u32 = v4l2_fourcc(b'R', b'G', b'B', b'O');
+pub const V4L2_PIX_FMT_ARGB555: crate::__u32 = v4l2_fourcc(b'A', b'R', b'1', b'5');
+pub const V4L2_PIX_FMT_XRGB555: crate::__u32 = v4l2_fourcc(b'X', b'R', b'1', b'5');
+pub const V4L2_PIX_FMT_RGBA555: crate::__u32 = v4l2_fourcc(b'R', b'A', b'1', b'5');
+pub const V4L2_PIX_FMT_RGBX555: crate::__u32 = v4l2_fourcc(b'R', b'X', b'1', b'5');
+pub const V4L2_PIX_FMT_ABGR555: crate::__u32 = v4l2_fourcc(b'A', b'B', b'1', b'5');
+pub const V4L2_PIX_FMT_XBGR555: crate::__u32 = v4l2_fourcc(b'X', b'B', b'1', b'5');
+pub const V4L2_PIX_FMT_BGRA555: crate::__u32 = v4l2_fourcc(b'B', b'A', b'1', b'5');
+pub const V4L2_PIX_FMT_BGRX555: crate::__u32 = v4l2_fourcc(b'B', b'X', b'1', b'5');
+pub const V4L2_PIX_FMT_RGB565: crate::__u32 = v4l2_fourcc(b'R', b'G', b'B', b'P');
+pub const V4L2_PIX_FMT_RGB555X: crate::__u32 = v4l2_fourcc(b'R', b'G', b'B', b'Q');
+pub const V4L2_PIX_FMT_ARGB555X: crate::__u32 = v4l2_fourcc_be(b'A', b'R', b'1', b'5');
+pub const V4L2_PIX_FMT_XRGB555X: crate::__u32 = v4l2_fourcc_be(b'X', b'R', b'1', b'5');
+pub const V4L2_PIX_FMT_RGB565X: crate::__u32 = v4l2_fourcc(b'R', b'G', b'B', b'R');
+
+/* RGB formats (3 or 4 bytes per pixel) */
+pub const V4L2_PIX_FMT_BGR666: crate::__u32 = v4l2_fourcc(b'B', b'G', b'R', b'H');
+pub const V4L2_PIX_FMT_BGR24: crate::__u32 = v4l2_fourcc(b'B', b'G', b'R', b'3');
+pub const V4L2_PIX_FMT_RGB24: crate::__u32 = v4l2_fourcc(b'R', b'G', b'B', b'3');
+pub const V4L2_PIX_FMT_BGR32: crate::__u32 = v4l2_fourcc(b'B', b'G', b'R', b'4');
+pub const V4L2_PIX_FMT_ABGR32: crate::__u32 = v4l2_fourcc(b'A', b'R', b'2', b'4');
+pub const V4L2_PIX_FMT_XBGR32: crate::__u32 = v4l2_fourcc(b'X', b'R', b'2', b'4');
+pub const V4L2_PIX_FMT_BGRA32: crate::__u32 = v4l2_fourcc(b'R', b'A', b'2', b'4');
+pub const V4L2_PIX_FMT_BGRX32: crate::__u32 = v4l2_fourcc(b'R', b'X', b'2', b'4');
+pub const V4L2_PIX_FMT_RGB32: crate::__u32 = v4l2_fourcc(b'R', b'G', b'B', b'4');
+pub const V4L2_PIX_FMT_RGBA32: crate::__u32 = v4l2_fourcc(b'A', b'B', b'2', b'4');
+pub const V4L2_PIX_FMT_RGBX32: crate::__u32 = v4l2_fourcc(b'X', b'B', b'2', b'4');
+pub const V4L2_PIX_FMT_ARGB32: crate::__u32 = v4l2_fourcc(b'B', b'A', b'2', b'4');
+pub const V4L2_PIX_FMT_XRGB32: crate::__u32 = v4l2_fourcc(b'B', b'X', b'2', b'4');
+
+/* Grey formats */
+pub const V4L2_PIX_FMT_GREY: crate::__u32 = v4l2_fourcc(b'G', b'R', b'E', b'Y');
+pub const V4L2_PIX_FMT_Y4: crate::__u32 = v4l2_fourcc(b'Y', b'0', b'4', b' ');
+pub const V4L2_PIX_FMT_Y6: crate::__u32 = v4l2_fourcc(b'Y', b'0', b'6', b' ');
+pub const V4L2_PIX_FMT_Y10: crate::__u32 = v4l2_fourcc(b'Y', b'1', b'0', b' ');
+pub const V4L2_PIX_FMT_Y12: crate::__u32 = v4l2_fourcc(b'Y', b'1', b'2', b' ');
+pub const V4L2_PIX_FMT_Y14: crate::__u32 = v4l2_fourcc(b'Y', b'1', b'4', b' ');
+pub const V4L2_PIX_FMT_Y16: crate::__u32 = v4l2_fourcc(b'Y', b'1', b'6', b' ');
+pub const V4L2_PIX_FMT_Y16_BE: crate::__u32 = v4l2_fourcc_be(b'Y', b'1', b'6', b' ');
+
+/* Grey bit-packed formats */
+pub const V4L2_PIX_FMT_Y10BPACK: crate::__u32 = v4l2_fourcc(b'Y', b'1', b'0', b'B');
+pub const V4L2_PIX_FMT_Y10P: crate::__u32 = v4l2_fourcc(b'Y', b'1', b'0', b'P');
+pub const V4L2_PIX_FMT_IPU3_Y10: crate::__u32 = v4l2_fourcc(b'i', b'p', b'3', b'y');
+
+/* Palette formats */
+pub const V4L2_PIX_FMT_PAL8: crate::__u32 = v4l2_fourcc(b'P', b'A', b'L', b'8');
+
+/* Chrominance formats */
+pub const V4L2_PIX_FMT_UV8: crate::__u32 = v4l2_fourcc(b'U', b'V', b'8', b' ');
+
+/* Luminance+Chrominance formats */
+pub const V4L2_PIX_FMT_YUYV: crate::__u32 = v4l2_fourcc(b'Y', b'U', b'Y', b'V');
+pub const V4L2_PIX_FMT_YYUV: crate::__u32 = v4l2_fourcc(b'Y', b'Y', b'U', b'V');
+pub const V4L2_PIX_FMT_YVYU: crate::__u32 = v4l2_fourcc(b'Y', b'V', b'Y', b'U');
+pub const V4L2_PIX_FMT_UYVY: crate::__u32 = v4l2_fourcc(b'U', b'Y', b'V', b'Y');
+pub const V4L2_PIX_FMT_VYUY: crate::__u32 = v4l2_fourcc(b'V', b'Y', b'U', b'Y');
+pub const V4L2_PIX_FMT_Y41P: crate::__u32 = v4l2_fourcc(b'Y', b'4', b'1', b'P');
+pub const V4L2_PIX_FMT_YUV444: crate::__u32 = v4l2_fourcc(b'Y', b'4', b'4', b'4');
+pub const V4L2_PIX_FMT_YUV555: crate::__u32 = v4l2_fourcc(b'Y', b'U', b'V', b'O');
+pub const V4L2_PIX_FMT_YUV565: crate::__u32 = v4l2_fourcc(b'Y', b'U', b'V', b'P');
+pub const V4L2_PIX_FMT_YUV24: crate::__u32 = v4l2_fourcc(b'Y', b'U', b'V', b'3');
+pub const V4L2_PIX_FMT_YUV32: crate::__u32 = v4l2_fourcc(b'Y', b'U', b'V', b'4');
+pub const V4L2_PIX_FMT_AYUV32: crate::__u32 = v4l2_fourcc(b'A', b'Y', b'U', b'V');
+pub const V4L2_PIX_FMT_XYUV32: crate::__u32 = v4l2_fourcc(b'X', b'Y', b'U', b'V');
+pub const V4L2_PIX_FMT_VUYA32: crate::__u32 = v4l2_fourcc(b'V', b'U', b'Y', b'A');
+pub const V4L2_PIX_FMT_VUYX32: crate::__u32 = v4l2_fourcc(b'V', b'U', b'Y', b'X');
+pub const V4L2_PIX_FMT_YUVA32: crate::__u32 = v4l2_fourcc(b'Y', b'U', b'V', b'A');
+pub const V4L2_PIX_FMT_YUVX32: crate::__u32 = v4l2_fourcc(b'Y', b'U', b'V', b'X');
+pub const V4L2_PIX_FMT_M420: crate::__u32 = v4l2_fourcc(b'M', b'4', b'2', b'0');
+
+/* two planes -- one Y, one Cr + Cb interleaved  */
+pub const V4L2_PIX_FMT_NV12: crate::__u32 = v4l2_fourcc(b'N', b'V', b'1', b'2');
+pub const V4L2_PIX_FMT_NV21: crate::__u32 = v4l2_fourcc(b'N', b'V', b'2', b'1');
+pub const V4L2_PIX_FMT_NV16: crate::__u32 = v4l2_fourcc(b'N', b'V', b'1', b'6');
+pub const V4L2_PIX_FMT_NV61: crate::__u32 = v4l2_fourcc(b'N', b'V', b'6', b'1');
+pub const V4L2_PIX_FMT_NV24: crate::__u32 = v4l2_fourcc(b'N', b'V', b'2', b'4');
+pub const V4L2_PIX_FMT_NV42: crate::__u32 = v4l2_fourcc(b'N', b'V', b'4', b'2');
+pub const V4L2_PIX_FMT_P010: crate::__u32 = v4l2_fourcc(b'P', b'0', b'1', b'0');
+
+/* two non contiguous planes - one Y, one Cr + Cb interleaved  */
+pub const V4L2_PIX_FMT_NV12M: crate::__u32 = v4l2_fourcc(b'N', b'M', b'1', b'2');
+pub const V4L2_PIX_FMT_NV21M: crate::__u32 = v4l2_fourcc(b'N', b'M', b'2', b'1');
+pub const V4L2_PIX_FMT_NV16M: crate::__u32 = v4l2_fourcc(b'N', b'M', b'1', b'6');
+pub const V4L2_PIX_FMT_NV61M: crate::__u32 = v4l2_fourcc(b'N', b'M', b'6', b'1');
+
+/* three planes - Y Cb, Cr */
+pub const V4L2_PIX_FMT_YUV410: crate::__u32 = v4l2_fourcc(b'Y', b'U', b'V', b'9');
+pub const V4L2_PIX_FMT_YVU410: crate::__u32 = v4l2_fourcc(b'Y', b'V', b'U', b'9');
+pub const V4L2_PIX_FMT_YUV411P: crate::__u32 = v4l2_fourcc(b'4', b'1', b'1', b'P');
+pub const V4L2_PIX_FMT_YUV420: crate::__u32 = v4l2_fourcc(b'Y', b'U', b'1', b'2');
+pub const V4L2_PIX_FMT_YVU420: crate::__u32 = v4l2_fourcc(b'Y', b'V', b'1', b'2');
+pub const V4L2_PIX_FMT_YUV422P: crate::__u32 = v4l2_fourcc(b'4', b'2', b'2', b'P');
+
+/* three non contiguous planes - Y, Cb, Cr */
+pub const V4L2_PIX_FMT_YUV420M: crate::__u32 = v4l2_fourcc(b'Y', b'M', b'1', b'2');
+pub const V4L2_PIX_FMT_YVU420M: crate::__u32 = v4l2_fourcc(b'Y', b'M', b'2', b'1');
+pub const V4L2_PIX_FMT_YUV422M: crate::__u32 = v4l2_fourcc(b'Y', b'M', b'1', b'6');
+pub const V4L2_PIX_FMT_YVU422M: crate::__u32 = v4l2_fourcc(b'Y', b'M', b'6', b'1');
+pub const V4L2_PIX_FMT_YUV444M: crate::__u32 = v4l2_fourcc(b'Y', b'M', b'2', b'4');
+pub const V4L2_PIX_FMT_YVU444M: crate::__u32 = v4l2_fourcc(b'Y', b'M', b'4', b'2');
+
+/* Tiled YUV formats */
+pub const V4L2_PIX_FMT_NV12_4L4: crate::__u32 = v4l2_fourcc(b'V', b'T', b'1', b'2');
+pub const V4L2_PIX_FMT_NV12_16L16: crate::__u32 = v4l2_fourcc(b'H', b'M', b'1', b'2');
+pub const V4L2_PIX_FMT_NV12_32L32: crate::__u32 = v4l2_fourcc(b'S', b'T', b'1', b'2');
+pub const V4L2_PIX_FMT_P010_4L4: crate::__u32 = v4l2_fourcc(b'T', b'0', b'1', b'0');
+
+/* Tiled YUV formats, non contiguous planes */
+pub const V4L2_PIX_FMT_NV12MT: crate::__u32 = v4l2_fourcc(b'T', b'M', b'1', b'2');
+pub const V4L2_PIX_FMT_NV12MT_16X16: crate::__u32 = v4l2_fourcc(b'V', b'M', b'1', b'2');
+pub const V4L2_PIX_FMT_NV12M_8L128: crate::__u32 = v4l2_fourcc(b'N', b'A', b'1', b'2');
+pub const V4L2_PIX_FMT_NV12M_10BE_8L128: crate::__u32 = v4l2_fourcc_be(b'N', b'T', b'1', b'2');
+
+/* Bayer formats - see http://www.siliconimaging.com/RGB%20Bayer.htm */
+pub const V4L2_PIX_FMT_SBGGR8: crate::__u32 = v4l2_fourcc(b'B', b'A', b'8', b'1');
+pub const V4L2_PIX_FMT_SGBRG8: crate::__u32 = v4l2_fourcc(b'G', b'B', b'R', b'G');
+pub const V4L2_PIX_FMT_SGRBG8: crate::__u32 = v4l2_fourcc(b'G', b'R', b'B', b'G');
+pub const V4L2_PIX_FMT_SRGGB8: crate::__u32 = v4l2_fourcc(b'R', b'G', b'G', b'B');
+pub const V4L2_PIX_FMT_SBGGR10: crate::__u32 = v4l2_fourcc(b'B', b'G', b'1', b'0');
+pub const V4L2_PIX_FMT_SGBRG10: crate::__u32 = v4l2_fourcc(b'G', b'B', b'1', b'0');
+pub const V4L2_PIX_FMT_SGRBG10: crate::__u32 = v4l2_fourcc(b'B', b'A', b'1', b'0');
+pub const V4L2_PIX_FMT_SRGGB10: crate::__u32 = v4l2_fourcc(b'R', b'G', b'1', b'0');
+/* 10bit raw bayer packed, 5 bytes for every 4 pixels */
+pub const V4L2_PIX_FMT_SBGGR10P: crate::__u32 = v4l2_fourcc(b'p', b'B', b'A', b'A');
+pub const V4L2_PIX_FMT_SGBRG10P: crate::__u32 = v4l2_fourcc(b'p', b'G', b'A', b'A');
+pub const V4L2_PIX_FMT_SGRBG10P: crate::__u32 = v4l2_fourcc(b'p', b'g', b'A', b'A');
+pub const V4L2_PIX_FMT_SRGGB10P: crate::__u32 = v4l2_fourcc(b'p', b'R', b'A', b'A');
+/* 10bit raw bayer a-law compressed to 8 bits */
+pub const V4L2_PIX_FMT_SBGGR10ALAW8: crate::__u32 = v4l2_fourcc(b'a', b'B', b'A', b'8');
+pub const V4L2_PIX_FMT_SGBRG10ALAW8: crate::__u32 = v4l2_fourcc(b'a', b'G', b'A', b'8');
+pub const V4L2_PIX_FMT_SGRBG10ALAW8: crate::__u32 = v4l2_fourcc(b'a', b'g', b'A', b'8');
+pub const V4L2_PIX_FMT_SRGGB10ALAW8: crate::__u32 = v4l2_fourcc(b'a', b'R', b'A', b'8');
+/* 10bit raw bayer DPCM compressed to 8 bits */
+pub const V4L2_PIX_FMT_SBGGR10DPCM8: crate::__u32 = v4l2_fourcc(b'b', b'B', b'A', b'8');
+pub const V4L2_PIX_FMT_SGBRG10DPCM8: crate::__u32 = v4l2_fourcc(b'b', b'G', b'A', b'8');
+pub const V4L2_PIX_FMT_SGRBG10DPCM8: crate::__u32 = v4l2_fourcc(b'B', b'D', b'1', b'0');
+pub const V4L2_PIX_FMT_SRGGB10DPCM8: crate::__u32 = v4l2_fourcc(b'b', b'R', b'A', b'8');
+pub const V4L2_PIX_FMT_SBGGR12: crate::__u32 = v4l2_fourcc(b'B', b'G', b'1', b'2');
+pub const V4L2_PIX_FMT_SGBRG12: crate::__u32 = v4l2_fourcc(b'G', b'B', b'1', b'2');
+pub const V4L2_PIX_FMT_SGRBG12: crate::__u32 = v4l2_fourcc(b'B', b'A', b'1', b'2');
+pub const V4L2_PIX_FMT_SRGGB12: crate::__u32 = v4l2_fourcc(b'R', b'G', b'1', b'2');
+/* 12bit raw bayer packed, 6 bytes for every 4 pixels */
+pub const V4L2_PIX_FMT_SBGGR12P: crate::__u32 = v4l2_fourcc(b'p', b'B', b'C', b'C');
+pub const V4L2_PIX_FMT_SGBRG12P: crate::__u32 = v4l2_fourcc(b'p', b'G', b'C', b'C');
+pub const V4L2_PIX_FMT_SGRBG12P: crate::__u32 = v4l2_fourcc(b'p', b'g', b'C', b'C');
+pub const V4L2_PIX_FMT_SRGGB12P: crate::__u32 = v4l2_fourcc(b'p', b'R', b'C', b'C');
+pub const V4L2_PIX_FMT_SBGGR14: crate::__u32 = v4l2_fourcc(b'B', b'G', b'1', b'4');
+pub const V4L2_PIX_FMT_SGBRG14: crate::__u32 = v4l2_fourcc(b'G', b'B', b'1', b'4');
+pub const V4L2_PIX_FMT_SGRBG14: crate::__u32 = v4l2_fourcc(b'G', b'R', b'1', b'4');
+pub const V4L2_PIX_FMT_SRGGB14: crate::__u32 = v4l2_fourcc(b'R', b'G', b'1', b'4');
+/* 14bit raw bayer packed, 7 bytes for every 4 pixels */
+pub const V4L2_PIX_FMT_SBGGR14P: crate::__u32 = v4l2_fourcc(b'p', b'B', b'E', b'E');
+pub const V4L2_PIX_FMT_SGBRG14P: crate::__u32 = v4l2_fourcc(b'p', b'G', b'E', b'E');
+pub const V4L2_PIX_FMT_SGRBG14P: crate::__u32 = v4l2_fourcc(b'p', b'g', b'E', b'E');
+pub const V4L2_PIX_FMT_SRGGB14P: crate::__u32 = v4l2_fourcc(b'p', b'R', b'E', b'E');
+pub const V4L2_PIX_FMT_SBGGR16: crate::__u32 = v4l2_fourcc(b'B', b'Y', b'R', b'2');
+pub const V4L2_PIX_FMT_SGBRG16: crate::__u32 = v4l2_fourcc(b'G', b'B', b'1', b'6');
+pub const V4L2_PIX_FMT_SGRBG16: crate::__u32 = v4l2_fourcc(b'G', b'R', b'1', b'6');
+pub const V4L2_PIX_FMT_SRGGB16: crate::__u32 = v4l2_fourcc(b'R', b'G', b'1', b'6');
+
+/* HSV formats */
+pub const V4L2_PIX_FMT_HSV24: crate::__u32 = v4l2_fourcc(b'H', b'S', b'V', b'3');
+pub const V4L2_PIX_FMT_HSV32: crate::__u32 = v4l2_fourcc(b'H', b'S', b'V', b'4');
+
+/* compressed formats */
+pub const V4L2_PIX_FMT_MJPEG: crate::__u32 = v4l2_fourcc(b'M', b'J', b'P', b'G');
+pub const V4L2_PIX_FMT_JPEG: crate::__u32 = v4l2_fourcc(b'J', b'P', b'E', b'G');
+pub const V4L2_PIX_FMT_DV: crate::__u32 = v4l2_fourcc(b'd', b'v', b's', b'd');
+pub const V4L2_PIX_FMT_MPEG: crate::__u32 = v4l2_fourcc(b'M', b'P', b'E', b'G');
+pub const V4L2_PIX_FMT_H264: crate::__u32 = v4l2_fourcc(b'H', b'2', b'6', b'4');
+pub const V4L2_PIX_FMT_H264_NO_SC: crate::__u32 = v4l2_fourcc(b'A', b'V', b'C', b'1');
+pub const V4L2_PIX_FMT_H264_MVC: crate::__u32 = v4l2_fourcc(b'M', b'2', b'6', b'4');
+pub const V4L2_PIX_FMT_H263: crate::__u32 = v4l2_fourcc(b'H', b'2', b'6', b'3');
+pub const V4L2_PIX_FMT_MPEG1: crate::__u32 = v4l2_fourcc(b'M', b'P', b'G', b'1');
+pub const V4L2_PIX_FMT_MPEG2: crate::__u32 = v4l2_fourcc(b'M', b'P', b'G', b'2');
+pub const V4L2_PIX_FMT_MPEG2_SLICE: crate::__u32 = v4l2_fourcc(b'M', b'G', b'2', b'S');
+pub const V4L2_PIX_FMT_MPEG4: crate::__u32 = v4l2_fourcc(b'M', b'P', b'G', b'4');
+pub const V4L2_PIX_FMT_XVID: crate::__u32 = v4l2_fourcc(b'X', b'V', b'I', b'D');
+pub const V4L2_PIX_FMT_VC1_ANNEX_G: crate::__u32 = v4l2_fourcc(b'V', b'C', b'1', b'G');
+pub const V4L2_PIX_FMT_VC1_ANNEX_L: crate::__u32 = v4l2_fourcc(b'V', b'C', b'1', b'L');
+pub const V4L2_PIX_FMT_VP8: crate::__u32 = v4l2_fourcc(b'V', b'P', b'8', b'0');
+pub const V4L2_PIX_FMT_VP8_FRAME: crate::__u32 = v4l2_fourcc(b'V', b'P', b'8', b'F');
+pub const V4L2_PIX_FMT_VP9: crate::__u32 = v4l2_fourcc(b'V', b'P', b'9', b'0');
+pub const V4L2_PIX_FMT_VP9_FRAME: crate::__u32 = v4l2_fourcc(b'V', b'P', b'9', b'F');
+pub const V4L2_PIX_FMT_HEVC: crate::__u32 = v4l2_fourcc(b'H', b'E', b'V', b'C');
+pub const V4L2_PIX_FMT_FWHT: crate::__u32 = v4l2_fourcc(b'F', b'W', b'H', b'T');
+pub const V4L2_PIX_FMT_FWHT_STATELESS: crate::__u32 = v4l2_fourcc(b'S', b'F', b'W', b'H');
+pub const V4L2_PIX_FMT_H264_SLICE: crate::__u32 = v4l2_fourcc(b'S', b'2', b'6', b'4');
+pub const V4L2_PIX_FMT_HEVC_SLICE: crate::__u32 = v4l2_fourcc(b'S', b'2', b'6', b'5');
+
+/*  Vendor-specific formats   */
+pub const V4L2_PIX_FMT_CPIA1: crate::__u32 = v4l2_fourcc(b'C', b'P', b'I', b'A');
+pub const V4L2_PIX_FMT_WNVA: crate::__u32 = v4l2_fourcc(b'W', b'N', b'V', b'A');
+pub const V4L2_PIX_FMT_SN9C10X: crate::__u32 = v4l2_fourcc(b'S', b'9', b'1', b'0');
+pub const V4L2_PIX_FMT_SN9C20X_I420: crate::__u32 = v4l2_fourcc(b'S', b'9', b'2', b'0');
+pub const V4L2_PIX_FMT_PWC1: crate::__u32 = v4l2_fourcc(b'P', b'W', b'C', b'1');
+pub const V4L2_PIX_FMT_PWC2: crate::__u32 = v4l2_fourcc(b'P', b'W', b'C', b'2');
+pub const V4L2_PIX_FMT_ET61X251: crate::__u32 = v4l2_fourcc(b'E', b'6', b'2', b'5');
+pub const V4L2_PIX_FMT_SPCA501: crate::__u32 = v4l2_fourcc(b'S', b'5', b'0', b'1');
+pub const V4L2_PIX_FMT_SPCA505: crate::__u32 = v4l2_fourcc(b'S', b'5', b'0', b'5');
+pub const V4L2_PIX_FMT_SPCA508: crate::__u32 = v4l2_fourcc(b'S', b'5', b'0', b'8');
+pub const V4L2_PIX_FMT_SPCA561: crate::__u32 = v4l2_fourcc(b'S', b'5', b'6', b'1');
+pub const V4L2_PIX_FMT_PAC207: crate::__u32 = v4l2_fourcc(b'P', b'2', b'0', b'7');
+pub const V4L2_PIX_FMT_MR97310A: crate::__u32 = v4l2_fourcc(b'M', b'3', b'1', b'0');
+pub const V4L2_PIX_FMT_JL2005BCD: crate::__u32 = v4l2_fourcc(b'J', b'L', b'2', b'0');
+pub const V4L2_PIX_FMT_SN9C2028: crate::__u32 = v4l2_fourcc(b'S', b'O', b'N', b'X');
+pub const V4L2_PIX_FMT_SQ905C: crate::__u32 = v4l2_fourcc(b'9', b'0', b'5', b'C');
+pub const V4L2_PIX_FMT_PJPG: crate::__u32 = v4l2_fourcc(b'P', b'J', b'P', b'G');
+pub const V4L2_PIX_FMT_OV511: crate::__u32 = v4l2_fourcc(b'O', b'5', b'1', b'1');
+pub const V4L2_PIX_FMT_OV518: crate::__u32 = v4l2_fourcc(b'O', b'5', b'1', b'8');
+pub const V4L2_PIX_FMT_STV0680: crate::__u32 = v4l2_fourcc(b'S', b'6', b'8', b'0');
+pub const V4L2_PIX_FMT_TM6000: crate::__u32 = v4l2_fourcc(b'T', b'M', b'6', b'0');
+pub const V4L2_PIX_FMT_CIT_YYVYUY: crate::__u32 = v4l2_fourcc(b'C', b'I', b'T', b'V');
+pub const V4L2_PIX_FMT_KONICA420: crate::__u32 = v4l2_fourcc(b'K', b'O', b'N', b'I');
+pub const V4L2_PIX_FMT_JPGL: crate::__u32 = v4l2_fourcc(b'J', b'P', b'G', b'L');
+pub const V4L2_PIX_FMT_SE401: crate::__u32 = v4l2_fourcc(b'S', b'4', b'0', b'1');
+pub const V4L2_PIX_FMT_S5C_UYVY_JPG: crate::__u32 = v4l2_fourcc(b'S', b'5', b'C', b'I');
+pub const V4L2_PIX_FMT_Y8I: crate::__u32 = v4l2_fourcc(b'Y', b'8', b'I', b' ');
+pub const V4L2_PIX_FMT_Y12I: crate::__u32 = v4l2_fourcc(b'Y', b'1', b'2', b'I');
+pub const V4L2_PIX_FMT_Z16: crate::__u32 = v4l2_fourcc(b'Z', b'1', b'6', b' ');
+pub const V4L2_PIX_FMT_MT21C: crate::__u32 = v4l2_fourcc(b'M', b'T', b'2', b'1');
+pub const V4L2_PIX_FMT_MM21: crate::__u32 = v4l2_fourcc(b'M', b'M', b'2', b'1');
+pub const V4L2_PIX_FMT_INZI: crate::__u32 = v4l2_fourcc(b'I', b'N', b'Z', b'I');
+pub const V4L2_PIX_FMT_CNF4: crate::__u32 = v4l2_fourcc(b'C', b'N', b'F', b'4');
+pub const V4L2_PIX_FMT_HI240: crate::__u32 = v4l2_fourcc(b'H', b'I', b'2', b'4');
+pub const V4L2_PIX_FMT_QC08C: crate::__u32 = v4l2_fourcc(b'Q', b'0', b'8', b'C');
+pub const V4L2_PIX_FMT_QC10C: crate::__u32 = v4l2_fourcc(b'Q', b'1', b'0', b'C');
+
+/* 10bit raw packed, 32 bytes for every 25 pixels, last LSB 6 bits unused */
+pub const V4L2_PIX_FMT_IPU3_SBGGR10: crate::__u32 = v4l2_fourcc(b'i', b'p', b'3', b'b');
+pub const V4L2_PIX_FMT_IPU3_SGBRG10: crate::__u32 = v4l2_fourcc(b'i', b'p', b'3', b'g');
+pub const V4L2_PIX_FMT_IPU3_SGRBG10: crate::__u32 = v4l2_fourcc(b'i', b'p', b'3', b'G');
+pub const V4L2_PIX_FMT_IPU3_SRGGB10: crate::__u32 = v4l2_fourcc(b'i', b'p', b'3', b'r');
+
+/* SDR formats - used only for Software Defined Radio devices */
+pub const V4L2_SDR_FMT_CU8: crate::__u32 = v4l2_fourcc(b'C', b'U', b'0', b'8');
+pub const V4L2_SDR_FMT_CU16LE: crate::__u32 = v4l2_fourcc(b'C', b'U', b'1', b'6');
+pub const V4L2_SDR_FMT_CS8: crate::__u32 = v4l2_fourcc(b'C', b'S', b'0', b'8');
+pub const V4L2_SDR_FMT_CS14LE: crate::__u32 = v4l2_fourcc(b'C', b'S', b'1', b'4');
+pub const V4L2_SDR_FMT_RU12LE: crate::__u32 = v4l2_fourcc(b'R', b'U', b'1', b'2');
+pub const V4L2_SDR_FMT_PCU16BE: crate::__u32 = v4l2_fourcc(b'P', b'C', b'1', b'6');
+pub const V4L2_SDR_FMT_PCU18BE: crate::__u32 = v4l2_fourcc(b'P', b'C', b'1', b'8');
+pub const V4L2_SDR_FMT_PCU20BE: crate::__u32 = v4l2_fourcc(b'P', b'C', b'2', b'0');
+
+/* Touch formats - used for Touch devices */
+pub const V4L2_TCH_FMT_DELTA_TD16: crate::__u32 = v4l2_fourcc(b'T', b'D', b'1', b'6');
+pub const V4L2_TCH_FMT_DELTA_TD08: crate::__u32 = v4l2_fourcc(b'T', b'D', b'0', b'8');
+pub const V4L2_TCH_FMT_TU16: crate::__u32 = v4l2_fourcc(b'T', b'U', b'1', b'6');
+pub const V4L2_TCH_FMT_TU08: crate::__u32 = v4l2_fourcc(b'T', b'U', b'0', b'8');
+
+/* Meta-data formats */
+pub const V4L2_META_FMT_VSP1_HGO: crate::__u32 = v4l2_fourcc(b'V', b'S', b'P', b'H');
+pub const V4L2_META_FMT_VSP1_HGT: crate::__u32 = v4l2_fourcc(b'V', b'S', b'P', b'T');
+pub const V4L2_META_FMT_UVC: crate::__u32 = v4l2_fourcc(b'U', b'V', b'C', b'H');
+pub const V4L2_META_FMT_D4XX: crate::__u32 = v4l2_fourcc(b'D', b'4', b'X', b'X');
+pub const V4L2_META_FMT_VIVID: crate::__u32 = v4l2_fourcc(b'V', b'I', b'V', b'D');
+
+/* Vendor specific - used for RK_ISP1 camera sub-system */
+pub const V4L2_META_FMT_RK_ISP1_PARAMS: crate::__u32 = v4l2_fourcc(b'R', b'K', b'1', b'P');
+pub const V4L2_META_FMT_RK_ISP1_STAT_3A: crate::__u32 = v4l2_fourcc(b'R', b'K', b'1', b'S');
+
+/* priv field value to indicates that subsequent fields are valid. */
+pub const V4L2_PIX_FMT_PRIV_MAGIC: c_uint = 0xfeedcafe;
+
+/* Flags */
+pub const V4L2_PIX_FMT_FLAG_PREMUL_ALPHA: c_uint = 0x00000001;
+pub const V4L2_PIX_FMT_FLAG_SET_CSC: c_uint = 0x00000002;
+
+/*
+ *      F O R M A T   E N U M E R A T I O N
+ */
+s! {
+    pub struct v4l2_fmtdesc {
+        /* Format number */
+        pub index: crate::__u32,
+        /* enum v4l2_buf_type */
+        pub type_: crate::__u32,
+        pub flags: crate::__u32,
+        /* Description string */
+        pub description: [crate::__u8; 32],
+        /* Format fourcc */
+        pub pixelformat: crate::__u32,
+        /* Media bus code */
+        pub mbus_code: crate::__u32,
+        reserved: Padding<[crate::__u32; 3]>,
+    }
+}
+
+pub const V4L2_FMT_FLAG_COMPRESSED: c_uint = 0x0001;
+pub const V4L2_FMT_FLAG_EMULATED: c_uint = 0x0002;
+pub const V4L2_FMT_FLAG_CONTINUOUS_BYTESTREAM: c_uint = 0x0004;
+pub const V4L2_FMT_FLAG_DYN_RESOLUTION: c_uint = 0x0008;
+pub const V4L2_FMT_FLAG_ENC_CAP_FRAME_INTERVAL: c_uint = 0x0010;
+pub const V4L2_FMT_FLAG_CSC_COLORSPACE: c_uint = 0x0020;
+pub const V4L2_FMT_FLAG_CSC_XFER_FUNC: c_uint = 0x0040;
+pub const V4L2_FMT_FLAG_CSC_YCBCR_ENC: c_uint = 0x0080;
+pub const V4L2_FMT_FLAG_CSC_HSV_ENC: c_uint = V4L2_FMT_FLAG_CSC_YCBCR_ENC;
+pub const V4L2_FMT_FLAG_CSC_QUANTIZATION: c_uint = 0x0100;
+
+/*
+ *      F R A M E   S I Z E   E N U M E R A T I O N
+ */
+c_enum! {
+    pub enum v4l2_frmsizetypes {
+        pub V4L2_FRMSIZE_TYPE_DISCRETE = 1,
+        pub V4L2_FRMSIZE_TYPE_CONTINUOUS = 2,
+        pub V4L2_FRMSIZE_TYPE_STEPWISE = 3,
+    }
+}
+
+s! {
+    pub struct v4l2_frmsize_discrete {
+        /* Frame width [pixel] */
+        pub width: crate::__u32,
+        /* Frame height [pixel] */
+        pub height: crate::__u32,
+    }
+
+    pub struct v4l2_frmsize_stepwise {
+        /* Minimum frame width [pixel] */
+        pub min_width: crate::__u32,
+        /* Maximum frame width [pixel] */
+        pub max_width: crate::__u32,
+        /* Frame width step size [pixel] */
+        pub step_width: crate::__u32,
+        /* Minimum frame height [pixel] */
+        pub min_height: crate::__u32,
+        /* Maximum frame height [pixel] */
+        pub max_height: crate::__u32,
+        /* Frame height step size [pixel] */
+        pub step_height: crate::__u32,
+    }
+}
+
+s_no_extra_traits! {
+    pub struct v4l2_frmsizeenum {
+        /* Frame size number */
+        pub index: crate::__u32,
+        /* Pixel format */
+        pub pixel_format: crate::__u32,
+        /* Frame size type the device supports. */
+        pub type_: crate::__u32,
+
+        /* Frame size */
+        pub anonymous_1: __c_anonymous_v4l2_frmsizeenum_1,
+
+        /* Reserved space for future use */
+        reserved: Padding<[crate::__u32; 2]>,
+    }
+
+    pub union __c_anonymous_v4l2_frmsizeenum_1 {
+        pub discrete: v4l2_frmsize_discrete,
+        pub stepwise: v4l2_frmsize_stepwise,
+    }
+}
+
+/*
+ *      F R A M E   R A T E   E N U M E R A T I O N
+ */
+c_enum! {
+    pub enum v4l2_frmivaltypes {
+        pub V4L2_FRMIVAL_TYPE_DISCRETE = 1,
+        pub V4L2_FRMIVAL_TYPE_CONTINUOUS = 2,
+        pub V4L2_FRMIVAL_TYPE_STEPWISE = 3,
+    }
+}
+
+s! {
+    pub struct v4l2_frmival_stepwise {
+        /* Minimum frame interval [s] */
+        pub min: v4l2_fract,
+        /* Maximum frame interval [s] */
+        pub max: v4l2_fract,
+        /* Frame interval step size [s] */
+        pub step: v4l2_fract,
+    }
+}
+
+s_no_extra_traits! {
+    pub struct v4l2_frmivalenum {
+        /* Frame format index */
+        pub index: crate::__u32,
+        /* Pixel format */
+        pub pixel_format: crate::__u32,
+        /* Frame width */
+        pub width: crate::__u32,
+        /* Frame height */
+        pub height: crate::__u32,
+        /* Frame interval type the device supports. */
+        pub type_: crate::__u32,
+
+        /* Frame interval */
+        pub anonymous_1: __c_anonymous_v4l2_frmivalenum_1,
+
+        /* Reserved space for future use */
+        reserved: Padding<[crate::__u32; 2]>,
+    }
+
+    pub union __c_anonymous_v4l2_frmivalenum_1 {
+        pub discrete: v4l2_fract,
+        pub stepwise: v4l2_frmival_stepwise,
+    }
+}
+
+/*
+ *      T I M E C O D E
+ */
+s! {
+    pub struct v4l2_timecode {
+        pub type_: crate::__u32,
+        pub flags: crate::__u32,
+        pub frames: crate::__u8,
+        pub seconds: crate::__u8,
+        pub minutes: crate::__u8,
+        pub hours: crate::__u8,
+        pub userbits: [crate::__u8; 4],
+    }
+}
+
+/*  Type  */
+pub const V4L2_TC_TYPE_24FPS: c_uint = 1;
+pub const V4L2_TC_TYPE_25FPS: c_uint = 2;
+pub const V4L2_TC_TYPE_30FPS: c_uint = 3;
+pub const V4L2_TC_TYPE_50FPS: c_uint = 4;
+pub const V4L2_TC_TYPE_60FPS: c_uint = 5;
+
+/*  Flags  */
+/* "drop-frame" mode */
+pub const V4L2_TC_FLAG_DROPFRAME: c_uint = 0x0001;
+pub const V4L2_TC_FLAG_COLORFRAME: c_uint = 0x0002;
+pub const V4L2_TC_USERBITS_field: c_uint = 0x000C;
+pub const V4L2_TC_USERBITS_USERDEFINED: c_uint = 0x0000;
+pub const V4L2_TC_USERBITS_8BITCHARS: c_uint = 0x0008;
+/* The above is based on SMPTE timecodes */
+
+s! {
+    pub struct v4l2_jpegcompression {
+        pub quality: c_int,
+
+        /* Number of APP segment to be written, must be 0..15 */
+        pub APPn: c_int,
+        /* Length of data in JPEG APPn segment */
+        pub APP_len: c_int,
+        /* Data in the JPEG APPn segment. */
+        pub APP_data: [c_char; 60],
+
+        /* Length of data in JPEG COM segment */
+        pub COM_len: c_int,
+        /* Data in JPEG COM segment */
+        pub COM_data: [c_char; 60],
+
+        /*
+         * Which markers should go into the JPEG output. Unless you exactly know
+         * what you do, leave them untouched. Including less markers will make
+         * the resulting code smaller, but there will be fewer applications
+         * which can read it. The presence of the APP and COM marker is
+         * influenced by APP_len and COM_len ONLY, not by this property!
+         */
+        pub jpeg_markers: crate::__u32,
+    }
+}
+
+/* Define Huffman Tables */
+pub const V4L2_JPEG_MARKER_DHT: c_uint = 1 << 3;
+/* Define Quantization Tables */
+pub const V4L2_JPEG_MARKER_DQT: c_uint = 1 << 4;
+/* Define Restart Interval */
+pub const V4L2_JPEG_MARKER_DRI: c_uint = 1 << 5;
+/* Comment segment */
+pub const V4L2_JPEG_MARKER_COM: c_uint = 1 << 6;
+/* App segment, driver will * always use APP0 */
+pub const V4L2_JPEG_MARKER_APP: c_uint = 1 << 7;
+
+/*
+ *      M E M O R Y - M A P P I N G   B U F F E R S
+ */
+s! {
+    pub struct v4l2_requestbuffers {
+        pub count: crate::__u32,
+        /* enum v4l2_buf_type */
+        pub type_: crate::__u32,
+        /* enum v4l2_memory */
+        pub memory: crate::__u32,
+        pub capabilities: crate::__u32,
+        pub flags: crate::__u8,
+        reserved: Padding<[crate::__u8; 3]>,
+    }
+}
+
+pub const V4L2_MEMORY_FLAG_NON_COHERENT: c_uint = 1 << 0;
+
+/* capabilities for struct v4l2_requestbuffers and v4l2_create_buffers */
+pub const V4L2_BUF_CAP_SUPPORTS_MMAP: c_uint = 1 << 0;
+pub const V4L2_BUF_CAP_SUPPORTS_USERPTR: c_uint = 1 << 1;
+pub const V4L2_BUF_CAP_SUPPORTS_DMABUF: c_uint = 1 << 2;
+pub const V4L2_BUF_CAP_SUPPORTS_REQUESTS: c_uint = 1 << 3;
+pub const V4L2_BUF_CAP_SUPPORTS_ORPHANED_BUFS: c_uint = 1 << 4;
+pub const V4L2_BUF_CAP_SUPPORTS_M2M_HOLD_CAPTURE_BUF: c_uint = 1 << 5;
+pub const V4L2_BUF_CAP_SUPPORTS_MMAP_CACHE_HINTS: c_uint = 1 << 6;
+pub const V4L2_BUF_CAP_SUPPORTS_MAX_NUM_BUFFERS: c_uint = 1 << 7;
+pub const V4L2_BUF_CAP_SUPPORTS_REMOVE_BUFS: c_uint = 1 << 8;
+
+s_no_extra_traits! {
+    /*
+     * struct v4l2_plane - plane info for multi-planar buffers
+     * @bytesused:              number of bytes occupied by data in the plane (payload)
+     * @length:         size of this plane (NOT the payload) in bytes
+     * @mem_offset:             when memory in the associated struct v4l2_buffer is
+     *                  V4L2_MEMORY_MMAP, equals the offset from the start of
+     *                  the device memory for this plane (or is a "cookie" that
+     *                  should be passed to mmap() called on the video node)
+     * @userptr:                when memory is V4L2_MEMORY_USERPTR, a userspace pointer
+     *                  pointing to this plane
+     * @fd:                     when memory is V4L2_MEMORY_DMABUF, a userspace file
+     *                  descriptor associated with this plane
+     * @m:                      union of @mem_offset, @userptr and @fd
+     * @data_offset:    offset in the plane to the start of data; usually 0,
+     *                  unless there is a header in front of the data
+     * @reserved:               drivers and applications must zero this array
+     *
+     * Multi-planar buffers consist of one or more planes, e.g. an YCbCr buffer
+     * with two planes can have one plane for Y, and another for interleaved CbCr
+     * components. Each plane can reside in a separate memory buffer, or even in
+     * a completely separate memory node (e.g. in embedded devices).
+     */
+    pub struct v4l2_plane {
+        pub bytesused: crate::__u32,
+        pub length: crate::__u32,
+        pub m: __c_anonymous_v4l2_plane_m,
+        pub data_offset: crate::__u32,
+        reserved: Padding<[crate::__u32; 11]>,
+    }
+
+    pub union __c_anonymous_v4l2_plane_m {
+        pub mem_offset: crate::__u32,
+        pub userptr: c_ulong,
+        pub fd: crate::__s32,
+    }
+
+    /*
+     * struct v4l2_buffer - video buffer info
+     * @index:  id number of the buffer
+     * @type:   enum v4l2_buf_type; buffer type (type == *_MPLANE for
+     *          multiplanar buffers);
+     * @bytesused:      number of bytes occupied by data in the buffer (payload);
+     *          unused (set to 0) for multiplanar buffers
+     * @flags:  buffer informational flags
+     * @field:  enum v4l2_field; field order of the image in the buffer
+     * @timestamp:      frame timestamp
+     * @timecode:       frame timecode
+     * @sequence:       sequence count of this frame
+     * @memory: enum v4l2_memory; the method, in which the actual video data is
+     *          passed
+     * @offset: for non-multiplanar buffers with memory == V4L2_MEMORY_MMAP;
+     *          offset from the start of the device memory for this plane,
+     *          (or a "cookie" that should be passed to mmap() as offset)
+     * @userptr:        for non-multiplanar buffers with memory == V4L2_MEMORY_USERPTR;
+     *          a userspace pointer pointing to this buffer
+     * @fd:             for non-multiplanar buffers with memory == V4L2_MEMORY_DMABUF;
+     *          a userspace file descriptor associated with this buffer
+     * @planes: for multiplanar buffers; userspace pointer to the array of plane
+     *          info structs for this buffer
+     * @m:              union of @offset, @userptr, @planes and @fd
+     * @length: size in bytes of the buffer (NOT its payload) for single-plane
+     *          buffers (when type != *_MPLANE); number of elements in the
+     *          planes array for multi-plane buffers
+     * @reserved2:      drivers and applications must zero this field
+     * @request_fd: fd of the request that this buffer should use
+     * @reserved:       for backwards compatibility with applications that do not know
+     *          about @request_fd
+     *
+     * Contains data exchanged by application and driver using one of the Streaming
+     * I/O methods.
+     */
+    pub struct v4l2_buffer {
+        pub index: crate::__u32,
+        pub type_: crate::__u32,
+        pub bytesused: crate::__u32,
+        pub flags: crate::__u32,
+        pub field: crate::__u32,
+        pub timestamp: crate::timeval,
+        pub timecode: v4l2_timecode,
+        pub sequence: crate::__u32,
+
+        /* memory location */
+        pub memory: crate::__u32,
+        pub m: __c_anonymous_v4l2_buffer_m,
+        pub length: crate::__u32,
+        reserved2: Padding<crate::__u32>,
+        pub anonymous_1: __c_anonymous_v4l2_buffer_1,
+    }
+
+    pub union __c_anonymous_v4l2_buffer_m {
+        pub offset: crate::__u32,
+        pub userptr: c_ulong,
+        pub planes: *mut v4l2_plane,
+        pub fd: crate::__s32,
+    }
+
+    pub union __c_anonymous_v4l2_buffer_1 {
+        pub request_fd: crate::__s32,
+        pub reserved: crate::__u32,
+    }
+}
+
+/*  Flags for 'flags' field */
+/* Buffer is mapped (flag) */
+pub const V4L2_BUF_FLAG_MAPPED: c_uint = 0x00000001;
+/* Buffer is queued for processing */
+pub const V4L2_BUF_FLAG_QUEUED: c_uint = 0x00000002;
+/* Buffer is ready */
+pub const V4L2_BUF_FLAG_DONE: c_uint = 0x00000004;
+/* Image is a keyframe (I-frame) */
+pub const V4L2_BUF_FLAG_KEYFRAME: c_uint = 0x00000008;
+/* Image is a P-frame */
+pub const V4L2_BUF_FLAG_PFRAME: c_uint = 0x00000010;
+/* Image is a B-frame */
+pub const V4L2_BUF_FLAG_BFRAME: c_uint = 0x00000020;
+/* Buffer is ready, but the data contained within is corrupted. */
+pub const V4L2_BUF_FLAG_ERROR: c_uint = 0x00000040;
+/* Buffer is added to an unqueued request */
+pub const V4L2_BUF_FLAG_IN_REQUEST: c_uint = 0x00000080;
+/* timecode field is valid */
+pub const V4L2_BUF_FLAG_TIMECODE: c_uint = 0x00000100;
+/* Don't return the capture buffer until OUTPUT timestamp changes */
+pub const V4L2_BUF_FLAG_M2M_HOLD_CAPTURE_BUF: c_uint = 0x00000200;
+/* Buffer is prepared for queuing */
+pub const V4L2_BUF_FLAG_PREPARED: c_uint = 0x00000400;
+/* Cache handling flags */
+pub const V4L2_BUF_FLAG_NO_CACHE_INVALIDATE: c_uint = 0x00000800;
+pub const V4L2_BUF_FLAG_NO_CACHE_CLEAN: c_uint = 0x00001000;
+/* Timestamp type */
+pub const V4L2_BUF_FLAG_TIMESTAMP_MASK: c_uint = 0x0000e000;
+pub const V4L2_BUF_FLAG_TIMESTAMP_UNKNOWN: c_uint = 0x00000000;
+pub const V4L2_BUF_FLAG_TIMESTAMP_MONOTONIC: c_uint = 0x00002000;
+pub const V4L2_BUF_FLAG_TIMESTAMP_COPY: c_uint = 0x00004000;
+/* Timestamp sources. */
+pub const V4L2_BUF_FLAG_TSTAMP_SRC_MASK: c_uint = 0x00070000;
+pub const V4L2_BUF_FLAG_TSTAMP_SRC_EOF: c_uint = 0x00000000;
+pub const V4L2_BUF_FLAG_TSTAMP_SRC_SOE: c_uint = 0x00010000;
+/* mem2mem encoder/decoder */
+pub const V4L2_BUF_FLAG_LAST: c_uint = 0x00100000;
+/* request_fd is valid */
+pub const V4L2_BUF_FLAG_REQUEST_FD: c_uint = 0x00800000;
+
+s! {
+    /*
+     * struct v4l2_exportbuffer - export of video buffer as DMABUF file descriptor
+     *
+     * @index:  id number of the buffer
+     * @type:   enum v4l2_buf_type; buffer type (type == *_MPLANE for
+     *          multiplanar buffers);
+     * @plane:  index of the plane to be exported, 0 for single plane queues
+     * @flags:  flags for newly created file, currently only O_CLOEXEC is
+     *          supported, refer to manual of open syscall for more details
+     * @fd:             file descriptor associated with DMABUF (set by driver)
+     * @reserved:       drivers and applications must zero this array
+     *
+     * Contains data used for exporting a video buffer as DMABUF file descriptor.
+     * The buffer is identified by a 'cookie' returned by VIDIOC_QUERYBUF
+     * (identical to the cookie used to mmap() the buffer to userspace). All
+     * reserved fields must be set to zero. The field reserved0 is expected to
+     * become a structure 'type' allowing an alternative layout of the structure
+     * content. Therefore this field should not be used for any other extensions.
+     */
+    pub struct v4l2_exportbuffer {
+        /* enum v4l2_buf_type */
+        pub type_: crate::__u32,
+        pub index: crate::__u32,
+        pub plane: crate::__u32,
+        pub flags: crate::__u32,
+        pub fd: crate::__s32,
+        reserved: Padding<[crate::__u32; 11]>,
+    }
+
+    /*
+     *  O V E R L A Y   P R E V I E W
+     */
+    pub struct v4l2_framebuffer {
+        pub capability: crate::__u32,
+        pub flags: crate::__u32,
+        /* FIXME: in theory we should pass something like PCI device + memory
+         * region + offset instead of some physical address */
+        pub base: *mut c_void,
+        pub fmt: __c_anonymous_v4l2_framebuffer_fmt,
+    }
+
+    pub struct __c_anonymous_v4l2_framebuffer_fmt {
+        pub width: crate::__u32,
+        pub height: crate::__u32,
+        pub pixelformat: crate::__u32,
+        /* enum v4l2_field */
+        pub field: crate::__u32,
+        /* for padding, zero if unused */
+        pub bytesperline: crate::__u32,
+        pub sizeimage: crate::__u32,
+        /* enum v4l2_colorspace */
+        pub colorspace: crate::__u32,
+        /* reserved field, set to 0 */
+        pub priv_: crate::__u32,
+    }
+}
+
+/*  Flags for the 'capability' field. Read only */
+pub const V4L2_FBUF_CAP_EXTERNOVERLAY: c_uint = 0x0001;
+pub const V4L2_FBUF_CAP_CHROMAKEY: c_uint = 0x0002;
+pub const V4L2_FBUF_CAP_LIST_CLIPPING: c_uint = 0x0004;
+pub const V4L2_FBUF_CAP_BITMAP_CLIPPING: c_uint = 0x0008;
+pub const V4L2_FBUF_CAP_LOCAL_ALPHA: c_uint = 0x0010;
+pub const V4L2_FBUF_CAP_GLOBAL_ALPHA: c_uint = 0x0020;
+pub const V4L2_FBUF_CAP_LOCAL_INV_ALPHA: c_uint = 0x0040;
+pub const V4L2_FBUF_CAP_SRC_CHROMAKEY: c_uint = 0x0080;
+/*  Flags for the 'flags' field. */
+pub const V4L2_FBUF_FLAG_PRIMARY: c_uint = 0x0001;
+pub const V4L2_FBUF_FLAG_OVERLAY: c_uint = 0x0002;
+pub const V4L2_FBUF_FLAG_CHROMAKEY: c_uint = 0x0004;
+pub const V4L2_FBUF_FLAG_LOCAL_ALPHA: c_uint = 0x0008;
+pub const V4L2_FBUF_FLAG_GLOBAL_ALPHA: c_uint = 0x0010;
+pub const V4L2_FBUF_FLAG_LOCAL_INV_ALPHA: c_uint = 0x0020;
+pub const V4L2_FBUF_FLAG_SRC_CHROMAKEY: c_uint = 0x0040;
+
+s! {
+    pub struct v4l2_clip {
+        pub c: v4l2_rect,
+        pub next: *mut v4l2_clip,
+    }
+
+    pub struct v4l2_window {
+        pub w: v4l2_rect,
+        /* enum v4l2_field */
+        pub field: crate::__u32,
+        pub chromakey: crate::__u32,
+        pub clips: *mut v4l2_clip,
+        pub clipcount: crate::__u32,
+        pub bitmap: *mut c_void,
+        pub global_alpha: crate::__u8,
+    }
+
+    /*
+     *  C A P T U R E   P A R A M E T E R S
+     */
+    pub struct v4l2_captureparm {
+        /*  Supported modes */
+        pub capability: crate::__u32,
+        /*  Current mode */
+        pub capturemode: crate::__u32,
+        /*  Time per frame in seconds */
+        pub timeperframe: v4l2_fract,
+        /*  Driver-specific extensions */
+        pub extendedmode: crate::__u32,
+        /*  # of buffers for read */
+        pub readbuffers: crate::__u32,
+        reserved: Padding<[crate::__u32; 4]>,
+    }
+}
+
+/*  Flags for 'capability' and 'capturemode' fields */
+/* High quality imaging mode */
+pub const V4L2_MODE_HIGHQUALITY: c_uint = 0x0001;
+/* timeperframe field is supported */
+pub const V4L2_CAP_TIMEPERFRAME: c_uint = 0x1000;
+
+s! {
+    pub struct v4l2_outputparm {
+        /*  Supported modes */
+        pub capability: crate::__u32,
+        /*  Current mode */
+        pub outputmode: crate::__u32,
+        /*  Time per frame in seconds */
+        pub timeperframe: v4l2_fract,
+        /*  Driver-specific extensions */
+        pub extendedmode: crate::__u32,
+        /*  # of buffers for write */
+        pub writebuffers: crate::__u32,
+        reserved: Padding<[crate::__u32; 4]>,
+    }
+
+    /*
+     *  I N P U T   I M A G E   C R O P P I N G
+     */
+    pub struct v4l2_cropcap {
+        /* enum v4l2_buf_type */
+        pub type_: crate::__u32,
+        pub bounds: v4l2_rect,
+        pub defrect: v4l2_rect,
+        pub pixelaspect: v4l2_fract,
+    }
+
+    pub struct v4l2_crop {
+        /* enum v4l2_buf_type */
+        pub type_: crate::__u32,
+        pub c: v4l2_rect,
+    }
+
+    /*
+     * struct v4l2_selection - selection info
+     * @type:   buffer type (do not use *_MPLANE types)
+     * @target: Selection target, used to choose one of possible rectangles;
+     *          defined in v4l2-common.h; V4L2_SEL_TGT_* .
+     * @flags:  constraints flags, defined in v4l2-common.h; V4L2_SEL_FLAG_*.
+     * @r:              coordinates of selection window
+     * @reserved:       for future use, rounds structure size to 64 bytes, set to zero
+     *
+     * Hardware may use multiple helper windows to process a video stream.
+     * The structure is used to exchange this selection areas between
+     * an application and a driver.
+     */
+    pub struct v4l2_selection {
+        pub type_: crate::__u32,
+        pub target: crate::__u32,
+        pub flags: crate::__u32,
+        pub r: v4l2_rect,
+        reserved: Padding<[crate::__u32; 9]>,
+    }
+}
+
+/*
+ *      A N A L O G   V I D E O   S T A N D A R D
+ */
+
+pub type v4l2_std_id = crate::__u64;
+
+/*
+ * Attention: Keep the V4L2_STD_* bit definitions in sync with
+ * include/dt-bindings/display/sdtv-standards.h SDTV_STD_* bit definitions.
+ */
+/* one bit for each */
+pub const V4L2_STD_PAL_B: v4l2_std_id = 0x00000001;
+pub const V4L2_STD_PAL_B1: v4l2_std_id = 0x00000002;
+pub const V4L2_STD_PAL_G: v4l2_std_id = 0x00000004;
+pub const V4L2_STD_PAL_H: v4l2_std_id = 0x00000008;
+pub const V4L2_STD_PAL_I: v4l2_std_id = 0x00000010;
+pub const V4L2_STD_PAL_D: v4l2_std_id = 0x00000020;
+pub const V4L2_STD_PAL_D1: v4l2_std_id = 0x00000040;
+pub const V4L2_STD_PAL_K: v4l2_std_id = 0x00000080;
+
+pub const V4L2_STD_PAL_M: v4l2_std_id = 0x00000100;
+pub const V4L2_STD_PAL_N: v4l2_std_id = 0x00000200;
+pub const V4L2_STD_PAL_Nc: v4l2_std_id = 0x00000400;
+pub const V4L2_STD_PAL_60: v4l2_std_id = 0x00000800;
+
+/* BTSC */
+pub const V4L2_STD_NTSC_M: v4l2_std_id = 0x00001000;
+/* EIA-J */
+pub const V4L2_STD_NTSC_M_JP: v4l2_std_id = 0x00002000;
+pub const V4L2_STD_NTSC_443: v4l2_std_id = 0x00004000;
+/* FM A2 */
+pub const V4L2_STD_NTSC_M_KR: v4l2_std_id = 0x00008000;
+
+pub const V4L2_STD_SECAM_B: v4l2_std_id = 0x00010000;
+pub const V4L2_STD_SECAM_D: v4l2_std_id = 0x00020000;
+pub const V4L2_STD_SECAM_G: v4l2_std_id = 0x00040000;
+pub const V4L2_STD_SECAM_H: v4l2_std_id = 0x00080000;
+pub const V4L2_STD_SECAM_K: v4l2_std_id = 0x00100000;
+pub const V4L2_STD_SECAM_K1: v4l2_std_id = 0x00200000;
+pub const V4L2_STD_SECAM_L: v4l2_std_id = 0x00400000;
+pub const V4L2_STD_SECAM_LC: v4l2_std_id = 0x00800000;
+
+/* ATSC/HDTV */
+pub const V4L2_STD_ATSC_8_VSB: v4l2_std_id = 0x01000000;
+pub const V4L2_STD_ATSC_16_VSB: v4l2_std_id = 0x02000000;
+
+/* FIXME:
+Although std_id is 64 bits, there is an issue on PPC32 architecture that
+makes switch(__u64) to break. So, there's a hack on v4l2-common.c rounding
+this value to 32 bits.
+As, currently, the max value is for V4L2_STD_ATSC_16_VSB (30 bits wide),
+it should work fine. However, if needed to add more than two standards,
+v4l2-common.c should be fixed.
+ */
+
+/*
+ * Some macros to merge video standards in order to make live easier for the
+ * drivers and V4L2 applications
+ */
+
+/*
+ * "Common" NTSC/M - It should be noticed that V4L2_STD_NTSC_443 is
+ * Missing here.
+ */
+pub const V4L2_STD_NTSC: v4l2_std_id = V4L2_STD_NTSC_M | V4L2_STD_NTSC_M_JP | V4L2_STD_NTSC_M_KR;
+/* Secam macros */
+pub const V4L2_STD_SECAM_DK: v4l2_std_id = V4L2_STD_SECAM_D | V4L2_STD_SECAM_K | V4L2_STD_SECAM_K1;
+/* All Secam Standards */
+pub const V4L2_STD_SECAM: v4l2_std_id = V4L2_STD_SECAM_B
+    | V4L2_STD_SECAM_G
+    | V4L2_STD_SECAM_H
+    | V4L2_STD_SECAM_DK
+    | V4L2_STD_SECAM_L
+    | V4L2_STD_SECAM_LC;
+/* PAL macros */
+pub const V4L2_STD_PAL_BG: v4l2_std_id = V4L2_STD_PAL_B | V4L2_STD_PAL_B1 | V4L2_STD_PAL_G;
+pub const V4L2_STD_PAL_DK: v4l2_std_id = V4L2_STD_PAL_D | V4L2_STD_PAL_D1 | V4L2_STD_PAL_K;
+/*
+ * "Common" PAL - This macro is there to be compatible with the old
+ * V4L1 concept of "PAL": /BGDKHI.
+ * Several PAL standards are missing here: /M, /N and /Nc
+ */
+pub const V4L2_STD_PAL: v4l2_std_id =
+    V4L2_STD_PAL_BG | V4L2_STD_PAL_DK | V4L2_STD_PAL_H | V4L2_STD_PAL_I;
+/* Chroma "agnostic" standards */
+pub const V4L2_STD_B: v4l2_std_id = V4L2_STD_PAL_B | V4L2_STD_PAL_B1 | V4L2_STD_SECAM_B;
+pub const V4L2_STD_G: v4l2_std_id = V4L2_STD_PAL_G | V4L2_STD_SECAM_G;
+pub const V4L2_STD_H: v4l2_std_id = V4L2_STD_PAL_H | V4L2_STD_SECAM_H;
+pub const V4L2_STD_L: v4l2_std_id = V4L2_STD_SECAM_L | V4L2_STD_SECAM_LC;
+pub const V4L2_STD_GH: v4l2_std_id = V4L2_STD_G | V4L2_STD_H;
+pub const V4L2_STD_DK: v4l2_std_id = V4L2_STD_PAL_DK | V4L2_STD_SECAM_DK;
+pub const V4L2_STD_BG: v4l2_std_id = V4L2_STD_B | V4L2_STD_G;
+pub const V4L2_STD_MN: v4l2_std_id =
+    V4L2_STD_PAL_M | V4L2_STD_PAL_N | V4L2_STD_PAL_Nc | V4L2_STD_NTSC;
+
+/* Standards where MTS/BTSC stereo could be found */
+pub const V4L2_STD_MTS: v4l2_std_id =
+    V4L2_STD_NTSC_M | V4L2_STD_PAL_M | V4L2_STD_PAL_N | V4L2_STD_PAL_Nc;
+
+/* Standards for Countries with 60Hz Line frequency */
+pub const V4L2_STD_525_60: v4l2_std_id =
+    V4L2_STD_PAL_M | V4L2_STD_PAL_60 | V4L2_STD_NTSC | V4L2_STD_NTSC_443;
+/* Standards for Countries with 50Hz Line frequency */
+pub const V4L2_STD_625_50: v4l2_std_id =
+    V4L2_STD_PAL | V4L2_STD_PAL_N | V4L2_STD_PAL_Nc | V4L2_STD_SECAM;
+
+pub const V4L2_STD_ATSC: v4l2_std_id = V4L2_STD_ATSC_8_VSB | V4L2_STD_ATSC_16_VSB;
+/* Macros with none and all analog standards */
+pub const V4L2_STD_UNKNOWN: v4l2_std_id = 0;
+pub const V4L2_STD_ALL: v4l2_std_id = V4L2_STD_525_60 | V4L2_STD_625_50;
+
+s! {
+    pub struct v4l2_standard {
+        pub index: crate::__u32,
+        pub id: v4l2_std_id,
+        pub name: [crate::__u8; 24],
+        /* Frames, not fields */
+        pub frameperiod: v4l2_fract,
+        pub framelines: crate::__u32,
+        reserved: Padding<[crate::__u32; 4]>,
+    }
+
+    /*
+     *  D V     B T     T I M I N G S
+     */
+
+    /* struct v4l2_bt_timings - BT.656/BT.1120 timing data
+     * @width:  total width of the active video in pixels
+     * @height: total height of the active video in lines
+     * @interlaced:     Interlaced or progressive
+     * @polarities:     Positive or negative polarities
+     * @pixelclock:     Pixel clock in HZ. Ex. 74.25MHz->74250000
+     * @hfrontporch:Horizontal front porch in pixels
+     * @hsync:  Horizontal Sync length in pixels
+     * @hbackporch:     Horizontal back porch in pixels
+     * @vfrontporch:Vertical front porch in lines
+     * @vsync:  Vertical Sync length in lines
+     * @vbackporch:     Vertical back porch in lines
+     * @il_vfrontporch:Vertical front porch for the even field
+     *          (aka field 2) of interlaced field formats
+     * @il_vsync:       Vertical Sync length for the even field
+     *          (aka field 2) of interlaced field formats
+     * @il_vbackporch:Vertical back porch for the even field
+     *          (aka field 2) of interlaced field formats
+     * @standards:      Standards the timing belongs to
+     * @flags:  Flags
+     * @picture_aspect: The picture aspect ratio (hor/vert).
+     * @cea861_vic:     VIC code as per the CEA-861 standard.
+     * @hdmi_vic:       VIC code as per the HDMI standard.
+     * @reserved:       Reserved fields, must be zeroed.
+     *
+     * A note regarding vertical interlaced timings: height refers to the total
+     * height of the active video frame (= two fields). The blanking timings refer
+     * to the blanking of each field. So the height of the total frame is
+     * calculated as follows:
+     *
+     * tot_height = height + vfrontporch + vsync + vbackporch +
+     *                       il_vfrontporch + il_vsync + il_vbackporch
+     *
+     * The active height of each field is height / 2.
+     */
+    #[repr(packed)]
+    pub struct v4l2_bt_timings {
+        pub width: crate::__u32,
+        pub height: crate::__u32,
+        pub interlaced: crate::__u32,
+        pub polarities: crate::__u32,
+        pub pixelclock: crate::__u64,
+        pub hfrontporch: crate::__u32,
+        pub hsync: crate::__u32,
+        pub hbackporch: crate::__u32,
+        pub vfrontporch: crate::__u32,
+        pub vsync: crate::__u32,
+        pub vbackporch: crate::__u32,
+        pub il_vfrontporch: crate::__u32,
+        pub il_vsync: crate::__u32,
+        pub il_vbackporch: crate::__u32,
+        pub standards: crate::__u32,
+        pub flags: crate::__u32,
+        pub picture_aspect: v4l2_fract,
+        pub cea861_vic: crate::__u8,
+        pub hdmi_vic: crate::__u8,
+        reserved: Padding<[crate::__u8; 46]>,
+    }
+}
+
+/* Interlaced or progressive format */
+pub const V4L2_DV_PROGRESSIVE: c_uint = 0;
+pub const V4L2_DV_INTERLACED: c_uint = 1;
+
+/* Polarities. If bit is not set, it is assumed to be negative polarity */
+pub const V4L2_DV_VSYNC_POS_POL: c_uint = 0x00000001;
+pub const V4L2_DV_HSYNC_POS_POL: c_uint = 0x00000002;
+
+/* Timings standards */
+/* CEA-861 Digital TV Profile */
+pub const V4L2_DV_BT_STD_CEA861: c_uint = 1 << 0;
+/* VESA Discrete Monitor Timings */
+pub const V4L2_DV_BT_STD_DMT: c_uint = 1 << 1;
+/* VESA Coordinated Video Timings */
+pub const V4L2_DV_BT_STD_CVT: c_uint = 1 << 2;
+/* VESA Generalized Timings Formula */
+pub const V4L2_DV_BT_STD_GTF: c_uint = 1 << 3;
+/* SDI Timings */
+pub const V4L2_DV_BT_STD_SDI: c_uint = 1 << 4;
+
+/* Flags */
+
+/*
+ * CVT/GTF specific: timing uses reduced blanking (CVT) or the 'Secondary
+ * GTF' curve (GTF). In both cases the horizontal and/or vertical blanking
+ * intervals are reduced, allowing a higher resolution over the same
+ * bandwidth. This is a read-only flag.
+ */
+pub const V4L2_DV_FL_REDUCED_BLANKING: c_uint = 1 << 0;
+/*
+ * CEA-861 specific: set for CEA-861 formats with a framerate of a multiple
+ * of six. These formats can be optionally played at 1 / 1.001 speed.
+ * This is a read-only flag.
+ */
+pub const V4L2_DV_FL_CAN_REDUCE_FPS: c_uint = 1 << 1;
+/*
+ * CEA-861 specific: only valid for video transmitters, the flag is cleared
+ * by receivers.
+ * If the framerate of the format is a multiple of six, then the pixelclock
+ * used to set up the transmitter is divided by 1.001 to make it compatible
+ * with 60 Hz based standards such as NTSC and PAL-M that use a framerate of
+ * 29.97 Hz. Otherwise this flag is cleared. If the transmitter can't generate
+ * such frequencies, then the flag will also be cleared.
+ */
+pub const V4L2_DV_FL_REDUCED_FPS: c_uint = 1 << 2;
+/*
+ * Specific to interlaced formats: if set, then field 1 is really one half-line
+ * longer and field 2 is really one half-line shorter, so each field has
+ * exactly the same number of half-lines. Whether half-lines can be detected
+ * or used depends on the hardware.
+ */
+pub const V4L2_DV_FL_HALF_LINE: c_uint = 1 << 3;
+/*
+ * If set, then this is a Consumer Electronics (CE) video format. Such formats
+ * differ from other formats (commonly called IT formats) in that if RGB
+ * encoding is used then by default the RGB values use limited range (i.e.
+ * use the range 16-235) as opposed to 0-255. All formats defined in CEA-861
+ * except for the 640x480 format are CE formats.
+ */
+pub const V4L2_DV_FL_IS_CE_VIDEO: c_uint = 1 << 4;
+/* Some formats like SMPTE-125M have an interlaced signal with a odd
+ * total height. For these formats, if this flag is set, the first
+ * field has the extra line. If not, it is the second field.
+ */
+pub const V4L2_DV_FL_FIRST_FIELD_EXTRA_LINE: c_uint = 1 << 5;
+/*
+ * If set, then the picture_aspect field is valid. Otherwise assume that the
+ * pixels are square, so the picture aspect ratio is the same as the width to
+ * height ratio.
+ */
+pub const V4L2_DV_FL_HAS_PICTURE_ASPECT: c_uint = 1 << 6;
+/*
+ * If set, then the cea861_vic field is valid and contains the Video
+ * Identification Code as per the CEA-861 standard.
+ */
+pub const V4L2_DV_FL_HAS_CEA861_VIC: c_uint = 1 << 7;
+/*
+ * If set, then the hdmi_vic field is valid and contains the Video
+ * Identification Code as per the HDMI standard (HDMI Vendor Specific
+ * InfoFrame).
+ */
+pub const V4L2_DV_FL_HAS_HDMI_VIC: c_uint = 1 << 8;
+/*
+ * CEA-861 specific: only valid for video receivers.
+ * If set, then HW can detect the difference between regular FPS and
+ * 1000/1001 FPS. Note: This flag is only valid for HDMI VIC codes with
+ * the V4L2_DV_FL_CAN_REDUCE_FPS flag set.
+ */
+pub const V4L2_DV_FL_CAN_DETECT_REDUCED_FPS: c_uint = 1 << 9;
+
+s_no_extra_traits! {
+    /* struct v4l2_dv_timings - DV timings
+     * @type:   the type of the timings
+     * @bt:     BT656/1120 timings
+     */
+    #[repr(packed)]
+    pub struct v4l2_dv_timings {
+        pub type_: crate::__u32,
+        pub anonymous_1: __c_anonymous_v4l2_dv_timings_1,
+    }
+
+    pub union __c_anonymous_v4l2_dv_timings_1 {
+        pub bt: v4l2_bt_timings,
+        pub reserved: [crate::__u32; 32],
+    }
+}
+
+/* Values for the type field */
+/* BT.656/1120 timing type */
+pub const V4L2_DV_BT_656_1120: c_uint = 0;
+
+s_no_extra_traits! {
+    /* struct v4l2_enum_dv_timings - DV timings enumeration
+     * @index:  enumeration index
+     * @pad:    the pad number for which to enumerate timings (used with
+     *          v4l-subdev nodes only)
+     * @reserved:       must be zeroed
+     * @timings:        the timings for the given index
+     */
+    pub struct v4l2_enum_dv_timings {
+        pub index: crate::__u32,
+        pub pad: crate::__u32,
+        reserved: Padding<[crate::__u32; 2]>,
+        pub timings: v4l2_dv_timings,
+    }
+}
+
+s! {
+    /* struct v4l2_bt_timings_cap - BT.656/BT.1120 timing capabilities
+     * @min_width:              width in pixels
+     * @max_width:              width in pixels
+     * @min_height:             height in lines
+     * @max_height:             height in lines
+     * @min_pixelclock: Pixel clock in HZ. Ex. 74.25MHz->74250000
+     * @max_pixelclock: Pixel clock in HZ. Ex. 74.25MHz->74250000
+     * @standards:              Supported standards
+     * @capabilities:   Supported capabilities
+     * @reserved:               Must be zeroed
+     */
+    #[repr(packed)]
+    pub struct v4l2_bt_timings_cap {
+        pub min_width: crate::__u32,
+        pub max_width: crate::__u32,
+        pub min_height: crate::__u32,
+        pub max_height: crate::__u32,
+        pub min_pixelclock: crate::__u64,
+        pub max_pixelclock: crate::__u64,
+        pub standards: crate::__u32,
+        pub capabilities: crate::__u32,
+        reserved: Padding<[crate::__u32; 16]>,
+    }
+}
+
+/* Supports interlaced formats */
+pub const V4L2_DV_BT_CAP_INTERLACED: c_uint = 1 << 0;
+/* Supports progressive formats */
+pub const V4L2_DV_BT_CAP_PROGRESSIVE: c_uint = 1 << 1;
+/* Supports CVT/GTF reduced blanking */
+pub const V4L2_DV_BT_CAP_REDUCED_BLANKING: c_uint = 1 << 2;
+/* Supports custom formats */
+pub const V4L2_DV_BT_CAP_CUSTOM: c_uint = 1 << 3;
+
+s_no_extra_traits! {
+    /* struct v4l2_dv_timings_cap - DV timings capabilities
+     * @type:   the type of the timings (same as in struct v4l2_dv_timings)
+     * @pad:    the pad number for which to query capabilities (used with
+     *          v4l-subdev nodes only)
+     * @bt:             the BT656/1120 timings capabilities
+     */
+    pub struct v4l2_dv_timings_cap {
+        pub type_: crate::__u32,
+        pub pad: crate::__u32,
+        reserved: Padding<[crate::__u32; 2]>,
+        pub anonymous_1: __c_anonymous_v4l2_dv_timings_cap_1,
+    }
+
+    pub union __c_anonymous_v4l2_dv_timings_cap_1 {
+        pub bt: v4l2_bt_timings_cap,
+        pub raw_data: [crate::__u32; 32],
+    }
+}
+
+s! {
+    /*
+     *  V I D E O   I N P U T S
+     */
+    pub struct v4l2_input {
+        /*  Which input */
+        pub index: crate::__u32,
+        /*  Label */
+        pub name: [crate::__u8; 32],
+        /*  Type of input */
+        pub type_: crate::__u32,
+        /*  Associated audios (bitfield) */
+        pub audioset: crate::__u32,
+        /*  Tuner index */
+        pub tuner: crate::__u32,
+        pub std: v4l2_std_id,
+        pub status: crate::__u32,
+        pub capabilities: crate::__u32,
+        reserved: Padding<[crate::__u32; 3]>,
+    }
+}
+
+/*  Values for the 'type' field */
+pub const V4L2_INPUT_TYPE_TUNER: c_uint = 1;
+pub const V4L2_INPUT_TYPE_CAMERA: c_uint = 2;
+pub const V4L2_INPUT_TYPE_TOUCH: c_uint = 3;
+
+/* field 'status' - general */
+/* Attached device is off */
+pub const V4L2_IN_ST_NO_POWER: c_uint = 0x00000001;
+pub const V4L2_IN_ST_NO_SIGNAL: c_uint = 0x00000002;
+pub const V4L2_IN_ST_NO_COLOR: c_uint = 0x00000004;
+
+/* field 'status' - sensor orientation */
+/* If sensor is mounted upside down set both bits */
+/* Frames are flipped horizontally */
+pub const V4L2_IN_ST_HFLIP: c_uint = 0x00000010;
+/* Frames are flipped vertically */
+pub const V4L2_IN_ST_VFLIP: c_uint = 0x00000020;
+
+/* field 'status' - analog */
+/* No horizontal sync lock */
+pub const V4L2_IN_ST_NO_H_LOCK: c_uint = 0x00000100;
+/* Color killer is active */
+pub const V4L2_IN_ST_COLOR_KILL: c_uint = 0x00000200;
+/* No vertical sync lock */
+pub const V4L2_IN_ST_NO_V_LOCK: c_uint = 0x00000400;
+/* No standard format lock */
+pub const V4L2_IN_ST_NO_STD_LOCK: c_uint = 0x00000800;
+
+/* field 'status' - digital */
+/* No synchronization lock */
+pub const V4L2_IN_ST_NO_SYNC: c_uint = 0x00010000;
+/* No equalizer lock */
+pub const V4L2_IN_ST_NO_EQU: c_uint = 0x00020000;
+/* Carrier recovery failed */
+pub const V4L2_IN_ST_NO_CARRIER: c_uint = 0x00040000;
+
+/* field 'status' - VCR and set-top box */
+/* Macrovision detected */
+pub const V4L2_IN_ST_MACROVISION: c_uint = 0x01000000;
+/* Conditional access denied */
+pub const V4L2_IN_ST_NO_ACCESS: c_uint = 0x02000000;
+/* VTR time constant */
+pub const V4L2_IN_ST_VTR: c_uint = 0x04000000;
+
+/* capabilities flags */
+/* Supports S_DV_TIMINGS */
+pub const V4L2_IN_CAP_DV_TIMINGS: c_uint = 0x00000002;
+/* For compatibility */
+pub const V4L2_IN_CAP_CUSTOM_TIMINGS: c_uint = V4L2_IN_CAP_DV_TIMINGS;
+
+s! {
+    /*
+     *  V I D E O   O U T P U T S
+     */
+    pub struct v4l2_output {
+        /*  Which output */
+        pub index: crate::__u32,
+        /*  Label */
+        pub name: [crate::__u8; 32],
+        /*  Type of output */
+        pub type_: crate::__u32,
+        /*  Associated audios (bitfield) */
+        pub audioset: crate::__u32,
+        /*  Associated modulator */
+        pub modulator: crate::__u32,
+        pub std: v4l2_std_id,
+        pub capabilities: crate::__u32,
+        reserved: Padding<[crate::__u32; 3]>,
+    }
+}
+
+/*  Values for the 'type' field */
+pub const V4L2_OUTPUT_TYPE_MODULATOR: c_uint = 1;
+pub const V4L2_OUTPUT_TYPE_ANALOG: c_uint = 2;
+pub const V4L2_OUTPUT_TYPE_ANALOGVGAOVERLAY: c_uint = 3;
+
+/* capabilities flags */
+/* Supports S_DV_TIMINGS */
+pub const V4L2_OUT_CAP_DV_TIMINGS: c_uint = 0x00000002;
+/* For compatibility */
+pub const V4L2_OUT_CAP_CUSTOM_TIMINGS: c_uint = V4L2_OUT_CAP_DV_TIMINGS;
+
+/*
+ *      C O N T R O L S
+ */
+s! {
+    pub struct v4l2_control {
+        pub id: crate::__u32,
+        pub value: crate::__s32,
+    }
+}
+
+s_no_extra_traits! {
+    #[repr(packed)]
+    pub struct v4l2_ext_control {
+        pub id: crate::__u32,
+        pub size: crate::__u32,
+        reserved2: Padding<[crate::__u32; 1]>,
+        pub anonymous_1: __c_anonymous_v4l2_ext_control_1,
+    }
+
+    /* The compound control payloads of linux/v4l2-controls.h are accessed through `ptr`. */
+    pub union __c_anonymous_v4l2_ext_control_1 {
+        pub value: crate::__s32,
+        pub value64: crate::__s64,
+        pub string: *mut c_char,
+        pub p_u8: *mut crate::__u8,
+        pub p_u16: *mut crate::__u16,
+        pub p_u32: *mut crate::__u32,
+        pub p_area: *mut v4l2_area,
+        pub ptr: *mut c_void,
+    }
+
+    pub struct v4l2_ext_controls {
+        pub anonymous_1: __c_anonymous_v4l2_ext_controls_1,
+        pub count: crate::__u32,
+        pub error_idx: crate::__u32,
+        pub request_fd: crate::__s32,
+        reserved: Padding<[crate::__u32; 1]>,
+        pub controls: *mut v4l2_ext_control,
+    }
+
+    pub union __c_anonymous_v4l2_ext_controls_1 {
+        pub ctrl_class: crate::__u32,
+        pub which: crate::__u32,
+    }
+}
+
+pub const V4L2_CTRL_ID_MASK: c_uint = 0x0fffffff;
+pub const V4L2_CTRL_MAX_DIMS: usize = 4;
+pub const V4L2_CTRL_WHICH_CUR_VAL: c_uint = 0;
+pub const V4L2_CTRL_WHICH_DEF_VAL: c_uint = 0x0f000000;
+pub const V4L2_CTRL_WHICH_REQUEST_VAL: c_uint = 0x0f010000;
+
+c_enum! {
+    pub enum v4l2_ctrl_type {
+        pub V4L2_CTRL_TYPE_INTEGER = 1,
+        pub V4L2_CTRL_TYPE_BOOLEAN = 2,
+        pub V4L2_CTRL_TYPE_MENU = 3,
+        pub V4L2_CTRL_TYPE_BUTTON = 4,
+        pub V4L2_CTRL_TYPE_INTEGER64 = 5,
+        pub V4L2_CTRL_TYPE_CTRL_CLASS = 6,
+        pub V4L2_CTRL_TYPE_STRING = 7,
+        pub V4L2_CTRL_TYPE_BITMASK = 8,
+        pub V4L2_CTRL_TYPE_INTEGER_MENU = 9,
+
+        /* Compound types are >= 0x0100 */
+        pub V4L2_CTRL_COMPOUND_TYPES = 0x0100,
+        pub V4L2_CTRL_TYPE_U8 = 0x0100,
+        pub V4L2_CTRL_TYPE_U16 = 0x0101,
+        pub V4L2_CTRL_TYPE_U32 = 0x0102,
+        pub V4L2_CTRL_TYPE_AREA = 0x0106,
+
+        pub V4L2_CTRL_TYPE_HDR10_CLL_INFO = 0x0110,
+        pub V4L2_CTRL_TYPE_HDR10_MASTERING_DISPLAY = 0x0111,
+
+        pub V4L2_CTRL_TYPE_H264_SPS = 0x0200,
+        pub V4L2_CTRL_TYPE_H264_PPS = 0x0201,
+        pub V4L2_CTRL_TYPE_H264_SCALING_MATRIX = 0x0202,
+        pub V4L2_CTRL_TYPE_H264_SLICE_PARAMS = 0x0203,
+        pub V4L2_CTRL_TYPE_H264_DECODE_PARAMS = 0x0204,
+        pub V4L2_CTRL_TYPE_H264_PRED_WEIGHTS = 0x0205,
+
+        pub V4L2_CTRL_TYPE_FWHT_PARAMS = 0x0220,
+
+        pub V4L2_CTRL_TYPE_VP8_FRAME = 0x0240,
+
+        pub V4L2_CTRL_TYPE_MPEG2_QUANTISATION = 0x0250,
+        pub V4L2_CTRL_TYPE_MPEG2_SEQUENCE = 0x0251,
+        pub V4L2_CTRL_TYPE_MPEG2_PICTURE = 0x0252,
+
+        pub V4L2_CTRL_TYPE_VP9_COMPRESSED_HDR = 0x0260,
+        pub V4L2_CTRL_TYPE_VP9_FRAME = 0x0261,
+
+        pub V4L2_CTRL_TYPE_HEVC_SPS = 0x0270,
+        pub V4L2_CTRL_TYPE_HEVC_PPS = 0x0271,
+        pub V4L2_CTRL_TYPE_HEVC_SLICE_PARAMS = 0x0272,
+        pub V4L2_CTRL_TYPE_HEVC_SCALING_MATRIX = 0x0273,
+        pub V4L2_CTRL_TYPE_HEVC_DECODE_PARAMS = 0x0274,
+    }
+}
+
+s! {
+    /*  Used in the VIDIOC_QUERYCTRL ioctl for querying controls */
+    pub struct v4l2_queryctrl {
+        pub id: crate::__u32,
+        /* enum v4l2_ctrl_type */
+        pub type_: crate::__u32,
+        /* Whatever */
+        pub name: [crate::__u8; 32],
+        /* Note signedness */
+        pub minimum: crate::__s32,
+        pub maximum: crate::__s32,
+        pub step: crate::__s32,
+        pub default_value: crate::__s32,
+        pub flags: crate::__u32,
+        reserved: Padding<[crate::__u32; 2]>,
+    }
+
+    /*  Used in the VIDIOC_QUERY_EXT_CTRL ioctl for querying extended controls */
+    pub struct v4l2_query_ext_ctrl {
+        pub id: crate::__u32,
+        pub type_: crate::__u32,
+        pub name: [c_char; 32],
+        pub minimum: crate::__s64,
+        pub maximum: crate::__s64,
+        pub step: crate::__u64,
+        pub default_value: crate::__s64,
+        pub flags: crate::__u32,
+        pub elem_size: crate::__u32,
+        pub elems: crate::__u32,
+        pub nr_of_dims: crate::__u32,
+        pub dims: [crate::__u32; V4L2_CTRL_MAX_DIMS],
+        reserved: Padding<[crate::__u32; 32]>,
+    }
+}
+
+s_no_extra_traits! {
+    /*  Used in the VIDIOC_QUERYMENU ioctl for querying menu items */
+    #[repr(packed)]
+    pub struct v4l2_querymenu {
+        pub id: crate::__u32,
+        pub index: crate::__u32,
+        pub anonymous_1: __c_anonymous_v4l2_querymenu_1,
+        reserved: Padding<crate::__u32>,
+    }
+
+    pub union __c_anonymous_v4l2_querymenu_1 {
+        /* Whatever */
+        pub name: [crate::__u8; 32],
+        pub value: crate::__s64,
+    }
+}
+
+/*  Control flags  */
+pub const V4L2_CTRL_FLAG_DISABLED: c_uint = 0x0001;
+pub const V4L2_CTRL_FLAG_GRABBED: c_uint = 0x0002;
+pub const V4L2_CTRL_FLAG_READ_ONLY: c_uint = 0x0004;
+pub const V4L2_CTRL_FLAG_UPDATE: c_uint = 0x0008;
+pub const V4L2_CTRL_FLAG_INACTIVE: c_uint = 0x0010;
+pub const V4L2_CTRL_FLAG_SLIDER: c_uint = 0x0020;
+pub const V4L2_CTRL_FLAG_WRITE_ONLY: c_uint = 0x0040;
+pub const V4L2_CTRL_FLAG_VOLATILE: c_uint = 0x0080;
+pub const V4L2_CTRL_FLAG_HAS_PAYLOAD: c_uint = 0x0100;
+pub const V4L2_CTRL_FLAG_EXECUTE_ON_WRITE: c_uint = 0x0200;
+pub const V4L2_CTRL_FLAG_MODIFY_LAYOUT: c_uint = 0x0400;
+pub const V4L2_CTRL_FLAG_DYNAMIC_ARRAY: c_uint = 0x0800;
+
+/*  Query flags, to be ORed with the control ID */
+pub const V4L2_CTRL_FLAG_NEXT_CTRL: c_uint = 0x80000000;
+pub const V4L2_CTRL_FLAG_NEXT_COMPOUND: c_uint = 0x40000000;
+
+/*  User-class control IDs defined by V4L2 */
+pub const V4L2_CID_MAX_CTRLS: c_uint = 1024;
+/*  IDs reserved for driver specific controls */
+pub const V4L2_CID_PRIVATE_BASE: c_uint = 0x08000000;
+
+/*
+ *      T U N I N G
+ */
+s! {
+    pub struct v4l2_tuner {
+        pub index: crate::__u32,
+        pub name: [crate::__u8; 32],
+        /* enum v4l2_tuner_type */
+        pub type_: crate::__u32,
+        pub capability: crate::__u32,
+        pub rangelow: crate::__u32,
+        pub rangehigh: crate::__u32,
+        pub rxsubchans: crate::__u32,
+        pub audmode: crate::__u32,
+        pub signal: crate::__s32,
+        pub afc: crate::__s32,
+        reserved: Padding<[crate::__u32; 4]>,
+    }
+
+    pub struct v4l2_modulator {
+        pub index: crate::__u32,
+        pub name: [crate::__u8; 32],
+        pub capability: crate::__u32,
+        pub rangelow: crate::__u32,
+        pub rangehigh: crate::__u32,
+        pub txsubchans: crate::__u32,
+        /* enum v4l2_tuner_type */
+        pub type_: crate::__u32,
+        reserved: Padding<[crate::__u32; 3]>,
+    }
+}
+
+/*  Flags for the 'capability' field */
+pub const V4L2_TUNER_CAP_LOW: c_uint = 0x0001;
+pub const V4L2_TUNER_CAP_NORM: c_uint = 0x0002;
+pub const V4L2_TUNER_CAP_HWSEEK_BOUNDED: c_uint = 0x0004;
+pub const V4L2_TUNER_CAP_HWSEEK_WRAP: c_uint = 0x0008;
+pub const V4L2_TUNER_CAP_STEREO: c_uint = 0x0010;
+pub const V4L2_TUNER_CAP_LANG2: c_uint = 0x0020;
+pub const V4L2_TUNER_CAP_SAP: c_uint = 0x0020;
+pub const V4L2_TUNER_CAP_LANG1: c_uint = 0x0040;
+pub const V4L2_TUNER_CAP_RDS: c_uint = 0x0080;
+pub const V4L2_TUNER_CAP_RDS_BLOCK_IO: c_uint = 0x0100;
+pub const V4L2_TUNER_CAP_RDS_CONTROLS: c_uint = 0x0200;
+pub const V4L2_TUNER_CAP_FREQ_BANDS: c_uint = 0x0400;
+pub const V4L2_TUNER_CAP_HWSEEK_PROG_LIM: c_uint = 0x0800;
+pub const V4L2_TUNER_CAP_1HZ: c_uint = 0x1000;
+
+/*  Flags for the 'rxsubchans' field */
+pub const V4L2_TUNER_SUB_MONO: c_uint = 0x0001;
+pub const V4L2_TUNER_SUB_STEREO: c_uint = 0x0002;
+pub const V4L2_TUNER_SUB_LANG2: c_uint = 0x0004;
+pub const V4L2_TUNER_SUB_SAP: c_uint = 0x0004;
+pub const V4L2_TUNER_SUB_LANG1: c_uint = 0x0008;
+pub const V4L2_TUNER_SUB_RDS: c_uint = 0x0010;
+
+/*  Values for the 'audmode' field */
+pub const V4L2_TUNER_MODE_MONO: c_uint = 0x0000;
+pub const V4L2_TUNER_MODE_STEREO: c_uint = 0x0001;
+pub const V4L2_TUNER_MODE_LANG2: c_uint = 0x0002;
+pub const V4L2_TUNER_MODE_SAP: c_uint = 0x0002;
+pub const V4L2_TUNER_MODE_LANG1: c_uint = 0x0003;
+pub const V4L2_TUNER_MODE_LANG1_LANG2: c_uint = 0x0004;
+
+s! {
+    pub struct v4l2_frequency {
+        pub tuner: crate::__u32,
+        /* enum v4l2_tuner_type */
+        pub type_: crate::__u32,
+        pub frequency: crate::__u32,
+        reserved: Padding<[crate::__u32; 8]>,
+    }
+}
+
+pub const V4L2_BAND_MODULATION_VSB: c_uint = 1 << 1;
+pub const V4L2_BAND_MODULATION_FM: c_uint = 1 << 2;
+pub const V4L2_BAND_MODULATION_AM: c_uint = 1 << 3;
+
+s! {
+    pub struct v4l2_frequency_band {
+        pub tuner: crate::__u32,
+        /* enum v4l2_tuner_type */
+        pub type_: crate::__u32,
+        pub index: crate::__u32,
+        pub capability: crate::__u32,
+        pub rangelow: crate::__u32,
+        pub rangehigh: crate::__u32,
+        pub modulation: crate::__u32,
+        reserved: Padding<[crate::__u32; 9]>,
+    }
+
+    pub struct v4l2_hw_freq_seek {
+        pub tuner: crate::__u32,
+        /* enum v4l2_tuner_type */
+        pub type_: crate::__u32,
+        pub seek_upward: crate::__u32,
+        pub wrap_around: crate::__u32,
+        pub spacing: crate::__u32,
+        pub rangelow: crate::__u32,
+        pub rangehigh: crate::__u32,
+        reserved: Padding<[crate::__u32; 5]>,
+    }
+
+    /*
+     *  R D S
+     */
+
+    #[repr(packed)]
+    pub struct v4l2_rds_data {
+        pub lsb: crate::__u8,
+        pub msb: crate::__u8,
+        pub block: crate::__u8,
+    }
+}
+
+pub const V4L2_RDS_BLOCK_MSK: c_uint = 0x7;
+pub const V4L2_RDS_BLOCK_A: c_uint = 0;
+pub const V4L2_RDS_BLOCK_B: c_uint = 1;
+pub const V4L2_RDS_BLOCK_C: c_uint = 2;
+pub const V4L2_RDS_BLOCK_D: c_uint = 3;
+pub const V4L2_RDS_BLOCK_C_ALT: c_uint = 4;
+pub const V4L2_RDS_BLOCK_INVALID: c_uint = 7;
+
+pub const V4L2_RDS_BLOCK_CORRECTED: c_uint = 0x40;
+pub const V4L2_RDS_BLOCK_ERROR: c_uint = 0x80;
+
+s! {
+    /*
+     *  A U D I O
+     */
+    pub struct v4l2_audio {
+        pub index: crate::__u32,
+        pub name: [crate::__u8; 32],
+        pub capability: crate::__u32,
+        pub mode: crate::__u32,
+        reserved: Padding<[crate::__u32; 2]>,
+    }
+}
+
+/*  Flags for the 'capability' field */
+pub const V4L2_AUDCAP_STEREO: c_uint = 0x00001;
+pub const V4L2_AUDCAP_AVL: c_uint = 0x00002;
+
+/*  Flags for the 'mode' field */
+pub const V4L2_AUDMODE_AVL: c_uint = 0x00001;
+
+s! {
+    pub struct v4l2_audioout {
+        pub index: crate::__u32,
+        pub name: [crate::__u8; 32],
+        pub capability: crate::__u32,
+        pub mode: crate::__u32,
+        reserved: Padding<[crate::__u32; 2]>,
+    }
+}
+
+/*
+ *      M P E G   S E R V I C E S
+ */
+pub const V4L2_ENC_IDX_FRAME_I: c_uint = 0;
+pub const V4L2_ENC_IDX_FRAME_P: c_uint = 1;
+pub const V4L2_ENC_IDX_FRAME_B: c_uint = 2;
+pub const V4L2_ENC_IDX_FRAME_MASK: c_uint = 0xf;
+
+s! {
+    pub struct v4l2_enc_idx_entry {
+        pub offset: crate::__u64,
+        pub pts: crate::__u64,
+        pub length: crate::__u32,
+        pub flags: crate::__u32,
+        reserved: Padding<[crate::__u32; 2]>,
+    }
+}
+
+pub const V4L2_ENC_IDX_ENTRIES: usize = 64;
+
+s! {
+    pub struct v4l2_enc_idx {
+        pub entries: crate::__u32,
+        pub entries_cap: crate::__u32,
+        reserved: Padding<[crate::__u32; 4]>,
+        pub entry: [v4l2_enc_idx_entry; V4L2_ENC_IDX_ENTRIES],
+    }
+}
+
+pub const V4L2_ENC_CMD_START: c_uint = 0;
+pub const V4L2_ENC_CMD_STOP: c_uint = 1;
+pub const V4L2_ENC_CMD_PAUSE: c_uint = 2;
+pub const V4L2_ENC_CMD_RESUME: c_uint = 3;
+
+/* Flags for V4L2_ENC_CMD_STOP */
+pub const V4L2_ENC_CMD_STOP_AT_GOP_END: c_uint = 1 << 0;
+
+s_no_extra_traits! {
+    pub struct v4l2_encoder_cmd {
+        pub cmd: crate::__u32,
+        pub flags: crate::__u32,
+        pub anonymous_1: __c_anonymous_v4l2_encoder_cmd_1,
+    }
+
+    pub union __c_anonymous_v4l2_encoder_cmd_1 {
+        pub raw: __c_anonymous_v4l2_encoder_cmd_raw,
+    }
+}
+
+s! {
+    pub struct __c_anonymous_v4l2_encoder_cmd_raw {
+        pub data: [crate::__u32; 8],
+    }
+}
+
+/* Decoder commands */
+pub const V4L2_DEC_CMD_START: c_uint = 0;
+pub const V4L2_DEC_CMD_STOP: c_uint = 1;
+pub const V4L2_DEC_CMD_PAUSE: c_uint = 2;
+pub const V4L2_DEC_CMD_RESUME: c_uint = 3;
+pub const V4L2_DEC_CMD_FLUSH: c_uint = 4;
+
+/* Flags for V4L2_DEC_CMD_START */
+pub const V4L2_DEC_CMD_START_MUTE_AUDIO: c_uint = 1 << 0;
+
+/* Flags for V4L2_DEC_CMD_PAUSE */
+pub const V4L2_DEC_CMD_PAUSE_TO_BLACK: c_uint = 1 << 0;
+
+/* Flags for V4L2_DEC_CMD_STOP */
+pub const V4L2_DEC_CMD_STOP_TO_BLACK: c_uint = 1 << 0;
+pub const V4L2_DEC_CMD_STOP_IMMEDIATELY: c_uint = 1 << 1;
+
+/* Play format requirements (returned by the driver): */
+
+/* The decoder has no special format requirements */
+pub const V4L2_DEC_START_FMT_NONE: c_uint = 0;
+/* The decoder requires full GOPs */
+pub const V4L2_DEC_START_FMT_GOP: c_uint = 1;
+
+s_no_extra_traits! {
+    /* The structure must be zeroed before use by the application
+    This ensures it can be extended safely in the future. */
+    pub struct v4l2_decoder_cmd {
+        pub cmd: crate::__u32,
+        pub flags: crate::__u32,
+        pub anonymous_1: __c_anonymous_v4l2_decoder_cmd_1,
+    }
+
+    pub union __c_anonymous_v4l2_decoder_cmd_1 {
+        pub stop: __c_anonymous_v4l2_decoder_cmd_stop,
+        pub start: __c_anonymous_v4l2_decoder_cmd_start,
+        pub raw: __c_anonymous_v4l2_decoder_cmd_raw,
+    }
+}
+
+s! {
+    pub struct __c_anonymous_v4l2_decoder_cmd_stop {
+        pub pts: crate::__u64,
+    }
+
+    pub struct __c_anonymous_v4l2_decoder_cmd_start {
+        /* 0 or 1000 specifies normal speed,
+        1 specifies forward single stepping,
+        -1 specifies backward single stepping,
+        >1: playback at speed/1000 of the normal speed,
+        <-1: reverse playback at (-speed/1000) of the normal speed. */
+        pub speed: crate::__s32,
+        pub format: crate::__u32,
+    }
+
+    pub struct __c_anonymous_v4l2_decoder_cmd_raw {
+        pub data: [crate::__u32; 16],
+    }
+
+    /*
+     *  D A T A   S E R V I C E S   ( V B I )
+     *
+     *  Data services API by Michael Schimek
+     */
+
+    /* Raw VBI */
+    pub struct v4l2_vbi_format {
+        /* in 1 Hz */
+        pub sampling_rate: crate::__u32,
+        pub offset: crate::__u32,
+        pub samples_per_line: crate::__u32,
+        /* V4L2_PIX_FMT_* */
+        pub sample_format: crate::__u32,
+        pub start: [crate::__s32; 2],
+        pub count: [crate::__u32; 2],
+        /* V4L2_VBI_* */
+        pub flags: crate::__u32,
+        /* must be zero */
+        reserved: Padding<[crate::__u32; 2]>,
+    }
+}
+
+/*  VBI flags  */
+pub const V4L2_VBI_UNSYNC: c_uint = 1 << 0;
+pub const V4L2_VBI_INTERLACED: c_uint = 1 << 1;
+
+/* ITU-R start lines for each field */
+pub const V4L2_VBI_ITU_525_F1_START: c_uint = 1;
+pub const V4L2_VBI_ITU_525_F2_START: c_uint = 264;
+pub const V4L2_VBI_ITU_625_F1_START: c_uint = 1;
+pub const V4L2_VBI_ITU_625_F2_START: c_uint = 314;
+
+s! {
+    /* Sliced VBI
+     *
+     *    This implements is a proposal V4L2 API to allow SLICED VBI
+     * required for some hardware encoders. It should change without
+     * notice in the definitive implementation.
+     */
+
+    pub struct v4l2_sliced_vbi_format {
+        pub service_set: crate::__u16,
+        /* service_lines[0][...] specifies lines 0-23 (1-23 used) of the first field
+        service_lines[1][...] specifies lines 0-23 (1-23 used) of the second field
+        (equals frame lines 313-336 for 625 line video
+        standards, 263-286 for 525 line standards) */
+        pub service_lines: [[crate::__u16; 24]; 2],
+        pub io_size: crate::__u32,
+        /* must be zero */
+        reserved: Padding<[crate::__u32; 2]>,
+    }
+}
+
+/* Teletext World System Teletext
+(WST), defined on ITU-R BT.653-2 */
+pub const V4L2_SLICED_TELETEXT_B: c_uint = 0x0001;
+/* Video Program System, defined on ETS 300 231*/
+pub const V4L2_SLICED_VPS: c_uint = 0x0400;
+/* Closed Caption, defined on EIA-608 */
+pub const V4L2_SLICED_CAPTION_525: c_uint = 0x1000;
+/* Wide Screen System, defined on ITU-R BT1119.1 */
+pub const V4L2_SLICED_WSS_625: c_uint = 0x4000;
+
+pub const V4L2_SLICED_VBI_525: c_uint = V4L2_SLICED_CAPTION_525;
+pub const V4L2_SLICED_VBI_625: c_uint =
+    V4L2_SLICED_TELETEXT_B | V4L2_SLICED_VPS | V4L2_SLICED_WSS_625;
+
+s! {
+    pub struct v4l2_sliced_vbi_cap {
+        pub service_set: crate::__u16,
+        /* service_lines[0][...] specifies lines 0-23 (1-23 used) of the first field
+        service_lines[1][...] specifies lines 0-23 (1-23 used) of the second field
+        (equals frame lines 313-336 for 625 line video
+        standards, 263-286 for 525 line standards) */
+        pub service_lines: [[crate::__u16; 24]; 2],
+        /* enum v4l2_buf_type */
+        pub type_: crate::__u32,
+        /* must be 0 */
+        reserved: Padding<[crate::__u32; 3]>,
+    }
+
+    pub struct v4l2_sliced_vbi_data {
+        pub id: crate::__u32,
+        /* 0: first field, 1: second field */
+        pub field: crate::__u32,
+        /* 1-23 */
+        pub line: crate::__u32,
+        /* must be 0 */
+        reserved: Padding<crate::__u32>,
+        pub data: [crate::__u8; 48],
+    }
+}
+
+/*
+ * Sliced VBI data inserted into MPEG Streams
+ */
+
+/*
+ * V4L2_MPEG_STREAM_VBI_FMT_IVTV:
+ *
+ * Structure of payload contained in an MPEG 2 Private Stream 1 PES Packet in an
+ * MPEG-2 Program Pack that contains V4L2_MPEG_STREAM_VBI_FMT_IVTV Sliced VBI
+ * data
+ *
+ * Note, the MPEG-2 Program Pack and Private Stream 1 PES packet header
+ * definitions are not included here.  See the MPEG-2 specifications for details
+ * on these headers.
+ */
+
+/* Line type IDs */
+pub const V4L2_MPEG_VBI_IVTV_TELETEXT_B: c_uint = 1;
+pub const V4L2_MPEG_VBI_IVTV_CAPTION_525: c_uint = 4;
+pub const V4L2_MPEG_VBI_IVTV_WSS_625: c_uint = 5;
+pub const V4L2_MPEG_VBI_IVTV_VPS: c_uint = 7;
+
+s! {
+    #[repr(packed)]
+    pub struct v4l2_mpeg_vbi_itv0_line {
+        /* One of V4L2_MPEG_VBI_IVTV_* above */
+        pub id: crate::__u8,
+        /* Sliced VBI data for the line */
+        pub data: [crate::__u8; 42],
+    }
+
+    #[repr(packed)]
+    pub struct v4l2_mpeg_vbi_itv0 {
+        /* Bitmasks of VBI service lines present */
+        pub linemask: [crate::__u32; 2],
+        pub line: [v4l2_mpeg_vbi_itv0_line; 35],
+    }
+
+    #[repr(packed)]
+    pub struct v4l2_mpeg_vbi_ITV0 {
+        pub line: [v4l2_mpeg_vbi_itv0_line; 36],
+    }
+}
+
+pub const V4L2_MPEG_VBI_IVTV_MAGIC0: &[u8] = b"itv0\0";
+pub const V4L2_MPEG_VBI_IVTV_MAGIC1: &[u8] = b"ITV0\0";
+
+s_no_extra_traits! {
+    #[repr(packed)]
+    pub struct v4l2_mpeg_vbi_fmt_ivtv {
+        pub magic: [crate::__u8; 4],
+        pub anonymous_1: __c_anonymous_v4l2_mpeg_vbi_fmt_ivtv_1,
+    }
+
+    pub union __c_anonymous_v4l2_mpeg_vbi_fmt_ivtv_1 {
+        pub itv0: v4l2_mpeg_vbi_itv0,
+        pub ITV0: v4l2_mpeg_vbi_ITV0,
+    }
+}
+
+/*
+ *      A G G R E G A T E   S T R U C T U R E S
+ */
+
+s! {
+    /*
+     * struct v4l2_plane_pix_format - additional, per-plane format definition
+     * @sizeimage:              maximum size in bytes required for data, for which
+     *                  this plane will be used
+     * @bytesperline:   distance in bytes between the leftmost pixels in two
+     *                  adjacent lines
+     * @reserved:               drivers and applications must zero this array
+     */
+    #[repr(packed)]
+    pub struct v4l2_plane_pix_format {
+        pub sizeimage: crate::__u32,
+        pub bytesperline: crate::__u32,
+        reserved: Padding<[crate::__u16; 6]>,
+    }
+}
+
+s_no_extra_traits! {
+    /*
+     * struct v4l2_pix_format_mplane - multiplanar format definition
+     * @width:          image width in pixels
+     * @height:         image height in pixels
+     * @pixelformat:    little endian four character code (fourcc)
+     * @field:          enum v4l2_field; field order (for interlaced video)
+     * @colorspace:             enum v4l2_colorspace; supplemental to pixelformat
+     * @plane_fmt:              per-plane information
+     * @num_planes:             number of planes for this format
+     * @flags:          format flags (V4L2_PIX_FMT_FLAG_*)
+     * @ycbcr_enc:              enum v4l2_ycbcr_encoding, Y'CbCr encoding
+     * @hsv_enc:                enum v4l2_hsv_encoding, HSV encoding
+     * @quantization:   enum v4l2_quantization, colorspace quantization
+     * @xfer_func:              enum v4l2_xfer_func, colorspace transfer function
+     * @reserved:               drivers and applications must zero this array
+     */
+    #[repr(packed)]
+    pub struct v4l2_pix_format_mplane {
+        pub width: crate::__u32,
+        pub height: crate::__u32,
+        pub pixelformat: crate::__u32,
+        pub field: crate::__u32,
+        pub colorspace: crate::__u32,
+
+        pub plane_fmt: [v4l2_plane_pix_format; VIDEO_MAX_PLANES],
+        pub num_planes: crate::__u8,
+        pub flags: crate::__u8,
+        pub anonymous_1: __c_anonymous_v4l2_pix_format_mplane_1,
+        pub quantization: crate::__u8,
+        pub xfer_func: crate::__u8,
+        reserved: Padding<[crate::__u8; 7]>,
+    }
+
+    pub union __c_anonymous_v4l2_pix_format_mplane_1 {
+        pub ycbcr_enc: crate::__u8,
+        pub hsv_enc: crate::__u8,
+    }
+}
+
+s! {
+    /*
+     * struct v4l2_sdr_format - SDR format definition
+     * @pixelformat:    little endian four character code (fourcc)
+     * @buffersize:             maximum size in bytes required for data
+     * @reserved:               drivers and applications must zero this array
+     */
+    #[repr(packed)]
+    pub struct v4l2_sdr_format {
+        pub pixelformat: crate::__u32,
+        pub buffersize: crate::__u32,
+        reserved: Padding<[crate::__u8; 24]>,
+    }
+
+    /*
+     * struct v4l2_meta_format - metadata format definition
+     * @dataformat:             little endian four character code (fourcc)
+     * @buffersize:             maximum size in bytes required for data
+     */
+    #[repr(packed)]
+    pub struct v4l2_meta_format {
+        pub dataformat: crate::__u32,
+        pub buffersize: crate::__u32,
+    }
+}
+
+s_no_extra_traits! {
+    /*
+     * struct v4l2_format - stream data format
+     * @type:   enum v4l2_buf_type; type of the data stream
+     * @pix:    definition of an image format
+     * @pix_mp: definition of a multiplanar image format
+     * @win:    definition of an overlaid image
+     * @vbi:    raw VBI capture or output parameters
+     * @sliced: sliced VBI capture or output parameters
+     * @raw_data:       placeholder for future extensions and custom formats
+     * @fmt:    union of @pix, @pix_mp, @win, @vbi, @sliced, @sdr, @meta
+     *          and @raw_data
+     */
+    pub struct v4l2_format {
+        pub type_: crate::__u32,
+        pub fmt: __c_anonymous_v4l2_format_fmt,
+    }
+
+    pub union __c_anonymous_v4l2_format_fmt {
+        /* V4L2_BUF_TYPE_VIDEO_CAPTURE */
+        pub pix: v4l2_pix_format,
+        /* V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE */
+        pub pix_mp: v4l2_pix_format_mplane,
+        /* V4L2_BUF_TYPE_VIDEO_OVERLAY */
+        pub win: v4l2_window,
+        /* V4L2_BUF_TYPE_VBI_CAPTURE */
+        pub vbi: v4l2_vbi_format,
+        /* V4L2_BUF_TYPE_SLICED_VBI_CAPTURE */
+        pub sliced: v4l2_sliced_vbi_format,
+        /* V4L2_BUF_TYPE_SDR_CAPTURE */
+        pub sdr: v4l2_sdr_format,
+        /* V4L2_BUF_TYPE_META_CAPTURE */
+        pub meta: v4l2_meta_format,
+        /* user-defined */
+        pub raw_data: [crate::__u8; 200],
+    }
+
+    /*  Stream type-dependent parameters
+     */
+    pub struct v4l2_streamparm {
+        /* enum v4l2_buf_type */
+        pub type_: crate::__u32,
+        pub parm: __c_anonymous_v4l2_streamparm_parm,
+    }
+
+    pub union __c_anonymous_v4l2_streamparm_parm {
+        pub capture: v4l2_captureparm,
+        pub output: v4l2_outputparm,
+        /* user-defined */
+        pub raw_data: [crate::__u8; 200],
+    }
+}
+
+/*
+ *      E V E N T S
+ */
+
+pub const V4L2_EVENT_ALL: c_uint = 0;
+pub const V4L2_EVENT_VSYNC: c_uint = 1;
+pub const V4L2_EVENT_EOS: c_uint = 2;
+pub const V4L2_EVENT_CTRL: c_uint = 3;
+pub const V4L2_EVENT_FRAME_SYNC: c_uint = 4;
+pub const V4L2_EVENT_SOURCE_CHANGE: c_uint = 5;
+pub const V4L2_EVENT_MOTION_DET: c_uint = 6;
+pub const V4L2_EVENT_PRIVATE_START: c_uint = 0x08000000;
+
+s! {
+    /* Payload for V4L2_EVENT_VSYNC */
+    #[repr(packed)]
+    pub struct v4l2_event_vsync {
+        /* Can be V4L2_FIELD_ANY, _NONE, _TOP or _BOTTOM */
+        pub field: crate::__u8,
+    }
+}
+
+/* Payload for V4L2_EVENT_CTRL */
+pub const V4L2_EVENT_CTRL_CH_VALUE: c_uint = 1 << 0;
+pub const V4L2_EVENT_CTRL_CH_FLAGS: c_uint = 1 << 1;
+pub const V4L2_EVENT_CTRL_CH_RANGE: c_uint = 1 << 2;
+pub const V4L2_EVENT_CTRL_CH_DIMENSIONS: c_uint = 1 << 3;
+
+s_no_extra_traits! {
+    pub struct v4l2_event_ctrl {
+        pub changes: crate::__u32,
+        pub type_: crate::__u32,
+        pub anonymous_1: __c_anonymous_v4l2_event_ctrl_1,
+        pub flags: crate::__u32,
+        pub minimum: crate::__s32,
+        pub maximum: crate::__s32,
+        pub step: crate::__s32,
+        pub default_value: crate::__s32,
+    }
+
+    pub union __c_anonymous_v4l2_event_ctrl_1 {
+        pub value: crate::__s32,
+        pub value64: crate::__s64,
+    }
+}
+
+s! {
+    pub struct v4l2_event_frame_sync {
+        pub frame_sequence: crate::__u32,
+    }
+}
+
+pub const V4L2_EVENT_SRC_CH_RESOLUTION: c_uint = 1 << 0;
+
+s! {
+    pub struct v4l2_event_src_change {
+        pub changes: crate::__u32,
+    }
+}
+
+pub const V4L2_EVENT_MD_FL_HAVE_FRAME_SEQ: c_uint = 1 << 0;
+
+s! {
+    /*
+     * struct v4l2_event_motion_det - motion detection event
+     * @flags:             if V4L2_EVENT_MD_FL_HAVE_FRAME_SEQ is set, then the
+     *                     frame_sequence field is valid.
+     * @frame_sequence:    the frame sequence number associated with this event.
+     * @region_mask:       which regions detected motion.
+     */
+    pub struct v4l2_event_motion_det {
+        pub flags: crate::__u32,
+        pub frame_sequence: crate::__u32,
+        pub region_mask: crate::__u32,
+    }
+}
+
+s_no_extra_traits! {
+    pub struct v4l2_event {
+        pub type_: crate::__u32,
+        pub u: __c_anonymous_v4l2_event_u,
+        pub pending: crate::__u32,
+        pub sequence: crate::__u32,
+        pub timestamp: crate::timespec,
+        pub id: crate::__u32,
+        reserved: Padding<[crate::__u32; 8]>,
+    }
+
+    pub union __c_anonymous_v4l2_event_u {
+        pub vsync: v4l2_event_vsync,
+        pub ctrl: v4l2_event_ctrl,
+        pub frame_sync: v4l2_event_frame_sync,
+        pub src_change: v4l2_event_src_change,
+        pub motion_det: v4l2_event_motion_det,
+        pub data: [crate::__u8; 64],
+    }
+}
+
+pub const V4L2_EVENT_SUB_FL_SEND_INITIAL: c_uint = 1 << 0;
+pub const V4L2_EVENT_SUB_FL_ALLOW_FEEDBACK: c_uint = 1 << 1;
+
+s! {
+    pub struct v4l2_event_subscription {
+        pub type_: crate::__u32,
+        pub id: crate::__u32,
+        pub flags: crate::__u32,
+        reserved: Padding<[crate::__u32; 5]>,
+    }
+}
+
+/*
+ *      A D V A N C E D   D E B U G G I N G
+ *
+ *      NOTE: EXPERIMENTAL API, NEVER RELY ON THIS IN APPLICATIONS!
+ *      FOR DEBUGGING, TESTING AND INTERNAL USE ONLY!
+ */
+
+/* VIDIOC_DBG_G_REGISTER and VIDIOC_DBG_S_REGISTER */
+
+/* Match against chip ID on the bridge (0 for the bridge) */
+pub const V4L2_CHIP_MATCH_BRIDGE: c_uint = 0;
+/* Match against subdev index */
+pub const V4L2_CHIP_MATCH_SUBDEV: c_uint = 4;
+
+/* The following four defines are no longer in use */
+pub const V4L2_CHIP_MATCH_HOST: c_uint = V4L2_CHIP_MATCH_BRIDGE;
+/* Match against I2C driver name */
+pub const V4L2_CHIP_MATCH_I2C_DRIVER: c_uint = 1;
+/* Match against I2C 7-bit address */
+pub const V4L2_CHIP_MATCH_I2C_ADDR: c_uint = 2;
+/* Match against ancillary AC97 chip */
+pub const V4L2_CHIP_MATCH_AC97: c_uint = 3;
+
+s_no_extra_traits! {
+    #[repr(packed)]
+    pub struct v4l2_dbg_match {
+        /* Match type */
+        pub type_: crate::__u32,
+        /* Match this chip, meaning determined by type */
+        pub anonymous_1: __c_anonymous_v4l2_dbg_match_1,
+    }
+
+    pub union __c_anonymous_v4l2_dbg_match_1 {
+        pub addr: crate::__u32,
+        pub name: [c_char; 32],
+    }
+
+    #[repr(packed)]
+    pub struct v4l2_dbg_register {
+        pub match_: v4l2_dbg_match,
+        /* register size in bytes */
+        pub size: crate::__u32,
+        pub reg: crate::__u64,
+        pub val: crate::__u64,
+    }
+}
+
+pub const V4L2_CHIP_FL_READABLE: c_uint = 1 << 0;
+pub const V4L2_CHIP_FL_WRITABLE: c_uint = 1 << 1;
+
+s_no_extra_traits! {
+    /* VIDIOC_DBG_G_CHIP_INFO */
+    #[repr(packed)]
+    pub struct v4l2_dbg_chip_info {
+        pub match_: v4l2_dbg_match,
+        pub name: [c_char; 32],
+        pub flags: crate::__u32,
+        reserved: Padding<[crate::__u32; 32]>,
+    }
+
+    /*
+     * struct v4l2_create_buffers - VIDIOC_CREATE_BUFS argument
+     * @index:  on return, index of the first created buffer
+     * @count:  entry: number of requested buffers,
+     *          return: number of created buffers
+     * @memory: enum v4l2_memory; buffer memory type
+     * @format: frame format, for which buffers are requested
+     * @capabilities: capabilities of this buffer type.
+     * @flags:  additional buffer management attributes (ignored unless the
+     *          queue has V4L2_BUF_CAP_SUPPORTS_MMAP_CACHE_HINTS capability
+     *          and configured for MMAP streaming I/O).
+     * @max_num_buffers: if V4L2_BUF_CAP_SUPPORTS_MAX_NUM_BUFFERS capability flag is set
+     *          this field indicate the maximum possible number of buffers
+     *          for this queue.
+     * @reserved:       future extensions
+     */
+    pub struct v4l2_create_buffers {
+        pub index: crate::__u32,
+        pub count: crate::__u32,
+        pub memory: crate::__u32,
+        pub format: v4l2_format,
+        pub capabilities: crate::__u32,
+        pub flags: crate::__u32,
+        pub max_num_buffers: crate::__u32,
+        reserved: Padding<[crate::__u32; 5]>,
+    }
+}
+
+s! {
+    /*
+     * struct v4l2_remove_buffers - VIDIOC_REMOVE_BUFS argument
+     * @index:  the first buffer to be removed
+     * @count:  number of buffers to removed
+     * @type:   enum v4l2_buf_type
+     * @reserved:       future extensions
+     */
+    pub struct v4l2_remove_buffers {
+        pub index: crate::__u32,
+        pub count: crate::__u32,
+        pub type_: crate::__u32,
+        reserved: Padding<[crate::__u32; 13]>,
+    }
+}
+
+/*
+ *      I O C T L   C O D E S   F O R   V I D E O   D E V I C E S
+ *
+ */
+const VIDIOC_MAGIC: c_uint = b'V' as c_uint;
+
+pub const VIDIOC_QUERYCAP: Ioctl = _IOR::<v4l2_capability>(VIDIOC_MAGIC, 0);
+pub const VIDIOC_ENUM_FMT: Ioctl = _IOWR::<v4l2_fmtdesc>(VIDIOC_MAGIC, 2);
+pub const VIDIOC_G_FMT: Ioctl = _IOWR::<v4l2_format>(VIDIOC_MAGIC, 4);
+pub const VIDIOC_S_FMT: Ioctl = _IOWR::<v4l2_format>(VIDIOC_MAGIC, 5);
+pub const VIDIOC_REQBUFS: Ioctl = _IOWR::<v4l2_requestbuffers>(VIDIOC_MAGIC, 8);
+pub const VIDIOC_QUERYBUF: Ioctl = _IOWR::<v4l2_buffer>(VIDIOC_MAGIC, 9);
+pub const VIDIOC_G_FBUF: Ioctl = _IOR::<v4l2_framebuffer>(VIDIOC_MAGIC, 10);
+pub const VIDIOC_S_FBUF: Ioctl = _IOW::<v4l2_framebuffer>(VIDIOC_MAGIC, 11);
+pub const VIDIOC_OVERLAY: Ioctl = _IOW::<c_int>(VIDIOC_MAGIC, 14);
+pub const VIDIOC_QBUF: Ioctl = _IOWR::<v4l2_buffer>(VIDIOC_MAGIC, 15);
+pub const VIDIOC_EXPBUF: Ioctl = _IOWR::<v4l2_exportbuffer>(VIDIOC_MAGIC, 16);
+pub const VIDIOC_DQBUF: Ioctl = _IOWR::<v4l2_buffer>(VIDIOC_MAGIC, 17);
+pub const VIDIOC_STREAMON: Ioctl = _IOW::<c_int>(VIDIOC_MAGIC, 18);
+pub const VIDIOC_STREAMOFF: Ioctl = _IOW::<c_int>(VIDIOC_MAGIC, 19);
+pub const VIDIOC_G_PARM: Ioctl = _IOWR::<v4l2_streamparm>(VIDIOC_MAGIC, 21);
+pub const VIDIOC_S_PARM: Ioctl = _IOWR::<v4l2_streamparm>(VIDIOC_MAGIC, 22);
+pub const VIDIOC_G_STD: Ioctl = _IOR::<v4l2_std_id>(VIDIOC_MAGIC, 23);
+pub const VIDIOC_S_STD: Ioctl = _IOW::<v4l2_std_id>(VIDIOC_MAGIC, 24);
+pub const VIDIOC_ENUMSTD: Ioctl = _IOWR::<v4l2_standard>(VIDIOC_MAGIC, 25);
+pub const VIDIOC_ENUMINPUT: Ioctl = _IOWR::<v4l2_input>(VIDIOC_MAGIC, 26);
+pub const VIDIOC_G_CTRL: Ioctl = _IOWR::<v4l2_control>(VIDIOC_MAGIC, 27);
+pub const VIDIOC_S_CTRL: Ioctl = _IOWR::<v4l2_control>(VIDIOC_MAGIC, 28);
+pub const VIDIOC_G_TUNER: Ioctl = _IOWR::<v4l2_tuner>(VIDIOC_MAGIC, 29);
+pub const VIDIOC_S_TUNER: Ioctl = _IOW::<v4l2_tuner>(VIDIOC_MAGIC, 30);
+pub const VIDIOC_G_AUDIO: Ioctl = _IOR::<v4l2_audio>(VIDIOC_MAGIC, 33);
+pub const VIDIOC_S_AUDIO: Ioctl = _IOW::<v4l2_audio>(VIDIOC_MAGIC, 34);
+pub const VIDIOC_QUERYCTRL: Ioctl = _IOWR::<v4l2_queryctrl>(VIDIOC_MAGIC, 36);
+pub const VIDIOC_QUERYMENU: Ioctl = _IOWR::<v4l2_querymenu>(VIDIOC_MAGIC, 37);
+pub const VIDIOC_G_INPUT: Ioctl = _IOR::<c_int>(VIDIOC_MAGIC, 38);
+pub const VIDIOC_S_INPUT: Ioctl = _IOWR::<c_int>(VIDIOC_MAGIC, 39);
+pub const VIDIOC_G_EDID: Ioctl = _IOWR::<crate::v4l2_edid>(VIDIOC_MAGIC, 40);
+pub const VIDIOC_S_EDID: Ioctl = _IOWR::<crate::v4l2_edid>(VIDIOC_MAGIC, 41);
+pub const VIDIOC_G_OUTPUT: Ioctl = _IOR::<c_int>(VIDIOC_MAGIC, 46);
+pub const VIDIOC_S_OUTPUT: Ioctl = _IOWR::<c_int>(VIDIOC_MAGIC, 47);
+pub const VIDIOC_ENUMOUTPUT: Ioctl = _IOWR::<v4l2_output>(VIDIOC_MAGIC, 48);
+pub const VIDIOC_G_AUDOUT: Ioctl = _IOR::<v4l2_audioout>(VIDIOC_MAGIC, 49);
+pub const VIDIOC_S_AUDOUT: Ioctl = _IOW::<v4l2_audioout>(VIDIOC_MAGIC, 50);
+pub const VIDIOC_G_MODULATOR: Ioctl = _IOWR::<v4l2_modulator>(VIDIOC_MAGIC, 54);
+pub const VIDIOC_S_MODULATOR: Ioctl = _IOW::<v4l2_modulator>(VIDIOC_MAGIC, 55);
+pub const VIDIOC_G_FREQUENCY: Ioctl = _IOWR::<v4l2_frequency>(VIDIOC_MAGIC, 56);
+pub const VIDIOC_S_FREQUENCY: Ioctl = _IOW::<v4l2_frequency>(VIDIOC_MAGIC, 57);
+pub const VIDIOC_CROPCAP: Ioctl = _IOWR::<v4l2_cropcap>(VIDIOC_MAGIC, 58);
+pub const VIDIOC_G_CROP: Ioctl = _IOWR::<v4l2_crop>(VIDIOC_MAGIC, 59);
+pub const VIDIOC_S_CROP: Ioctl = _IOW::<v4l2_crop>(VIDIOC_MAGIC, 60);
+pub const VIDIOC_G_JPEGCOMP: Ioctl = _IOR::<v4l2_jpegcompression>(VIDIOC_MAGIC, 61);
+pub const VIDIOC_S_JPEGCOMP: Ioctl = _IOW::<v4l2_jpegcompression>(VIDIOC_MAGIC, 62);
+pub const VIDIOC_QUERYSTD: Ioctl = _IOR::<v4l2_std_id>(VIDIOC_MAGIC, 63);
+pub const VIDIOC_TRY_FMT: Ioctl = _IOWR::<v4l2_format>(VIDIOC_MAGIC, 64);
+pub const VIDIOC_ENUMAUDIO: Ioctl = _IOWR::<v4l2_audio>(VIDIOC_MAGIC, 65);
+pub const VIDIOC_ENUMAUDOUT: Ioctl = _IOWR::<v4l2_audioout>(VIDIOC_MAGIC, 66);
+/* enum v4l2_priority */
+pub const VIDIOC_G_PRIORITY: Ioctl = _IOR::<crate::__u32>(VIDIOC_MAGIC, 67);
+/* enum v4l2_priority */
+pub const VIDIOC_S_PRIORITY: Ioctl = _IOW::<crate::__u32>(VIDIOC_MAGIC, 68);
+pub const VIDIOC_G_SLICED_VBI_CAP: Ioctl = _IOWR::<v4l2_sliced_vbi_cap>(VIDIOC_MAGIC, 69);
+pub const VIDIOC_LOG_STATUS: Ioctl = _IO(VIDIOC_MAGIC, 70);
+pub const VIDIOC_G_EXT_CTRLS: Ioctl = _IOWR::<v4l2_ext_controls>(VIDIOC_MAGIC, 71);
+pub const VIDIOC_S_EXT_CTRLS: Ioctl = _IOWR::<v4l2_ext_controls>(VIDIOC_MAGIC, 72);
+pub const VIDIOC_TRY_EXT_CTRLS: Ioctl = _IOWR::<v4l2_ext_controls>(VIDIOC_MAGIC, 73);
+pub const VIDIOC_ENUM_FRAMESIZES: Ioctl = _IOWR::<v4l2_frmsizeenum>(VIDIOC_MAGIC, 74);
+pub const VIDIOC_ENUM_FRAMEINTERVALS: Ioctl = _IOWR::<v4l2_frmivalenum>(VIDIOC_MAGIC, 75);
+pub const VIDIOC_G_ENC_INDEX: Ioctl = _IOR::<v4l2_enc_idx>(VIDIOC_MAGIC, 76);
+pub const VIDIOC_ENCODER_CMD: Ioctl = _IOWR::<v4l2_encoder_cmd>(VIDIOC_MAGIC, 77);
+pub const VIDIOC_TRY_ENCODER_CMD: Ioctl = _IOWR::<v4l2_encoder_cmd>(VIDIOC_MAGIC, 78);
+
+/*
+ * Experimental, meant for debugging, testing and internal use.
+ * Only implemented if CONFIG_VIDEO_ADV_DEBUG is defined.
+ * You must be root to use these ioctls. Never use these in applications!
+ */
+pub const VIDIOC_DBG_S_REGISTER: Ioctl = _IOW::<v4l2_dbg_register>(VIDIOC_MAGIC, 79);
+pub const VIDIOC_DBG_G_REGISTER: Ioctl = _IOWR::<v4l2_dbg_register>(VIDIOC_MAGIC, 80);
+
+pub const VIDIOC_S_HW_FREQ_SEEK: Ioctl = _IOW::<v4l2_hw_freq_seek>(VIDIOC_MAGIC, 82);
+pub const VIDIOC_S_DV_TIMINGS: Ioctl = _IOWR::<v4l2_dv_timings>(VIDIOC_MAGIC, 87);
+pub const VIDIOC_G_DV_TIMINGS: Ioctl = _IOWR::<v4l2_dv_timings>(VIDIOC_MAGIC, 88);
+pub const VIDIOC_DQEVENT: Ioctl = _IOR::<v4l2_event>(VIDIOC_MAGIC, 89);
+pub const VIDIOC_SUBSCRIBE_EVENT: Ioctl = _IOW::<v4l2_event_subscription>(VIDIOC_MAGIC, 90);
+pub const VIDIOC_UNSUBSCRIBE_EVENT: Ioctl = _IOW::<v4l2_event_subscription>(VIDIOC_MAGIC, 91);
+pub const VIDIOC_CREATE_BUFS: Ioctl = _IOWR::<v4l2_create_buffers>(VIDIOC_MAGIC, 92);
+pub const VIDIOC_PREPARE_BUF: Ioctl = _IOWR::<v4l2_buffer>(VIDIOC_MAGIC, 93);
+pub const VIDIOC_G_SELECTION: Ioctl = _IOWR::<v4l2_selection>(VIDIOC_MAGIC, 94);
+pub const VIDIOC_S_SELECTION: Ioctl = _IOWR::<v4l2_selection>(VIDIOC_MAGIC, 95);
+pub const VIDIOC_DECODER_CMD: Ioctl = _IOWR::<v4l2_decoder_cmd>(VIDIOC_MAGIC, 96);
+pub const VIDIOC_TRY_DECODER_CMD: Ioctl = _IOWR::<v4l2_decoder_cmd>(VIDIOC_MAGIC, 97);
+pub const VIDIOC_ENUM_DV_TIMINGS: Ioctl = _IOWR::<v4l2_enum_dv_timings>(VIDIOC_MAGIC, 98);
+pub const VIDIOC_QUERY_DV_TIMINGS: Ioctl = _IOR::<v4l2_dv_timings>(VIDIOC_MAGIC, 99);
+pub const VIDIOC_DV_TIMINGS_CAP: Ioctl = _IOWR::<v4l2_dv_timings_cap>(VIDIOC_MAGIC, 100);
+pub const VIDIOC_ENUM_FREQ_BANDS: Ioctl = _IOWR::<v4l2_frequency_band>(VIDIOC_MAGIC, 101);
+
+/*
+ * Experimental, meant for debugging, testing and internal use.
+ * Never use this in applications!
+ */
+pub const VIDIOC_DBG_G_CHIP_INFO: Ioctl = _IOWR::<v4l2_dbg_chip_info>(VIDIOC_MAGIC, 102);
+
+pub const VIDIOC_QUERY_EXT_CTRL: Ioctl = _IOWR::<v4l2_query_ext_ctrl>(VIDIOC_MAGIC, 103);
+pub const VIDIOC_REMOVE_BUFS: Ioctl = _IOWR::<v4l2_remove_buffers>(VIDIOC_MAGIC, 104);
+
+/* 192-255 are private */
+pub const BASE_VIDIOC_PRIVATE: c_uint = 192;
+
+/* Deprecated definitions kept for backwards compatibility */
+pub const V4L2_PIX_FMT_HM12: crate::__u32 = V4L2_PIX_FMT_NV12_16L16;
+pub const V4L2_PIX_FMT_SUNXI_TILED_NV12: crate::__u32 = V4L2_PIX_FMT_NV12_32L32;
@@ -175,21 +175,69 @@ cfg_if! {
     if #[cfg(target_os = "android")] {
         pub use sys::socket::*;
     } else if #[cfg(target_os = "linux")] {
+        pub use drm::drm::*;
+        pub use drm::drm_mode::*;
+        pub use linux::aio_abi::*;
+        pub use linux::audit::*;
+        pub use linux::bpf::*;
+        pub use linux::bpf_common::*;
         pub use linux::can::bcm::*;
         pub use linux::can::error::*;
         pub use linux::can::j1939::*;
         pub use linux::can::netlink::*;
         pub use linux::can::raw::*;
+        pub use linux::capability::*;
+        pub use linux::cgroupstats::*;
+        pub use linux::cn_proc::*;
+        pub use linux::connector::*;
+        pub use linux::devlink::*;
+        pub use linux::dm_ioctl::*;
+        pub use linux::ethtool::*;
+        pub use linux::ethtool_netlink::*;
+        pub use linux::fscrypt::*;
+        pub use linux::fsverity::*;
         pub use linux::futex::*;
+        pub use linux::if_addr::*;
         pub use linux::if_link::*;
         pub use linux::if_packet::*;
+        pub use linux::input::*;
+        pub use linux::input_event_codes::*;
+        pub use linux::io_uring::*;
+        pub use linux::iommufd::*;
         pub use linux::keyctl::*;
+        #[cfg(any(
+            target_arch = "aarch64",
+            target_arch = "riscv64",
+            target_arch = "s390x",
+            target_arch = "x86_64"
+        ))]
+        pub use linux::kvm::*;
+        pub use linux::landlock::*;
+        pub use linux::r#loop::*;
         pub use linux::membarrier::*;
+        pub use linux::mman::*;
         pub use linux::mount::*;
+        pub use linux::neighbour::*;
         pub use linux::netlink::*;
+        pub use linux::nl80211::*;
+        pub use linux::perf_event::*;
         pub use linux::pidfd::*;
+        pub use linux::rseq::*;
+        pub use linux::rtnetlink::*;
+        pub use linux::sched::*;
+        pub use linux::seccomp::*;
+        pub use linux::securebits::*;
+        pub use linux::taskstats::*;
+        pub use linux::time_types::*;
+        pub use linux::uinput::*;
+        pub use linux::userfaultfd::*;
+        pub use linux::v4l2_common::*;
+        pub use linux::vfio::*;
+        pub use linux::videodev2::*;
         #[cfg(target_env = "gnu")]
         pub use net::route::*;
+        #[cfg(target_env = "gnu")]
+        pub use sys::rseq::*;
     } else if #[cfg(target_vendor = "apple")] {
         pub use pthread_::introspection::*;
         pub use pthread_::pthread_spis::*;
@@ -653,6 +653,8 @@ pub const SYS_memfd_secret: c_long = 4000 + 447;
 pub const SYS_process_mrelease: c_long = 4000 + 448;
 pub const SYS_futex_waitv: c_long = 4000 + 449;
 pub const SYS_set_mempolicy_home_node: c_long = 4000 + 450;
+pub const SYS_cachestat: c_long = 4000 + 451;
+pub const SYS_mseal: c_long = 4000 + 462;
 
 pub const O_DIRECT: c_int = 0x8000;
 pub const O_DIRECTORY: c_int = 0x10000;
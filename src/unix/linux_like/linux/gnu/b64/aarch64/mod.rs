@@ -227,21 +227,6 @@ s! {
         pub fpsr: c_uint,
         pub fpcr: c_uint,
     }
-
-    #[repr(align(8))]
-    pub struct clone_args {
-        pub flags: c_ulonglong,
-        pub pidfd: c_ulonglong,
-        pub child_tid: c_ulonglong,
-        pub parent_tid: c_ulonglong,
-        pub exit_signal: c_ulonglong,
-        pub stack: c_ulonglong,
-        pub stack_size: c_ulonglong,
-        pub tls: c_ulonglong,
-        pub set_tid: c_ulonglong,
-        pub set_tid_size: c_ulonglong,
-        pub cgroup: c_ulonglong,
-    }
 }
 
 s_no_extra_traits! {
@@ -946,6 +931,7 @@ pub const SYS_memfd_secret: c_long = 447;
 pub const SYS_process_mrelease: c_long = 448;
 pub const SYS_futex_waitv: c_long = 449;
 pub const SYS_set_mempolicy_home_node: c_long = 450;
+pub const SYS_cachestat: c_long = 451;
 pub const SYS_mseal: c_long = 462;
 
 pub const PROT_BTI: c_int = 0x10;
@@ -603,6 +603,8 @@ pub const SYS_memfd_secret: c_long = 5000 + 447;
 pub const SYS_process_mrelease: c_long = 5000 + 448;
 pub const SYS_futex_waitv: c_long = 5000 + 449;
 pub const SYS_set_mempolicy_home_node: c_long = 5000 + 450;
+pub const SYS_cachestat: c_long = 5000 + 451;
+pub const SYS_mseal: c_long = 5000 + 462;
 
 pub const SFD_CLOEXEC: c_int = 0x080000;
 
@@ -197,21 +197,6 @@ s! {
         pub ss_flags: c_int,
         pub ss_size: size_t,
     }
-
-    #[repr(align(8))]
-    pub struct clone_args {
-        pub flags: c_ulonglong,
-        pub pidfd: c_ulonglong,
-        pub child_tid: c_ulonglong,
-        pub parent_tid: c_ulonglong,
-        pub exit_signal: c_ulonglong,
-        pub stack: c_ulonglong,
-        pub stack_size: c_ulonglong,
-        pub tls: c_ulonglong,
-        pub set_tid: c_ulonglong,
-        pub set_tid_size: c_ulonglong,
-        pub cgroup: c_ulonglong,
-    }
 }
 
 s_no_extra_traits! {
@@ -1044,6 +1029,8 @@ pub const SYS_memfd_secret: c_long = 447;
 pub const SYS_process_mrelease: c_long = 448;
 pub const SYS_futex_waitv: c_long = 449;
 pub const SYS_set_mempolicy_home_node: c_long = 450;
+pub const SYS_cachestat: c_long = 451;
+pub const SYS_mseal: c_long = 462;
 
 extern "C" {
     pub fn sysctl(
@@ -275,21 +275,6 @@ s! {
         pub pad: crate::__u32,
     }
 
-    #[repr(align(8))]
-    pub struct clone_args {
-        pub flags: c_ulonglong,
-        pub pidfd: c_ulonglong,
-        pub child_tid: c_ulonglong,
-        pub parent_tid: c_ulonglong,
-        pub exit_signal: c_ulonglong,
-        pub stack: c_ulonglong,
-        pub stack_size: c_ulonglong,
-        pub tls: c_ulonglong,
-        pub set_tid: c_ulonglong,
-        pub set_tid_size: c_ulonglong,
-        pub cgroup: c_ulonglong,
-    }
-
     pub struct user_fpregs_struct {
         pub cwd: c_ushort,
         pub swd: c_ushort,
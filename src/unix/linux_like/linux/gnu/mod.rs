@@ -6,7 +6,6 @@ pub type __priority_which_t = c_uint;
 pub type __rlimit_resource_t = c_uint;
 pub type Lmid_t = c_long;
 pub type regoff_t = c_int;
-pub type __kernel_rwf_t = c_int;
 pub type __be16 = crate::__u16;
 
 cfg_if! {
@@ -200,27 +199,6 @@ s! {
         pub len: crate::__u64,
     }
 
-    pub struct iocb {
-        pub aio_data: crate::__u64,
-        #[cfg(target_endian = "little")]
-        pub aio_key: crate::__u32,
-        #[cfg(target_endian = "little")]
-        pub aio_rw_flags: crate::__kernel_rwf_t,
-        #[cfg(target_endian = "big")]
-        pub aio_rw_flags: crate::__kernel_rwf_t,
-        #[cfg(target_endian = "big")]
-        pub aio_key: crate::__u32,
-        pub aio_lio_opcode: crate::__u16,
-        pub aio_reqprio: crate::__s16,
-        pub aio_fildes: crate::__u32,
-        pub aio_buf: crate::__u64,
-        pub aio_nbytes: crate::__u64,
-        pub aio_offset: crate::__s64,
-        aio_reserved2: Padding<crate::__u64>,
-        pub aio_flags: crate::__u32,
-        pub aio_resfd: crate::__u32,
-    }
-
     // netinet/tcp.h
 
     pub struct tcp_info {
@@ -767,38 +745,6 @@ pub const PTRACE_SYSCALL_INFO_SECCOMP: crate::__u8 = 3;
 pub const PTRACE_SET_SYSCALL_USER_DISPATCH_CONFIG: c_uint = 0x4210;
 pub const PTRACE_GET_SYSCALL_USER_DISPATCH_CONFIG: c_uint = 0x4211;
 
-// linux/rtnetlink.h
-pub const TCA_PAD: c_ushort = 9;
-pub const TCA_DUMP_INVISIBLE: c_ushort = 10;
-pub const TCA_CHAIN: c_ushort = 11;
-pub const TCA_HW_OFFLOAD: c_ushort = 12;
-
-pub const RTM_DELNETCONF: u16 = 81;
-pub const RTM_NEWSTATS: u16 = 92;
-pub const RTM_GETSTATS: u16 = 94;
-pub const RTM_NEWCACHEREPORT: u16 = 96;
-
-pub const RTM_F_LOOKUP_TABLE: c_uint = 0x1000;
-pub const RTM_F_FIB_MATCH: c_uint = 0x2000;
-
-pub const RTA_VIA: c_ushort = 18;
-pub const RTA_NEWDST: c_ushort = 19;
-pub const RTA_PREF: c_ushort = 20;
-pub const RTA_ENCAP_TYPE: c_ushort = 21;
-pub const RTA_ENCAP: c_ushort = 22;
-pub const RTA_EXPIRES: c_ushort = 23;
-pub const RTA_PAD: c_ushort = 24;
-pub const RTA_UID: c_ushort = 25;
-pub const RTA_TTL_PROPAGATE: c_ushort = 26;
-
-// linux/neighbor.h
-pub const NTF_EXT_LEARNED: u8 = 0x10;
-pub const NTF_OFFLOADED: u8 = 0x20;
-
-pub const NDA_MASTER: c_ushort = 9;
-pub const NDA_LINK_NETNSID: c_ushort = 10;
-pub const NDA_SRC_VNI: c_ushort = 11;
-
 // linux/personality.h
 pub const UNAME26: c_int = 0x0020000;
 pub const FDPIC_FUNCPTRS: c_int = 0x0080000;
@@ -896,6 +842,9 @@ pub const GLOB_TILDE_CHECK: c_int = 1 << 14;
 
 pub const MADV_COLLAPSE: c_int = 25;
 
+pub const PKEY_DISABLE_ACCESS: c_uint = 0x1;
+pub const PKEY_DISABLE_WRITE: c_uint = 0x2;
+
 cfg_if! {
     if #[cfg(any(
         target_arch = "arm",
@@ -1168,6 +1117,14 @@ extern "C" {
 
     pub fn memfd_create(name: *const c_char, flags: c_uint) -> c_int;
     pub fn mlock2(addr: *const c_void, len: size_t, flags: c_uint) -> c_int;
+    // `glibc` has no `memfd_secret` wrapper, use `syscall(SYS_memfd_secret, flags)`
+
+    // Added in `glibc` 2.27
+    pub fn pkey_alloc(flags: c_uint, access_rights: c_uint) -> c_int;
+    pub fn pkey_free(pkey: c_int) -> c_int;
+    pub fn pkey_mprotect(addr: *mut c_void, len: size_t, prot: c_int, pkey: c_int) -> c_int;
+    pub fn pkey_get(pkey: c_int) -> c_int;
+    pub fn pkey_set(pkey: c_int, access_rights: c_uint) -> c_int;
 
     pub fn euidaccess(pathname: *const c_char, mode: c_int) -> c_int;
     pub fn eaccess(pathname: *const c_char, mode: c_int) -> c_int;
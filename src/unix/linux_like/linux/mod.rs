@@ -25,7 +25,9 @@ pub type pthread_once_t = c_int;
 pub type pthread_spinlock_t = c_int;
 pub type __kernel_fsid_t = __c_anonymous__kernel_fsid_t;
 pub type __kernel_clockid_t = c_int;
+pub type __kernel_rwf_t = c_int;
 
+pub type __s8 = i8;
 pub type __u8 = c_uchar;
 pub type __u16 = c_ushort;
 pub type __s16 = c_short;
@@ -310,42 +312,6 @@ s! {
         pub ee_data: u32,
     }
 
-    // linux/seccomp.h
-    pub struct seccomp_data {
-        pub nr: c_int,
-        pub arch: __u32,
-        pub instruction_pointer: crate::__u64,
-        pub args: [crate::__u64; 6],
-    }
-
-    pub struct seccomp_notif_sizes {
-        pub seccomp_notif: __u16,
-        pub seccomp_notif_resp: __u16,
-        pub seccomp_data: __u16,
-    }
-
-    pub struct seccomp_notif {
-        pub id: crate::__u64,
-        pub pid: __u32,
-        pub flags: __u32,
-        pub data: seccomp_data,
-    }
-
-    pub struct seccomp_notif_resp {
-        pub id: crate::__u64,
-        pub val: crate::__s64,
-        pub error: __s32,
-        pub flags: __u32,
-    }
-
-    pub struct seccomp_notif_addfd {
-        pub id: crate::__u64,
-        pub flags: __u32,
-        pub srcfd: __u32,
-        pub newfd: __u32,
-        pub newfd_flags: __u32,
-    }
-
     pub struct in6_ifreq {
         pub ifr6_addr: crate::in6_addr,
         pub ifr6_prefixlen: u32,
@@ -1119,16 +1085,6 @@ s! {
         pub f_handle: [c_uchar; 0],
     }
 
-    // include/uapi/linux/rtnetlink.h
-    pub struct ifinfomsg {
-        pub ifi_family: c_uchar,
-        __ifi_pad: Padding<c_uchar>,
-        pub ifi_type: c_ushort,
-        pub ifi_index: c_int,
-        pub ifi_flags: c_uint,
-        pub ifi_change: c_uint,
-    }
-
     // netdb.h
     pub struct netent {
         pub n_name: *mut c_char,
@@ -1262,31 +1218,6 @@ pub const AT_HANDLE_FID: c_int = 0x200;
 pub const AT_HANDLE_MNT_ID_UNIQUE: c_int = 0x001;
 pub const AT_HANDLE_CONNECTABLE: c_int = 0x002;
 
-// linux/if_addr.h
-pub const IFA_UNSPEC: c_ushort = 0;
-pub const IFA_ADDRESS: c_ushort = 1;
-pub const IFA_LOCAL: c_ushort = 2;
-pub const IFA_LABEL: c_ushort = 3;
-pub const IFA_BROADCAST: c_ushort = 4;
-pub const IFA_ANYCAST: c_ushort = 5;
-pub const IFA_CACHEINFO: c_ushort = 6;
-pub const IFA_MULTICAST: c_ushort = 7;
-pub const IFA_FLAGS: c_ushort = 8;
-
-pub const IFA_F_SECONDARY: u32 = 0x01;
-pub const IFA_F_TEMPORARY: u32 = 0x01;
-pub const IFA_F_NODAD: u32 = 0x02;
-pub const IFA_F_OPTIMISTIC: u32 = 0x04;
-pub const IFA_F_DADFAILED: u32 = 0x08;
-pub const IFA_F_HOMEADDRESS: u32 = 0x10;
-pub const IFA_F_DEPRECATED: u32 = 0x20;
-pub const IFA_F_TENTATIVE: u32 = 0x40;
-pub const IFA_F_PERMANENT: u32 = 0x80;
-pub const IFA_F_MANAGETEMPADDR: u32 = 0x100;
-pub const IFA_F_NOPREFIXROUTE: u32 = 0x200;
-pub const IFA_F_MCAUTOJOIN: u32 = 0x400;
-pub const IFA_F_STABLE_PRIVACY: u32 = 0x800;
-
 // linux/fs.h
 
 // Flags for preadv2/pwritev2
@@ -1402,42 +1333,6 @@ pub const GRND_NONBLOCK: c_uint = 0x0001;
 pub const GRND_RANDOM: c_uint = 0x0002;
 pub const GRND_INSECURE: c_uint = 0x0004;
 
-// <linux/seccomp.h>
-pub const SECCOMP_MODE_DISABLED: c_uint = 0;
-pub const SECCOMP_MODE_STRICT: c_uint = 1;
-pub const SECCOMP_MODE_FILTER: c_uint = 2;
-
-pub const SECCOMP_SET_MODE_STRICT: c_uint = 0;
-pub const SECCOMP_SET_MODE_FILTER: c_uint = 1;
-pub const SECCOMP_GET_ACTION_AVAIL: c_uint = 2;
-pub const SECCOMP_GET_NOTIF_SIZES: c_uint = 3;
-
-pub const SECCOMP_FILTER_FLAG_TSYNC: c_ulong = 1 << 0;
-pub const SECCOMP_FILTER_FLAG_LOG: c_ulong = 1 << 1;
-pub const SECCOMP_FILTER_FLAG_SPEC_ALLOW: c_ulong = 1 << 2;
-pub const SECCOMP_FILTER_FLAG_NEW_LISTENER: c_ulong = 1 << 3;
-pub const SECCOMP_FILTER_FLAG_TSYNC_ESRCH: c_ulong = 1 << 4;
-pub const SECCOMP_FILTER_FLAG_WAIT_KILLABLE_RECV: c_ulong = 1 << 5;
-
-pub const SECCOMP_RET_KILL_PROCESS: c_uint = 0x80000000;
-pub const SECCOMP_RET_KILL_THREAD: c_uint = 0x00000000;
-pub const SECCOMP_RET_KILL: c_uint = SECCOMP_RET_KILL_THREAD;
-pub const SECCOMP_RET_TRAP: c_uint = 0x00030000;
-pub const SECCOMP_RET_ERRNO: c_uint = 0x00050000;
-pub const SECCOMP_RET_USER_NOTIF: c_uint = 0x7fc00000;
-pub const SECCOMP_RET_TRACE: c_uint = 0x7ff00000;
-pub const SECCOMP_RET_LOG: c_uint = 0x7ffc0000;
-pub const SECCOMP_RET_ALLOW: c_uint = 0x7fff0000;
-
-pub const SECCOMP_RET_ACTION_FULL: c_uint = 0xffff0000;
-pub const SECCOMP_RET_ACTION: c_uint = 0x7fff0000;
-pub const SECCOMP_RET_DATA: c_uint = 0x0000ffff;
-
-pub const SECCOMP_USER_NOTIF_FLAG_CONTINUE: c_ulong = 1;
-
-pub const SECCOMP_ADDFD_FLAG_SETFD: c_ulong = 1;
-pub const SECCOMP_ADDFD_FLAG_SEND: c_ulong = 2;
-
 pub const TFD_CLOEXEC: c_int = O_CLOEXEC;
 pub const TFD_NONBLOCK: c_int = O_NONBLOCK;
 pub const TFD_TIMER_ABSTIME: c_int = 1;
@@ -1511,47 +1406,6 @@ pub const SKF_LL_OFF: c_int = -0x200000;
 pub const BPF_NET_OFF: c_int = SKF_NET_OFF;
 pub const BPF_LL_OFF: c_int = SKF_LL_OFF;
 pub const BPF_MEMWORDS: c_int = 16;
-pub const BPF_MAXINSNS: c_int = 4096;
-
-// linux/bpf_common.h
-pub const BPF_LD: __u32 = 0x00;
-pub const BPF_LDX: __u32 = 0x01;
-pub const BPF_ST: __u32 = 0x02;
-pub const BPF_STX: __u32 = 0x03;
-pub const BPF_ALU: __u32 = 0x04;
-pub const BPF_JMP: __u32 = 0x05;
-pub const BPF_RET: __u32 = 0x06;
-pub const BPF_MISC: __u32 = 0x07;
-pub const BPF_W: __u32 = 0x00;
-pub const BPF_H: __u32 = 0x08;
-pub const BPF_B: __u32 = 0x10;
-pub const BPF_IMM: __u32 = 0x00;
-pub const BPF_ABS: __u32 = 0x20;
-pub const BPF_IND: __u32 = 0x40;
-pub const BPF_MEM: __u32 = 0x60;
-pub const BPF_LEN: __u32 = 0x80;
-pub const BPF_MSH: __u32 = 0xa0;
-pub const BPF_ADD: __u32 = 0x00;
-pub const BPF_SUB: __u32 = 0x10;
-pub const BPF_MUL: __u32 = 0x20;
-pub const BPF_DIV: __u32 = 0x30;
-pub const BPF_OR: __u32 = 0x40;
-pub const BPF_AND: __u32 = 0x50;
-pub const BPF_LSH: __u32 = 0x60;
-pub const BPF_RSH: __u32 = 0x70;
-pub const BPF_NEG: __u32 = 0x80;
-pub const BPF_MOD: __u32 = 0x90;
-pub const BPF_XOR: __u32 = 0xa0;
-pub const BPF_JA: __u32 = 0x00;
-pub const BPF_JEQ: __u32 = 0x10;
-pub const BPF_JGT: __u32 = 0x20;
-pub const BPF_JGE: __u32 = 0x30;
-pub const BPF_JSET: __u32 = 0x40;
-pub const BPF_K: __u32 = 0x00;
-pub const BPF_X: __u32 = 0x08;
-
-// linux/filter.h
-
 pub const BPF_A: __u32 = 0x10;
 pub const BPF_TAX: __u32 = 0x00;
 pub const BPF_TXA: __u32 = 0x80;
@@ -2288,152 +2142,11 @@ pub const IW_EV_ADDR_PK_LEN: usize = 20; // IW_EV_LCP_PK_LEN + size_of::<crate::
 pub const IW_EV_QUAL_PK_LEN: usize = 8; // IW_EV_LCP_PK_LEN + size_of::<iw_quality>();
 pub const IW_EV_POINT_PK_LEN: usize = 8; // IW_EV_LCP_PK_LEN + 4;
 
-// linux/neighbor.h
-pub const NUD_NONE: u16 = 0x00;
-pub const NUD_INCOMPLETE: u16 = 0x01;
-pub const NUD_REACHABLE: u16 = 0x02;
-pub const NUD_STALE: u16 = 0x04;
-pub const NUD_DELAY: u16 = 0x08;
-pub const NUD_PROBE: u16 = 0x10;
-pub const NUD_FAILED: u16 = 0x20;
-pub const NUD_NOARP: u16 = 0x40;
-pub const NUD_PERMANENT: u16 = 0x80;
-
-pub const NTF_USE: u8 = 0x01;
-pub const NTF_SELF: u8 = 0x02;
-pub const NTF_MASTER: u8 = 0x04;
-pub const NTF_PROXY: u8 = 0x08;
-pub const NTF_ROUTER: u8 = 0x80;
-
-pub const NDA_UNSPEC: c_ushort = 0;
-pub const NDA_DST: c_ushort = 1;
-pub const NDA_LLADDR: c_ushort = 2;
-pub const NDA_CACHEINFO: c_ushort = 3;
-pub const NDA_PROBES: c_ushort = 4;
-pub const NDA_VLAN: c_ushort = 5;
-pub const NDA_PORT: c_ushort = 6;
-pub const NDA_VNI: c_ushort = 7;
-pub const NDA_IFINDEX: c_ushort = 8;
-
 // linux/netlink.h
 
 pub const NLM_F_BULK: c_int = 0x200;
 
 // linux/rtnetlink.h
-pub const TCA_UNSPEC: c_ushort = 0;
-pub const TCA_KIND: c_ushort = 1;
-pub const TCA_OPTIONS: c_ushort = 2;
-pub const TCA_STATS: c_ushort = 3;
-pub const TCA_XSTATS: c_ushort = 4;
-pub const TCA_RATE: c_ushort = 5;
-pub const TCA_FCNT: c_ushort = 6;
-pub const TCA_STATS2: c_ushort = 7;
-pub const TCA_STAB: c_ushort = 8;
-
-pub const RTM_NEWLINK: u16 = 16;
-pub const RTM_DELLINK: u16 = 17;
-pub const RTM_GETLINK: u16 = 18;
-pub const RTM_SETLINK: u16 = 19;
-pub const RTM_NEWADDR: u16 = 20;
-pub const RTM_DELADDR: u16 = 21;
-pub const RTM_GETADDR: u16 = 22;
-pub const RTM_NEWROUTE: u16 = 24;
-pub const RTM_DELROUTE: u16 = 25;
-pub const RTM_GETROUTE: u16 = 26;
-pub const RTM_NEWNEIGH: u16 = 28;
-pub const RTM_DELNEIGH: u16 = 29;
-pub const RTM_GETNEIGH: u16 = 30;
-pub const RTM_NEWRULE: u16 = 32;
-pub const RTM_DELRULE: u16 = 33;
-pub const RTM_GETRULE: u16 = 34;
-pub const RTM_NEWQDISC: u16 = 36;
-pub const RTM_DELQDISC: u16 = 37;
-pub const RTM_GETQDISC: u16 = 38;
-pub const RTM_NEWTCLASS: u16 = 40;
-pub const RTM_DELTCLASS: u16 = 41;
-pub const RTM_GETTCLASS: u16 = 42;
-pub const RTM_NEWTFILTER: u16 = 44;
-pub const RTM_DELTFILTER: u16 = 45;
-pub const RTM_GETTFILTER: u16 = 46;
-pub const RTM_NEWACTION: u16 = 48;
-pub const RTM_DELACTION: u16 = 49;
-pub const RTM_GETACTION: u16 = 50;
-pub const RTM_NEWPREFIX: u16 = 52;
-pub const RTM_GETMULTICAST: u16 = 58;
-pub const RTM_GETANYCAST: u16 = 62;
-pub const RTM_NEWNEIGHTBL: u16 = 64;
-pub const RTM_GETNEIGHTBL: u16 = 66;
-pub const RTM_SETNEIGHTBL: u16 = 67;
-pub const RTM_NEWNDUSEROPT: u16 = 68;
-pub const RTM_NEWADDRLABEL: u16 = 72;
-pub const RTM_DELADDRLABEL: u16 = 73;
-pub const RTM_GETADDRLABEL: u16 = 74;
-pub const RTM_GETDCB: u16 = 78;
-pub const RTM_SETDCB: u16 = 79;
-pub const RTM_NEWNETCONF: u16 = 80;
-pub const RTM_GETNETCONF: u16 = 82;
-pub const RTM_NEWMDB: u16 = 84;
-pub const RTM_DELMDB: u16 = 85;
-pub const RTM_GETMDB: u16 = 86;
-pub const RTM_NEWNSID: u16 = 88;
-pub const RTM_DELNSID: u16 = 89;
-pub const RTM_GETNSID: u16 = 90;
-
-pub const RTM_F_NOTIFY: c_uint = 0x100;
-pub const RTM_F_CLONED: c_uint = 0x200;
-pub const RTM_F_EQUALIZE: c_uint = 0x400;
-pub const RTM_F_PREFIX: c_uint = 0x800;
-
-pub const RTA_UNSPEC: c_ushort = 0;
-pub const RTA_DST: c_ushort = 1;
-pub const RTA_SRC: c_ushort = 2;
-pub const RTA_IIF: c_ushort = 3;
-pub const RTA_OIF: c_ushort = 4;
-pub const RTA_GATEWAY: c_ushort = 5;
-pub const RTA_PRIORITY: c_ushort = 6;
-pub const RTA_PREFSRC: c_ushort = 7;
-pub const RTA_METRICS: c_ushort = 8;
-pub const RTA_MULTIPATH: c_ushort = 9;
-pub const RTA_PROTOINFO: c_ushort = 10; // No longer used
-pub const RTA_FLOW: c_ushort = 11;
-pub const RTA_CACHEINFO: c_ushort = 12;
-pub const RTA_SESSION: c_ushort = 13; // No longer used
-pub const RTA_MP_ALGO: c_ushort = 14; // No longer used
-pub const RTA_TABLE: c_ushort = 15;
-pub const RTA_MARK: c_ushort = 16;
-pub const RTA_MFC_STATS: c_ushort = 17;
-
-pub const RTN_UNSPEC: c_uchar = 0;
-pub const RTN_UNICAST: c_uchar = 1;
-pub const RTN_LOCAL: c_uchar = 2;
-pub const RTN_BROADCAST: c_uchar = 3;
-pub const RTN_ANYCAST: c_uchar = 4;
-pub const RTN_MULTICAST: c_uchar = 5;
-pub const RTN_BLACKHOLE: c_uchar = 6;
-pub const RTN_UNREACHABLE: c_uchar = 7;
-pub const RTN_PROHIBIT: c_uchar = 8;
-pub const RTN_THROW: c_uchar = 9;
-pub const RTN_NAT: c_uchar = 10;
-pub const RTN_XRESOLVE: c_uchar = 11;
-
-pub const RTPROT_UNSPEC: c_uchar = 0;
-pub const RTPROT_REDIRECT: c_uchar = 1;
-pub const RTPROT_KERNEL: c_uchar = 2;
-pub const RTPROT_BOOT: c_uchar = 3;
-pub const RTPROT_STATIC: c_uchar = 4;
-
-pub const RT_SCOPE_UNIVERSE: c_uchar = 0;
-pub const RT_SCOPE_SITE: c_uchar = 200;
-pub const RT_SCOPE_LINK: c_uchar = 253;
-pub const RT_SCOPE_HOST: c_uchar = 254;
-pub const RT_SCOPE_NOWHERE: c_uchar = 255;
-
-pub const RT_TABLE_UNSPEC: c_uchar = 0;
-pub const RT_TABLE_COMPAT: c_uchar = 252;
-pub const RT_TABLE_DEFAULT: c_uchar = 253;
-pub const RT_TABLE_MAIN: c_uchar = 254;
-pub const RT_TABLE_LOCAL: c_uchar = 255;
-
 pub const RTMSG_OVERRUN: u32 = crate::NLMSG_OVERRUN as u32;
 pub const RTMSG_NEWDEVICE: u32 = 0x11;
 pub const RTMSG_DELDEVICE: u32 = 0x12;
@@ -2444,113 +2157,6 @@ pub const RTMSG_DELRULE: u32 = 0x32;
 pub const RTMSG_CONTROL: u32 = 0x40;
 pub const RTMSG_AR_FAILED: u32 = 0x51;
 
-pub const RTEXT_FILTER_VF: c_int = 1 << 0;
-pub const RTEXT_FILTER_BRVLAN: c_int = 1 << 1;
-pub const RTEXT_FILTER_BRVLAN_COMPRESSED: c_int = 1 << 2;
-pub const RTEXT_FILTER_SKIP_STATS: c_int = 1 << 3;
-pub const RTEXT_FILTER_MRP: c_int = 1 << 4;
-pub const RTEXT_FILTER_CFM_CONFIG: c_int = 1 << 5;
-pub const RTEXT_FILTER_CFM_STATUS: c_int = 1 << 6;
-
-// userspace compat definitions for RTNLGRP_*
-pub const RTMGRP_LINK: c_int = 0x00001;
-pub const RTMGRP_NOTIFY: c_int = 0x00002;
-pub const RTMGRP_NEIGH: c_int = 0x00004;
-pub const RTMGRP_TC: c_int = 0x00008;
-pub const RTMGRP_IPV4_IFADDR: c_int = 0x00010;
-pub const RTMGRP_IPV4_MROUTE: c_int = 0x00020;
-pub const RTMGRP_IPV4_ROUTE: c_int = 0x00040;
-pub const RTMGRP_IPV4_RULE: c_int = 0x00080;
-pub const RTMGRP_IPV6_IFADDR: c_int = 0x00100;
-pub const RTMGRP_IPV6_MROUTE: c_int = 0x00200;
-pub const RTMGRP_IPV6_ROUTE: c_int = 0x00400;
-pub const RTMGRP_IPV6_IFINFO: c_int = 0x00800;
-pub const RTMGRP_DECnet_IFADDR: c_int = 0x01000;
-pub const RTMGRP_DECnet_ROUTE: c_int = 0x04000;
-pub const RTMGRP_IPV6_PREFIX: c_int = 0x20000;
-
-// enum rtnetlink_groups
-pub const RTNLGRP_NONE: c_uint = 0x00;
-pub const RTNLGRP_LINK: c_uint = 0x01;
-pub const RTNLGRP_NOTIFY: c_uint = 0x02;
-pub const RTNLGRP_NEIGH: c_uint = 0x03;
-pub const RTNLGRP_TC: c_uint = 0x04;
-pub const RTNLGRP_IPV4_IFADDR: c_uint = 0x05;
-pub const RTNLGRP_IPV4_MROUTE: c_uint = 0x06;
-pub const RTNLGRP_IPV4_ROUTE: c_uint = 0x07;
-pub const RTNLGRP_IPV4_RULE: c_uint = 0x08;
-pub const RTNLGRP_IPV6_IFADDR: c_uint = 0x09;
-pub const RTNLGRP_IPV6_MROUTE: c_uint = 0x0a;
-pub const RTNLGRP_IPV6_ROUTE: c_uint = 0x0b;
-pub const RTNLGRP_IPV6_IFINFO: c_uint = 0x0c;
-pub const RTNLGRP_DECnet_IFADDR: c_uint = 0x0d;
-pub const RTNLGRP_NOP2: c_uint = 0x0e;
-pub const RTNLGRP_DECnet_ROUTE: c_uint = 0x0f;
-pub const RTNLGRP_DECnet_RULE: c_uint = 0x10;
-pub const RTNLGRP_NOP4: c_uint = 0x11;
-pub const RTNLGRP_IPV6_PREFIX: c_uint = 0x12;
-pub const RTNLGRP_IPV6_RULE: c_uint = 0x13;
-pub const RTNLGRP_ND_USEROPT: c_uint = 0x14;
-pub const RTNLGRP_PHONET_IFADDR: c_uint = 0x15;
-pub const RTNLGRP_PHONET_ROUTE: c_uint = 0x16;
-pub const RTNLGRP_DCB: c_uint = 0x17;
-pub const RTNLGRP_IPV4_NETCONF: c_uint = 0x18;
-pub const RTNLGRP_IPV6_NETCONF: c_uint = 0x19;
-pub const RTNLGRP_MDB: c_uint = 0x1a;
-pub const RTNLGRP_MPLS_ROUTE: c_uint = 0x1b;
-pub const RTNLGRP_NSID: c_uint = 0x1c;
-pub const RTNLGRP_MPLS_NETCONF: c_uint = 0x1d;
-pub const RTNLGRP_IPV4_MROUTE_R: c_uint = 0x1e;
-pub const RTNLGRP_IPV6_MROUTE_R: c_uint = 0x1f;
-pub const RTNLGRP_NEXTHOP: c_uint = 0x20;
-pub const RTNLGRP_BRVLAN: c_uint = 0x21;
-pub const RTNLGRP_MCTP_IFADDR: c_uint = 0x22;
-pub const RTNLGRP_TUNNEL: c_uint = 0x23;
-pub const RTNLGRP_STATS: c_uint = 0x24;
-
-// linux/cn_proc.h
-c_enum! {
-    pub enum proc_cn_mcast_op {
-        pub PROC_CN_MCAST_LISTEN = 1,
-        pub PROC_CN_MCAST_IGNORE = 2,
-    }
-
-    pub enum proc_cn_event {
-        pub PROC_EVENT_NONE = 0x00000000,
-        pub PROC_EVENT_FORK = 0x00000001,
-        pub PROC_EVENT_EXEC = 0x00000002,
-        pub PROC_EVENT_UID = 0x00000004,
-        pub PROC_EVENT_GID = 0x00000040,
-        pub PROC_EVENT_SID = 0x00000080,
-        pub PROC_EVENT_PTRACE = 0x00000100,
-        pub PROC_EVENT_COMM = 0x00000200,
-        pub PROC_EVENT_NONZERO_EXIT = 0x20000000,
-        pub PROC_EVENT_COREDUMP = 0x40000000,
-        pub PROC_EVENT_EXIT = 0x80000000,
-    }
-}
-
-// linux/connector.h
-pub const CN_IDX_PROC: c_uint = 0x1;
-pub const CN_VAL_PROC: c_uint = 0x1;
-pub const CN_IDX_CIFS: c_uint = 0x2;
-pub const CN_VAL_CIFS: c_uint = 0x1;
-pub const CN_W1_IDX: c_uint = 0x3;
-pub const CN_W1_VAL: c_uint = 0x1;
-pub const CN_IDX_V86D: c_uint = 0x4;
-pub const CN_VAL_V86D_UVESAFB: c_uint = 0x1;
-pub const CN_IDX_BB: c_uint = 0x5;
-pub const CN_DST_IDX: c_uint = 0x6;
-pub const CN_DST_VAL: c_uint = 0x1;
-pub const CN_IDX_DM: c_uint = 0x7;
-pub const CN_VAL_DM_USERSPACE_LOG: c_uint = 0x1;
-pub const CN_IDX_DRBD: c_uint = 0x8;
-pub const CN_VAL_DRBD: c_uint = 0x1;
-pub const CN_KVP_IDX: c_uint = 0x9;
-pub const CN_KVP_VAL: c_uint = 0x1;
-pub const CN_VSS_IDX: c_uint = 0xA;
-pub const CN_VSS_VAL: c_uint = 0x1;
-
 // linux/module.h
 pub const MODULE_INIT_IGNORE_MODVERSIONS: c_uint = 0x0001;
 pub const MODULE_INIT_IGNORE_VERMAGIC: c_uint = 0x0002;
@@ -2786,61 +2392,6 @@ pub const IN_ONLYDIR: u32 = 0x0100_0000;
 pub const IN_DONT_FOLLOW: u32 = 0x0200_0000;
 pub const IN_EXCL_UNLINK: u32 = 0x0400_0000;
 
-// uapi/linux/securebits.h
-const SECURE_NOROOT: c_int = 0;
-const SECURE_NOROOT_LOCKED: c_int = 1;
-
-pub const SECBIT_NOROOT: c_int = issecure_mask(SECURE_NOROOT);
-pub const SECBIT_NOROOT_LOCKED: c_int = issecure_mask(SECURE_NOROOT_LOCKED);
-
-const SECURE_NO_SETUID_FIXUP: c_int = 2;
-const SECURE_NO_SETUID_FIXUP_LOCKED: c_int = 3;
-
-pub const SECBIT_NO_SETUID_FIXUP: c_int = issecure_mask(SECURE_NO_SETUID_FIXUP);
-pub const SECBIT_NO_SETUID_FIXUP_LOCKED: c_int = issecure_mask(SECURE_NO_SETUID_FIXUP_LOCKED);
-
-const SECURE_KEEP_CAPS: c_int = 4;
-const SECURE_KEEP_CAPS_LOCKED: c_int = 5;
-
-pub const SECBIT_KEEP_CAPS: c_int = issecure_mask(SECURE_KEEP_CAPS);
-pub const SECBIT_KEEP_CAPS_LOCKED: c_int = issecure_mask(SECURE_KEEP_CAPS_LOCKED);
-
-const SECURE_NO_CAP_AMBIENT_RAISE: c_int = 6;
-const SECURE_NO_CAP_AMBIENT_RAISE_LOCKED: c_int = 7;
-
-pub const SECBIT_NO_CAP_AMBIENT_RAISE: c_int = issecure_mask(SECURE_NO_CAP_AMBIENT_RAISE);
-pub const SECBIT_NO_CAP_AMBIENT_RAISE_LOCKED: c_int =
-    issecure_mask(SECURE_NO_CAP_AMBIENT_RAISE_LOCKED);
-
-const SECURE_EXEC_RESTRICT_FILE: c_int = 8;
-const SECURE_EXEC_RESTRICT_FILE_LOCKED: c_int = 9;
-
-pub const SECBIT_EXEC_RESTRICT_FILE: c_int = issecure_mask(SECURE_EXEC_RESTRICT_FILE);
-pub const SECBIT_EXEC_RESTRICT_FILE_LOCKED: c_int = issecure_mask(SECURE_EXEC_RESTRICT_FILE_LOCKED);
-
-const SECURE_EXEC_DENY_INTERACTIVE: c_int = 10;
-const SECURE_EXEC_DENY_INTERACTIVE_LOCKED: c_int = 11;
-
-pub const SECBIT_EXEC_DENY_INTERACTIVE: c_int = issecure_mask(SECURE_EXEC_DENY_INTERACTIVE);
-pub const SECBIT_EXEC_DENY_INTERACTIVE_LOCKED: c_int =
-    issecure_mask(SECURE_EXEC_DENY_INTERACTIVE_LOCKED);
-
-pub const SECUREBITS_DEFAULT: c_int = 0x00000000;
-pub const SECURE_ALL_BITS: c_int = SECBIT_NOROOT
-    | SECBIT_NO_SETUID_FIXUP
-    | SECBIT_KEEP_CAPS
-    | SECBIT_NO_CAP_AMBIENT_RAISE
-    | SECBIT_EXEC_RESTRICT_FILE
-    | SECBIT_EXEC_DENY_INTERACTIVE;
-pub const SECURE_ALL_LOCKS: c_int = SECURE_ALL_BITS << 1;
-
-pub const SECURE_ALL_UNPRIVILEGED: c_int =
-    issecure_mask(SECURE_EXEC_RESTRICT_FILE) | issecure_mask(SECURE_EXEC_DENY_INTERACTIVE);
-
-const fn issecure_mask(x: c_int) -> c_int {
-    1 << x
-}
-
 pub const IN_MASK_CREATE: u32 = 0x1000_0000;
 pub const IN_MASK_ADD: u32 = 0x2000_0000;
 pub const IN_ISDIR: u32 = 0x4000_0000;
@@ -3584,9 +3135,6 @@ pub const PF_SUSPEND_TASK: c_int = u32_cast_int(PF_SUSPEND_TASK_UINT);
 // desired information as-is in terms of integer representation.
 const PF_SUSPEND_TASK_UINT: c_uint = 0x80000000;
 
-pub const CLONE_CLEAR_SIGHAND: c_ulonglong = 0x100000000;
-pub const CLONE_INTO_CGROUP: c_ulonglong = 0x200000000;
-
 pub const SCHED_FLAG_RESET_ON_FORK: c_int = 0x01;
 pub const SCHED_FLAG_RECLAIM: c_int = 0x02;
 pub const SCHED_FLAG_DL_OVERRUN: c_int = 0x04;
@@ -3674,26 +3222,6 @@ f! {
         ee.offset(1) as *mut crate::sockaddr
     }
 
-    pub fn BPF_CLASS(code: __u32) -> __u32 {
-        code & 0x07
-    }
-
-    pub fn BPF_SIZE(code: __u32) -> __u32 {
-        code & 0x18
-    }
-
-    pub fn BPF_MODE(code: __u32) -> __u32 {
-        code & 0xe0
-    }
-
-    pub fn BPF_OP(code: __u32) -> __u32 {
-        code & 0xf0
-    }
-
-    pub fn BPF_SRC(code: __u32) -> __u32 {
-        code & 0x08
-    }
-
     pub fn BPF_RVAL(code: __u32) -> __u32 {
         code & 0x18
     }
@@ -1706,18 +1706,18 @@ cfg_if! {
                 const _IOC_SIZEBITS: u32 = 13;
                 const _IOC_DIRBITS: u32 = 3;
 
-                const _IOC_NONE: u32 = 1;
-                const _IOC_READ: u32 = 2;
-                const _IOC_WRITE: u32 = 4;
+                pub(crate) const _IOC_NONE: u32 = 1;
+                pub(crate) const _IOC_READ: u32 = 2;
+                pub(crate) const _IOC_WRITE: u32 = 4;
             } else {
                 // https://github.com/torvalds/linux/blob/b311c1b497e51a628aa89e7cb954481e5f9dced2/include/uapi/asm-generic/ioctl.h
 
                 const _IOC_SIZEBITS: u32 = 14;
                 const _IOC_DIRBITS: u32 = 2;
 
-                const _IOC_NONE: u32 = 0;
-                const _IOC_WRITE: u32 = 1;
-                const _IOC_READ: u32 = 2;
+                pub(crate) const _IOC_NONE: u32 = 0;
+                pub(crate) const _IOC_WRITE: u32 = 1;
+                pub(crate) const _IOC_READ: u32 = 2;
             }
         }
         const _IOC_NRMASK: u32 = (1 << _IOC_NRBITS) - 1;
@@ -1733,7 +1733,7 @@ cfg_if! {
         // adapted from https://github.com/torvalds/linux/blob/8a696a29c6905594e4abf78eaafcb62165ac61f1/rust/kernel/ioctl.rs
 
         /// Build an ioctl number, analogous to the C macro of the same name.
-        const fn _IOC(dir: u32, ty: u32, nr: u32, size: usize) -> Ioctl {
+        pub(crate) const fn _IOC(dir: u32, ty: u32, nr: u32, size: usize) -> Ioctl {
             core::debug_assert!(dir <= _IOC_DIRMASK);
             core::debug_assert!(ty <= _IOC_TYPEMASK);
             core::debug_assert!(nr <= _IOC_NRMASK);